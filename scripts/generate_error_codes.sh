@@ -0,0 +1,42 @@
+#!/bin/bash
+
+# Generate the Win32 / NTSTATUS code name tables from the windows crate
+# Usage: ./scripts/generate_error_codes.sh > src/error_codes_table.rs
+# The crate source is looked up in the cargo registry, pass its Foundation/mod.rs as $1 to override.
+
+version=$(sed -n '/^name = "windows"$/{n;s/version = "\(.*\)"/\1/p}' Cargo.lock)
+foundation=${1:-$(ls -d "${CARGO_HOME:-$HOME/.cargo}"/registry/src/*/windows-"$version"/src/Windows/Win32/Foundation/mod.rs 2>/dev/null | head -n 1)}
+if [ ! -f "$foundation" ]; then
+    echo "windows $version Foundation/mod.rs not found, run cargo fetch first" >&2
+    exit 1
+fi
+
+# one name per code: ERROR_ / STATUS_ names are preferred over aliases, then the alphabetically first
+table() {
+    local type=$1 prefix=$2
+    sed -n "s/^pub const \([A-Z0-9_]*\): $type = $type(\(0x[0-9A-Fa-f_]*\|[0-9_]*\)\(u32\|_u32 as _\));$/\2 \1/p" "$foundation" |
+        while read -r code name; do
+            code=${code//_/}
+            preferred=1
+            [[ $name == "$prefix"* ]] && preferred=0
+            printf '%08X %d %s\n' "$((code))" "$preferred" "$name"
+        done |
+        sort -k1,1 -k2,2n -k3,3 |
+        awk 'BEGIN { last = "-" } $1 "" != last { last = $1 ""; print }' |
+        while read -r code _ name; do
+            [[ $prefix == "ERROR_" ]] && name=${name#ERROR_}
+            printf '    (0x%s, "%s"),\n' "$code" "$name"
+        done
+}
+
+echo "// Generated by scripts/generate_error_codes.sh from windows $version, do not edit."
+echo ""
+echo "/// Win32 error codes and their names without the \`ERROR_\` prefix, sorted by code."
+echo "pub const WIN32_ERROR_NAMES: &[(u32, &str)] = &["
+table WIN32_ERROR ERROR_
+echo "];"
+echo ""
+echo "/// NTSTATUS codes and their names, sorted by code."
+echo "pub const NTSTATUS_NAMES: &[(u32, &str)] = &["
+table NTSTATUS STATUS_
+echo "];"
@@ -1,15 +1,21 @@
 use crate::{
-    collections::{CONSUMER_CPUS, HashMap, HashSet, List, TIDS_CAPED, TIDS_FULL, list},
-    config::{ProcessLevelConfig, ThreadLevelConfig, cpu_indices_to_mask, format_cpu_indices},
-    error_codes::{error_from_code_win32, error_from_ntstatus},
-    logging::{Operation, is_new_error},
-    priority::{IOPriority, MemoryPriority, MemoryPriorityInformation, ProcessPriority, ThreadPriority},
+    blacklist::Blacklist,
+    collections::{CONSUMER_CPUS, HashMap, HashSet, List, PENDING, TIDS_CAPED, TIDS_FULL, list},
+    config::{
+        IdealResetStrategy, PrimePinMode, PrimePlacement, ProcessLevelConfig, RuleAction, ThreadLevelConfig, ThreadQuota, cpu_indices_to_mask,
+        format_cpu_indices, thread_selected,
+    },
+    drift::{DRIFT_ATTRIBUTES, DriftAttribute},
+    error_codes::{ErrorKind, ErrorSource, error_from_code_win32, error_from_ntstatus, error_name},
+    logging::{Operation, is_new_error, log_to_find},
+    priority::{IOPriority, MemoryPriority, MemoryPriorityInformation, PowerThrottling, ProcessPriority, ThreadPriority},
     process::ProcessEntry,
-    scheduler::PrimeThreadScheduler,
+    scheduler::{DESCRIPTION_QUERIES, PrimeThreadScheduler, ThreadStats},
     winapi::{
-        NtQueryInformationProcess, NtSetInformationProcess, ProcessHandle, cpusetids_from_indices, filter_indices_by_mask,
-        get_cpu_set_information, get_thread_handle, get_thread_ideal_processor_ex, get_thread_start_address, indices_from_cpusetids,
-        resolve_address_to_module, set_thread_ideal_processor_ex,
+        CpuSetData, JobAssignment, NtQueryInformationProcess, NtSetInformationProcess, ProcessHandle, assign_process_to_job, cpu_set_data_of,
+        cpusetids_from_indices, filter_indices_by_mask, get_cpu_set_information, get_thread_description, get_thread_handle,
+        get_thread_ideal_processor_ex, get_thread_start_address, indices_from_cpusetids, processor_number_from_index,
+        resolve_address_to_module, set_thread_ideal_processor_ex, terminate_process,
     },
 };
 
@@ -17,21 +23,64 @@ use ntapi::ntexapi::SYSTEM_THREAD_INFORMATION;
 use rand::random;
 use std::{cmp::Reverse, ffi::c_void, mem::size_of};
 use windows::Win32::{
-    Foundation::{GetLastError, HANDLE},
+    Foundation::{ERROR_INVALID_PARAMETER, GetLastError, HANDLE},
     System::{
         Threading::{
-            GetPriorityClass, GetProcessAffinityMask, GetProcessDefaultCpuSets, GetProcessInformation, GetThreadPriority,
-            ProcessMemoryPriority, SetPriorityClass, SetProcessAffinityMask, SetProcessDefaultCpuSets, SetProcessInformation,
-            SetThreadPriority, SetThreadSelectedCpuSets,
+            GetPriorityClass, GetProcessAffinityMask, GetProcessDefaultCpuSets, GetProcessGroupAffinity, GetProcessInformation,
+            GetThreadInformation, GetThreadPriority, PROCESS_CREATION_FLAGS, PROCESS_POWER_THROTTLING_CURRENT_VERSION,
+            PROCESS_POWER_THROTTLING_STATE, ProcessMemoryPriority, ProcessPowerThrottling, SetPriorityClass, SetProcessAffinityMask,
+            SetProcessDefaultCpuSets, SetProcessInformation, SetThreadAffinityMask, SetThreadInformation, SetThreadPriority,
+            SetThreadSelectedCpuSets, THREAD_POWER_THROTTLING_CURRENT_VERSION, THREAD_POWER_THROTTLING_STATE, ThreadPowerThrottling,
         },
         WindowsProgramming::QueryThreadCycleTime,
     },
 };
 
+/// A failed Windows call of an apply operation.
+#[derive(Debug, Clone)]
+pub struct ApplyError {
+    pub operation: Operation,
+    pub pid: u32,
+    /// 0 for process-level operations.
+    pub tid: u32,
+    pub process_name: String,
+    pub source: ErrorSource,
+    pub code: u32,
+    /// format: r#"$fn_name: [$operation][$error_message] details"#
+    pub message: String,
+}
+
+impl ApplyError {
+    pub fn new(operation: Operation, pid: u32, tid: u32, process_name: &str, code: u32) -> Self {
+        Self {
+            operation,
+            pid,
+            tid,
+            process_name: process_name.to_string(),
+            source: operation.error_source(code),
+            code,
+            message: String::new(),
+        }
+    }
+
+    /// Whether this pid/tid/operation/code combination failed for the first time, see [`is_new_error`].
+    pub fn is_new(&self) -> bool {
+        is_new_error(self.pid, self.tid, &self.process_name, self.operation, self.code)
+    }
+
+    /// `OpenProcess` and `OpenThread` report a PID or TID that no longer exists as an invalid parameter.
+    pub fn kind(&self) -> ErrorKind {
+        match ErrorKind::classify(self.source, self.code) {
+            ErrorKind::InvalidParameter if self.operation.opens_handle() => ErrorKind::ProcessGone,
+            kind => kind,
+        }
+    }
+}
+
 #[derive(Debug, Default)]
 pub struct ApplyConfigResult {
     pub changes: Vec<String>,
-    pub errors: Vec<String>,
+    pub errors: Vec<ApplyError>,
 }
 
 impl ApplyConfigResult {
@@ -46,15 +95,21 @@ impl ApplyConfigResult {
         self.changes.push(change);
     }
 
-    /// format: r#"$fn_name: [$operation][$error_message] details"#
     #[inline(always)]
-    pub fn add_error(&mut self, error: String) {
+    pub fn add_error(&mut self, error: ApplyError) {
         self.errors.push(error);
     }
 
     pub fn is_empty(&self) -> bool {
         self.changes.is_empty() && self.errors.is_empty()
     }
+
+    /// Logs the errors to the find log, a process that exited between the snapshot and the call is not a failure.
+    pub fn log_errors(&self) {
+        for error in self.errors.iter().filter(|error| error.kind() != ErrorKind::ProcessGone) {
+            log_to_find(&error.message);
+        }
+    }
 }
 
 /// Extracts read and write handles from ProcessHandle, preferring full access handles over limited.
@@ -77,8 +132,10 @@ fn log_error_if_new(
     apply_config_result: &mut ApplyConfigResult,
     format_msg: impl FnOnce() -> String,
 ) {
-    if is_new_error(pid, tid, process_name, operation, error_code) {
-        apply_config_result.add_error(format_msg());
+    let mut error = ApplyError::new(operation, pid, tid, process_name, error_code);
+    if error.is_new() {
+        error.message = format_msg();
+        apply_config_result.add_error(error);
     }
 }
 
@@ -211,8 +268,8 @@ pub fn apply_affinity<'a>(
 /// Resets ideal processors for all threads.
 ///
 /// When process affinity is changed, Windows may reset thread ideal processors.
-/// This redistributes threads across the new affinity CPUs with a random shift
-/// to avoid always assigning too much threads to the same CPUs.
+/// This redistributes threads (busiest first) across the new affinity CPUs using the rule's
+/// `reset` strategy, see [`plan_ideal_processor_reset`].
 /// # Arguments
 /// * `cpus` - The set of CPU indices to distribute thread ideal processors across. Callers pass `&config.affinity_cpus` after an affinity
 ///   change, or `&config.cpu_set_cpus` after a CPU-set change (when `cpu_set_reset_ideal` is set).
@@ -228,11 +285,6 @@ pub fn reset_thread_ideal_processors<'a>(
         return;
     }
 
-    if dry_run {
-        apply_config_result.add_change(format!("Reset Ideal Processors: {} threads based on CPU time", cpus.len()));
-        return;
-    }
-
     // Collect thread IDs and their CPU times
     let mut tid_time_list: List<[(u32, i64); TIDS_FULL]> = List::new();
     for (tid, thread_info) in threads() {
@@ -244,55 +296,183 @@ pub fn reset_thread_ideal_processors<'a>(
         return;
     }
 
-    // Sort by CPU time descending
-    tid_time_list.sort_unstable_by_key(|(_, time)| Reverse(*time));
+    // Sort by CPU time descending, tid as tie breaker to keep plans reproducible
+    tid_time_list.sort_unstable_by_key(|&(tid, time)| (Reverse(time), tid));
+
+    let plan = plan_ideal_processor_reset(
+        config.ideal_reset_strategy,
+        config.ideal_reset_seed,
+        tid_time_list.len(),
+        cpus,
+        &get_cpu_set_information().lock().unwrap(),
+    );
+
+    if dry_run {
+        let assignments: Vec<String> = tid_time_list
+            .iter()
+            .zip(plan.iter())
+            .map(|(&(tid, _), cpu)| format!("{}->{}", tid, cpu))
+            .collect();
+        apply_config_result.add_change(format!(
+            "Reset Ideal Processors ({}): {}",
+            config.ideal_reset_strategy.as_str(),
+            assignments.join(", ")
+        ));
+        return;
+    }
 
-    let target_cpu_count = cpus.len();
-    let random_shift = random::<u8>();
     let mut counter_set_success = 0;
-    let _: () = tid_time_list
-        .iter()
-        .map(|&(tid, _)| {
-            if let Some(thread_handle) = get_thread_handle(tid, pid, &config.name) {
-                let handle = if thread_handle.w_handle.is_invalid() {
-                    thread_handle.w_limited_handle
-                } else {
-                    thread_handle.w_handle
-                };
-                if !handle.is_invalid() {
-                    let target_cpu_index = (counter_set_success + random_shift as usize) % target_cpu_count;
-                    let target_cpu = cpus[target_cpu_index];
-                    match set_thread_ideal_processor_ex(handle, 0, target_cpu as u8) {
-                        Err(_) => {
-                            let error_code = unsafe { GetLastError().0 };
-                            log_error_if_new(
-                                pid,
-                                tid,
-                                &config.name,
-                                Operation::SetThreadIdealProcessorEx,
-                                error_code,
-                                apply_config_result,
-                                || {
-                                    format!(
-                                        "reset_ideal_processor: [SET_IDEAL][{}] {:>5}-{:>5}-{} - SetThreadIdealProcessorEx failed",
-                                        error_from_code_win32(error_code),
-                                        pid,
-                                        tid,
-                                        config.name
-                                    )
-                                },
-                            );
-                        }
-                        Ok(_) => {
-                            counter_set_success += 1;
-                        }
+    for (&(tid, _), &target_cpu) in tid_time_list.iter().zip(plan.iter()) {
+        if let Some(thread_handle) = get_thread_handle(tid, pid, &config.name) {
+            let handle = if thread_handle.w_handle.is_invalid() {
+                thread_handle.w_limited_handle
+            } else {
+                thread_handle.w_handle
+            };
+            if !handle.is_invalid() {
+                let (group, number) = processor_number_from_index(target_cpu);
+                match set_thread_ideal_processor_ex(handle, group, number) {
+                    Err(_) => {
+                        let error_code = unsafe { GetLastError().0 };
+                        log_error_if_new(
+                            pid,
+                            tid,
+                            &config.name,
+                            Operation::SetThreadIdealProcessorEx,
+                            error_code,
+                            apply_config_result,
+                            || {
+                                format!(
+                                    "reset_ideal_processor: [SET_IDEAL][{}] {:>5}-{:>5}-{} - SetThreadIdealProcessorEx failed",
+                                    error_from_code_win32(error_code),
+                                    pid,
+                                    tid,
+                                    config.name
+                                )
+                            },
+                        );
+                    }
+                    Ok(_) => {
+                        counter_set_success += 1;
                     }
                 }
-                drop(thread_handle);
             }
-        })
-        .collect();
-    apply_config_result.add_change(format!("reset ideal processor for {} threads", counter_set_success));
+            drop(thread_handle);
+        }
+    }
+    apply_config_result.add_change(format!(
+        "reset ideal processor for {} threads ({})",
+        counter_set_success,
+        config.ideal_reset_strategy.as_str()
+    ));
+}
+
+/// Plans the ideal CPU of each thread for an ideal processor reset.
+///
+/// `thread_count` threads are expected sorted busiest first, the result holds the target CPU index per thread rank.
+/// - `RoundRobin`: CPU list order, shifted by `seed` (random shift when no seed is configured)
+/// - `PhysicalCoresFirst`: first logical CPU of every physical core, then the SMT siblings
+/// - `Packed`: CPUs ordered by LLC then core, so siblings and cache neighbours fill up first
+/// - `SpreadLlc`: LLCs interleaved, each LLC walked cores-first
+/// - `SeededRandom`: CPU list shuffled with `seed` (random seed when none is configured)
+///
+/// Every strategy except the unseeded ones is fully deterministic for a given topology.
+pub fn plan_ideal_processor_reset(
+    strategy: IdealResetStrategy,
+    seed: Option<u64>,
+    thread_count: usize,
+    cpus: &[u32],
+    topology: &[CpuSetData],
+) -> List<[u32; TIDS_FULL]> {
+    if cpus.is_empty() {
+        return List::new();
+    }
+    // (core, llc) of a CPU index, unknown CPUs are their own core in LLC 0
+    let location_of = |cpu: u32| -> ((u16, u32), (u16, u8)) {
+        cpu_set_data_of(topology, cpu)
+            .map(|data| ((data.group, data.core_index as u32), (data.group, data.last_level_cache_index)))
+            .unwrap_or(((u16::MAX, cpu), (processor_number_from_index(cpu).0, 0)))
+    };
+    // rank of each CPU among the SMT siblings of its core (0 = first logical CPU of the core)
+    let sibling_rank = |order: &[u32], cpu: u32| -> usize {
+        let core = location_of(cpu).0;
+        order
+            .iter()
+            .take_while(|&&c| c != cpu)
+            .filter(|&&c| location_of(c).0 == core)
+            .count()
+    };
+
+    let mut order: List<[u32; CONSUMER_CPUS]> = cpus.iter().copied().collect();
+    let mut shift = 0usize;
+    match strategy {
+        IdealResetStrategy::RoundRobin => {
+            shift = match seed {
+                Some(seed) => (seed % order.len() as u64) as usize,
+                None => random::<u8>() as usize,
+            };
+        }
+        IdealResetStrategy::PhysicalCoresFirst => {
+            let ranks: List<[usize; CONSUMER_CPUS]> = order.iter().map(|&cpu| sibling_rank(cpus, cpu)).collect();
+            let mut ranked: List<[(usize, usize, u32); CONSUMER_CPUS]> = order
+                .iter()
+                .enumerate()
+                .map(|(position, &cpu)| (ranks[position], position, cpu))
+                .collect();
+            ranked.sort_unstable();
+            order = ranked.into_iter().map(|(_, _, cpu)| cpu).collect();
+        }
+        IdealResetStrategy::Packed => {
+            order.sort_unstable_by_key(|&cpu| {
+                let (core, llc) = location_of(cpu);
+                (llc, core, cpu)
+            });
+        }
+        IdealResetStrategy::SpreadLlc => {
+            let mut llcs: List<[(u16, u8); CONSUMER_CPUS]> = List::new();
+            for &cpu in cpus {
+                let llc = location_of(cpu).1;
+                if !llcs.contains(&llc) {
+                    llcs.push(llc);
+                }
+            }
+            // position of each CPU inside its LLC when that LLC is walked cores-first
+            let mut keyed: List<[(usize, usize, u32); CONSUMER_CPUS]> = List::new();
+            for (llc_position, &llc) in llcs.iter().enumerate() {
+                let llc_cpus: List<[u32; CONSUMER_CPUS]> = cpus.iter().copied().filter(|&cpu| location_of(cpu).1 == llc).collect();
+                let mut ranked: List<[(usize, usize, u32); CONSUMER_CPUS]> = llc_cpus
+                    .iter()
+                    .enumerate()
+                    .map(|(position, &cpu)| (sibling_rank(&llc_cpus, cpu), position, cpu))
+                    .collect();
+                ranked.sort_unstable();
+                for (position_in_llc, (_, _, cpu)) in ranked.into_iter().enumerate() {
+                    keyed.push((position_in_llc, llc_position, cpu));
+                }
+            }
+            keyed.sort_unstable();
+            order = keyed.into_iter().map(|(_, _, cpu)| cpu).collect();
+        }
+        IdealResetStrategy::SeededRandom => {
+            // Fisher-Yates, balanced like round-robin but in shuffled order
+            let mut rng_state = seed.unwrap_or_else(random::<u64>);
+            for i in (1..order.len()).rev() {
+                let j = (splitmix64(&mut rng_state) % (i as u64 + 1)) as usize;
+                order.swap(i, j);
+            }
+        }
+    }
+    (0..thread_count).map(|rank| order[(rank + shift) % order.len()]).collect()
+}
+
+/// SplitMix64 step, a small stable PRNG so seeded plans stay reproducible across builds and crate updates.
+#[inline]
+fn splitmix64(state: &mut u64) -> u64 {
+    *state = state.wrapping_add(0x9E37_79B9_7F4A_7C15);
+    let mut z = *state;
+    z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+    z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+    z ^ (z >> 31)
 }
 
 pub fn apply_process_default_cpuset<'a>(
@@ -307,20 +487,39 @@ pub fn apply_process_default_cpuset<'a>(
         return;
     };
     if !config.cpu_set_cpus.is_empty() && !get_cpu_set_information().lock().unwrap().is_empty() {
-        if dry_run {
-            apply_config_result.add_change(format!("CPU Set: -> [{}]", format_cpu_indices(&config.cpu_set_cpus)));
-        } else {
-            let target_cpusetids = cpusetids_from_indices(&config.cpu_set_cpus);
-            let mut current_cpusetids: List<[u32; CONSUMER_CPUS]> = List::new();
-            if !target_cpusetids.is_empty() {
-                let mut toset: bool = false;
-                let mut requiredidcount: u32 = 0;
-                let query_result = unsafe { GetProcessDefaultCpuSets(r_handle, None, &mut requiredidcount) }.as_bool();
-                if query_result {
-                    toset = true; // query succeeded with None, it doesn't have a default CPU set. Otherwise, it has.
+        let target_cpusetids = cpusetids_from_indices(&config.cpu_set_cpus);
+        let mut current_cpusetids: List<[u32; CONSUMER_CPUS]> = List::new();
+        if !target_cpusetids.is_empty() {
+            let mut toset: bool = false;
+            let mut requiredidcount: u32 = 0;
+            let query_result = unsafe { GetProcessDefaultCpuSets(r_handle, None, &mut requiredidcount) }.as_bool();
+            if query_result {
+                toset = true; // query succeeded with None, it doesn't have a default CPU set. Otherwise, it has.
+            } else {
+                let error_code = unsafe { GetLastError().0 };
+                if error_code != 122 {
+                    log_error_if_new(
+                        pid,
+                        0,
+                        &config.name,
+                        Operation::GetProcessDefaultCpuSets,
+                        error_code,
+                        apply_config_result,
+                        || {
+                            format!(
+                                "apply_process_default_cpuset: [GET_PROCESS_DEFAULT_CPUSETS][{}] {:>5}-{}",
+                                error_from_code_win32(error_code),
+                                pid,
+                                config.name
+                            )
+                        },
+                    );
                 } else {
-                    let error_code = unsafe { GetLastError().0 };
-                    if error_code != 122 {
+                    current_cpusetids = list![0u32; requiredidcount as usize];
+                    let second_query =
+                        unsafe { GetProcessDefaultCpuSets(r_handle, Some(&mut current_cpusetids[..]), &mut requiredidcount) }.as_bool();
+                    if !second_query {
+                        let error_code = unsafe { GetLastError().0 };
                         log_error_if_new(
                             pid,
                             0,
@@ -338,62 +537,48 @@ pub fn apply_process_default_cpuset<'a>(
                             },
                         );
                     } else {
-                        current_cpusetids = list![0u32; requiredidcount as usize];
-                        let second_query =
-                            unsafe { GetProcessDefaultCpuSets(r_handle, Some(&mut current_cpusetids[..]), &mut requiredidcount) }.as_bool();
-                        if !second_query {
-                            let error_code = unsafe { GetLastError().0 };
-                            log_error_if_new(
-                                pid,
-                                0,
-                                &config.name,
-                                Operation::GetProcessDefaultCpuSets,
-                                error_code,
-                                apply_config_result,
-                                || {
-                                    format!(
-                                        "apply_process_default_cpuset: [GET_PROCESS_DEFAULT_CPUSETS][{}] {:>5}-{}",
-                                        error_from_code_win32(error_code),
-                                        pid,
-                                        config.name
-                                    )
-                                },
-                            );
-                        } else {
-                            toset = current_cpusetids != target_cpusetids;
-                        }
+                        toset = current_cpusetids != target_cpusetids;
                     }
                 }
-                if toset {
-                    if config.cpu_set_reset_ideal {
-                        reset_thread_ideal_processors(pid, config, dry_run, &config.cpu_set_cpus, threads, apply_config_result);
-                    }
-                    let set_result = unsafe { SetProcessDefaultCpuSets(w_handle, Some(&target_cpusetids)) }.as_bool();
-                    if !set_result {
-                        let error_code = unsafe { GetLastError().0 };
-                        log_error_if_new(
-                            pid,
-                            0,
-                            &config.name,
-                            Operation::SetProcessDefaultCpuSets,
-                            error_code,
-                            apply_config_result,
-                            || {
-                                format!(
-                                    "apply_process_default_cpuset: [SET_PROCESS_DEFAULT_CPUSETS][{}] {:>5}-{}",
-                                    error_from_code_win32(error_code),
-                                    pid,
-                                    config.name
-                                )
-                            },
-                        );
-                    } else {
-                        apply_config_result.add_change(format!(
-                            "CPU Set: [{}] -> [{}]",
-                            format_cpu_indices(&indices_from_cpusetids(&current_cpusetids)),
-                            format_cpu_indices(&config.cpu_set_cpus)
-                        ));
-                    }
+            }
+            if toset && dry_run {
+                if config.cpu_set_reset_ideal {
+                    reset_thread_ideal_processors(pid, config, dry_run, &config.cpu_set_cpus, threads, apply_config_result);
+                }
+                apply_config_result.add_change(format!(
+                    "CPU Set: [{}] -> [{}]",
+                    format_cpu_indices(&indices_from_cpusetids(&current_cpusetids)),
+                    format_cpu_indices(&config.cpu_set_cpus)
+                ));
+            } else if toset {
+                if config.cpu_set_reset_ideal {
+                    reset_thread_ideal_processors(pid, config, dry_run, &config.cpu_set_cpus, threads, apply_config_result);
+                }
+                let set_result = unsafe { SetProcessDefaultCpuSets(w_handle, Some(&target_cpusetids)) }.as_bool();
+                if !set_result {
+                    let error_code = unsafe { GetLastError().0 };
+                    log_error_if_new(
+                        pid,
+                        0,
+                        &config.name,
+                        Operation::SetProcessDefaultCpuSets,
+                        error_code,
+                        apply_config_result,
+                        || {
+                            format!(
+                                "apply_process_default_cpuset: [SET_PROCESS_DEFAULT_CPUSETS][{}] {:>5}-{}",
+                                error_from_code_win32(error_code),
+                                pid,
+                                config.name
+                            )
+                        },
+                    );
+                } else {
+                    apply_config_result.add_change(format!(
+                        "CPU Set: [{}] -> [{}]",
+                        format_cpu_indices(&indices_from_cpusetids(&current_cpusetids)),
+                        format_cpu_indices(&config.cpu_set_cpus)
+                    ));
                 }
             }
         }
@@ -535,7 +720,7 @@ pub fn apply_memory_priority(
                         config.memory_priority.as_str()
                     );
                     if dry_run {
-                        apply_config_result.add_change(format!("Memory Priority: -> {}", config.memory_priority.as_str()));
+                        apply_config_result.add_change(change_msg);
                     } else {
                         let mem_prio_info = MemoryPriorityInformation(memory_priority_flag.0);
                         match unsafe {
@@ -577,6 +762,487 @@ pub fn apply_memory_priority(
     }
 }
 
+/// Applies process power throttling (EcoQoS / efficiency mode) via `ProcessPowerThrottling`.
+///
+/// The current state is read back first so an already matching process is left alone.
+pub fn apply_power_throttling(
+    pid: u32,
+    config: &ProcessLevelConfig,
+    dry_run: bool,
+    process_handle: &ProcessHandle,
+    apply_config_result: &mut ApplyConfigResult,
+) {
+    let (Some(r_handle), Some(w_handle)) = get_handles(process_handle) else {
+        return;
+    };
+    let Some((control_mask, state_mask)) = config.power_throttling.as_win_const() else {
+        return;
+    };
+    let mut current_state = PROCESS_POWER_THROTTLING_STATE {
+        Version: PROCESS_POWER_THROTTLING_CURRENT_VERSION,
+        ..Default::default()
+    };
+    // a failed read-back does not hold the setting back, the set below reports its own error
+    let current = match unsafe {
+        GetProcessInformation(
+            r_handle,
+            ProcessPowerThrottling,
+            &mut current_state as *mut _ as *mut c_void,
+            size_of::<PROCESS_POWER_THROTTLING_STATE>() as u32,
+        )
+    } {
+        Err(_) => {
+            let error_code = unsafe { GetLastError().0 };
+            log_error_if_new(
+                pid,
+                0,
+                &config.name,
+                Operation::GetProcessInformation2ProcessPowerThrottling,
+                error_code,
+                apply_config_result,
+                || {
+                    format!(
+                        "apply_config: [QUERY_POWER_THROTTLING][{}] {:>5}-{}",
+                        error_from_code_win32(error_code),
+                        pid,
+                        config.name
+                    )
+                },
+            );
+            None
+        }
+        Ok(_) => Some(PowerThrottling::from_win_const(current_state.ControlMask, current_state.StateMask)),
+    };
+    if current == Some(config.power_throttling) {
+        return;
+    }
+    let current = current.map_or("unknown", |current| current.as_str());
+    if dry_run {
+        apply_config_result.add_change(format!("Power Throttling: {} -> {}", current, config.power_throttling.as_str()));
+        return;
+    }
+    let new_state = PROCESS_POWER_THROTTLING_STATE {
+        Version: PROCESS_POWER_THROTTLING_CURRENT_VERSION,
+        ControlMask: control_mask,
+        StateMask: state_mask,
+    };
+    match unsafe {
+        SetProcessInformation(
+            w_handle,
+            ProcessPowerThrottling,
+            &new_state as *const _ as *const c_void,
+            size_of::<PROCESS_POWER_THROTTLING_STATE>() as u32,
+        )
+    } {
+        Err(_) => {
+            let error_code = unsafe { GetLastError().0 };
+            log_error_if_new(
+                pid,
+                0,
+                &config.name,
+                Operation::SetProcessInformation2ProcessPowerThrottling,
+                error_code,
+                apply_config_result,
+                || {
+                    format!(
+                        "apply_config: [SET_POWER_THROTTLING][{}] {:>5}-{} -> {}",
+                        error_from_code_win32(error_code),
+                        pid,
+                        config.name,
+                        config.power_throttling.as_str()
+                    )
+                },
+            );
+        }
+        Ok(_) => {
+            apply_config_result.add_change(format!("Power Throttling: {} -> {}", current, config.power_throttling.as_str()));
+        }
+    }
+}
+
+/// Places the process into its rule's job object to enforce CPU rate, memory and process count limits.
+///
+/// Processes of one rule (or rule group) share a single job, see [`assign_process_to_job`].
+pub fn apply_job_limits(pid: u32, config: &ProcessLevelConfig, dry_run: bool, apply_config_result: &mut ApplyConfigResult) {
+    let Some(limits) = &config.job_limits else {
+        return;
+    };
+    if dry_run {
+        apply_config_result.add_change(format!("Job Limits: -> job '{}' ({})", limits.key, limits.describe()));
+        return;
+    }
+    match assign_process_to_job(pid, limits) {
+        Ok(JobAssignment::AlreadyAssigned) => {}
+        Ok(JobAssignment::Assigned { nested }) => {
+            apply_config_result.add_change(format!(
+                "Job Limits: -> job '{}' ({}){}",
+                limits.key,
+                limits.describe(),
+                if nested { " nested in existing job" } else { "" }
+            ));
+        }
+        Err((operation, error_code)) => {
+            let tag = match operation {
+                Operation::OpenProcess2processSetQuota => "OPEN_PROCESS_SET_QUOTA",
+                Operation::CreateJobObject => "CREATE_JOB",
+                Operation::SetInformationJobObject => "SET_JOB_LIMITS",
+                _ => "ASSIGN_JOB",
+            };
+            log_error_if_new(pid, 0, &config.name, operation, error_code, apply_config_result, || {
+                format!(
+                    "apply_job_limits: [{}][{}] {:>5}-{} -> job '{}'",
+                    tag,
+                    error_from_code_win32(error_code),
+                    pid,
+                    config.name,
+                    limits.key
+                )
+            });
+        }
+    }
+}
+
+/// Process-level state captured before a conditional rule takes effect, see [`capture_process_state`].
+///
+/// Only the attributes the rule changes are captured; `None` fields are left alone on restore.
+#[derive(Debug, Default)]
+pub struct OriginalProcessState {
+    pub priority_class: Option<u32>,
+    pub affinity_mask: Option<usize>,
+    /// Every processor the process may use, an `affinity_mask` equal to it was never restricted.
+    pub system_affinity_mask: Option<usize>,
+    /// Processor group the masks are relative to, the only group the process runs in.
+    pub affinity_group: Option<u16>,
+    /// An empty list means the process had no default CPU set.
+    pub cpu_set_ids: Option<List<[u32; CONSUMER_CPUS]>>,
+    pub io_priority: Option<u32>,
+    pub memory_priority: Option<u32>,
+    pub power_throttling: Option<(u32, u32)>,
+}
+
+impl OriginalProcessState {
+    /// Attributes of `config` whose current value, read by [`capture_process_state`], no longer matches the rule.
+    ///
+    /// Attributes that could not be read are not reported, a failing getter is not a drift.
+    pub fn drifted(&self, config: &ProcessLevelConfig) -> List<[DriftAttribute; DRIFT_ATTRIBUTES]> {
+        let mut drifted = List::new();
+        if let (Some(current), Some(wanted)) = (self.priority_class, config.priority.as_win_const())
+            && current != wanted.0
+        {
+            drifted.push(DriftAttribute::Priority);
+        }
+        let affinity_mask = cpu_indices_to_mask(&config.affinity_cpus);
+        if let Some(current) = self.affinity_mask
+            && affinity_mask != 0
+            && current != affinity_mask
+        {
+            drifted.push(DriftAttribute::Affinity);
+        }
+        if let Some(current) = &self.cpu_set_ids
+            && *current != cpusetids_from_indices(&config.cpu_set_cpus)
+        {
+            drifted.push(DriftAttribute::CpuSet);
+        }
+        if let (Some(current), Some(wanted)) = (self.io_priority, config.io_priority.as_win_const())
+            && current != wanted
+        {
+            drifted.push(DriftAttribute::IoPriority);
+        }
+        if let (Some(current), Some(wanted)) = (self.memory_priority, config.memory_priority.as_win_const())
+            && current != wanted.0
+        {
+            drifted.push(DriftAttribute::MemoryPriority);
+        }
+        if let Some((control_mask, state_mask)) = self.power_throttling
+            && PowerThrottling::from_win_const(control_mask, state_mask) != config.power_throttling
+        {
+            drifted.push(DriftAttribute::PowerThrottling);
+        }
+        drifted
+    }
+}
+
+/// Reads the current values of every attribute `config` is about to change.
+pub fn capture_process_state(config: &ProcessLevelConfig, process_handle: &ProcessHandle) -> OriginalProcessState {
+    let mut attributes: List<[DriftAttribute; DRIFT_ATTRIBUTES]> = List::new();
+    if config.priority.as_win_const().is_some() {
+        attributes.push(DriftAttribute::Priority);
+    }
+    if !config.affinity_cpus.is_empty() {
+        attributes.push(DriftAttribute::Affinity);
+    }
+    if !config.cpu_set_cpus.is_empty() {
+        attributes.push(DriftAttribute::CpuSet);
+    }
+    if config.io_priority.as_win_const().is_some() {
+        attributes.push(DriftAttribute::IoPriority);
+    }
+    if config.memory_priority.as_win_const().is_some() {
+        attributes.push(DriftAttribute::MemoryPriority);
+    }
+    if config.power_throttling.as_win_const().is_some() {
+        attributes.push(DriftAttribute::PowerThrottling);
+    }
+    read_process_state(&attributes, process_handle)
+}
+
+/// Reads the current values of `attributes`, the others stay `None`; also used by `-capture` for whole processes.
+pub fn read_process_state(attributes: &[DriftAttribute], process_handle: &ProcessHandle) -> OriginalProcessState {
+    let mut state = OriginalProcessState::default();
+    let (Some(r_handle), Some(_)) = get_handles(process_handle) else {
+        return state;
+    };
+    if attributes.contains(&DriftAttribute::Priority) {
+        let priority_class = unsafe { GetPriorityClass(r_handle) };
+        state.priority_class = (priority_class != 0).then_some(priority_class);
+    }
+    if attributes.contains(&DriftAttribute::Affinity) {
+        let (mut current_mask, mut system_mask) = (0usize, 0usize);
+        if unsafe { GetProcessAffinityMask(r_handle, &mut current_mask, &mut system_mask) }.is_ok() {
+            state.affinity_mask = Some(current_mask);
+            state.system_affinity_mask = Some(system_mask);
+            let (mut group_count, mut groups) = (1u16, [0u16; 1]);
+            if unsafe { GetProcessGroupAffinity(r_handle, &mut group_count, groups.as_mut_ptr()) }.as_bool() && group_count == 1 {
+                state.affinity_group = Some(groups[0]);
+            }
+        }
+    }
+    if attributes.contains(&DriftAttribute::CpuSet) {
+        let mut required_count: u32 = 0;
+        if unsafe { GetProcessDefaultCpuSets(r_handle, None, &mut required_count) }.as_bool() {
+            state.cpu_set_ids = Some(List::new());
+        } else {
+            let mut cpu_set_ids: List<[u32; CONSUMER_CPUS]> = list![0u32; required_count as usize];
+            if unsafe { GetProcessDefaultCpuSets(r_handle, Some(&mut cpu_set_ids[..]), &mut required_count) }.as_bool() {
+                cpu_set_ids.truncate(required_count as usize);
+                state.cpu_set_ids = Some(cpu_set_ids);
+            }
+        }
+    }
+    if attributes.contains(&DriftAttribute::IoPriority) {
+        const PROCESS_INFORMATION_IO_PRIORITY: u32 = 33;
+        let mut io_priority: u32 = 0;
+        let mut return_length: u32 = 0;
+        let status = unsafe {
+            NtQueryInformationProcess(
+                r_handle,
+                PROCESS_INFORMATION_IO_PRIORITY,
+                &mut io_priority as *mut _ as *mut c_void,
+                size_of::<u32>() as u32,
+                &mut return_length,
+            )
+        };
+        state.io_priority = (status.0 >= 0).then_some(io_priority);
+    }
+    if attributes.contains(&DriftAttribute::MemoryPriority) {
+        let mut memory_priority = MemoryPriorityInformation(0);
+        if unsafe {
+            GetProcessInformation(
+                r_handle,
+                ProcessMemoryPriority,
+                &mut memory_priority as *mut _ as *mut c_void,
+                size_of::<MemoryPriorityInformation>() as u32,
+            )
+        }
+        .is_ok()
+        {
+            state.memory_priority = Some(memory_priority.0);
+        }
+    }
+    if attributes.contains(&DriftAttribute::PowerThrottling) {
+        let mut throttling = PROCESS_POWER_THROTTLING_STATE {
+            Version: PROCESS_POWER_THROTTLING_CURRENT_VERSION,
+            ..Default::default()
+        };
+        if unsafe {
+            GetProcessInformation(
+                r_handle,
+                ProcessPowerThrottling,
+                &mut throttling as *mut _ as *mut c_void,
+                size_of::<PROCESS_POWER_THROTTLING_STATE>() as u32,
+            )
+        }
+        .is_ok()
+        {
+            state.power_throttling = Some((throttling.ControlMask, throttling.StateMask));
+        }
+    }
+    state
+}
+
+/// Writes back the values captured by [`capture_process_state`] once a conditional rule stops applying.
+pub fn restore_process_state(
+    pid: u32,
+    name: &str,
+    state: &OriginalProcessState,
+    process_handle: &ProcessHandle,
+    apply_config_result: &mut ApplyConfigResult,
+) {
+    let (Some(_), Some(w_handle)) = get_handles(process_handle) else {
+        return;
+    };
+    let mut failures: List<[(Operation, &'static str, u32); PENDING]> = List::new();
+    let last_error = || unsafe { GetLastError().0 };
+    if let Some(priority_class) = state.priority_class {
+        if unsafe { SetPriorityClass(w_handle, PROCESS_CREATION_FLAGS(priority_class)) }.is_ok() {
+            apply_config_result.add_change(format!(
+                "Priority: -> {} (restored)",
+                ProcessPriority::from_win_const(priority_class)
+            ));
+        } else {
+            failures.push((Operation::SetPriorityClass, "RESTORE_PRIORITY_CLASS", last_error()));
+        }
+    }
+    if let Some(affinity_mask) = state.affinity_mask {
+        if unsafe { SetProcessAffinityMask(w_handle, affinity_mask) }.is_ok() {
+            apply_config_result.add_change(format!("Affinity: -> {:#X} (restored)", affinity_mask));
+        } else {
+            failures.push((Operation::SetProcessAffinityMask, "RESTORE_PROCESS_AFFINITY_MASK", last_error()));
+        }
+    }
+    if let Some(cpu_set_ids) = &state.cpu_set_ids {
+        let ids = if cpu_set_ids.is_empty() { None } else { Some(&cpu_set_ids[..]) };
+        if unsafe { SetProcessDefaultCpuSets(w_handle, ids) }.as_bool() {
+            apply_config_result.add_change(format!(
+                "CPU Set: -> [{}] (restored)",
+                format_cpu_indices(&indices_from_cpusetids(cpu_set_ids))
+            ));
+        } else {
+            failures.push((Operation::SetProcessDefaultCpuSets, "RESTORE_PROCESS_DEFAULT_CPUSETS", last_error()));
+        }
+    }
+    if let Some(io_priority) = state.io_priority {
+        const PROCESS_INFORMATION_IO_PRIORITY: u32 = 33;
+        let status = unsafe {
+            NtSetInformationProcess(
+                w_handle,
+                PROCESS_INFORMATION_IO_PRIORITY,
+                &io_priority as *const _ as *const c_void,
+                size_of::<u32>() as u32,
+            )
+        };
+        if status.0 >= 0 {
+            apply_config_result.add_change(format!("IO Priority: -> {} (restored)", IOPriority::from_win_const(io_priority)));
+        } else {
+            failures.push((
+                Operation::NtSetInformationProcess2ProcessInformationIOPriority,
+                "RESTORE_IO_PRIORITY",
+                i32::cast_unsigned(status.0),
+            ));
+        }
+    }
+    if let Some(memory_priority) = state.memory_priority {
+        let info = MemoryPriorityInformation(memory_priority);
+        if unsafe {
+            SetProcessInformation(
+                w_handle,
+                ProcessMemoryPriority,
+                &info as *const _ as *const c_void,
+                size_of::<MemoryPriorityInformation>() as u32,
+            )
+        }
+        .is_ok()
+        {
+            apply_config_result.add_change(format!(
+                "Memory Priority: -> {} (restored)",
+                MemoryPriority::from_win_const(memory_priority)
+            ));
+        } else {
+            failures.push((
+                Operation::SetProcessInformation2ProcessMemoryPriority,
+                "RESTORE_MEMORY_PRIORITY",
+                last_error(),
+            ));
+        }
+    }
+    if let Some((control_mask, state_mask)) = state.power_throttling {
+        let throttling = PROCESS_POWER_THROTTLING_STATE {
+            Version: PROCESS_POWER_THROTTLING_CURRENT_VERSION,
+            ControlMask: control_mask,
+            StateMask: state_mask,
+        };
+        if unsafe {
+            SetProcessInformation(
+                w_handle,
+                ProcessPowerThrottling,
+                &throttling as *const _ as *const c_void,
+                size_of::<PROCESS_POWER_THROTTLING_STATE>() as u32,
+            )
+        }
+        .is_ok()
+        {
+            apply_config_result.add_change(format!(
+                "Power Throttling: -> {} (restored)",
+                PowerThrottling::from_win_const(control_mask, state_mask).as_str()
+            ));
+        } else {
+            failures.push((
+                Operation::SetProcessInformation2ProcessPowerThrottling,
+                "RESTORE_POWER_THROTTLING",
+                last_error(),
+            ));
+        }
+    }
+    for (operation, tag, error_code) in failures {
+        let message = error_name(operation.error_source(error_code), error_code);
+        log_error_if_new(pid, 0, name, operation, error_code, apply_config_result, || {
+            format!("restore_process_state: [{}][{}] {:>5}-{}", tag, message, pid, name)
+        });
+    }
+}
+
+/// Carries out the rule's `action` on a newly matched process. Returns true when the process is gone,
+/// so the remaining process-level settings are skipped.
+///
+/// Blacklisted processes are never acted on, the blacklist wins over any rule.
+pub fn apply_rule_action(
+    pid: u32,
+    config: &ProcessLevelConfig,
+    dry_run: bool,
+    blacklist: &Blacklist,
+    apply_config_result: &mut ApplyConfigResult,
+) -> bool {
+    if config.action == RuleAction::None {
+        return false;
+    }
+    if blacklist.contains(&config.name) {
+        apply_config_result.add_change(format!(
+            "Action: {} skipped, process is blacklisted ({}, {})",
+            config.action.as_str(),
+            config.origin,
+            blacklist.describe(&config.name)
+        ));
+        return false;
+    }
+    if dry_run {
+        apply_config_result.add_change(format!("Action: -> {} ({})", config.action.as_str(), config.origin));
+        return false;
+    }
+    match terminate_process(pid) {
+        Ok(()) => {
+            apply_config_result.add_change(format!("Action: -> {} ({})", config.action.as_str(), config.origin));
+            true
+        }
+        Err((operation, error_code)) => {
+            let tag = match operation {
+                Operation::OpenProcess2processTerminate => "OPEN_PROCESS_TERMINATE",
+                _ => "TERMINATE",
+            };
+            log_error_if_new(pid, 0, &config.name, operation, error_code, apply_config_result, || {
+                format!(
+                    "apply_rule_action: [{}][{}] {:>5}-{} ({})",
+                    tag,
+                    error_from_code_win32(error_code),
+                    pid,
+                    config.name,
+                    config.origin
+                )
+            });
+            false
+        }
+    }
+}
+
 /// Prefetches thread cycle counts for prime thread selection.
 ///
 /// Opens handles to top CPU-consuming threads (by kernel+user time) and
@@ -645,6 +1311,13 @@ pub fn prefetch_all_thread_cycles<'a>(
         if thread_stats.start_address == 0 {
             thread_stats.start_address = get_thread_start_address(r_handle);
         }
+        // Threads often name themselves right after they start, so ask a few times before taking them as unnamed
+        if thread_stats.description.is_empty() && thread_stats.description_misses < DESCRIPTION_QUERIES && config.uses_thread_descriptions() {
+            thread_stats.description = get_thread_description(r_handle);
+            if thread_stats.description.is_empty() {
+                thread_stats.description_misses += 1;
+            }
+        }
         let mut cycles: u64 = 0;
         match unsafe { QueryThreadCycleTime(r_handle, &mut cycles) } {
             Ok(_) => {
@@ -704,6 +1377,8 @@ pub fn prefetch_all_thread_cycles<'a>(
 /// 4. Demote threads that no longer qualify
 ///
 /// Prime threads are pinned to specific CPUs via CPU Sets for better cache locality.
+/// A dry run selects over the real cycle counts but only records the pins in the scheduler,
+/// so the hysteresis carries over between samples without touching any thread.
 #[allow(clippy::too_many_arguments)]
 pub fn apply_prime_threads<'a>(
     pid: u32,
@@ -721,12 +1396,6 @@ pub fn apply_prime_threads<'a>(
     if !do_prime && !has_tracking {
         return;
     }
-    if dry_run {
-        if has_prime_cpus {
-            apply_config_result.add_change(format!("Prime CPUs: -> [{}]", format_cpu_indices(&config.prime_threads_cpus)));
-        }
-        return;
-    }
     if has_tracking {
         prime_core_scheduler.set_tracking_info(pid, config.track_top_x_threads, config.name.clone());
     }
@@ -767,10 +1436,11 @@ pub fn apply_prime_threads<'a>(
         })
         .collect();
 
-    apply_prime_threads_select(pid, prime_count, &mut tid_with_delta_cycles, prime_core_scheduler);
+    apply_prime_threads_select(pid, config, prime_count, &mut tid_with_delta_cycles, prime_core_scheduler);
     apply_prime_threads_promote(
         pid,
         config,
+        dry_run,
         current_mask,
         &tid_with_delta_cycles,
         prime_core_scheduler,
@@ -779,6 +1449,7 @@ pub fn apply_prime_threads<'a>(
     apply_prime_threads_demote(
         pid,
         config,
+        dry_run,
         threads,
         &tid_with_delta_cycles,
         prime_core_scheduler,
@@ -791,15 +1462,209 @@ pub fn apply_prime_threads<'a>(
 /// Hysteresis prevents threads from rapidly flipping between prime/non-prime:
 /// - Currently prime threads stay prime if cycles >= keep_threshold% of max
 /// - Non-prime threads become prime if cycles >= entry_threshold% of max AND active_streak >= min_active_streak
+///
+/// When prefixes carry thread quotas, candidates are classified by their matching prefix first so each
+/// prefix gets its reserved minimum and never exceeds its maximum; threads matching no prefix are skipped.
 pub fn apply_prime_threads_select(
     pid: u32,
+    config: &ThreadLevelConfig,
     prime_count: usize,
     tid_with_delta_cycles: &mut [(u32, u64, bool)],
     prime_core_scheduler: &mut PrimeThreadScheduler,
 ) {
-    prime_core_scheduler.select_top_threads_with_hysteresis(pid, tid_with_delta_cycles, prime_count, |thread_stats| {
-        !thread_stats.pinned_cpu_set_ids.is_empty()
-    });
+    let is_pinned = |thread_stats: &ThreadStats| !thread_stats.pinned_cpu_set_ids.is_empty();
+    if !config.has_thread_quotas() {
+        prime_core_scheduler.select_top_threads_with_hysteresis(pid, tid_with_delta_cycles, prime_count, is_pinned);
+        return;
+    }
+    let mut group_of: HashMap<u32, usize> = HashMap::default();
+    for &(tid, _, _) in tid_with_delta_cycles.iter() {
+        let thread_stats = prime_core_scheduler.get_thread_stats(pid, tid);
+        let start_module = resolve_address_to_module(pid, thread_stats.start_address);
+        if let Some(index) = match_prime_prefix(config, &start_module, &thread_stats.description) {
+            group_of.insert(tid, index);
+        }
+    }
+    let quotas: List<[ThreadQuota; CONSUMER_CPUS]> = config.prime_threads_prefixes.iter().map(|prefix| prefix.quota).collect();
+    prime_core_scheduler.select_top_threads_with_quotas(pid, tid_with_delta_cycles, prime_count, is_pinned, &group_of, &quotas);
+}
+
+/// Finds the prime prefix a thread belongs to: the first matching inclusion selector, unless an exclusion matches.
+fn match_prime_prefix(config: &ThreadLevelConfig, start_module: &str, description: &str) -> Option<usize> {
+    let prefixes = &config.prime_threads_prefixes;
+    if prefixes
+        .iter()
+        .any(|prefix| prefix.selector.exclude && prefix.selector.matches(start_module, description))
+    {
+        return None;
+    }
+    prefixes
+        .iter()
+        .position(|prefix| !prefix.selector.exclude && prefix.selector.matches(start_module, description))
+}
+
+/// Attribute a pin mode overwrote, restored on demotion.
+enum PinOriginal {
+    None,
+    AffinityMask(usize),
+    IdealProcessor(u16, u8),
+}
+
+/// Pins a prime thread to `cpus` using the rule's pin mode.
+///
+/// Errors carry the operation, log tag and Win32 error code for [`log_error_if_new`].
+fn pin_prime_thread(handle: HANDLE, pin_mode: PrimePinMode, cpus: &[u32]) -> Result<PinOriginal, (Operation, &'static str, u32)> {
+    match pin_mode {
+        PrimePinMode::CpuSet => {
+            if unsafe { SetThreadSelectedCpuSets(handle, &cpusetids_from_indices(cpus)) }.as_bool() {
+                Ok(PinOriginal::None)
+            } else {
+                Err((Operation::SetThreadSelectedCpuSets, "SET_THREAD_SELECTED_CPU_SETS", unsafe {
+                    GetLastError().0
+                }))
+            }
+        }
+        PrimePinMode::Affinity => {
+            let mask = cpu_indices_to_mask(cpus);
+            if mask == 0 {
+                // CPUs outside processor group 0 cannot be expressed as a thread affinity mask
+                return Err((
+                    Operation::SetThreadAffinityMask,
+                    "SET_THREAD_AFFINITY_MASK",
+                    ERROR_INVALID_PARAMETER.0,
+                ));
+            }
+            match unsafe { SetThreadAffinityMask(handle, mask) } {
+                0 => Err((Operation::SetThreadAffinityMask, "SET_THREAD_AFFINITY_MASK", unsafe {
+                    GetLastError().0
+                })),
+                previous_mask => Ok(PinOriginal::AffinityMask(previous_mask)),
+            }
+        }
+        PrimePinMode::Ideal => {
+            let (group, number) = processor_number_from_index(cpus[0]);
+            match set_thread_ideal_processor_ex(handle, group, number) {
+                Ok(previous) => Ok(PinOriginal::IdealProcessor(previous.Group, previous.Number)),
+                Err(_) => Err((Operation::SetThreadIdealProcessorEx2PrimeThread, "SET_IDEAL_PRIME", unsafe {
+                    GetLastError().0
+                })),
+            }
+        }
+    }
+}
+
+/// Undoes [`pin_prime_thread`] using the mode and original values recorded on the thread.
+fn unpin_prime_thread(handle: HANDLE, thread_stats: &mut ThreadStats) -> Result<(), (Operation, &'static str, u32)> {
+    match thread_stats.pin_mode {
+        PrimePinMode::CpuSet => {
+            if unsafe { SetThreadSelectedCpuSets(handle, &[]) }.as_bool() {
+                Ok(())
+            } else {
+                Err((Operation::SetThreadSelectedCpuSets, "SET_THREAD_SELECTED_CPU_SETS", unsafe {
+                    GetLastError().0
+                }))
+            }
+        }
+        PrimePinMode::Affinity => match thread_stats.original_affinity_mask.take() {
+            Some(mask) if unsafe { SetThreadAffinityMask(handle, mask) } == 0 => {
+                Err((Operation::SetThreadAffinityMask, "RESTORE_THREAD_AFFINITY_MASK", unsafe {
+                    GetLastError().0
+                }))
+            }
+            _ => Ok(()),
+        },
+        PrimePinMode::Ideal => match thread_stats.original_ideal_processor.take() {
+            Some((group, number)) if set_thread_ideal_processor_ex(handle, group, number).is_err() => {
+                Err((Operation::SetThreadIdealProcessorEx2PrimeThread, "RESTORE_IDEAL_PRIME", unsafe {
+                    GetLastError().0
+                }))
+            }
+            _ => Ok(()),
+        },
+    }
+}
+
+/// Picks the CPUs a single prime thread is pinned to, according to the rule's placement mode.
+///
+/// `occupied_cpus` are the CPUs already held by other prime threads of the same process.
+/// - `All`: the whole candidate list (no topology awareness)
+/// - `PhysicalCore`: one logical CPU of a physical core not yet holding a prime thread, so SMT siblings never share two prime threads
+/// - `PackLlc`: candidate CPUs of the LLC already holding most of the process's prime threads (largest LLC first)
+/// - `SpreadLlc`: candidate CPUs of the LLC holding the fewest of the process's prime threads
+///
+/// CPUs missing from the CPU set information are treated as their own core in LLC 0.
+pub fn place_prime_thread(
+    placement: PrimePlacement,
+    candidate_cpus: &[u32],
+    occupied_cpus: &[u32],
+    topology: &[CpuSetData],
+) -> List<[u32; CONSUMER_CPUS]> {
+    if candidate_cpus.is_empty() {
+        return List::new();
+    }
+    let core_of = |cpu: u32| -> (u16, u32) {
+        cpu_set_data_of(topology, cpu)
+            .map(|data| (data.group, data.core_index as u32))
+            .unwrap_or((u16::MAX, cpu))
+    };
+    let llc_of = |cpu: u32| -> (u16, u8) {
+        cpu_set_data_of(topology, cpu)
+            .map(|data| (data.group, data.last_level_cache_index))
+            .unwrap_or((processor_number_from_index(cpu).0, 0))
+    };
+    match placement {
+        PrimePlacement::All => candidate_cpus.iter().copied().collect(),
+        PrimePlacement::PhysicalCore => {
+            let occupied_cores: List<[(u16, u32); CONSUMER_CPUS]> = occupied_cpus.iter().map(|&cpu| core_of(cpu)).collect();
+            // first free core, otherwise the least crowded one, preferring a logical CPU not taken itself
+            let chosen = candidate_cpus
+                .iter()
+                .copied()
+                .min_by_key(|&cpu| {
+                    let core = core_of(cpu);
+                    (occupied_cores.iter().filter(|&&c| c == core).count(), occupied_cpus.contains(&cpu))
+                })
+                .unwrap_or(candidate_cpus[0]);
+            list![chosen]
+        }
+        PrimePlacement::PackLlc | PrimePlacement::SpreadLlc => {
+            let mut llcs: List<[(u16, u8); CONSUMER_CPUS]> = List::new();
+            for &cpu in candidate_cpus {
+                let llc = llc_of(cpu);
+                if !llcs.contains(&llc) {
+                    llcs.push(llc);
+                }
+            }
+            let stats_of = |llc: (u16, u8)| -> (usize, usize, usize) {
+                let cpus = candidate_cpus.iter().filter(|&&cpu| llc_of(cpu) == llc).count();
+                let occupied = occupied_cpus.iter().filter(|&&cpu| llc_of(cpu) == llc).count();
+                let free = candidate_cpus
+                    .iter()
+                    .filter(|&&cpu| llc_of(cpu) == llc && !occupied_cpus.contains(&cpu))
+                    .count();
+                (cpus, occupied, free)
+            };
+            let chosen_llc = if placement == PrimePlacement::PackLlc {
+                // most occupied LLC that still has a free CPU, largest LLC when nothing is placed yet
+                llcs.iter()
+                    .copied()
+                    .max_by_key(|&llc| {
+                        let (cpus, occupied, free) = stats_of(llc);
+                        (free > 0, occupied, cpus)
+                    })
+                    .unwrap_or(llcs[0])
+            } else {
+                llcs.iter()
+                    .copied()
+                    .min_by_key(|&llc| {
+                        let (cpus, occupied, _) = stats_of(llc);
+                        (occupied, Reverse(cpus))
+                    })
+                    .unwrap_or(llcs[0])
+            };
+            candidate_cpus.iter().copied().filter(|&cpu| llc_of(cpu) == chosen_llc).collect()
+        }
+    }
 }
 
 /// Promotes selected threads to prime status with CPU pinning and optional priority boost.
@@ -807,15 +1672,32 @@ pub fn apply_prime_threads_select(
 /// For each thread marked as prime:
 /// - Resolves start address to module name for prefix matching
 /// - Applies module-specific CPU set if prefixes are configured
+/// - Narrows the CPU set by the rule's placement mode (see [`place_prime_thread`])
 /// - Boosts thread priority (either explicitly configured or auto-boosted by one level)
+///
+/// With `dry_run` nothing is set, the would-be pins are recorded and reported as `(simulated)`.
 pub fn apply_prime_threads_promote(
     pid: u32,
     config: &ThreadLevelConfig,
+    dry_run: bool,
     current_mask: &mut usize,
     tid_with_delta_cycles: &[(u32, u64, bool)],
     prime_core_scheduler: &mut PrimeThreadScheduler,
     apply_config_result: &mut ApplyConfigResult,
 ) {
+    let mut occupied_cpus: List<[u32; CONSUMER_CPUS]> = List::new();
+    let mut topology: List<[CpuSetData; CONSUMER_CPUS]> = List::new();
+    if (config.prime_placement != PrimePlacement::All || config.prime_pin_mode == PrimePinMode::Ideal)
+        && let Some(process_stats) = prime_core_scheduler.pid_to_process_stats.get(&pid)
+    {
+        // threads that lost their prime selection are demoted after this pass, their CPUs are free
+        for &(tid, _, is_prime) in tid_with_delta_cycles {
+            if is_prime && let Some(thread_stats) = process_stats.tid_to_thread_stats.get(&tid) {
+                occupied_cpus.extend(indices_from_cpusetids(&thread_stats.pinned_cpu_set_ids));
+            }
+        }
+        topology = get_cpu_set_information().lock().unwrap().iter().copied().collect();
+    }
     for &(tid, delta_cycles, is_prime) in tid_with_delta_cycles {
         if !is_prime {
             continue;
@@ -843,58 +1725,84 @@ pub fn apply_prime_threads_promote(
                 false => &thread_handle.w_handle,
             };
             let start_module = resolve_address_to_module(pid, thread_stats.start_address);
-            let mut matched = false;
             let mut prime_cpus_to_set = &config.prime_threads_cpus;
             let mut thread_priority_to_set = ThreadPriority::None;
-            for prefix in &config.prime_threads_prefixes {
-                if start_module.to_lowercase().starts_with(&prefix.prefix.to_lowercase()) {
-                    matched = true;
+            match match_prime_prefix(config, &start_module, &thread_stats.description) {
+                Some(index) => {
+                    let prefix = &config.prime_threads_prefixes[index];
                     if let Some(ref cpus) = prefix.cpus {
                         prime_cpus_to_set = cpus;
                     }
                     thread_priority_to_set = prefix.thread_priority;
-                    break;
                 }
-            }
-            if !matched && !config.prime_threads_prefixes.is_empty() {
-                continue;
+                None if !config.prime_threads_prefixes.is_empty() => continue,
+                None => {}
             }
             let filtered_cpus = if *current_mask != 0 {
                 filter_indices_by_mask(prime_cpus_to_set, *current_mask)
             } else {
                 prime_cpus_to_set.clone()
             };
-            let cpu_setids = cpusetids_from_indices(&filtered_cpus);
+            let mut placed_cpus = place_prime_thread(config.prime_placement, &filtered_cpus, &occupied_cpus, &topology);
+            if config.prime_pin_mode == PrimePinMode::Ideal {
+                // one ideal CPU per prime thread, the first placed CPU no other prime thread has
+                placed_cpus = placed_cpus
+                    .iter()
+                    .find(|cpu| !occupied_cpus.contains(cpu))
+                    .or(placed_cpus.first())
+                    .copied()
+                    .into_iter()
+                    .collect();
+            }
+            let cpu_setids = cpusetids_from_indices(&placed_cpus);
             if !cpu_setids.is_empty() {
-                if !unsafe { SetThreadSelectedCpuSets(*handle, &cpu_setids) }.as_bool() {
-                    let error_code = unsafe { GetLastError().0 };
-                    log_error_if_new(
-                        pid,
-                        tid,
-                        &config.name,
-                        Operation::SetThreadSelectedCpuSets,
-                        error_code,
-                        apply_config_result,
-                        || {
+                let pinned = if dry_run {
+                    Ok(PinOriginal::None)
+                } else {
+                    pin_prime_thread(*handle, config.prime_pin_mode, &placed_cpus)
+                };
+                match pinned {
+                    Err((operation, tag, error_code)) => {
+                        log_error_if_new(pid, tid, &config.name, operation, error_code, apply_config_result, || {
                             format!(
-                                "apply_prime_threads_promote: [SET_THREAD_SELECTED_CPU_SETS][{}] {:>5}-{:>5}-{}",
+                                "apply_prime_threads_promote: [{}][{}] {:>5}-{:>5}-{}",
+                                tag,
                                 error_from_code_win32(error_code),
                                 pid,
                                 tid,
                                 config.name
                             )
-                        },
-                    );
-                } else {
-                    thread_stats.pinned_cpu_set_ids = cpu_setids.clone();
-                    let promoted_cpus = indices_from_cpusetids(&cpu_setids);
-                    apply_config_result.add_change(format!(
-                        "Thread {} -> (promoted, [{}], cycles={}, start={})",
-                        tid,
-                        format_cpu_indices(&promoted_cpus),
-                        delta_cycles,
-                        start_module
-                    ));
+                        });
+                    }
+                    Ok(original) => {
+                        thread_stats.pinned_cpu_set_ids = cpu_setids.clone();
+                        thread_stats.pin_mode = config.prime_pin_mode;
+                        match original {
+                            PinOriginal::None => {}
+                            PinOriginal::AffinityMask(mask) => thread_stats.original_affinity_mask = Some(mask),
+                            PinOriginal::IdealProcessor(group, number) => thread_stats.original_ideal_processor = Some((group, number)),
+                        }
+                        let promoted_cpus = indices_from_cpusetids(&cpu_setids);
+                        let mut mode_info = String::new();
+                        if config.prime_placement != PrimePlacement::All {
+                            mode_info.push_str(&format!(", placement={}", config.prime_placement.as_str()));
+                        }
+                        if config.prime_pin_mode != PrimePinMode::CpuSet {
+                            mode_info.push_str(&format!(", pin={}", config.prime_pin_mode.as_str()));
+                        }
+                        if dry_run {
+                            mode_info.push_str(", simulated");
+                        }
+                        apply_config_result.add_change(format!(
+                            "Thread {} -> (promoted, [{}]{}, cycles={}, start={})",
+                            tid,
+                            format_cpu_indices(&promoted_cpus),
+                            mode_info,
+                            delta_cycles,
+                            start_module
+                        ));
+                        occupied_cpus.extend(indices_from_cpusetids(&cpu_setids));
+                    }
                 }
 
                 let current_priority = unsafe {
@@ -906,14 +1814,17 @@ pub fn apply_prime_threads_promote(
 
                 if current_priority != 0x7FFFFFFF_i32 {
                     let current_priority = ThreadPriority::from_win_const(current_priority);
-                    thread_stats.original_priority = Some(current_priority);
                     let new_priority = if thread_priority_to_set != ThreadPriority::None {
                         thread_priority_to_set
                     } else {
                         current_priority.boost_one()
                     };
+                    // a simulated promotion has no priority to restore on demotion
+                    if !dry_run {
+                        thread_stats.original_priority = Some(current_priority);
+                    }
                     if new_priority != current_priority {
-                        if unsafe { SetThreadPriority(*handle, new_priority.to_thread_priority_struct()) }.is_err() {
+                        if !dry_run && unsafe { SetThreadPriority(*handle, new_priority.to_thread_priority_struct()) }.is_err() {
                             let error_code = unsafe { GetLastError().0 };
                             log_error_if_new(
                                 pid,
@@ -953,9 +1864,11 @@ pub fn apply_prime_threads_promote(
 ///
 /// Removes CPU set pinning and restores original thread priority.
 /// Clears pinned_cpu_set_ids even on failure to prevent infinite retry loops.
+/// With `dry_run` only the recorded pins of simulated promotions are cleared.
 pub fn apply_prime_threads_demote<'a>(
     pid: u32,
     config: &ThreadLevelConfig,
+    dry_run: bool,
     threads: &impl Fn() -> &'a HashMap<u32, SYSTEM_THREAD_INFORMATION>,
     tid_with_delta_cycles: &[(u32, u64, bool)],
     prime_core_scheduler: &mut PrimeThreadScheduler,
@@ -994,25 +1907,23 @@ pub fn apply_prime_threads_demote<'a>(
             },
             _ => continue,
         };
-        if !unsafe { SetThreadSelectedCpuSets(*handle, &[]) }.as_bool() {
-            let error_code = unsafe { GetLastError().0 };
-            log_error_if_new(
-                pid,
-                tid,
-                &config.name,
-                Operation::SetThreadSelectedCpuSets,
-                error_code,
-                apply_config_result,
-                || {
-                    format!(
-                        "apply_prime_threads_demote: [SET_THREAD_SELECTED_CPU_SETS][{}] {:>5}-{:>5}-{}",
-                        error_from_code_win32(error_code),
-                        pid,
-                        tid,
-                        config.name
-                    )
-                },
-            );
+        let handle = *handle;
+        let unpinned = if dry_run {
+            Ok(())
+        } else {
+            unpin_prime_thread(handle, thread_stats)
+        };
+        if let Err((operation, tag, error_code)) = unpinned {
+            log_error_if_new(pid, tid, &config.name, operation, error_code, apply_config_result, || {
+                format!(
+                    "apply_prime_threads_demote: [{}][{}] {:>5}-{:>5}-{}",
+                    tag,
+                    error_from_code_win32(error_code),
+                    pid,
+                    tid,
+                    config.name
+                )
+            });
         } else {
             let start_module = resolve_address_to_module(pid, thread_stats.start_address);
             apply_config_result.add_change(format!("Thread {} -> (demoted, start={})", tid, start_module));
@@ -1021,7 +1932,7 @@ pub fn apply_prime_threads_demote<'a>(
         thread_stats.pinned_cpu_set_ids.clear();
 
         if let Some(original_priority) = thread_stats.original_priority.take()
-            && unsafe { SetThreadPriority(*handle, original_priority.to_thread_priority_struct()) }.is_err()
+            && unsafe { SetThreadPriority(handle, original_priority.to_thread_priority_struct()) }.is_err()
         {
             let error_code = unsafe { GetLastError().0 };
             log_error_if_new(
@@ -1045,12 +1956,55 @@ pub fn apply_prime_threads_demote<'a>(
     }
 }
 
+/// Demotes every pinned prime thread of a process whose rule went away, e.g. after a profile switch.
+///
+/// Unlike [`apply_prime_threads_demote`] this needs no thread snapshot, it works from the handles cached in the thread stats.
+pub fn release_prime_threads(pid: u32, prime_core_scheduler: &mut PrimeThreadScheduler, apply_config_result: &mut ApplyConfigResult) {
+    let Some(process_stats) = prime_core_scheduler.pid_to_process_stats.get_mut(&pid) else {
+        return;
+    };
+    let process_name = process_stats.process_name.clone();
+    for (tid, thread_stats) in process_stats.tid_to_thread_stats.iter_mut() {
+        if thread_stats.pinned_cpu_set_ids.is_empty() {
+            continue;
+        }
+        let Some(handle) = thread_stats.handle.as_ref().map(|thread_handle| {
+            if thread_handle.w_handle.is_invalid() {
+                thread_handle.w_limited_handle
+            } else {
+                thread_handle.w_handle
+            }
+        }) else {
+            continue;
+        };
+        if let Err((operation, tag, error_code)) = unpin_prime_thread(handle, thread_stats) {
+            log_error_if_new(pid, *tid, &process_name, operation, error_code, apply_config_result, || {
+                format!(
+                    "release_prime_threads: [{}][{}] {:>5}-{:>5}-{}",
+                    tag,
+                    error_from_code_win32(error_code),
+                    pid,
+                    tid,
+                    process_name
+                )
+            });
+        } else {
+            apply_config_result.add_change(format!("Thread {} -> (released)", tid));
+        }
+        thread_stats.pinned_cpu_set_ids.clear();
+        if let Some(original_priority) = thread_stats.original_priority.take() {
+            let _ = unsafe { SetThreadPriority(handle, original_priority.to_thread_priority_struct()) };
+        }
+    }
+}
+
 /// Assigns ideal processors to threads based on their start module.
 ///
 /// For each rule, identifies threads whose start module matches the prefix,
 /// selects top N by cycle count (N = number of CPUs in rule), and assigns
 /// each to a dedicated CPU. When a thread drops out of top N, its ideal
-/// processor is restored to the previous value.
+/// processor is restored to the previous value. A dry run reads the current
+/// ideal processors and simulates the assignments without setting them.
 pub fn apply_ideal_processors<'a>(
     pid: u32,
     config: &ThreadLevelConfig,
@@ -1063,24 +2017,6 @@ pub fn apply_ideal_processors<'a>(
         return;
     }
 
-    if dry_run {
-        for rule in &config.ideal_processor_rules {
-            let cpus_str = format_cpu_indices(&rule.cpus);
-            let prefixes_str = if rule.prefixes.is_empty() {
-                "all modules".to_string()
-            } else {
-                rule.prefixes.join("; ")
-            };
-            apply_config_result.add_change(format!(
-                "Ideal Processor: CPUs [{}] for top {} threads from [{}]",
-                cpus_str,
-                rule.cpus.len(),
-                prefixes_str
-            ));
-        }
-        return;
-    }
-
     let mut module_names: Vec<String> = Vec::new();
     let mut all_threads: List<[(u32, u64, usize, usize); TIDS_CAPED]> = List::new();
     for &tid in threads().keys() {
@@ -1102,13 +2038,8 @@ pub fn apply_ideal_processors<'a>(
         let mut thread_infos: List<[(u32, u64, usize, usize); TIDS_CAPED]> = List::new();
         for &(tid, delta_cycles, start_addr, name_idx) in &all_threads {
             let start_module = &module_names[name_idx];
-            let matches = if rule.prefixes.is_empty() {
-                true
-            } else {
-                let lower = start_module.to_lowercase();
-                rule.prefixes.iter().any(|prefix| lower.starts_with(prefix))
-            };
-            if matches {
+            let description = &prime_scheduler.get_thread_stats(pid, tid).description;
+            if thread_selected(&rule.prefixes, start_module, description) {
                 thread_infos.push((tid, delta_cycles, start_addr, name_idx));
             }
         }
@@ -1217,7 +2148,12 @@ pub fn apply_ideal_processors<'a>(
                 break;
             };
             counter_free_pool += 1;
-            match set_thread_ideal_processor_ex(*handle, 0, target_cpu as u8) {
+            let assigned = if dry_run {
+                Ok(())
+            } else {
+                set_thread_ideal_processor_ex(*handle, 0, target_cpu as u8).map(|_| ())
+            };
+            match assigned {
                 Err(_) => {
                     let error_code = unsafe { GetLastError().0 };
                     log_error_if_new(
@@ -1248,8 +2184,11 @@ pub fn apply_ideal_processors<'a>(
                         .map(|(_, _, _, idx)| module_names[*idx].as_str())
                         .unwrap_or("?");
                     apply_config_result.add_change(format!(
-                        "Thread {} -> ideal CPU {} (group 0) start={}",
-                        tid, target_cpu, start_module
+                        "Thread {} -> ideal CPU {} (group 0) start={}{}",
+                        tid,
+                        target_cpu,
+                        start_module,
+                        if dry_run { " (simulated)" } else { "" }
                     ));
                 }
             };
@@ -1288,7 +2227,12 @@ pub fn apply_ideal_processors<'a>(
                     _ => continue,
                 };
 
-                match set_thread_ideal_processor_ex(*handle, prev_group, prev_number) {
+                let restored = if dry_run {
+                    Ok(())
+                } else {
+                    set_thread_ideal_processor_ex(*handle, prev_group, prev_number).map(|_| ())
+                };
+                match restored {
                     Err(_) => {
                         let error_code = unsafe { GetLastError().0 };
                         log_error_if_new(
@@ -1313,8 +2257,12 @@ pub fn apply_ideal_processors<'a>(
                         thread_stats.ideal_processor.current_group = prev_group;
                         thread_stats.ideal_processor.current_number = prev_number;
                         apply_config_result.add_change(format!(
-                            "Thread {} -> restored ideal CPU {} (group {}) start={}",
-                            tid, prev_number, prev_group, start_module
+                            "Thread {} -> restored ideal CPU {} (group {}) start={}{}",
+                            tid,
+                            prev_number,
+                            prev_group,
+                            start_module,
+                            if dry_run { " (simulated)" } else { "" }
                         ));
                     }
                 }
@@ -1338,3 +2286,210 @@ pub fn update_thread_stats(pid: u32, prime_scheduler: &mut PrimeThreadScheduler)
         }
     }
 }
+
+/// Applies thread power throttling to every non-prime thread of the process.
+///
+/// Threads are handled once: the applied state is remembered in `ThreadStats.power_throttling` and the
+/// thread is not queried again while its desired state stays the same, so a change made by the application
+/// itself is not undone. The read-back only skips the set when the thread already has the desired state.
+/// Threads promoted to prime get their throttling handed back to Windows, and are throttled again once demoted.
+pub fn apply_thread_power_throttling<'a>(
+    pid: u32,
+    config: &ThreadLevelConfig,
+    dry_run: bool,
+    threads: &impl Fn() -> &'a HashMap<u32, SYSTEM_THREAD_INFORMATION>,
+    prime_scheduler: &mut PrimeThreadScheduler,
+    apply_config_result: &mut ApplyConfigResult,
+) {
+    if config.thread_power_throttling == PowerThrottling::None {
+        return;
+    }
+    if dry_run {
+        apply_config_result.add_change(format!(
+            "Thread Power Throttling: non-prime threads -> {}",
+            config.thread_power_throttling.as_str()
+        ));
+        return;
+    }
+
+    let mut counter_changed = 0;
+    for &tid in threads().keys() {
+        let thread_stats = prime_scheduler.get_thread_stats(pid, tid);
+        let is_prime = !thread_stats.pinned_cpu_set_ids.is_empty();
+        let desired = if is_prime {
+            PowerThrottling::None
+        } else {
+            config.thread_power_throttling
+        };
+        if thread_stats.power_throttling == desired {
+            continue;
+        }
+        if thread_stats.handle.is_none() {
+            match get_thread_handle(tid, pid, &config.name) {
+                Some(thread_handle) => thread_stats.handle = Some(thread_handle),
+                None => continue,
+            }
+        }
+        let Some(thread_handle) = thread_stats.handle.as_ref() else {
+            continue;
+        };
+        let r_handle = match thread_handle.r_handle.is_invalid() {
+            true => thread_handle.r_limited_handle,
+            false => thread_handle.r_handle,
+        };
+        let w_handle = match thread_handle.w_handle.is_invalid() {
+            true => thread_handle.w_limited_handle,
+            false => thread_handle.w_handle,
+        };
+
+        let mut current_state = THREAD_POWER_THROTTLING_STATE {
+            Version: THREAD_POWER_THROTTLING_CURRENT_VERSION,
+            ..Default::default()
+        };
+        let current = match unsafe {
+            GetThreadInformation(
+                r_handle,
+                ThreadPowerThrottling,
+                &mut current_state as *mut _ as *mut c_void,
+                size_of::<THREAD_POWER_THROTTLING_STATE>() as u32,
+            )
+        } {
+            Ok(_) => Some(PowerThrottling::from_win_const(current_state.ControlMask, current_state.StateMask)),
+            Err(_) => {
+                let error_code = unsafe { GetLastError().0 };
+                log_error_if_new(
+                    pid,
+                    tid,
+                    &config.name,
+                    Operation::GetThreadInformation2ThreadPowerThrottling,
+                    error_code,
+                    apply_config_result,
+                    || {
+                        format!(
+                            "apply_thread_power_throttling: [QUERY_THREAD_POWER_THROTTLING][{}] {:>5}-{:>5}-{}",
+                            error_from_code_win32(error_code),
+                            pid,
+                            tid,
+                            config.name
+                        )
+                    },
+                );
+                None
+            }
+        };
+        if current == Some(desired) {
+            thread_stats.power_throttling = desired;
+            continue;
+        }
+
+        // None hands the decision back to Windows with an empty control mask
+        let (control_mask, state_mask) = desired.as_win_const().unwrap_or((0, 0));
+        let new_state = THREAD_POWER_THROTTLING_STATE {
+            Version: THREAD_POWER_THROTTLING_CURRENT_VERSION,
+            ControlMask: control_mask,
+            StateMask: state_mask,
+        };
+        match unsafe {
+            SetThreadInformation(
+                w_handle,
+                ThreadPowerThrottling,
+                &new_state as *const _ as *const c_void,
+                size_of::<THREAD_POWER_THROTTLING_STATE>() as u32,
+            )
+        } {
+            Err(_) => {
+                let error_code = unsafe { GetLastError().0 };
+                log_error_if_new(
+                    pid,
+                    tid,
+                    &config.name,
+                    Operation::SetThreadInformation2ThreadPowerThrottling,
+                    error_code,
+                    apply_config_result,
+                    || {
+                        format!(
+                            "apply_thread_power_throttling: [SET_THREAD_POWER_THROTTLING][{}] {:>5}-{:>5}-{} -> {}",
+                            error_from_code_win32(error_code),
+                            pid,
+                            tid,
+                            config.name,
+                            desired.as_str()
+                        )
+                    },
+                );
+            }
+            Ok(_) => counter_changed += 1,
+        }
+        // whether this failed or not, remember the target to avoid retrying every iteration
+        thread_stats.power_throttling = desired;
+    }
+    if counter_changed > 0 {
+        apply_config_result.add_change(format!(
+            "Thread Power Throttling: {} threads -> {} (prime threads excluded)",
+            counter_changed,
+            config.thread_power_throttling.as_str()
+        ));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Two LLCs of two SMT cores each: CPU n is on core n / 2 and LLC n / 4.
+    fn two_llc_topology() -> Vec<CpuSetData> {
+        (0..8u8).map(|cpu| CpuSetData::synthetic(0, cpu, cpu / 2, cpu / 4)).collect()
+    }
+
+    fn plan(strategy: IdealResetStrategy, seed: Option<u64>, thread_count: usize, cpus: &[u32]) -> Vec<u32> {
+        plan_ideal_processor_reset(strategy, seed, thread_count, cpus, &two_llc_topology()).to_vec()
+    }
+
+    const CPUS: &[u32] = &[0, 1, 2, 3, 4, 5, 6, 7];
+
+    #[test]
+    fn round_robin_follows_cpu_list_shifted_by_seed() {
+        assert_eq!(
+            plan(IdealResetStrategy::RoundRobin, Some(0), 10, CPUS),
+            [0, 1, 2, 3, 4, 5, 6, 7, 0, 1]
+        );
+        assert_eq!(plan(IdealResetStrategy::RoundRobin, Some(11), 4, CPUS), [3, 4, 5, 6]);
+    }
+
+    #[test]
+    fn physical_cores_first_uses_siblings_last() {
+        assert_eq!(
+            plan(IdealResetStrategy::PhysicalCoresFirst, None, 8, CPUS),
+            [0, 2, 4, 6, 1, 3, 5, 7]
+        );
+    }
+
+    #[test]
+    fn packed_orders_by_llc_then_core() {
+        let shuffled = [6, 1, 4, 3, 0, 7, 2, 5];
+        assert_eq!(plan(IdealResetStrategy::Packed, None, 8, &shuffled), [0, 1, 2, 3, 4, 5, 6, 7]);
+    }
+
+    #[test]
+    fn spread_interleaves_llcs_cores_first() {
+        assert_eq!(plan(IdealResetStrategy::SpreadLlc, None, 8, CPUS), [0, 4, 2, 6, 1, 5, 3, 7]);
+    }
+
+    #[test]
+    fn seeded_random_is_reproducible() {
+        assert_eq!(plan(IdealResetStrategy::SeededRandom, Some(42), 8, CPUS), [3, 1, 6, 2, 4, 0, 7, 5]);
+        assert_eq!(
+            plan(IdealResetStrategy::SeededRandom, Some(42), 10, CPUS),
+            plan(IdealResetStrategy::SeededRandom, Some(42), 10, CPUS)
+        );
+    }
+
+    #[test]
+    fn locations_are_resolved_per_processor_group() {
+        // CPU 64 is group 1 number 0 on its own core, it must not alias CPU 0 of group 0
+        let mut topology = two_llc_topology();
+        topology.push(CpuSetData::synthetic(1, 0, 0, 0));
+        let order = plan_ideal_processor_reset(IdealResetStrategy::PhysicalCoresFirst, None, 3, &[0, 1, 64], &topology);
+        assert_eq!(order.to_vec(), [0, 64, 1]);
+    }
+}
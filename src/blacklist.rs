@@ -0,0 +1,286 @@
+//! The `-blacklist` file: process names or patterns the service leaves alone, grouped in sections.
+//!
+//! Every section has a scope: `find` only keeps its processes out of `-find` and `-processlogs`,
+//! `rules` also skips every config rule for them, and `never` additionally exempts them from dynamic
+//! restraint. Terminate and suspend actions skip any blacklisted process, whatever the section.
+//!
+//! ```ini
+//! [protected scope=never]
+//! secure system
+//! smss.exe:reason=protected process
+//!
+//! [benchmarks scope=rules]
+//! cinebench*.exe:reason=measure stock scheduling:expires=2026-12-31
+//! ```
+
+use crate::{cli::CliArgs, get_local_time, log, logging::log_to_find, util::glob_match};
+
+use chrono::NaiveDate;
+use std::{
+    fs::{File, metadata},
+    io::{BufRead, BufReader},
+    path::Path,
+    time::SystemTime,
+};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum BlacklistScope {
+    Find,
+    Rules,
+    Never,
+}
+
+impl BlacklistScope {
+    const TABLE: &'static [(Self, &'static str)] = &[(Self::Find, "find"), (Self::Rules, "rules"), (Self::Never, "never")];
+
+    pub fn as_str(&self) -> &'static str {
+        Self::TABLE
+            .iter()
+            .find(|(v, _)| v == self)
+            .map(|(_, name)| *name)
+            .unwrap_or("unknown")
+    }
+
+    pub fn from_str(s: &str) -> Option<Self> {
+        let s = s.to_lowercase();
+        Self::TABLE.iter().find(|(_, name)| *name == s.as_str()).map(|(v, _)| *v)
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct BlacklistEntry {
+    /// Lowercase process name, `*` and `?` match like in machine conditions.
+    pub pattern: String,
+    pub section: String,
+    pub scope: BlacklistScope,
+    pub reason: Option<String>,
+    /// Last day the entry counts, inclusive.
+    pub expires: Option<NaiveDate>,
+    pub line: usize,
+}
+
+impl BlacklistEntry {
+    fn matches(&self, name: &str, today: NaiveDate) -> bool {
+        self.expires.is_none_or(|expires| today <= expires) && glob_match(&self.pattern, name)
+    }
+}
+
+#[derive(Debug, Default)]
+pub struct Blacklist {
+    pub entries: Vec<BlacklistEntry>,
+    pub errors: Vec<String>,
+    pub warnings: Vec<String>,
+}
+
+impl Blacklist {
+    /// Reads a blacklist file, problems are collected as `Line N: ...` errors and warnings like in the config.
+    ///
+    /// Sections start with `[name]` or `[name scope=find|rules|never]`, entries before the first section and
+    /// sections without a scope use `find`. An entry is a name or pattern optionally followed by
+    /// `:reason=text` and `:expires=YYYY-MM-DD`.
+    pub fn read<P: AsRef<Path>>(path: P) -> Blacklist {
+        let mut blacklist = Blacklist::default();
+        let lines: Vec<String> = match File::open(path) {
+            Ok(file) => BufReader::new(file).lines().map_while(Result::ok).collect(),
+            Err(e) => {
+                blacklist.errors.push(format!("Cannot open blacklist file: {}", e));
+                return blacklist;
+            }
+        };
+        let today = get_local_time!().date_naive();
+        let mut section = String::new();
+        let mut scope = BlacklistScope::Find;
+
+        for (i, line) in lines.iter().enumerate() {
+            let line_number = i + 1;
+            let line = line.trim();
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+            if let Some(header) = line.strip_prefix('[').and_then(|rest| rest.strip_suffix(']')) {
+                let (name, header_scope) = match header.split_once("scope=") {
+                    Some((name, header_scope)) => (name.trim(), Some(header_scope.trim())),
+                    None => (header.trim(), None),
+                };
+                section = name.to_lowercase();
+                scope = match header_scope.map(|value| (value, BlacklistScope::from_str(value))) {
+                    None => BlacklistScope::Find,
+                    Some((_, Some(parsed))) => parsed,
+                    Some((value, None)) => {
+                        blacklist.errors.push(format!(
+                            "Line {}: Invalid scope '{}' for section '{}' - use find, rules or never",
+                            line_number, value, section
+                        ));
+                        BlacklistScope::Find
+                    }
+                };
+                continue;
+            }
+
+            let mut parts = line.split(':');
+            let pattern = parts.next().unwrap_or_default().trim().to_lowercase();
+            if pattern.is_empty() {
+                blacklist.errors.push(format!("Line {}: Empty process name", line_number));
+                continue;
+            }
+            let mut entry = BlacklistEntry {
+                pattern,
+                section: section.clone(),
+                scope,
+                reason: None,
+                expires: None,
+                line: line_number,
+            };
+            for part in parts {
+                let Some((key, value)) = part.split_once('=') else {
+                    blacklist.errors.push(format!(
+                        "Line {}: Invalid option '{}' - expected reason=text or expires=YYYY-MM-DD",
+                        line_number,
+                        part.trim()
+                    ));
+                    continue;
+                };
+                let value = value.trim();
+                match key.trim().to_lowercase().as_str() {
+                    "reason" => entry.reason = Some(value.to_string()),
+                    "expires" => match NaiveDate::parse_from_str(value, "%Y-%m-%d") {
+                        Ok(date) => entry.expires = Some(date),
+                        Err(_) => blacklist.errors.push(format!(
+                            "Line {}: Invalid expiry date '{}' - expected YYYY-MM-DD",
+                            line_number, value
+                        )),
+                    },
+                    other => blacklist
+                        .errors
+                        .push(format!("Line {}: Unknown option '{}' - use reason or expires", line_number, other)),
+                }
+            }
+            if let Some(expires) = entry.expires
+                && expires < today
+            {
+                blacklist.warnings.push(format!(
+                    "Line {}: Entry '{}' expired on {} and is ignored",
+                    line_number, entry.pattern, expires
+                ));
+            }
+            if let Some(earlier) = blacklist.entries.iter().find(|earlier| earlier.pattern == entry.pattern) {
+                blacklist.warnings.push(format!(
+                    "Line {}: Redundant entry - '{}' already listed at line {}",
+                    line_number, entry.pattern, earlier.line
+                ));
+            }
+            blacklist.entries.push(entry);
+        }
+        blacklist
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    /// The widest scope of the unexpired entries matching `name`.
+    pub fn scope_of(&self, name: &str) -> Option<BlacklistScope> {
+        let today = get_local_time!().date_naive();
+        self.entries
+            .iter()
+            .filter(|entry| entry.matches(name, today))
+            .map(|entry| entry.scope)
+            .max()
+    }
+
+    /// The section, line and reason of the entry deciding the scope of `name`, for log lines.
+    pub fn describe(&self, name: &str) -> String {
+        let today = get_local_time!().date_naive();
+        let Some(entry) = self
+            .entries
+            .iter()
+            .filter(|entry| entry.matches(name, today))
+            .max_by_key(|entry| entry.scope)
+        else {
+            return String::new();
+        };
+        let section = if entry.section.is_empty() { "unsectioned" } else { &entry.section };
+        match &entry.reason {
+            Some(reason) => format!("blacklist [{}] line {}: {}", section, entry.line, reason),
+            None => format!("blacklist [{}] line {}", section, entry.line),
+        }
+    }
+
+    /// Whether any section lists `name`, which keeps it out of `-find`, `-processlogs` and rule actions.
+    pub fn contains(&self, name: &str) -> bool {
+        self.scope_of(name).is_some()
+    }
+
+    /// Whether config rules must skip `name`, `rules` and `never` sections.
+    pub fn excludes_from_rules(&self, name: &str) -> bool {
+        self.scope_of(name) >= Some(BlacklistScope::Rules)
+    }
+
+    /// Whether nothing may touch `name`, including dynamic restraint.
+    pub fn never_touch(&self, name: &str) -> bool {
+        self.scope_of(name) == Some(BlacklistScope::Never)
+    }
+
+    /// Logs the loaded sections, or every error and warning through the `-find` log like the config report.
+    pub fn print_report(&self) {
+        for error in &self.errors {
+            log_to_find(&format!("✗ Blacklist {}", error));
+        }
+        for warning in &self.warnings {
+            log_to_find(&format!("⚠ Blacklist {}", warning));
+        }
+        if !self.errors.is_empty() {
+            log_to_find(&format!("Found {} blacklist error(s). Fix them before running.", self.errors.len()));
+        }
+        let mut sections: Vec<(&str, BlacklistScope, usize)> = Vec::new();
+        for entry in &self.entries {
+            match sections
+                .iter_mut()
+                .find(|(name, scope, _)| *name == entry.section && *scope == entry.scope)
+            {
+                Some((_, _, count)) => *count += 1,
+                None => sections.push((&entry.section, entry.scope, 1)),
+            }
+        }
+        let summary: Vec<String> = sections
+            .iter()
+            .map(|(name, scope, count)| {
+                let name = if name.is_empty() { "unsectioned" } else { name };
+                format!("{} ({}, {})", name, scope.as_str(), count)
+            })
+            .collect();
+        log!("{} blacklist items loaded: {}", self.entries.len(), summary.join(", "));
+    }
+}
+
+pub fn hotreload_blacklist(cli: &CliArgs, blacklist: &mut Blacklist, last_blacklist_mod_time: &mut Option<SystemTime>) {
+    if let Some(ref blacklist_file) = cli.blacklist_file_name {
+        match metadata(blacklist_file) {
+            Err(_) => {
+                if last_blacklist_mod_time.is_some() {
+                    *last_blacklist_mod_time = None;
+                    log!("Blacklist file '{}' no longer accessible, clearing blacklist.", blacklist_file);
+                    *blacklist = Blacklist::default();
+                }
+            }
+            Ok(metadata) => {
+                if let Ok(mod_time) = metadata.modified()
+                    && Some(mod_time) != *last_blacklist_mod_time
+                {
+                    *last_blacklist_mod_time = Some(mod_time);
+                    log!("Blacklist file '{}' changed, reloading...", blacklist_file);
+                    let new_blacklist = Blacklist::read(blacklist_file);
+                    if new_blacklist.errors.is_empty() {
+                        *blacklist = new_blacklist;
+                        blacklist.print_report();
+                    } else {
+                        log!("Blacklist file '{}' has errors, keeping previous blacklist.", blacklist_file);
+                        for error in &new_blacklist.errors {
+                            log!("  - {}", error);
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
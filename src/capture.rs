@@ -0,0 +1,221 @@
+//! `-capture`: writes a config reproducing how the running processes are tuned right now.
+//!
+//! Meant for onboarding a machine tuned by hand or by another tool. The priority class, affinity, CPU set,
+//! IO priority and memory priority of every running process are read, values at the Windows defaults
+//! become `none` / `0`, and processes left entirely at the defaults are not written. Processes with
+//! identical settings are grouped like `-autogroup` does, and CPU lists equal to an alias of the `-config`
+//! file are written as that alias.
+
+use crate::{
+    apply::{OriginalProcessState, read_process_state},
+    blacklist::Blacklist,
+    cli::{CliArgs, get_config_help_lines},
+    collections::{CONSUMER_CPUS, HashMap, List},
+    config::{format_cpu_indices, push_grouped_rules, read_config_as},
+    drift::DriftAttribute,
+    get_local_time, log,
+    machine::MachineInfo,
+    priority::{IOPriority, MemoryPriority, ProcessPriority},
+    process::{PID_TO_PROCESS_MAP, ProcessSnapshot, SNAPSHOT_BUFFER},
+    util::glob_match,
+    winapi::{enable_debug_privilege, get_process_handle, indices_from_cpusetids, is_running_as_admin},
+};
+
+use std::{fs::write, path::Path};
+
+/// The attributes a captured rule sets, power throttling is left out.
+const CAPTURED_ATTRIBUTES: &[DriftAttribute] = &[
+    DriftAttribute::Priority,
+    DriftAttribute::Affinity,
+    DriftAttribute::CpuSet,
+    DriftAttribute::IoPriority,
+    DriftAttribute::MemoryPriority,
+];
+
+/// Captures every running process matching `-filter` and not blacklisted into the `-out` config.
+pub fn capture_config(cli: &CliArgs) {
+    let out_path = cli.out_file_name.as_deref().unwrap_or("captured_config.ini");
+    enable_debug_privilege(cli.no_debug_priv);
+    if !is_running_as_admin() {
+        log!("Not running as administrator, processes of other users and services may be skipped.");
+    }
+
+    // only the aliases are taken from the config, its rules do not matter here
+    let mut aliases: Vec<(String, List<[u32; CONSUMER_CPUS]>)> = if Path::new(&cli.config_file_name).exists() {
+        read_config_as(&cli.config_file_name, cli.profile.as_deref(), MachineInfo::current())
+            .cpu_aliases
+            .into_iter()
+            .filter(|(_, cpus)| !cpus.is_empty())
+            .collect()
+    } else {
+        Vec::new()
+    };
+    aliases.sort();
+    let blacklist = match &cli.blacklist_file_name {
+        Some(blacklist_file) => {
+            let blacklist = Blacklist::read(blacklist_file);
+            blacklist.print_report();
+            blacklist
+        }
+        None => Blacklist::default(),
+    };
+    let filter = cli.capture_filter.as_ref().map(|filter| filter.to_lowercase());
+
+    let mut processes: Vec<(String, u32)> = {
+        let buffer = &mut SNAPSHOT_BUFFER.lock().unwrap();
+        let pid_to_process = &mut PID_TO_PROCESS_MAP.lock().unwrap();
+        match ProcessSnapshot::take(buffer, pid_to_process) {
+            Ok(snapshot) => snapshot
+                .pid_to_process
+                .values()
+                .map(|process| (process.get_name().to_string(), process.pid()))
+                .collect(),
+            Err(err) => {
+                log!("Failed to take process snapshot: {}", err);
+                return;
+            }
+        }
+    };
+    processes.sort();
+
+    let own_pid = std::process::id();
+    let mut used_aliases: Vec<&str> = Vec::new();
+    let mut captured: HashMap<String, String> = HashMap::default();
+    let mut rule_order: Vec<String> = Vec::new();
+    let mut rule_to_members: HashMap<String, Vec<String>> = HashMap::default();
+    let mut differing: Vec<String> = Vec::new();
+    let (mut skipped, mut unreadable, mut at_defaults) = (0usize, 0usize, 0usize);
+    for (name, pid) in &processes {
+        if *pid == 0 || *pid == own_pid || name.is_empty() {
+            continue;
+        }
+        if blacklist.contains(name) || filter.as_ref().is_some_and(|filter| !glob_match(filter, name)) {
+            skipped += 1;
+            continue;
+        }
+        let Some(process_handle) = get_process_handle(*pid, name) else {
+            unreadable += 1;
+            continue;
+        };
+        let state = read_process_state(CAPTURED_ATTRIBUTES, &process_handle);
+        let Some(rule) = rule_from_state(&state, &aliases, &mut used_aliases) else {
+            at_defaults += 1;
+            continue;
+        };
+        // instances are visited by PID, the lowest one decides the rule
+        match captured.get(name) {
+            Some(existing) => {
+                if *existing != rule && !differing.contains(name) {
+                    differing.push(name.clone());
+                }
+            }
+            None => {
+                captured.insert(name.clone(), rule.clone());
+                if !rule_to_members.contains_key(&rule) {
+                    rule_order.push(rule.clone());
+                }
+                rule_to_members.entry(rule).or_default().push(name.clone());
+            }
+        }
+    }
+
+    let mut output_lines: Vec<String> = get_config_help_lines().iter().map(|line| line.to_string()).collect();
+    output_lines.push(String::new());
+    output_lines.push(format!(
+        "# Captured from the running system on {}",
+        get_local_time!().format("%Y-%m-%d %H:%M")
+    ));
+    if !differing.is_empty() {
+        output_lines.push(format!(
+            "# Instances with different settings, the lowest PID was captured: {}",
+            differing.join(", ")
+        ));
+    }
+    output_lines.push(String::new());
+    if !used_aliases.is_empty() {
+        output_lines.push(format!("# CPU Aliases (from {})", cli.config_file_name));
+        for (alias, cpus) in aliases.iter().filter(|(alias, _)| used_aliases.contains(&alias.as_str())) {
+            output_lines.push(format!("*{} = {}", alias, format_cpu_indices(cpus)));
+        }
+        output_lines.push(String::new());
+    }
+    let (single_count, grouped_member_count, group_count) = push_grouped_rules(&rule_order, &mut rule_to_members, &mut output_lines);
+    while output_lines.last().is_some_and(|line| line.trim().is_empty()) {
+        output_lines.pop();
+    }
+    output_lines.push(String::new());
+
+    if let Err(e) = write(out_path, output_lines.join("\n")) {
+        log!("Failed to write {}: {}", out_path, e);
+        return;
+    }
+    log!(
+        "Captured {} processes → {} individual rules + {} processes in {} groups ({} at defaults, {} unreadable, {} filtered or blacklisted)",
+        captured.len(),
+        single_count,
+        grouped_member_count,
+        group_count,
+        at_defaults,
+        unreadable,
+        skipped
+    );
+    log!("Written to {}", out_path);
+}
+
+/// The fields after `name:` of a captured process, `None` when every readable attribute is at its default.
+fn rule_from_state<'a>(
+    state: &OriginalProcessState,
+    aliases: &'a [(String, List<[u32; CONSUMER_CPUS]>)],
+    used_aliases: &mut Vec<&'a str>,
+) -> Option<String> {
+    let mut cpu_spec = |cpus: List<[u32; CONSUMER_CPUS]>| match aliases.iter().find(|(_, alias_cpus)| *alias_cpus == cpus) {
+        Some((alias, _)) => {
+            if !used_aliases.contains(&alias.as_str()) {
+                used_aliases.push(alias);
+            }
+            format!("*{}", alias)
+        }
+        None => format_cpu_indices(&cpus),
+    };
+
+    let priority = state
+        .priority_class
+        .map(ProcessPriority::from_win_const)
+        .filter(|priority| *priority != ProcessPriority::Normal.as_str() && *priority != "unknown")
+        .unwrap_or("none");
+    let affinity = match (state.affinity_mask, state.system_affinity_mask) {
+        (Some(mask), Some(system_mask)) if mask != 0 && mask != system_mask => {
+            // the mask is relative to the process's processor group, config indices span 64 per group
+            let group_offset = state.affinity_group.unwrap_or(0) as u32 * 64;
+            cpu_spec(
+                (0..usize::BITS)
+                    .filter(|cpu| (mask >> cpu) & 1 == 1)
+                    .map(|cpu| cpu + group_offset)
+                    .collect(),
+            )
+        }
+        _ => "0".to_string(),
+    };
+    let cpuset = match &state.cpu_set_ids {
+        Some(cpu_set_ids) if !cpu_set_ids.is_empty() => cpu_spec(indices_from_cpusetids(cpu_set_ids)),
+        _ => "0".to_string(),
+    };
+    let io_priority = state
+        .io_priority
+        .map(IOPriority::from_win_const)
+        .filter(|io| *io != IOPriority::Normal.as_str() && *io != "unknown")
+        .unwrap_or("none");
+    let memory_priority = state
+        .memory_priority
+        .map(MemoryPriority::from_win_const)
+        .filter(|memory| *memory != MemoryPriority::Normal.as_str() && *memory != "unknown")
+        .unwrap_or("none");
+
+    if priority == "none" && affinity == "0" && cpuset == "0" && io_priority == "none" && memory_priority == "none" {
+        return None;
+    }
+    Some(format!(
+        "{}:{}:{}:0:{}:{}",
+        priority, affinity, cpuset, io_priority, memory_priority
+    ))
+}
@@ -8,6 +8,7 @@ pub struct CliArgs {
     pub help_all_mode: bool,
     pub convert_mode: bool,
     pub autogroup_mode: bool,
+    pub capture_mode: bool,
     pub find_mode: bool,
     pub validate_mode: bool,
     pub process_logs_mode: bool,
@@ -25,6 +26,26 @@ pub struct CliArgs {
     pub no_inc_base_priority: bool,
     pub no_etw: bool,
     pub continuous_process_level_apply: bool,
+    pub verify: bool,
+    pub profile: Option<String>,
+    pub switch_profile: Option<String>,
+    pub explain: Option<String>,
+    pub list_groups_mode: bool,
+    /// `-processlogs` path lookup backend: `auto`, `es` or `fs`.
+    pub resolver: Option<String>,
+    /// `;` separated roots of the built-in path search.
+    pub search_roots: Option<String>,
+    /// `;` separated directory patterns the built-in path search skips.
+    pub search_exclude: Option<String>,
+    pub search_cache: Option<String>,
+    /// Machine facts replaced for `-validate` and `-explain`, `--as host=...,cpus=...`.
+    pub machine_as: Option<String>,
+    /// Process name pattern `-capture` is limited to, `*` and `?` wildcards.
+    pub capture_filter: Option<String>,
+    /// Loops a `-dryrun` samples, by default enough for the prime thread selection when there are thread-level rules.
+    pub dry_run_samples: Option<u32>,
+    /// File the `-dryrun` changes of every sample are written to.
+    pub report_file_name: Option<String>,
 }
 
 impl CliArgs {
@@ -59,6 +80,9 @@ pub fn parse_args(args: &[String], cli: &mut CliArgs) -> Result<()> {
             "-autogroup" => {
                 cli.autogroup_mode = true;
             }
+            "-capture" => {
+                cli.capture_mode = true;
+            }
             "-find" => {
                 cli.find_mode = true;
             }
@@ -66,12 +90,24 @@ pub fn parse_args(args: &[String], cli: &mut CliArgs) -> Result<()> {
                 cli.validate_mode = true;
                 *get_use_console!() = true;
             }
+            "-list-groups" | "-listgroups" => {
+                cli.list_groups_mode = true;
+                *get_use_console!() = true;
+            }
             "-processlogs" => {
                 cli.process_logs_mode = true;
             }
             "-dryrun" | "-dry-run" | "--dry-run" => {
                 cli.dry_run = true;
             }
+            "-samples" if i + 1 < args.len() => {
+                cli.dry_run_samples = Some(args[i + 1].parse().unwrap_or(1).max(1));
+                i += 1;
+            }
+            "-report" if i + 1 < args.len() => {
+                cli.report_file_name = Some(args[i + 1].clone());
+                i += 1;
+            }
             "-interval" if i + 1 < args.len() => {
                 cli.interval_ms = args[i + 1].parse().unwrap_or(5000).clamp(16, 86400000);
                 i += 1;
@@ -91,10 +127,47 @@ pub fn parse_args(args: &[String], cli: &mut CliArgs) -> Result<()> {
                 cli.config_file_name = args[i + 1].clone();
                 i += 1;
             }
+            "-profile" if i + 1 < args.len() => {
+                cli.profile = Some(args[i + 1].clone());
+                i += 1;
+            }
+            "-explain" if i + 1 < args.len() => {
+                cli.explain = Some(args[i + 1].clone());
+                i += 1;
+            }
+            "--as" | "-as" if i + 1 < args.len() => {
+                cli.machine_as = Some(args[i + 1].clone());
+                i += 1;
+            }
+            "-switch_profile" if i + 1 < args.len() => {
+                cli.switch_profile = Some(args[i + 1].clone());
+                *get_use_console!() = true;
+                i += 1;
+            }
             "-blacklist" if i + 1 < args.len() => {
                 cli.blacklist_file_name = Some(args[i + 1].clone());
                 i += 1;
             }
+            "-filter" if i + 1 < args.len() => {
+                cli.capture_filter = Some(args[i + 1].clone());
+                i += 1;
+            }
+            "-resolver" if i + 1 < args.len() => {
+                cli.resolver = Some(args[i + 1].clone());
+                i += 1;
+            }
+            "-search_roots" if i + 1 < args.len() => {
+                cli.search_roots = Some(args[i + 1].clone());
+                i += 1;
+            }
+            "-search_exclude" if i + 1 < args.len() => {
+                cli.search_exclude = Some(args[i + 1].clone());
+                i += 1;
+            }
+            "-search_cache" if i + 1 < args.len() => {
+                cli.search_cache = Some(args[i + 1].clone());
+                i += 1;
+            }
             "-in" if i + 1 < args.len() => {
                 cli.in_file_name = Some(args[i + 1].clone());
                 i += 1;
@@ -119,6 +192,9 @@ pub fn parse_args(args: &[String], cli: &mut CliArgs) -> Result<()> {
             "-continuous_process_level_apply" => {
                 cli.continuous_process_level_apply = true;
             }
+            "-verify" => {
+                cli.verify = true;
+            }
             _ => {}
         }
         i += 1;
@@ -140,6 +216,8 @@ pub fn print_help() {
       -config <file>       config file to use (default: config.ini)
       -find                find processes with default affinity (-blacklist <file>)
       -interval <ms>       check interval in milliseconds (default: 5000)
+      -profile <name>      start with a [profile name] block of the config active
+      -verify              re-check applied processes every grade tick and re-apply only drifted settings
 
       -noUAC               disable UAC elevation request
       -resolution <t>      time resolution 5210 -> 0.5210ms (default: 0, 0 means do not set time resolution)
@@ -147,9 +225,14 @@ pub fn print_help() {
     Modes:
       -validate            validate config file syntax without running
       -processlogs         process logs (from -find mode) to find new processes and search paths (-config <file> -blacklist <file> -in <logs dir> -out <file>)
-      -dryrun              show what would be changed without applying
+      -dryrun              show current -> desired values without applying (-samples <n> -report <file>)
       -convert             convert Process Lasso config (-in <file> -out <file>)
       -autogroup           auto-group rules with identical settings (-in <file> -out <file>)
+      -capture             write a config from the current settings of running processes (-out <file> -filter <glob>)
+      -switch_profile <name>  switch the running service to another profile (none = no profile)
+      -explain <process>   show the effective rule of a process and where each field came from
+      -list-groups         list process groups with their members, metadata and effective settings
+      --as <machine>       with -validate/-explain, read [if] blocks as on another machine (host=,cpu=,cpus=,ecores=,groups=)
     "#
     );
 }
@@ -168,20 +251,39 @@ pub fn print_cli_help() {
           -helpall | --helpall              print this detailed help with debug options
           -console                          use console as output instead of log file
           -noUAC | -nouac                   disable UAC elevation request
-          -config <file>                    the config file u wanna use (config.ini by default)
+          -config <file>                    the config file u wanna use (config.ini by default), or a directory of *.ini files
           -find                             find those whose affinity is same as system default which is all possible cores windows could use
-          -blacklist <file>                 the blacklist for -find
+                                            and record their behavior stats (cpu, threads, lifetime, foreground, io) for -processlogs
+          -blacklist <file>                 the blacklist, [section scope=find|rules|never] with name or pattern entries (:reason=, :expires=)
           -interval <ms>                    set interval for checking again (5000 by default, minimal 16)
           -resolution <t>                   time resolution 5210 -> 0.5210ms (default: 0, 0 means do not set time resolution)
+          -profile <name>                   activate a [profile name] block of the config at startup
+          -verify                           read back priority, affinity, CPU set, IO/memory priority and power throttling of applied processes on every grade tick, re-apply only what drifted and count drifts per process
 
           Operating Modes:
           -validate                         validate config file for syntax errors and undefined aliases then exit
-          -processlogs                      process logs (from -find mode) to find new processes and search paths with everything (-config <file> -blacklist <file> -in <logs dir> -out <file>)
-          -dryrun                           simulate changes without applying (shows what would happen)
+          -processlogs                      process logs (from -find mode) to find new processes and search their paths (-config <file> -blacklist <file> -in <logs dir> -out <file>)
+                                            and suggest game / background / updater rules from their recorded stats
+          -resolver <auto|es|fs>            path lookup for -processlogs: es = Everything's es.exe, fs = built-in search, auto = es, falling back to fs (default)
+          -search_roots <dir;dir>           roots of the built-in search (default: Program Files, Program Files (x86), ProgramData, LOCALAPPDATA, APPDATA)
+          -search_exclude <glob;glob>       directory names or paths the built-in search skips (default: $recycle.bin;system volume information;*\windows\winsxs;node_modules;.git)
+          -search_cache <file>              index cache of the built-in search, reused for a day (default: <logs dir>/path_index.cache)
+          -dryrun                           simulate changes without applying, logs current -> desired per attribute
+          -samples <n>                      loops a dry run samples to simulate prime/ideal thread selection
+                                            (default: 1, or enough for MIN_ACTIVE_STREAK with thread-level rules)
+          -report <file>                    also write the dry run changes of every sample and a summary to a file
           -convert                          convert process configs from -in <file>(from process lasso) to -out <file>
           -autogroup                        auto-group rules with identical settings into named group blocks (-in <file> -out <file>)
+          -capture                          write a config reproducing the priority, affinity, cpu set, io and memory priority of running processes,
+                                            grouped like -autogroup, cpu lists written as aliases of -config (-out <file>, default: captured_config.ini)
+          -filter <glob>                    only capture processes whose name matches, e.g. -filter "*game*.exe" (-blacklist <file> is honored too)
+          -switch_profile <name>            write <name> to <config>.profile, the running service switches to it (none = no profile)
+          -explain <process>                print the effective rule of <process> with the template, rule or default each field came from
+          -list-groups                      print every process group with owner/reason/ticket, enabled state, members (referenced groups expanded), effective settings and member overrides
+          --as <machine>                    with -validate or -explain, select [if] blocks as on the described machine instead of this one
+                                            e.g. --as "cpu=AMD Ryzen 9 7950X3D 16-Core Processor,cpus=32,ecores=0,groups=1"
           -in <file>                        input file for -convert / logs directory for -processlogs (default: logs)
-          -out <file>                       output file for -convert / results file for -processlogs (default: new_processes_results.txt)
+          -out <file>                       output file for -convert / -capture / results file for -processlogs (default: new_processes_results.txt)
 
           Debug & Testing Options:
           -loop <count>                     number of loops to run (default: infinite) - for testing
@@ -295,10 +397,72 @@ pub fn get_config_help_lines() -> Vec<&'static str> {
         ##     *p@worker*e@background      - Alias *p for worker, *e for background
         ##
         ## ----------------------------------------------------------------------------
+        ## THREAD SELECTORS
+        ## ----------------------------------------------------------------------------
+        ##   Prefixes after @ in prime_cpus and ideal_processor accept these selectors:
+        ##     engine.dll                 - Start module prefix (case-insensitive)
+        ##     engine.dll+0x1A30          - Exact start address
+        ##     engine.dll+0x1000-0x2000   - Start address within an offset range (inclusive)
+        ##     ~RenderThread              - Thread description prefix (SetThreadDescription)
+        ##     !ntdll.dll                 - Exclusion: leading ! removes matching threads
+        ##
+        ##   A thread is selected when it matches any inclusion (or there are none)
+        ##   and no exclusion. In prime prefixes a trailing !priority still sets the
+        ##   thread priority, e.g. *p@~GameThread!highest;!ntdll.dll
+        ##
+        ##   Prime prefixes accept a thread quota, (max) or (min-max), limiting how
+        ##   many prime slots that prefix may claim; min slots are reserved:
+        ##     *p@engine.dll(1-2);render.dll*e@helper.dll(2)
+        ##
+        ## ----------------------------------------------------------------------------
+        ## RULE OPTIONS
+        ## ----------------------------------------------------------------------------
+        ##   Named options can be appended to any rule as extra `option=value` fields:
+        ##     game.exe:high:*a:0:*p:normal:none:0:1:placement=core
+        ##
+        ##   placement        - How each prime thread is placed within its prime CPUs (default: all)
+        ##                        all    - every prime thread gets the whole prime CPU list
+        ##                        core   - one prime thread per physical core, SMT sibling left idle
+        ##                        pack   - pack the process's prime threads into one LLC (CCD/L3)
+        ##                        spread - spread the process's prime threads across LLCs
+        ##   pin              - How prime threads are pinned (default: cpuset)
+        ##                        cpuset   - SetThreadSelectedCpuSets, soft preference
+        ##                        affinity - SetThreadAffinityMask, strict (group 0 CPUs only)
+        ##                        ideal    - ideal processor only, on the first placed CPU no other prime thread has
+        ##   power            - Process power throttling / EcoQoS: on, off, none (default: none)
+        ##   thread_power     - Power throttling for non-prime threads: on, off, none (default: none)
+        ##   cpu_rate         - Job object CPU rate hard cap in percent (1-100)
+        ##   working_set      - Job object max working set per process (e.g. 512M), or min-max (64M-512M)
+        ##   commit           - Job object max committed memory for the whole job (e.g. 2G)
+        ##   processes        - Job object max active process count
+        ##                        Group members share one job; single rules get their own
+        ##   job              - Name of the job object, rules naming the same job share it
+        ##                        Processes can never leave a job: removing the rule only
+        ##                        lifts the job's limits, the processes stay in it
+        ##   while            - Rule only active while any listed process runs (e.g. cs2.exe;valorant.exe)
+        ##                        !a.exe;b.exe = while none of them runs; reverted when it stops holding
+        ##   schedule         - Rule only active in local-time windows (e.g. mon-fri@0900-1800;sat@1000-1400)
+        ##                        days: daily, weekdays, weekends, mon-fri, mon,wed; 2200-0600 wraps midnight
+        ##   delay            - Hold the rule back until this long after process start (5, 500ms, 2m)
+        ##   stages           - Apply at each time after process start (e.g. 0;5;30), not with delay
+        ##   on_module        - Re-apply once each listed module is loaded (e.g. d3d11.dll;nvapi64.dll)
+        ##   action           - terminate: kill matching processes on sight (default: none)
+        ##   suspend_while    - Suspend while any trigger runs, resume after (e.g. bench.exe;3dmark.exe)
+        ##                        Blacklisted and critical system processes are never touched
+        ##   reset            - Ideal processor reset distribution (default: round_robin)
+        ##                        round_robin - CPU list order with a random shift
+        ##                        cores_first - one thread per physical core before SMT siblings
+        ##                        packed      - fill cores and LLCs in order
+        ##                        spread      - interleave LLCs
+        ##                        random      - shuffled CPU list
+        ##                      Can also be given with the cpuset @ prefix: @packed@*p resets over *p packed
+        ##   seed             - Fixed seed for round_robin shift / random shuffle (reproducible resets)
+        ##
+        ## ----------------------------------------------------------------------------
         ## PROCESS GROUPS
         ## ----------------------------------------------------------------------------
         ##   Group multiple processes with the same rule using { } syntax.
-        ##   Group name is optional (for documentation/debugging only):
+        ##   Group name is optional, named groups can be referenced by later groups:
         ##
         ##   # Named group (multi-line)
         ##   group_name {
@@ -313,6 +477,88 @@ pub fn get_config_help_lines() -> Vec<&'static str> {
         ##   # Anonymous group (no name)
         ##   { notepad.exe: calc.exe }:none:*e:0:0:low:none:0:1
         ##
+        ##   # Members only, no rule: referenced with @name
+        ##   shooters { cs2.exe: valorant.exe }
+        ##   games {
+        ##       @shooters: minecraft.exe
+        ##       owner = alice                           - Metadata shown by -list-groups
+        ##       reason = stutters on E-cores
+        ##       ticket = OPS-142
+        ##       enabled = true                          - false disables the group's rule
+        ##   }:high:*p:0:*p:normal:none
+        ##   cs2.exe:io=high                             - Member rule, the group rule fills the rest
+        ##
+        ##   -list-groups prints members, metadata, settings and member overrides.
+        ##
+        ## ----------------------------------------------------------------------------
+        ## PROFILES
+        ## ----------------------------------------------------------------------------
+        ##   Lines inside a profile block only count while that profile is active.
+        ##   Its constants, aliases and rules override global ones of the same name.
+        ##
+        ##   [profile gaming]
+        ##   cs2.exe:high:*p:0:*p:normal:none
+        ##   [end]
+        ##
+        ##   [profile night schedule=daily@2200-0600]  - schedule applied to every rule inside
+        ##
+        ##   Select at startup with -profile gaming. Switch a running service with
+        ##   -switch_profile <name> or by writing the name into <config>.profile.
+        ##
+        ## ----------------------------------------------------------------------------
+        ## TEMPLATES
+        ## ----------------------------------------------------------------------------
+        ##   &game = high:*p:0:*p:normal:none               - Template, fields as in a rule
+        ##   &game_e = &game:cpuset=*e                      - Extends a template by field name
+        ##   cs2.exe:&game:io=high                          - Rule extending a template
+        ##   Field names: priority affinity cpuset prime io memory ideal grade
+        ##   -explain <process> shows where every effective field came from.
+        ##
+        ## ----------------------------------------------------------------------------
+        ## INCLUDES
+        ## ----------------------------------------------------------------------------
+        ##   @INCLUDE = base.ini                         - Read another file here (error if missing)
+        ##   @INCLUDE_IF_EXISTS = machines/%COMPUTERNAME%.ini - Same, skipped if missing
+        ##   @INCLUDE_DIR = conf.d                       - Every *.ini of the directory, by name
+        ##   -config <directory> reads all *.ini files of the directory the same way.
+        ##   Paths are relative to the including file. Later files override earlier ones:
+        ##   constants and aliases are replaced, a rule for the same process replaces the
+        ##   earlier rule (a duplicate within one file is still warned about).
+        ##
+        ## ----------------------------------------------------------------------------
+        ## MACHINE CONDITIONS
+        ## ----------------------------------------------------------------------------
+        ##   Lines of an [if] block only count on machines matching its condition.
+        ##   All space separated terms must hold, ! negates a term. Blocks may nest.
+        ##
+        ##   [if cpu=*7950X3D*]                           - CPU brand glob (* and ?, no case)
+        ##   *cache = 0-7;16-23
+        ##   [elif ecores cpus>=20]                       - Hybrid CPU with 20+ logical CPUs
+        ##   *p = 0-15
+        ##   [elif host=LAPTOP-*]                         - Host name glob
+        ##   [else]
+        ##   [endif]
+        ##
+        ##   Terms: host=glob cpu=glob (also !=), cpus and groups with = != > >= < <=, ecores
+        ##   Check another machine's branch: -validate --as "cpu=Intel(R) Core(TM) i7-14700K,cpus=28,ecores=1"
+        ##
+        ## ----------------------------------------------------------------------------
+        ## DYNAMIC RESTRAINT
+        ## ----------------------------------------------------------------------------
+        ##   Processes without a rule that keep hogging the CPU while the system is busy
+        ##   are temporarily restrained and restored once they calm down.
+        ##   Shares are percent of the total CPU time of all logical processors.
+        ##
+        ##   @RESTRAINT_CPU_SHARE = 25      - Hot threshold per process (0 = disabled, default)
+        ##   @RESTRAINT_SYSTEM_BUSY = 50    - Only restrain while total CPU usage is above this
+        ##   @RESTRAINT_SUSTAIN = 3         - Consecutive hot samples (loops) before restraining
+        ##   @RESTRAINT_RELEASE_SHARE = 12  - Calm threshold (default: half of CPU_SHARE)
+        ##   @RESTRAINT_CALM = 5            - Consecutive calm samples before restoring
+        ##   @RESTRAINT_PRIORITY = idle     - Priority class while restrained (default: below normal)
+        ##   @RESTRAINT_CPUSET = *e         - CPU set while restrained (default: unchanged)
+        ##   @RESTRAINT_EXEMPT = a.exe;b.exe - Never restrain these
+        ##   @RESTRAINT_LOG = 1             - Log restrain/release events to logs/*.restraint.log
+        ##
         ## ============================================================================"#,
     ]
 }
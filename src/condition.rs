@@ -0,0 +1,166 @@
+//! Activation of rules carrying a `while` condition.
+//!
+//! Conditional rules bypass the once-per-PID `process_level_applied` bookkeeping: every loop the
+//! condition is evaluated against the running processes. When it starts to hold, the process is queued
+//! for a regular apply, which captures its original state in [`OriginalStates`]; when it stops holding,
+//! that state is written back and prime threads are demoted. Schedules are evaluated the
+//! same way against the local time passed in by the main loop.
+
+use crate::{
+    apply::{ApplyConfigResult, apply_prime_threads_demote},
+    collections::{HashMap, HashSet, List, PENDING, PIDS},
+    config::{ConfigResult, RuleCondition},
+    event_trace::EtwProcessEvent,
+    log,
+    originals::OriginalStates,
+    process::ProcessEntry,
+    scheduler::PrimeThreadScheduler,
+};
+
+use chrono::Weekday;
+use std::fmt::Write;
+
+#[derive(Debug)]
+struct ActiveRule {
+    name: String,
+    grade: u32,
+}
+
+#[derive(Debug, Default)]
+pub struct ConditionTracker {
+    active: HashMap<u32, ActiveRule>,
+    /// Running processes named in any condition, their exit flips conditions without waiting for the next interval.
+    condition_pids: HashSet<u32>,
+    has_conditions: bool,
+    has_schedules: bool,
+}
+
+impl ConditionTracker {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Whether a rule with this condition may be applied to `pid` in the current loop.
+    pub fn allows(&self, pid: u32, condition: &Option<RuleCondition>) -> bool {
+        condition.is_none() || self.active.contains_key(&pid)
+    }
+
+    /// Whether an ETW event may flip a condition.
+    pub fn is_tracked(&self, event: &EtwProcessEvent) -> bool {
+        self.has_conditions && (event.is_start || self.condition_pids.contains(&event.pid))
+    }
+
+    /// Whether any rule carries a schedule, which needs polling since no event announces the clock.
+    pub fn has_schedules(&self) -> bool {
+        self.has_schedules
+    }
+
+    /// Evaluates every conditional rule against the running processes.
+    ///
+    /// Newly activated processes are pushed to `process_level_pending` so they are applied in this loop;
+    /// deactivated ones are restored and removed from `process_level_applied`.
+    #[allow(clippy::too_many_arguments)]
+    pub fn update(
+        &mut self,
+        pids_and_names: &[(u32, &str)],
+        pid_to_process: &HashMap<u32, ProcessEntry>,
+        configs: &ConfigResult,
+        prime_core_scheduler: &mut PrimeThreadScheduler,
+        original_states: &mut OriginalStates,
+        process_level_applied: &mut List<[u32; PIDS]>,
+        process_level_pending: &mut List<[u32; PENDING]>,
+        now: (Weekday, u16),
+        dry_run: bool,
+    ) {
+        let running: HashSet<&str> = pids_and_names.iter().map(|(_, name)| *name).collect();
+        let mut condition_names: HashSet<&str> = HashSet::default();
+        let mut wanted: HashMap<u32, (u32, &str, &RuleCondition)> = HashMap::default();
+        let mut has_schedules = false;
+        let grades = configs.process_level_configs.keys().chain(configs.thread_level_configs.keys());
+        for grade in grades {
+            for (pid, name) in pids_and_names {
+                let condition = configs
+                    .process_level_configs
+                    .get(grade)
+                    .and_then(|graded| graded.get(*name))
+                    .and_then(|config| config.condition.as_ref())
+                    .or_else(|| {
+                        configs
+                            .thread_level_configs
+                            .get(grade)
+                            .and_then(|graded| graded.get(*name))
+                            .and_then(|config| config.condition.as_ref())
+                    });
+                let Some(condition) = condition else {
+                    continue;
+                };
+                condition_names.extend(condition.processes.iter().map(String::as_str));
+                has_schedules |= condition.schedule.is_some();
+                if condition.is_met(&running, now) {
+                    wanted.insert(*pid, (*grade, *name, condition));
+                }
+            }
+        }
+        self.has_conditions = !condition_names.is_empty();
+        self.has_schedules = has_schedules;
+        self.condition_pids = pids_and_names
+            .iter()
+            .filter(|(_, name)| condition_names.contains(name))
+            .map(|(pid, _)| *pid)
+            .collect();
+
+        let deactivated: List<[u32; PENDING]> = self.active.keys().filter(|pid| !wanted.contains_key(pid)).copied().collect();
+        for pid in deactivated {
+            let Some(rule) = self.active.remove(&pid) else {
+                continue;
+            };
+            process_level_applied.retain(|applied| *applied != pid);
+            // a pid that is gone or reused by another process has nothing left to restore
+            let Some(process) = pid_to_process.get(&pid).filter(|process| process.get_name() == rule.name) else {
+                prime_core_scheduler.drop_process_by_pid(&pid);
+                continue;
+            };
+            let mut result = ApplyConfigResult::new();
+            // restored even when a reload removed the rule, the captured values belong to the process
+            original_states.restore(pid, &mut result);
+            if let Some(config) = configs
+                .thread_level_configs
+                .get(&rule.grade)
+                .and_then(|graded| graded.get(&rule.name))
+                && prime_core_scheduler.pid_to_process_stats.contains_key(&pid)
+            {
+                let threads = process.get_threads();
+                apply_prime_threads_demote(pid, config, dry_run, &|| &threads, &[], prime_core_scheduler, &mut result);
+            }
+            prime_core_scheduler.drop_process_by_pid(&pid);
+            log_deactivation(pid, &rule.name, &result);
+        }
+
+        for (pid, (grade, name, condition)) in wanted {
+            if self.active.contains_key(&pid) {
+                continue;
+            }
+            self.active.insert(
+                pid,
+                ActiveRule {
+                    name: name.to_string(),
+                    grade,
+                },
+            );
+            process_level_applied.retain(|applied| *applied != pid);
+            if !process_level_pending.contains(&pid) {
+                process_level_pending.push(pid);
+            }
+            log!("{:>5}::{}::Condition: -> active ({})", pid, name, condition);
+        }
+    }
+}
+
+fn log_deactivation(pid: u32, name: &str, result: &ApplyConfigResult) {
+    let mut message = format!("{:>5}::{}::Condition: -> inactive", pid, name);
+    for change in &result.changes {
+        let _ = write!(message, ", {}", change);
+    }
+    log!("{}", message);
+    result.log_errors();
+}
@@ -1,32 +1,425 @@
 use crate::{
+    apply::{ApplyConfigResult, release_prime_threads},
     cli::{CliArgs, get_config_help_lines},
-    collections::{CONSUMER_CPUS, HashMap, HashSet, List, PIDS},
+    collections::{CONSUMER_CPUS, HashMap, HashSet, List, PENDING, PIDS},
+    error_codes::error_from_code_win32,
     log,
     logging::{log_message, log_to_find},
-    priority::{IOPriority, MemoryPriority, ProcessPriority, ThreadPriority},
+    machine::MachineInfo,
+    originals::OriginalStates,
+    priority::{IOPriority, MemoryPriority, PowerThrottling, ProcessPriority, ThreadPriority},
+    process::ProcessEntry,
+    restraint::RestraintSettings,
     scheduler::PrimeThreadScheduler,
+    winapi::release_jobs_except,
 };
 
+use chrono::Weekday;
 use std::{
-    fs::{File, metadata, read, read_to_string},
+    fmt,
+    fs::{File, metadata, read, read_dir, read_to_string},
     io::{BufRead, BufReader, Result, Write},
-    path::Path,
+    path::{Path, PathBuf},
+    time::SystemTime,
 };
 
-#[derive(Debug, Clone)]
+/// What a [`ThreadSelector`] compares a thread against.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ThreadMatcher {
+    /// `engine.dll` - start module prefix, as `resolve_address_to_module` prints it. Empty matches every thread.
+    ModulePrefix(String),
+    /// `engine.dll+0x1A30` - exact start address.
+    Address { module: String, offset: usize },
+    /// `engine.dll+0x1000-0x2000` - start address within an inclusive offset range.
+    OffsetRange { module: String, start: usize, end: usize },
+    /// `~RenderThread` - thread description prefix, as set by `SetThreadDescription`.
+    Description(String),
+}
+
+/// A thread selector used in prime prefixes and ideal processor rules.
+///
+/// All comparisons are case-insensitive. A leading `!` turns the selector into an exclusion.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ThreadSelector {
+    pub matcher: ThreadMatcher,
+    pub exclude: bool,
+}
+
+impl ThreadSelector {
+    /// Selector matching every thread, used when a rule has no prefixes.
+    pub fn all() -> Self {
+        ThreadSelector {
+            matcher: ThreadMatcher::ModulePrefix(String::new()),
+            exclude: false,
+        }
+    }
+
+    pub fn parse(text: &str) -> std::result::Result<Self, String> {
+        let text = text.trim();
+        let (exclude, text) = match text.strip_prefix('!') {
+            Some(rest) => (true, rest.trim()),
+            None => (false, text),
+        };
+        if exclude && text.is_empty() {
+            return Err("empty exclusion selector '!'".to_string());
+        }
+        let lower = text.to_lowercase();
+        let matcher = if let Some(description) = lower.strip_prefix('~') {
+            if description.is_empty() {
+                return Err("empty thread description selector '~'".to_string());
+            }
+            ThreadMatcher::Description(description.to_string())
+        } else if let Some((module, offsets)) = lower.rsplit_once('+')
+            && let Some(range) = parse_offset_range(offsets)
+        {
+            // a suffix that is no offset is part of the module name, e.g. notepad++.exe
+            let module = module.trim().to_string();
+            if module.is_empty() {
+                return Err(format!("missing module name in '{}'", text));
+            }
+            match range {
+                (start, Some(end)) if start > end => return Err(format!("offset range start is after its end in '{}'", text)),
+                (start, Some(end)) => ThreadMatcher::OffsetRange { module, start, end },
+                (offset, None) => ThreadMatcher::Address { module, offset },
+            }
+        } else {
+            ThreadMatcher::ModulePrefix(lower)
+        };
+        Ok(ThreadSelector { matcher, exclude })
+    }
+
+    /// Checks the selector against a thread, ignoring `exclude`.
+    ///
+    /// `start_module` is the `resolve_address_to_module` output, `description` the thread description (may be empty).
+    pub fn matches(&self, start_module: &str, description: &str) -> bool {
+        match &self.matcher {
+            ThreadMatcher::ModulePrefix(prefix) => start_module.to_lowercase().starts_with(prefix.as_str()),
+            ThreadMatcher::Description(prefix) => !description.is_empty() && description.to_lowercase().starts_with(prefix.as_str()),
+            ThreadMatcher::Address { module, offset } => {
+                split_start_module(start_module).is_some_and(|(m, o)| m.eq_ignore_ascii_case(module) && o == *offset)
+            }
+            ThreadMatcher::OffsetRange { module, start, end } => {
+                split_start_module(start_module).is_some_and(|(m, o)| m.eq_ignore_ascii_case(module) && (*start..=*end).contains(&o))
+            }
+        }
+    }
+
+    pub fn uses_description(&self) -> bool {
+        matches!(self.matcher, ThreadMatcher::Description(_))
+    }
+}
+
+impl fmt::Display for ThreadSelector {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.exclude {
+            write!(f, "!")?;
+        }
+        match &self.matcher {
+            ThreadMatcher::ModulePrefix(prefix) => write!(f, "{}", prefix),
+            ThreadMatcher::Address { module, offset } => write!(f, "{}+0x{:X}", module, offset),
+            ThreadMatcher::OffsetRange { module, start, end } => write!(f, "{}+0x{:X}-0x{:X}", module, start, end),
+            ThreadMatcher::Description(prefix) => write!(f, "~{}", prefix),
+        }
+    }
+}
+
+/// Checks a thread against a selector list: it must match at least one inclusion selector
+/// (or the list has none) and no exclusion selector.
+pub fn thread_selected(selectors: &[ThreadSelector], start_module: &str, description: &str) -> bool {
+    let mut has_inclusions = false;
+    let mut included = false;
+    for selector in selectors {
+        if selector.exclude {
+            if selector.matches(start_module, description) {
+                return false;
+            }
+        } else {
+            has_inclusions = true;
+            included = included || selector.matches(start_module, description);
+        }
+    }
+    included || !has_inclusions
+}
+
+fn parse_offset(text: &str) -> Option<usize> {
+    let text = text.trim();
+    let hex = text.strip_prefix("0x").unwrap_or(text);
+    usize::from_str_radix(hex, 16).ok()
+}
+
+/// Parses the part after `+` of a selector, `0x1A30` or `0x1000-0x2000`, `None` when it is no offset.
+fn parse_offset_range(text: &str) -> Option<(usize, Option<usize>)> {
+    match text.split_once('-') {
+        Some((start, end)) => Some((parse_offset(start)?, Some(parse_offset(end)?))),
+        None => Some((parse_offset(text)?, None)),
+    }
+}
+
+/// Splits `module.dll+0x1A30` into its module name and offset, `None` for addresses outside any module.
+fn split_start_module(start_module: &str) -> Option<(&str, usize)> {
+    let (module, offset) = start_module.rsplit_once('+')?;
+    Some((module, parse_offset(offset)?))
+}
+
+/// Activation condition of a rule, from the `while` and `schedule` rule options.
+///
+/// `while=a.exe;b.exe` holds while any of the processes runs, `while=!a.exe;b.exe` while none of them does.
+/// With both options the rule is active only while both hold.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RuleCondition {
+    /// Empty when the rule only has a schedule.
+    pub processes: Vec<String>,
+    pub negate: bool,
+    pub schedule: Option<Schedule>,
+}
+
+impl RuleCondition {
+    /// Parses a `while` value into a condition without schedule.
+    pub fn parse(text: &str) -> std::result::Result<Self, String> {
+        let text = text.trim();
+        let (negate, list) = match text.strip_prefix('!') {
+            Some(rest) => (true, rest),
+            None => (false, text),
+        };
+        let processes: Vec<String> = list
+            .split(';')
+            .map(|name| name.trim().to_lowercase())
+            .filter(|name| !name.is_empty())
+            .collect();
+        if processes.is_empty() {
+            return Err(format!("condition '{}' names no process", text));
+        }
+        Ok(RuleCondition {
+            processes,
+            negate,
+            schedule: None,
+        })
+    }
+
+    /// `now` is the local time as (weekday, minute of day).
+    pub fn is_met(&self, running: &HashSet<&str>, now: (Weekday, u16)) -> bool {
+        let processes_met = self.processes.is_empty() || self.processes.iter().any(|name| running.contains(name.as_str())) != self.negate;
+        processes_met && self.schedule.as_ref().is_none_or(|schedule| schedule.is_active(now.0, now.1))
+    }
+}
+
+impl fmt::Display for RuleCondition {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if !self.processes.is_empty() {
+            write!(f, "while {}{}", if self.negate { "!" } else { "" }, self.processes.join(";"))?;
+        }
+        if let Some(schedule) = &self.schedule {
+            if !self.processes.is_empty() {
+                write!(f, ", ")?;
+            }
+            write!(f, "schedule {}", schedule)?;
+        }
+        Ok(())
+    }
+}
+
+/// One `days@HHMM-HHMM` window of a schedule. A window whose end is not after its start crosses midnight
+/// and belongs to the day it starts on.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ScheduleWindow {
+    /// Bit 0 = Monday ... bit 6 = Sunday.
+    pub days: u8,
+    /// Minutes since midnight.
+    pub start: u16,
+    pub end: u16,
+}
+
+/// Local time windows of a rule or profile, from the `schedule` option,
+/// e.g. `mon-fri@0900-1800;sat@1000-1400` or `daily@2200-0600`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Schedule {
+    pub windows: Vec<ScheduleWindow>,
+}
+
+impl Schedule {
+    const DAYS: [&'static str; 7] = ["mon", "tue", "wed", "thu", "fri", "sat", "sun"];
+
+    pub fn parse(text: &str) -> std::result::Result<Self, String> {
+        let mut windows = Vec::new();
+        for window in text.split(';').map(str::trim).filter(|window| !window.is_empty()) {
+            let (days, times) = window
+                .split_once('@')
+                .ok_or_else(|| format!("window '{}' needs the form days@HHMM-HHMM", window))?;
+            let (start, end) = times
+                .split_once('-')
+                .ok_or_else(|| format!("time range '{}' needs the form HHMM-HHMM", times))?;
+            windows.push(ScheduleWindow {
+                days: Self::parse_days(days)?,
+                start: Self::parse_time(start)?,
+                end: Self::parse_time(end)?,
+            });
+        }
+        if windows.is_empty() {
+            return Err(format!("schedule '{}' has no window", text));
+        }
+        Ok(Schedule { windows })
+    }
+
+    fn parse_days(text: &str) -> std::result::Result<u8, String> {
+        let day_index = |day: &str| {
+            let day = day.trim().to_lowercase();
+            Self::DAYS
+                .iter()
+                .position(|name| *name == day)
+                .ok_or_else(|| format!("unknown day '{}' (expected mon..sun, daily, weekdays or weekends)", day))
+        };
+        let mut days = 0u8;
+        for part in text.split(',') {
+            let part = part.trim().to_lowercase();
+            days |= match part.as_str() {
+                "daily" | "*" => 0b111_1111,
+                "weekdays" => 0b001_1111,
+                "weekends" => 0b110_0000,
+                _ => match part.split_once('-') {
+                    // ranges may wrap around the week, e.g. fri-mon
+                    Some((first, last)) => {
+                        let (first, last) = (day_index(first)?, day_index(last)?);
+                        let mut mask = 0u8;
+                        let mut day = first;
+                        loop {
+                            mask |= 1 << day;
+                            if day == last {
+                                break;
+                            }
+                            day = (day + 1) % 7;
+                        }
+                        mask
+                    }
+                    None => 1 << day_index(&part)?,
+                },
+            };
+        }
+        Ok(days)
+    }
+
+    fn parse_time(text: &str) -> std::result::Result<u16, String> {
+        let text = text.trim();
+        let invalid = || format!("invalid time '{}' (expected HHMM, 0000-2400)", text);
+        if text.len() != 4 || !text.chars().all(|c| c.is_ascii_digit()) {
+            return Err(invalid());
+        }
+        let (hours, minutes) = (text[..2].parse::<u16>().unwrap_or(99), text[2..].parse::<u16>().unwrap_or(99));
+        if minutes >= 60 || hours > 24 || (hours == 24 && minutes != 0) {
+            return Err(invalid());
+        }
+        Ok(hours * 60 + minutes)
+    }
+
+    /// Whether local `weekday` at `minute` (since midnight) falls into any window.
+    pub fn is_active(&self, weekday: Weekday, minute: u16) -> bool {
+        let today = weekday.num_days_from_monday();
+        let yesterday = (today + 6) % 7;
+        self.windows.iter().any(|window| {
+            let on = |day: u32| window.days & (1 << day) != 0;
+            if window.start < window.end {
+                on(today) && (window.start..window.end).contains(&minute)
+            } else {
+                (on(today) && minute >= window.start) || (on(yesterday) && minute < window.end)
+            }
+        })
+    }
+}
+
+impl fmt::Display for Schedule {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (index, window) in self.windows.iter().enumerate() {
+            if index > 0 {
+                write!(f, ";")?;
+            }
+            let days: Vec<&str> = (0..7)
+                .filter(|day| window.days & (1 << day) != 0)
+                .map(|day| Self::DAYS[day])
+                .collect();
+            write!(
+                f,
+                "{}@{:02}{:02}-{:02}{:02}",
+                days.join(","),
+                window.start / 60,
+                window.start % 60,
+                window.end / 60,
+                window.end % 60
+            )?;
+        }
+        Ok(())
+    }
+}
+
+/// How many prime slots threads of one prefix may claim, from the `(max)` / `(min-max)` prefix suffix.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ThreadQuota {
+    pub min: usize,
+    pub max: usize,
+}
+
+impl ThreadQuota {
+    pub const UNLIMITED: Self = ThreadQuota { min: 0, max: usize::MAX };
+
+    pub fn is_limited(&self) -> bool {
+        *self != Self::UNLIMITED
+    }
+
+    /// Parses the inside of a quota suffix: `max` or `min-max`.
+    pub fn parse(text: &str) -> std::result::Result<Self, String> {
+        let text = text.trim();
+        let parse_count = |value: &str| {
+            value
+                .trim()
+                .parse::<usize>()
+                .map_err(|_| format!("invalid thread count '{}'", value.trim()))
+        };
+        let quota = match text.split_once('-') {
+            Some((min, max)) => ThreadQuota {
+                min: parse_count(min)?,
+                max: parse_count(max)?,
+            },
+            None => ThreadQuota {
+                min: 0,
+                max: parse_count(text)?,
+            },
+        };
+        if quota.max == 0 {
+            return Err(format!(
+                "max threads must be at least 1 in '({})', use a '!' exclusion instead",
+                text
+            ));
+        }
+        if quota.min > quota.max {
+            return Err(format!("min threads exceed max threads in '({})'", text));
+        }
+        Ok(quota)
+    }
+}
+
+impl fmt::Display for ThreadQuota {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.min > 0 {
+            write!(f, "({}-{})", self.min, self.max)
+        } else {
+            write!(f, "({})", self.max)
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
 pub struct PrimePrefix {
-    pub prefix: String,
+    pub selector: ThreadSelector,
+    pub quota: ThreadQuota,
     pub cpus: Option<List<[u32; CONSUMER_CPUS]>>,
     pub thread_priority: ThreadPriority,
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq)]
 pub struct IdealProcessorRule {
     pub cpus: List<[u32; CONSUMER_CPUS]>,
-    pub prefixes: Vec<String>,
+    pub prefixes: Vec<ThreadSelector>,
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq)]
 pub struct ProcessLevelConfig {
     pub name: String,
     pub priority: ProcessPriority,
@@ -35,17 +428,331 @@ pub struct ProcessLevelConfig {
     pub cpu_set_reset_ideal: bool,
     pub io_priority: IOPriority,
     pub memory_priority: MemoryPriority,
+    pub power_throttling: PowerThrottling,
+    pub job_limits: Option<JobLimits>,
+    pub ideal_reset_strategy: IdealResetStrategy,
+    pub ideal_reset_seed: Option<u64>,
+    pub action: RuleAction,
+    /// Trigger process names; matching processes are suspended while any of them is running.
+    pub suspend_while: Vec<String>,
+    /// The rule this config came from, `line N label`, recorded with every action.
+    pub origin: String,
+    pub condition: Option<RuleCondition>,
+    /// The profile block the rule was declared in, `None` for global rules.
+    pub profile: Option<String>,
+    pub stages: Option<ApplyStages>,
 }
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq)]
 pub struct ThreadLevelConfig {
     pub name: String,
     pub prime_threads_cpus: List<[u32; CONSUMER_CPUS]>,
     pub prime_threads_prefixes: Vec<PrimePrefix>,
     pub track_top_x_threads: i32,
     pub ideal_processor_rules: Vec<IdealProcessorRule>,
+    pub prime_placement: PrimePlacement,
+    pub prime_pin_mode: PrimePinMode,
+    /// Power throttling applied to every thread that is not currently prime.
+    pub thread_power_throttling: PowerThrottling,
+    pub condition: Option<RuleCondition>,
+    /// The profile block the rule was declared in, `None` for global rules.
+    pub profile: Option<String>,
 }
 
-#[derive(Debug, Clone)]
+impl ThreadLevelConfig {
+    /// Whether any prime prefix carries a thread quota, so selection needs to classify threads by prefix.
+    pub fn has_thread_quotas(&self) -> bool {
+        self.prime_threads_prefixes.iter().any(|prefix| prefix.quota.is_limited())
+    }
+
+    /// Whether any selector needs thread descriptions, so they are only queried when used.
+    pub fn uses_thread_descriptions(&self) -> bool {
+        self.prime_threads_prefixes.iter().any(|prefix| prefix.selector.uses_description())
+            || self
+                .ideal_processor_rules
+                .iter()
+                .any(|rule| rule.prefixes.iter().any(|selector| selector.uses_description()))
+    }
+}
+
+/// How `reset_thread_ideal_processors` distributes threads (sorted by CPU time) over the target CPUs.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum IdealResetStrategy {
+    /// Round-robin over the CPU list, shifted randomly (or by the seed when configured).
+    RoundRobin,
+    /// One thread per physical core before any SMT sibling gets a second one.
+    PhysicalCoresFirst,
+    /// Fill SMT siblings and cores of one LLC before moving to the next.
+    Packed,
+    /// Interleave LLCs so consecutive busy threads land in different cache domains.
+    SpreadLlc,
+    /// Shuffled CPU order, reproducible when a seed is configured.
+    SeededRandom,
+}
+
+impl IdealResetStrategy {
+    const TABLE: &'static [(Self, &'static str)] = &[
+        (Self::RoundRobin, "round_robin"),
+        (Self::PhysicalCoresFirst, "cores_first"),
+        (Self::Packed, "packed"),
+        (Self::SpreadLlc, "spread"),
+        (Self::SeededRandom, "random"),
+    ];
+
+    pub fn as_str(&self) -> &'static str {
+        Self::TABLE
+            .iter()
+            .find(|(v, _)| v == self)
+            .map(|(_, name)| *name)
+            .unwrap_or("unknown")
+    }
+
+    pub fn from_str(s: &str) -> Option<Self> {
+        let s = s.to_lowercase().replace('-', "_");
+        Self::TABLE.iter().find(|(_, name)| *name == s.as_str()).map(|(v, _)| *v)
+    }
+}
+
+/// How a promoted prime thread is placed within the CPUs of its matching prime segment.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PrimePlacement {
+    /// Every prime thread gets the whole prime CPU list.
+    All,
+    /// One prime thread per physical core, the SMT sibling is left to non-prime work.
+    PhysicalCore,
+    /// Prime threads of one process are packed into the same last level cache domain.
+    PackLlc,
+    /// Prime threads of one process are spread across last level cache domains.
+    SpreadLlc,
+}
+
+/// Hard limits enforced by placing matching processes into a job object.
+///
+/// Every process of one rule shares the job, so a group's limits apply to all its members together.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct JobLimits {
+    /// Job object key, `job:<name>` for a `job=` option, otherwise `rule:` and the rule's sorted member names,
+    /// so moving the rule or renaming its group keeps the job.
+    pub key: String,
+    /// CPU rate hard cap in percent of total system CPU time (1-100).
+    pub cpu_rate: Option<u32>,
+    /// Maximum working set per process, in bytes.
+    pub working_set: Option<usize>,
+    /// Minimum working set per process from `working_set=min-max`, in bytes.
+    pub min_working_set: Option<usize>,
+    /// Maximum committed memory of the whole job, in bytes.
+    pub commit: Option<usize>,
+    /// Maximum number of simultaneously active processes in the job.
+    pub active_processes: Option<u32>,
+}
+
+impl JobLimits {
+    pub fn unlimited(key: String) -> Self {
+        JobLimits {
+            key,
+            cpu_rate: None,
+            working_set: None,
+            min_working_set: None,
+            commit: None,
+            active_processes: None,
+        }
+    }
+
+    /// The key of a rule's own job, see [`JobLimits::key`].
+    pub fn rule_key(members: &[String]) -> String {
+        let mut members: Vec<&str> = members.iter().map(String::as_str).collect();
+        members.sort_unstable();
+        members.dedup();
+        format!("rule:{}", members.join(";"))
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.cpu_rate.is_none() && self.working_set.is_none() && self.commit.is_none() && self.active_processes.is_none()
+    }
+
+    pub fn describe(&self) -> String {
+        let mut parts: Vec<String> = Vec::new();
+        if let Some(cpu_rate) = self.cpu_rate {
+            parts.push(format!("cpu_rate={}%", cpu_rate));
+        }
+        match (self.min_working_set, self.working_set) {
+            (Some(min), Some(max)) => parts.push(format!("working_set={}-{}", format_byte_size(min), format_byte_size(max))),
+            (_, Some(max)) => parts.push(format!("working_set={}", format_byte_size(max))),
+            _ => {}
+        }
+        if let Some(commit) = self.commit {
+            parts.push(format!("commit={}", format_byte_size(commit)));
+        }
+        if let Some(active_processes) = self.active_processes {
+            parts.push(format!("processes={}", active_processes));
+        }
+        parts.join(", ")
+    }
+}
+
+/// When a rule is (re-)applied after its process starts, from the `delay`, `stages` and `on_module` options.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ApplyStages {
+    /// Milliseconds after process start at which the rule is applied, ascending; the first one delays the initial apply.
+    pub times_ms: Vec<u32>,
+    /// Modules whose load re-applies the rule once, lowercase.
+    pub modules: Vec<String>,
+}
+
+impl ApplyStages {
+    pub fn describe(&self) -> String {
+        let mut parts: Vec<String> = Vec::new();
+        if self.times_ms != [0] {
+            let times: Vec<String> = self.times_ms.iter().map(|time| format_stage_time(*time)).collect();
+            parts.push(format!("stages={}", times.join(";")));
+        }
+        if !self.modules.is_empty() {
+            parts.push(format!("on_module={}", self.modules.join(";")));
+        }
+        parts.join(", ")
+    }
+}
+
+/// Parses a stage time in seconds, or with an `ms`, `s` or `m` suffix, e.g. `5`, `500ms`, `2m`.
+pub fn parse_stage_time(text: &str) -> Option<u32> {
+    let text = text.trim().to_lowercase();
+    let number_end = text.find(|c: char| !c.is_ascii_digit()).unwrap_or(text.len());
+    let value = text[..number_end].parse::<u32>().ok()?;
+    let multiplier: u32 = match text[number_end..].trim() {
+        "" | "s" => 1000,
+        "ms" => 1,
+        "m" => 60_000,
+        _ => return None,
+    };
+    value.checked_mul(multiplier)
+}
+
+pub fn format_stage_time(time_ms: u32) -> String {
+    if time_ms.is_multiple_of(1000) {
+        format!("{}s", time_ms / 1000)
+    } else {
+        format!("{}ms", time_ms)
+    }
+}
+
+/// Parses a byte size with an optional K/M/G (or KB/MB/GB) suffix, e.g. `512M`, `2GB`, `1048576`.
+pub fn parse_byte_size(text: &str) -> Option<usize> {
+    let text = text.trim().to_uppercase();
+    let number_end = text.find(|c: char| !c.is_ascii_digit()).unwrap_or(text.len());
+    let value = text[..number_end].parse::<usize>().ok()?;
+    let multiplier: usize = match text[number_end..].trim() {
+        "" | "B" => 1,
+        "K" | "KB" => 1 << 10,
+        "M" | "MB" => 1 << 20,
+        "G" | "GB" => 1 << 30,
+        _ => return None,
+    };
+    value.checked_mul(multiplier)
+}
+
+pub fn format_byte_size(bytes: usize) -> String {
+    if bytes >= 1 << 30 && bytes.is_multiple_of(1 << 30) {
+        format!("{}G", bytes >> 30)
+    } else if bytes >= 1 << 20 && bytes.is_multiple_of(1 << 20) {
+        format!("{}M", bytes >> 20)
+    } else if bytes >= 1 << 10 && bytes.is_multiple_of(1 << 10) {
+        format!("{}K", bytes >> 10)
+    } else {
+        format!("{}", bytes)
+    }
+}
+
+/// What a rule does to matching processes besides tuning them, from the `action` rule option.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RuleAction {
+    None,
+    /// Terminate matching processes on sight.
+    Terminate,
+}
+
+impl RuleAction {
+    const TABLE: &'static [(Self, &'static str)] = &[(Self::None, "none"), (Self::Terminate, "terminate")];
+
+    pub fn as_str(&self) -> &'static str {
+        Self::TABLE
+            .iter()
+            .find(|(v, _)| v == self)
+            .map(|(_, name)| *name)
+            .unwrap_or("unknown")
+    }
+
+    pub fn from_str(s: &str) -> Option<Self> {
+        let s = s.to_lowercase();
+        Self::TABLE.iter().find(|(_, name)| *name == s.as_str()).map(|(v, _)| *v)
+    }
+}
+
+/// Processes that `action` and `suspend_while` rules refuse to touch, killing or freezing them takes Windows down.
+const PROTECTED_PROCESSES: &[&str] = &[
+    "system",
+    "registry",
+    "smss.exe",
+    "csrss.exe",
+    "wininit.exe",
+    "winlogon.exe",
+    "services.exe",
+    "lsass.exe",
+    "svchost.exe",
+    "dwm.exe",
+    "fontdrvhost.exe",
+    "affinityservicerust.exe",
+];
+
+/// How a promoted prime thread is held on its CPUs, from the `pin` rule option.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PrimePinMode {
+    /// `SetThreadSelectedCpuSets`, a soft preference the OS may override.
+    CpuSet,
+    /// `SetThreadAffinityMask`, strict pinning limited to processor group 0 CPUs.
+    Affinity,
+    /// `SetThreadIdealProcessorEx` on the first placed CPU only, the thread may still run anywhere.
+    Ideal,
+}
+
+impl PrimePinMode {
+    const TABLE: &'static [(Self, &'static str)] = &[(Self::CpuSet, "cpuset"), (Self::Affinity, "affinity"), (Self::Ideal, "ideal")];
+
+    pub fn as_str(&self) -> &'static str {
+        Self::TABLE
+            .iter()
+            .find(|(v, _)| v == self)
+            .map(|(_, name)| *name)
+            .unwrap_or("unknown")
+    }
+
+    pub fn from_str(s: &str) -> Option<Self> {
+        let s = s.to_lowercase();
+        Self::TABLE.iter().find(|(_, name)| *name == s.as_str()).map(|(v, _)| *v)
+    }
+}
+
+impl PrimePlacement {
+    const TABLE: &'static [(Self, &'static str)] = &[
+        (Self::All, "all"),
+        (Self::PhysicalCore, "core"),
+        (Self::PackLlc, "pack"),
+        (Self::SpreadLlc, "spread"),
+    ];
+
+    pub fn as_str(&self) -> &'static str {
+        Self::TABLE
+            .iter()
+            .find(|(v, _)| v == self)
+            .map(|(_, name)| *name)
+            .unwrap_or("unknown")
+    }
+
+    pub fn from_str(s: &str) -> Option<Self> {
+        let s = s.to_lowercase();
+        Self::TABLE.iter().find(|(_, name)| *name == s.as_str()).map(|(v, _)| *v)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
 pub struct ConfigConstants {
     pub min_active_streak: u8,
     pub keep_threshold: f64,
@@ -172,13 +879,211 @@ pub struct ConfigResult {
     pub errors: Vec<String>,
     pub warnings: Vec<String>,
     pub thread_level_configs_count: usize,
+    pub restraint: RestraintSettings,
+    /// Profiles declared with `[profile name]` blocks, in file order.
+    pub profiles: Vec<String>,
+    /// Schedules declared on profile headers, `[profile name schedule=...]`.
+    pub profile_schedules: HashMap<String, Schedule>,
+    pub active_profile: Option<String>,
+    /// Every file read, the config itself (or the first file of a config directory) first.
+    pub config_files: Vec<PathBuf>,
+    /// Directories whose `*.ini` files were included, watched for added or removed files.
+    pub watched_dirs: Vec<PathBuf>,
+    /// The config path and every `@INCLUDE` / `@INCLUDE_IF_EXISTS` target, existing or not, watched for
+    /// being created or removed.
+    pub watched_files: Vec<PathBuf>,
+    /// `(index into config_files, line)` of every combined line, includes are expanded in place.
+    /// Every file ends with a blank `(index, 0)` marker line.
+    pub line_sources: Vec<(usize, usize)>,
+    /// Combined line number of the current definition of every rule, to tell layered overrides from duplicates.
+    pub rule_lines: HashMap<String, usize>,
+    /// Rule templates by name, `&name = ...`.
+    pub templates: HashMap<String, RuleTemplate>,
+    /// Effective fields and options of every rule with their origin, for `-explain`.
+    pub explanations: HashMap<String, Vec<FieldSource>>,
+    /// Process groups in definition order, a redefined group replaces the earlier one in place.
+    pub groups: Vec<ProcessGroup>,
+    /// Index into `groups` of the group whose rule a process got last.
+    pub member_groups: HashMap<String, usize>,
+    /// CPU aliases by name without the `*`, as defined after reading the global lines and the active profile.
+    pub cpu_aliases: HashMap<String, List<[u32; CONSUMER_CPUS]>>,
 }
 
+/// `(path, exists, modification time)` of every watched config path, see [`ConfigResult::signature`].
+pub type ConfigSignature = Vec<(PathBuf, bool, Option<SystemTime>)>;
+
 impl ConfigResult {
     pub fn is_valid(&self) -> bool {
         self.errors.is_empty()
     }
 
+    /// Index into `config_files` of a combined line number.
+    pub fn line_file(&self, line_number: usize) -> usize {
+        self.line_sources
+            .get(line_number.wrapping_sub(1))
+            .map_or(0, |(file_index, _)| *file_index)
+    }
+
+    /// A combined line number as `N`, or `N [file]` once more than one file was read.
+    pub fn line_label(&self, line_number: usize) -> String {
+        match self.line_sources.get(line_number.wrapping_sub(1)) {
+            Some((file_index, line)) if self.config_files.len() > 1 => {
+                format!("{} [{}]", line, self.config_files[*file_index].display())
+            }
+            Some((_, line)) => line.to_string(),
+            None => line_number.to_string(),
+        }
+    }
+
+    /// Prints the effective rule of a process with the origin of every field, the `-explain` mode.
+    pub fn print_explanation(&self, name: &str) {
+        let name = name.trim().to_lowercase();
+        let Some(sources) = self.explanations.get(&name) else {
+            log!("No rule for '{}'", name);
+            return;
+        };
+        match self.rule_lines.get(&name) {
+            Some(line) => log!("Rule for '{}' (line {}):", name, self.line_label(*line)),
+            None => log!("Rule for '{}':", name),
+        }
+        for source in sources {
+            let origin = match (&source.template, source.line) {
+                (_, 0) => "default".to_string(),
+                (Some(template), line) => format!("{} (line {})", template, self.line_label(line)),
+                (None, line) => format!("rule (line {})", self.line_label(line)),
+            };
+            log!("  {:<14} = {:<24} {}", source.field, source.value, origin);
+        }
+    }
+
+    /// The group whose rule `member` got, if that group is enabled and has a rule.
+    fn member_group(&self, member: &str) -> Option<&ProcessGroup> {
+        self.member_groups
+            .get(member)
+            .and_then(|index| self.groups.get(*index))
+            .filter(|group| group.enabled && group.has_rule)
+    }
+
+    /// Prints every group with its metadata, members and effective settings, the `-list-groups` mode.
+    pub fn print_groups(&self) {
+        if self.groups.is_empty() {
+            log!("No process groups");
+            return;
+        }
+        for group in &self.groups {
+            log!(
+                "Group '{}' (line {}){}",
+                group.name,
+                self.line_label(group.line),
+                if group.enabled { "" } else { " [disabled]" }
+            );
+            for (key, value) in [("owner", &group.owner), ("reason", &group.reason), ("ticket", &group.ticket)] {
+                if let Some(value) = value {
+                    log!("  {:<9} {}", key, value);
+                }
+            }
+            if !group.includes.is_empty() {
+                log!("  includes  @{}", group.includes.join(", @"));
+            }
+            log!("  members   ({}) {}", group.members.len(), group.members.join(", "));
+            if !group.has_rule {
+                log!("  settings  none, members only");
+                continue;
+            }
+            if !group.enabled {
+                log!("  settings  not applied while disabled");
+                continue;
+            }
+            let settings: Vec<String> = group
+                .rule
+                .iter()
+                .filter(|source| source.line != 0)
+                .map(|source| format!("{}={}", source.field, source.value))
+                .collect();
+            log!("  settings  {}", settings.join(", "));
+            // members whose rule was replaced or refined after the group
+            for member in &group.members {
+                let own_line = self.rule_lines.get(member).filter(|line| **line != group.line);
+                if let Some(line) = own_line {
+                    let overridden: Vec<String> = self
+                        .explanations
+                        .get(member)
+                        .into_iter()
+                        .flatten()
+                        .filter(|source| source.line == *line)
+                        .map(|source| format!("{}={}", source.field, source.value))
+                        .collect();
+                    log!(
+                        "  override  {} (line {}): {}",
+                        member,
+                        self.line_label(*line),
+                        overridden.join(", ")
+                    );
+                }
+            }
+        }
+    }
+
+    /// Existence and modification time of every config file, include target and included directory,
+    /// the hot reload trigger.
+    pub fn signature(&self) -> ConfigSignature {
+        self.config_files
+            .iter()
+            .chain(&self.watched_files)
+            .chain(&self.watched_dirs)
+            .map(|path| match metadata(path) {
+                Ok(m) => (path.clone(), true, m.modified().ok()),
+                Err(_) => (path.clone(), false, None),
+            })
+            .collect()
+    }
+
+    /// Whether any process-level or thread-level rule targets this process name.
+    pub fn has_rule_for(&self, name: &str) -> bool {
+        self.process_level_configs.values().any(|configs| configs.contains_key(name))
+            || self.thread_level_configs.values().any(|configs| configs.contains_key(name))
+    }
+
+    /// Whether a start of `name` concerns a terminate or suspend rule, as its target or as a `suspend_while`
+    /// trigger, and should be reacted to immediately.
+    pub fn has_process_action_for(&self, name: &str) -> bool {
+        self.process_level_configs
+            .values()
+            .flat_map(|configs| configs.iter())
+            .any(|(rule_name, config)| {
+                (rule_name == name && (config.action != RuleAction::None || !config.suspend_while.is_empty()))
+                    || config.suspend_while.iter().any(|trigger| trigger == name)
+            })
+    }
+
+    /// Keys of every job object a rule of this config uses.
+    pub fn job_keys(&self) -> HashSet<String> {
+        self.process_level_configs
+            .values()
+            .flat_map(|graded| graded.values())
+            .filter_map(|config| config.job_limits.as_ref().map(|limits| limits.key.clone()))
+            .collect()
+    }
+
+    /// Lifts the limits of jobs no rule uses anymore, after a reload or profile switch replaced the rules.
+    pub fn release_unused_jobs(&self, reason: &str) {
+        for (key, error_code) in release_jobs_except(&self.job_keys()) {
+            match error_code {
+                None => log!(
+                    "{}: job '{}' has no rule anymore, limits removed (its processes stay in the job)",
+                    reason,
+                    key
+                ),
+                Some(error_code) => log!(
+                    "{}: [SET_JOB_LIMITS][{}] failed to remove the limits of job '{}'",
+                    reason,
+                    error_from_code_win32(error_code),
+                    key
+                ),
+            }
+        }
+    }
+
     pub fn total_rules(&self) -> usize {
         let a: usize = self.process_level_configs.values().map(|grade_configs| grade_configs.len()).sum();
         let b: usize = self.thread_level_configs.values().map(|grade_configs| grade_configs.len()).sum();
@@ -195,6 +1100,11 @@ impl ConfigResult {
                 );
             }
             log!("Parsed {} process rules", self.process_rules_count);
+            if let Some(profile) = &self.active_profile {
+                log!("Active profile: {} (declared: {})", profile, self.profiles.join(", "));
+            } else if !self.profiles.is_empty() {
+                log!("No active profile (declared: {})", self.profiles.join(", "));
+            }
             if !self.warnings.is_empty() {
                 for warning in &self.warnings {
                     log_to_find(&format!("⚠ {}", warning));
@@ -239,16 +1149,36 @@ fn resolve_cpu_spec(
     }
 }
 
+/// Collects `:` separated group members, `key = value` items are kept as written for group properties.
+///
+/// A whole line of the form `reason = value` is one property even if the value contains `:`.
 fn collect_members(text: &str, members: &mut Vec<String>) {
+    if let Some((key, value)) = text.split_once('=')
+        && !key.contains(':')
+        && !key.trim().starts_with('#')
+    {
+        members.push(format!("{}={}", key.trim().to_lowercase(), value.trim()));
+        return;
+    }
     for item in text.split(':') {
-        let item = item.trim().to_lowercase();
-        if !item.is_empty() && !item.starts_with('#') {
-            members.push(item);
+        let item = item.trim();
+        if item.is_empty() || item.starts_with('#') {
+            continue;
+        }
+        match item.split_once('=') {
+            Some((key, value)) => members.push(format!("{}={}", key.trim().to_lowercase(), value.trim())),
+            None => members.push(item.to_lowercase()),
         }
     }
 }
 
-fn parse_constant(name: &str, value: &str, line_number: usize, result: &mut ConfigResult) {
+fn parse_constant(
+    name: &str,
+    value: &str,
+    line_number: usize,
+    cpu_aliases: &HashMap<String, List<[u32; CONSUMER_CPUS]>>,
+    result: &mut ConfigResult,
+) {
     match name {
         "MIN_ACTIVE_STREAK" => {
             if let Ok(v) = value.parse::<u8>() {
@@ -277,17 +1207,101 @@ fn parse_constant(name: &str, value: &str, line_number: usize, result: &mut Conf
                 }
                 result.constants_count += 1;
             } else {
-                result
-                    .errors
-                    .push(format!("Line {}: Invalid constant value '{}' for '{}'", line_number, value, name));
+                result
+                    .errors
+                    .push(format!("Line {}: Invalid constant value '{}' for '{}'", line_number, value, name));
+            }
+        }
+        _ if name.starts_with("RESTRAINT_") => parse_restraint_constant(name, value, line_number, cpu_aliases, result),
+        _ => {
+            result
+                .warnings
+                .push(format!("Line {}: Unknown constant '{}' - will be ignored", line_number, name));
+        }
+    }
+}
+
+/// Parses the `@RESTRAINT_*` constants that configure dynamic restraint of CPU hogs.
+fn parse_restraint_constant(
+    name: &str,
+    value: &str,
+    line_number: usize,
+    cpu_aliases: &HashMap<String, List<[u32; CONSUMER_CPUS]>>,
+    result: &mut ConfigResult,
+) {
+    let error_count = result.errors.len();
+    let restraint = &mut result.restraint;
+    let invalid = |expected: &str| {
+        format!(
+            "Line {}: Invalid constant value '{}' for '{}' (expected {})",
+            line_number, value, name, expected
+        )
+    };
+    let parse_share = |value: &str| {
+        value
+            .trim_end_matches('%')
+            .trim()
+            .parse::<f64>()
+            .ok()
+            .filter(|v| (0.0..=100.0).contains(v))
+    };
+    match name {
+        "RESTRAINT_CPU_SHARE" => match parse_share(value) {
+            Some(v) => restraint.cpu_share = v,
+            None => result.errors.push(invalid("percent 0-100")),
+        },
+        "RESTRAINT_RELEASE_SHARE" => match parse_share(value) {
+            Some(v) => restraint.release_share = Some(v),
+            None => result.errors.push(invalid("percent 0-100")),
+        },
+        "RESTRAINT_SYSTEM_BUSY" => match parse_share(value) {
+            Some(v) => restraint.system_busy = v,
+            None => result.errors.push(invalid("percent 0-100")),
+        },
+        "RESTRAINT_SUSTAIN" | "RESTRAINT_CALM" => match value.parse::<u32>() {
+            Ok(v) if v > 0 => {
+                if name == "RESTRAINT_SUSTAIN" {
+                    restraint.sustain = v;
+                } else {
+                    restraint.calm = v;
+                }
+            }
+            _ => result.errors.push(invalid("sample count > 0")),
+        },
+        "RESTRAINT_PRIORITY" => {
+            let priority = ProcessPriority::from_str(value);
+            if priority == ProcessPriority::None && !value.eq_ignore_ascii_case("none") {
+                result.errors.push(invalid("priority class or none"));
+            } else {
+                restraint.priority = priority;
             }
         }
+        "RESTRAINT_CPUSET" => {
+            restraint.cpus = resolve_cpu_spec(value, "RESTRAINT_CPUSET", line_number, cpu_aliases, &mut result.errors);
+        }
+        "RESTRAINT_EXEMPT" => {
+            restraint.exempt = value
+                .split([';', ':'])
+                .map(|item| item.trim().to_lowercase())
+                .filter(|item| !item.is_empty())
+                .collect();
+        }
+        "RESTRAINT_LOG" => match value {
+            "0" | "false" | "off" => restraint.log = false,
+            "1" | "true" | "on" => restraint.log = true,
+            _ => result.errors.push(invalid("0 or 1")),
+        },
         _ => {
             result
                 .warnings
                 .push(format!("Line {}: Unknown constant '{}' - will be ignored", line_number, name));
+            return;
         }
     }
+    if result.errors.len() == error_count {
+        log_message(&format!("Config: {} = {}", name, value));
+        result.constants_count += 1;
+    }
 }
 
 fn parse_alias(
@@ -371,11 +1385,16 @@ fn parse_ideal_processor_spec(
             continue;
         }
 
-        let prefixes: Vec<String> = prefixes_str
-            .split(';')
-            .map(|p| p.trim().to_lowercase())
-            .filter(|p| !p.is_empty())
-            .collect();
+        let mut prefixes: Vec<ThreadSelector> = Vec::new();
+        for prefix_str in prefixes_str.split(';').map(str::trim).filter(|p| !p.is_empty()) {
+            match ThreadSelector::parse(prefix_str) {
+                Ok(selector) => prefixes.push(selector),
+                Err(e) => errors.push(format!(
+                    "Line {}: Invalid thread selector in ideal processor rule: {}",
+                    line_number, e
+                )),
+            }
+        }
 
         rules.push(IdealProcessorRule { cpus, prefixes });
     }
@@ -417,17 +1436,491 @@ fn collect_group_block(lines: &[String], start_index: usize, first_line_content:
     None
 }
 
+/// Splits named `key=value` option fields from the positional rule fields.
+///
+/// Options may follow any positional field, e.g. `game.exe:high:*a:0:*p:normal:none:0:1:placement=core`.
+/// Keys are returned lowercased, values trimmed.
+fn split_rule_options<'a>(rule_parts: &[&'a str]) -> (Vec<&'a str>, Vec<(String, &'a str)>) {
+    let mut positional = Vec::with_capacity(rule_parts.len());
+    let mut options = Vec::new();
+    for part in rule_parts {
+        match part.find('=') {
+            Some(eq_pos) => options.push((part[..eq_pos].trim().to_lowercase(), part[eq_pos + 1..].trim())),
+            None => positional.push(*part),
+        }
+    }
+    (positional, options)
+}
+
+/// Positional rule fields in order, also the option names that override them (`cpuset=*e`).
+const RULE_FIELDS: [&str; 8] = ["priority", "affinity", "cpuset", "prime", "io", "memory", "ideal", "grade"];
+const RULE_FIELD_DEFAULTS: [&str; 8] = ["none", "0", "0", "0", "none", "none", "0", "1"];
+
+/// A `&name = ...` rule template, kept as written and resolved when a rule extends it.
+#[derive(Debug, Clone)]
+pub struct RuleTemplate {
+    pub parts: Vec<String>,
+    pub line: usize,
+}
+
+/// A `name { ... }` process group, kept for `@name` references from later groups and for `-list-groups`.
+#[derive(Debug, Clone)]
+pub struct ProcessGroup {
+    /// Lowercased name, `anonymous@L<line>` for groups without one.
+    pub name: String,
+    pub line: usize,
+    /// Every member, including those of referenced groups.
+    pub members: Vec<String>,
+    /// Groups referenced with `@name` between the braces.
+    pub includes: Vec<String>,
+    /// `enabled = false` keeps the group and its members out of every rule.
+    pub enabled: bool,
+    pub owner: Option<String>,
+    pub reason: Option<String>,
+    pub ticket: Option<String>,
+    pub has_rule: bool,
+    /// Effective fields and options of the group rule, the defaults of member rules.
+    pub rule: Vec<FieldSource>,
+}
+
+/// Where an effective rule field or option came from, shown by `-explain`.
+#[derive(Debug, Clone)]
+pub struct FieldSource {
+    pub field: String,
+    pub value: String,
+    /// The template (`&name`) or group (`group name`) that set the value, `None` for the rule itself.
+    pub template: Option<String>,
+    /// Line of the rule or template, 0 for a default value.
+    pub line: usize,
+}
+
+#[derive(Debug, Clone, Default)]
+struct ResolvedRule {
+    fields: [Option<FieldSource>; 8],
+    options: Vec<FieldSource>,
+}
+
+impl ResolvedRule {
+    /// Fills slots from positional fields; a plain number in the ideal slot is the grade, as in [`parse_and_insert_rules`].
+    fn set_positional(&mut self, positional: &[&str], template: Option<&str>, line: usize) {
+        for (index, value) in positional.iter().enumerate().take(RULE_FIELDS.len()) {
+            let value = value.trim();
+            let slot = if index == 6 && !value.starts_with('*') && value != "0" && value.parse::<u32>().is_ok() {
+                7
+            } else {
+                index
+            };
+            self.fields[slot] = Some(FieldSource {
+                field: RULE_FIELDS[slot].to_string(),
+                value: value.to_string(),
+                template: template.map(str::to_string),
+                line,
+            });
+            if slot == 7 {
+                break;
+            }
+        }
+    }
+
+    /// Applies `key=value` parts: field names replace the field, other options replace an earlier option of the same key.
+    fn overlay(&mut self, options: &[(String, &str)], template: Option<&str>, line: usize) {
+        for (key, value) in options {
+            let source = FieldSource {
+                field: key.clone(),
+                value: value.to_string(),
+                template: template.map(str::to_string),
+                line,
+            };
+            match RULE_FIELDS.iter().position(|field| field == key) {
+                Some(slot) => self.fields[slot] = Some(source),
+                None => {
+                    self.options.retain(|option| option.field != *key);
+                    self.options.push(source);
+                }
+            }
+        }
+    }
+
+    /// The rule of a group as the base of a member's own rule, fields the group left at their default stay unset.
+    fn from_group(group: &ProcessGroup) -> Self {
+        let mut resolved = ResolvedRule::default();
+        for source in group.rule.iter().filter(|source| source.line != 0) {
+            let source = FieldSource {
+                template: Some(source.template.clone().unwrap_or_else(|| format!("group {}", group.name))),
+                ..source.clone()
+            };
+            match RULE_FIELDS.iter().position(|field| *field == source.field) {
+                Some(slot) => resolved.fields[slot] = Some(source),
+                None => resolved.options.push(source),
+            }
+        }
+        resolved
+    }
+
+    /// Every field with defaults filled in, followed by the options.
+    fn sources(&self) -> Vec<FieldSource> {
+        let fields = self.fields.iter().enumerate().map(|(slot, source)| {
+            source.clone().unwrap_or_else(|| FieldSource {
+                field: RULE_FIELDS[slot].to_string(),
+                value: RULE_FIELD_DEFAULTS[slot].to_string(),
+                template: None,
+                line: 0,
+            })
+        });
+        fields.chain(self.options.iter().cloned()).collect()
+    }
+}
+
+/// Resolves a template and its ancestors, `stack` holds the templates being resolved to detect cycles.
+fn resolve_template(name: &str, result: &ConfigResult, stack: &mut Vec<String>) -> std::result::Result<ResolvedRule, String> {
+    let Some(template) = result.templates.get(name) else {
+        return Err(format!("Unknown template '&{}'", name));
+    };
+    if stack.iter().any(|open| open == name) {
+        return Err(format!("Template cycle &{} -> &{}", stack.join(" -> &"), name));
+    }
+    stack.push(name.to_string());
+    let parts: Vec<&str> = template.parts.iter().map(String::as_str).collect();
+    let (positional, options) = split_rule_options(&parts);
+    let mut resolved = match positional.first().and_then(|first| first.trim().strip_prefix('&')) {
+        Some(parent) => {
+            if positional.len() > 1 {
+                return Err(format!(
+                    "Template '&{}' extends '&{}' and must override fields by name, e.g. cpuset=*e",
+                    name,
+                    parent.trim()
+                ));
+            }
+            resolve_template(&parent.trim().to_lowercase(), result, stack)?
+        }
+        None => {
+            let mut resolved = ResolvedRule::default();
+            resolved.set_positional(&positional, Some(&format!("&{}", name)), template.line);
+            resolved
+        }
+    };
+    resolved.overlay(&options, Some(&format!("&{}", name)), template.line);
+    stack.pop();
+    Ok(resolved)
+}
+
+/// Resolves a rule's template reference and named field overrides into ordinary rule parts.
+///
+/// A rule of a single group member with only named fields (`cs2.exe:io=high`) starts from the group rule.
+/// Rules without any of these are returned unchanged. The effective fields of every member are recorded
+/// in `result.explanations`. Returns `None` when the template cannot be resolved.
+fn expand_rule_parts(members: &[String], rule_parts: &[&str], line_number: usize, result: &mut ConfigResult) -> Option<Vec<String>> {
+    let (positional, options) = split_rule_options(rule_parts);
+    let template = positional.first().and_then(|first| first.trim().strip_prefix('&'));
+    let group_base = match members {
+        [member] if positional.is_empty() => result.member_group(member).map(ResolvedRule::from_group),
+        _ => None,
+    };
+    let extends_group = group_base.is_some();
+    let mut resolved = match template {
+        Some(template) => {
+            if positional.len() > 1 {
+                result.errors.push(format!(
+                    "Line {}: Rules extending '&{}' must override fields by name, e.g. cpuset=*e",
+                    line_number,
+                    template.trim()
+                ));
+                return None;
+            }
+            match resolve_template(&template.trim().to_lowercase(), result, &mut Vec::new()) {
+                Ok(resolved) => resolved,
+                Err(e) => {
+                    result.errors.push(format!("Line {}: {}", line_number, e));
+                    return None;
+                }
+            }
+        }
+        None => match group_base {
+            Some(base) => base,
+            None => {
+                let mut resolved = ResolvedRule::default();
+                resolved.set_positional(&positional, None, line_number);
+                resolved
+            }
+        },
+    };
+    resolved.overlay(&options, None, line_number);
+    for member in members {
+        result.explanations.insert(member.clone(), resolved.sources());
+    }
+
+    let named_fields = options.iter().any(|(key, _)| RULE_FIELDS.contains(&key.as_str()));
+    if template.is_none() && !named_fields && !extends_group {
+        return Some(rule_parts.iter().map(|part| part.to_string()).collect());
+    }
+    Some(
+        resolved
+            .sources()
+            .into_iter()
+            .enumerate()
+            .map(|(index, source)| {
+                if index < RULE_FIELDS.len() {
+                    source.value
+                } else {
+                    format!("{}={}", source.field, source.value)
+                }
+            })
+            .collect(),
+    )
+}
+
 /// Parses rule fields and inserts config entries for all group members.
 ///
-/// Rule format: priority:affinity:cpuset:prime_cpus:io_priority:memory_priority:ideal_processor:grade
+/// Rule format: priority:affinity:cpuset:prime_cpus:io_priority:memory_priority:ideal_processor:grade[:option=value...]
 /// Each field is optional with sensible defaults.
+/// Rules inside the active profile block (`profile` is `Some`) silently replace global rules for the same process.
+/// The first field may instead name a template (`&name`), fields can be overridden by name (`cpuset=*e`).
+/// `group_label` names the rule in its origin: the group label, or the process name for a single-process rule.
 fn parse_and_insert_rules(
     members: &[String],
+    group_label: &str,
     rule_parts: &[&str],
     line_number: usize,
+    profile: Option<&str>,
     cpu_aliases: &HashMap<String, List<[u32; CONSUMER_CPUS]>>,
     result: &mut ConfigResult,
 ) {
+    let Some(expanded_parts) = expand_rule_parts(members, rule_parts, line_number, result) else {
+        return;
+    };
+    let rule_parts: Vec<&str> = expanded_parts.iter().map(String::as_str).collect();
+    let (rule_parts, rule_options) = split_rule_options(&rule_parts);
+    let mut prime_placement = PrimePlacement::All;
+    let mut prime_pin_mode = PrimePinMode::CpuSet;
+    let mut power_throttling = PowerThrottling::None;
+    let mut thread_power_throttling = PowerThrottling::None;
+    let mut job_limits = JobLimits::unlimited(JobLimits::rule_key(members));
+    let mut ideal_reset_strategy = IdealResetStrategy::RoundRobin;
+    let mut ideal_reset_seed = None;
+    let mut action = RuleAction::None;
+    let mut suspend_while: Vec<String> = Vec::new();
+    let mut condition: Option<RuleCondition> = None;
+    let mut schedule: Option<Schedule> = None;
+    let mut delay: Option<u32> = None;
+    let mut stage_times: Option<Vec<u32>> = None;
+    let mut stage_modules: Vec<String> = Vec::new();
+    for (key, value) in &rule_options {
+        match key.as_str() {
+            "while" => match RuleCondition::parse(value) {
+                Ok(parsed) => {
+                    for name in members {
+                        if parsed.processes.contains(name) {
+                            result.warnings.push(format!(
+                                "Line {}: Condition '{}' of '{}' depends on the process itself",
+                                line_number, parsed, name
+                            ));
+                        }
+                    }
+                    condition = Some(parsed);
+                }
+                Err(e) => result.errors.push(format!("Line {}: Invalid while condition: {}", line_number, e)),
+            },
+            "schedule" => match Schedule::parse(value) {
+                Ok(parsed) => schedule = Some(parsed),
+                Err(e) => result
+                    .errors
+                    .push(format!("Line {}: Invalid schedule '{}': {}", line_number, value, e)),
+            },
+            "delay" => match parse_stage_time(value) {
+                Some(time) => delay = Some(time),
+                None => result.errors.push(format!(
+                    "Line {}: Invalid delay '{}' - expected seconds or a time like 500ms or 2m",
+                    line_number, value
+                )),
+            },
+            "stages" => {
+                let times: Option<Vec<u32>> = value
+                    .split(';')
+                    .filter(|time| !time.trim().is_empty())
+                    .map(parse_stage_time)
+                    .collect();
+                match times {
+                    Some(mut times) if !times.is_empty() => {
+                        times.sort_unstable();
+                        times.dedup();
+                        stage_times = Some(times);
+                    }
+                    _ => result.errors.push(format!(
+                        "Line {}: Invalid stages '{}' - expected times after process start like 0;5;30",
+                        line_number, value
+                    )),
+                }
+            }
+            "on_module" => {
+                stage_modules = value
+                    .split(';')
+                    .map(|module| module.trim().to_lowercase())
+                    .filter(|module| !module.is_empty())
+                    .collect();
+                if stage_modules.is_empty() {
+                    result
+                        .errors
+                        .push(format!("Line {}: on_module needs at least one module name", line_number));
+                }
+            }
+            "action" => match RuleAction::from_str(value) {
+                Some(parsed) => action = parsed,
+                None => result.errors.push(format!(
+                    "Line {}: Unknown rule action '{}' - expected terminate or none",
+                    line_number, value
+                )),
+            },
+            "suspend_while" => {
+                suspend_while = value
+                    .split(';')
+                    .map(|trigger| trigger.trim().to_lowercase())
+                    .filter(|trigger| !trigger.is_empty())
+                    .collect();
+                if suspend_while.is_empty() {
+                    result.errors.push(format!(
+                        "Line {}: suspend_while needs at least one trigger process name",
+                        line_number
+                    ));
+                }
+            }
+            "reset" => match IdealResetStrategy::from_str(value) {
+                Some(strategy) => ideal_reset_strategy = strategy,
+                None => result.errors.push(format!(
+                    "Line {}: Unknown ideal processor reset strategy '{}' - expected round_robin, cores_first, packed, spread or random",
+                    line_number, value
+                )),
+            },
+            "seed" => match value.parse::<u64>() {
+                Ok(seed) => ideal_reset_seed = Some(seed),
+                Err(_) => result
+                    .errors
+                    .push(format!("Line {}: Invalid seed '{}' (expected u64)", line_number, value)),
+            },
+            "placement" => match PrimePlacement::from_str(value) {
+                Some(placement) => prime_placement = placement,
+                None => result.errors.push(format!(
+                    "Line {}: Unknown prime placement '{}' - expected all, core, pack or spread",
+                    line_number, value
+                )),
+            },
+            "power" | "thread_power" => {
+                let throttling = PowerThrottling::from_str(value);
+                if throttling == PowerThrottling::None && !value.eq_ignore_ascii_case("none") {
+                    result.errors.push(format!(
+                        "Line {}: Unknown power throttling '{}' - expected on, off or none",
+                        line_number, value
+                    ));
+                } else if key == "power" {
+                    power_throttling = throttling;
+                } else {
+                    thread_power_throttling = throttling;
+                }
+            }
+            "cpu_rate" => match value.trim().trim_end_matches('%').parse::<u32>() {
+                Ok(rate) if (1..=100).contains(&rate) => job_limits.cpu_rate = Some(rate),
+                _ => result.errors.push(format!(
+                    "Line {}: Invalid cpu_rate '{}' - expected a percentage from 1 to 100",
+                    line_number, value
+                )),
+            },
+            "working_set" => {
+                let sizes = match value.split_once('-') {
+                    Some((min, max)) => parse_byte_size(min).zip(parse_byte_size(max)).map(|(min, max)| (Some(min), max)),
+                    None => parse_byte_size(value).map(|max| (None, max)),
+                };
+                match sizes {
+                    Some((min, max)) if max > 0 && min.is_none_or(|min| (1..=max).contains(&min)) => {
+                        job_limits.min_working_set = min;
+                        job_limits.working_set = Some(max);
+                    }
+                    _ => result.errors.push(format!(
+                        "Line {}: Invalid working_set '{}' - expected a size like 512M, or min-max like 64M-512M",
+                        line_number, value
+                    )),
+                }
+            }
+            "commit" => match parse_byte_size(value) {
+                Some(bytes) if bytes > 0 => job_limits.commit = Some(bytes),
+                _ => result.errors.push(format!(
+                    "Line {}: Invalid commit '{}' - expected a size like 512M or 2G",
+                    line_number, value
+                )),
+            },
+            "job" => {
+                let name = value.trim().to_lowercase();
+                if name.is_empty() {
+                    result.errors.push(format!("Line {}: Empty job name", line_number));
+                } else {
+                    job_limits.key = format!("job:{}", name);
+                }
+            }
+            "processes" => match value.trim().parse::<u32>() {
+                Ok(count) if count > 0 => job_limits.active_processes = Some(count),
+                _ => result.errors.push(format!(
+                    "Line {}: Invalid processes '{}' - expected a positive process count",
+                    line_number, value
+                )),
+            },
+            "pin" => match PrimePinMode::from_str(value) {
+                Some(pin_mode) => prime_pin_mode = pin_mode,
+                None => result.errors.push(format!(
+                    "Line {}: Unknown prime pin mode '{}' - expected cpuset, affinity or ideal",
+                    line_number, value
+                )),
+            },
+            _ => result
+                .warnings
+                .push(format!("Line {}: Unknown rule option '{}' - will be ignored", line_number, key)),
+        }
+    }
+
+    // rules in a scheduled profile block inherit its schedule unless they bring their own
+    let schedule = schedule.or_else(|| profile.and_then(|profile| result.profile_schedules.get(profile).cloned()));
+    let condition = match (condition, schedule) {
+        (Some(condition), schedule) => Some(RuleCondition { schedule, ..condition }),
+        (None, Some(schedule)) => Some(RuleCondition {
+            processes: Vec::new(),
+            negate: false,
+            schedule: Some(schedule),
+        }),
+        (None, None) => None,
+    };
+
+    if delay.is_some() && stage_times.is_some() {
+        result.errors.push(format!(
+            "Line {}: delay and stages cannot be combined - list the delay as the first stage instead",
+            line_number
+        ));
+    }
+    let stages = (delay.is_some() || stage_times.is_some() || !stage_modules.is_empty()).then(|| ApplyStages {
+        times_ms: stage_times.or(delay.map(|delay| vec![delay])).unwrap_or_else(|| vec![0]),
+        modules: stage_modules,
+    });
+
+    if action != RuleAction::None || !suspend_while.is_empty() {
+        if action != RuleAction::None && !suspend_while.is_empty() {
+            result.errors.push(format!(
+                "Line {}: action={} and suspend_while cannot be combined in one rule",
+                line_number,
+                action.as_str()
+            ));
+        }
+        for name in members {
+            if PROTECTED_PROCESSES.contains(&name.as_str()) {
+                result.errors.push(format!(
+                    "Line {}: '{}' is a protected system process and cannot be terminated or suspended",
+                    line_number, name
+                ));
+            }
+            if suspend_while.contains(name) {
+                result.errors.push(format!(
+                    "Line {}: '{}' cannot be suspended while itself is running",
+                    line_number, name
+                ));
+            }
+        }
+    }
+
     if rule_parts.len() < 2 {
         result.errors.push(format!(
             "Line {}: Too few fields ({}) - expected at least 2 (priority,affinity)",
@@ -451,6 +1944,21 @@ fn parse_and_insert_rules(
     let (cpu_set_cpus, cpu_set_reset_ideal) = if rule_parts.len() >= 3 {
         let spec = rule_parts[2].trim();
         if let Some(stripped) = spec.strip_prefix('@') {
+            // `@packed@*p` selects the reset strategy together with the reset, overriding `reset=`
+            let stripped = match stripped.split_once('@') {
+                Some((strategy, cpus)) => {
+                    match IdealResetStrategy::from_str(strategy.trim()) {
+                        Some(strategy) => ideal_reset_strategy = strategy,
+                        None => result.errors.push(format!(
+                            "Line {}: Unknown ideal processor reset strategy '{}' - expected round_robin, cores_first, packed, spread or random",
+                            line_number,
+                            strategy.trim()
+                        )),
+                    }
+                    cpus
+                }
+                None => stripped,
+            };
             (
                 resolve_cpu_spec(stripped, "cpuset", line_number, cpu_aliases, &mut result.errors),
                 true,
@@ -549,8 +2057,29 @@ fn parse_and_insert_rules(
                                 continue;
                             }
 
-                            if let Some(bang_pos) = prefix_str.find('!') {
-                                let prefix = prefix_str[..bang_pos].to_string();
+                            // An optional (max) / (min-max) quota may appear anywhere after the selector
+                            let mut quota = ThreadQuota::UNLIMITED;
+                            let quota_stripped: String;
+                            let prefix_str = match (prefix_str.find('('), prefix_str.find(')')) {
+                                (Some(open), Some(close)) if open < close => {
+                                    match ThreadQuota::parse(&prefix_str[open + 1..close]) {
+                                        Ok(parsed) => quota = parsed,
+                                        Err(e) => {
+                                            result
+                                                .errors
+                                                .push(format!("Line {}: Invalid thread quota in prime specification: {}", line_number, e));
+                                            continue;
+                                        }
+                                    }
+                                    quota_stripped = format!("{}{}", &prefix_str[..open], &prefix_str[close + 1..]);
+                                    quota_stripped.trim()
+                                }
+                                _ => prefix_str,
+                            };
+                            // A leading '!' marks an exclusion, a later '!' separates the thread priority
+                            let priority_search_start = if prefix_str.starts_with('!') { 1 } else { 0 };
+                            let (selector_str, thread_prio) = if let Some(bang_pos) = prefix_str[priority_search_start..].find('!') {
+                                let bang_pos = bang_pos + priority_search_start;
                                 let prio_str = &prefix_str[bang_pos + 1..];
                                 let thread_prio = ThreadPriority::from_str(prio_str.trim());
                                 if thread_prio == ThreadPriority::None && !prio_str.trim().eq_ignore_ascii_case("none") {
@@ -559,25 +2088,69 @@ fn parse_and_insert_rules(
                                         line_number, prio_str
                                     ));
                                 }
-                                all_prefixes.push(PrimePrefix {
-                                    prefix,
-                                    cpus: Some(segment_cpus.clone()),
-                                    thread_priority: thread_prio,
-                                });
+                                (&prefix_str[..bang_pos], thread_prio)
                             } else {
-                                all_prefixes.push(PrimePrefix {
-                                    prefix: prefix_str.to_string(),
-                                    cpus: Some(segment_cpus.clone()),
-                                    thread_priority: ThreadPriority::None,
-                                });
+                                (prefix_str, ThreadPriority::None)
+                            };
+                            let selector = match ThreadSelector::parse(selector_str) {
+                                Ok(selector) => selector,
+                                Err(e) => {
+                                    result.errors.push(format!(
+                                        "Line {}: Invalid thread selector in prime specification: {}",
+                                        line_number, e
+                                    ));
+                                    continue;
+                                }
+                            };
+                            if selector.exclude && thread_prio != ThreadPriority::None {
+                                result.warnings.push(format!(
+                                    "Line {}: Thread priority on exclusion selector '{}' is ignored",
+                                    line_number, selector
+                                ));
+                            }
+                            if selector.exclude && quota.is_limited() {
+                                result.warnings.push(format!(
+                                    "Line {}: Thread quota on exclusion selector '{}' is ignored",
+                                    line_number, selector
+                                ));
+                            } else if quota.max != usize::MAX && quota.max > segment_cpus.len() {
+                                result.errors.push(format!(
+                                    "Line {}: Thread quota {} for '{}' exceeds its {} CPUs",
+                                    line_number,
+                                    quota,
+                                    selector,
+                                    segment_cpus.len()
+                                ));
                             }
+                            all_prefixes.push(PrimePrefix {
+                                selector,
+                                quota,
+                                cpus: Some(segment_cpus.clone()),
+                                thread_priority: thread_prio,
+                            });
                         }
                     }
                 }
 
-                if all_prefixes.is_empty() {
+                let reserved_threads: usize = all_prefixes
+                    .iter()
+                    .filter(|prefix| !prefix.selector.exclude)
+                    .map(|prefix| prefix.quota.min)
+                    .sum();
+                if reserved_threads > base_cpus.len() {
+                    result.errors.push(format!(
+                        "Line {}: Prime thread quotas reserve {} threads but only {} prime CPUs are available",
+                        line_number,
+                        reserved_threads,
+                        base_cpus.len()
+                    ));
+                }
+
+                // Exclusion-only specs still prime every other thread on the base CPUs
+                if !all_prefixes.iter().any(|prefix| !prefix.selector.exclude) {
                     all_prefixes.push(PrimePrefix {
-                        prefix: "".to_string(),
+                        selector: ThreadSelector::all(),
+                        quota: ThreadQuota::UNLIMITED,
                         cpus: None,
                         thread_priority: ThreadPriority::None,
                     });
@@ -589,7 +2162,8 @@ fn parse_and_insert_rules(
                 (
                     cpus,
                     vec![PrimePrefix {
-                        prefix: "".to_string(),
+                        selector: ThreadSelector::all(),
+                        quota: ThreadQuota::UNLIMITED,
                         cpus: None,
                         thread_priority: ThreadPriority::None,
                     }],
@@ -601,7 +2175,8 @@ fn parse_and_insert_rules(
         (
             List::new(),
             vec![PrimePrefix {
-                prefix: "".to_string(),
+                selector: ThreadSelector::all(),
+                quota: ThreadQuota::UNLIMITED,
                 cpus: None,
                 thread_priority: ThreadPriority::None,
             }],
@@ -609,6 +2184,23 @@ fn parse_and_insert_rules(
         )
     };
 
+    // a thread affinity mask only covers processor group 0, SetThreadAffinityMask cannot pin to other groups
+    if prime_pin_mode == PrimePinMode::Affinity {
+        let outside_group_0: List<[u32; CONSUMER_CPUS]> = prime_threads_cpus
+            .iter()
+            .chain(prime_threads_prefixes.iter().flat_map(|prefix| prefix.cpus.iter().flatten()))
+            .copied()
+            .filter(|&cpu| cpu >= 64)
+            .collect();
+        if !outside_group_0.is_empty() {
+            result.errors.push(format!(
+                "Line {}: pin=affinity only supports processor group 0 CPUs (0-63), prime CPUs include {}",
+                line_number,
+                format_cpu_indices(&outside_group_0)
+            ));
+        }
+    }
+
     let io_priority = if rule_parts.len() >= 5 {
         let io_str = rule_parts[4].trim();
         let io_p = IOPriority::from_str(io_str);
@@ -681,6 +2273,44 @@ fn parse_and_insert_rules(
     };
 
     for name in members {
+        // a rule redefined in a later file is a layered override, a duplicate within one file stays a warning
+        let overrides_layer = result
+            .rule_lines
+            .get(name)
+            .is_some_and(|line| result.line_file(*line) != result.line_file(line_number));
+        // a member's own single-process rule after its group refines the group rule instead of duplicating it
+        let overrides_group = group_label == name
+            && result
+                .member_group(name)
+                .is_some_and(|group| result.rule_lines.get(name) == Some(&group.line));
+        if overrides_layer || overrides_group {
+            for graded in result.process_level_configs.values_mut() {
+                graded.remove(name);
+            }
+            for graded in result.thread_level_configs.values_mut() {
+                graded.remove(name);
+            }
+        }
+        result.rule_lines.insert(name.clone(), line_number);
+        if profile.is_some() {
+            let is_global = |rule_profile: &Option<String>| rule_profile.is_none();
+            let overrides_global = result
+                .process_level_configs
+                .values()
+                .any(|f| f.get(name).is_some_and(|config| is_global(&config.profile)))
+                || result
+                    .thread_level_configs
+                    .values()
+                    .any(|f| f.get(name).is_some_and(|config| is_global(&config.profile)));
+            if overrides_global {
+                for graded in result.process_level_configs.values_mut() {
+                    graded.remove(name);
+                }
+                for graded in result.thread_level_configs.values_mut() {
+                    graded.remove(name);
+                }
+            }
+        }
         if result.process_level_configs.values().any(|f| f.contains_key(name)) {
             result.redundant_rules_count += 1;
             result.warnings.push(format!(
@@ -700,7 +2330,13 @@ fn parse_and_insert_rules(
             || !&affinity_cpus.is_empty()
             || !&cpu_set_cpus.is_empty()
             || io_priority != IOPriority::None
-            || memory_priority != MemoryPriority::None;
+            || memory_priority != MemoryPriority::None
+            || power_throttling != PowerThrottling::None
+            || !job_limits.is_empty()
+            || action != RuleAction::None
+            || !suspend_while.is_empty()
+            // staged rules keep a process level entry so the delay also holds back thread level settings
+            || stages.is_some();
         if process_level_valid {
             result.process_level_configs.entry(grade).or_default().insert(
                 name.clone(),
@@ -712,48 +2348,543 @@ fn parse_and_insert_rules(
                     cpu_set_reset_ideal,
                     io_priority,
                     memory_priority,
+                    power_throttling,
+                    job_limits: (!job_limits.is_empty()).then(|| job_limits.clone()),
+                    ideal_reset_strategy,
+                    ideal_reset_seed,
+                    action,
+                    suspend_while: suspend_while.clone(),
+                    origin: match profile {
+                        Some(profile) => format!("line {} {} [profile {}]", line_number, group_label, profile),
+                        None => format!("line {} {}", line_number, group_label),
+                    },
+                    condition: condition.clone(),
+                    profile: profile.map(str::to_string),
+                    stages: stages.clone(),
                 },
             );
         }
 
-        let thread_level_valid = !&prime_threads_cpus.is_empty() || track_top_x_threads != 0 || !&ideal_processor_rules.is_empty();
-        if thread_level_valid {
-            let thread_level_config = ThreadLevelConfig {
-                name: name.clone(),
-                prime_threads_cpus: prime_threads_cpus.clone(),
-                prime_threads_prefixes: prime_threads_prefixes.clone(),
-                track_top_x_threads,
-                ideal_processor_rules: ideal_processor_rules.clone(),
+        let thread_level_valid = !&prime_threads_cpus.is_empty()
+            || track_top_x_threads != 0
+            || !&ideal_processor_rules.is_empty()
+            || thread_power_throttling != PowerThrottling::None;
+        if thread_level_valid {
+            let thread_level_config = ThreadLevelConfig {
+                name: name.clone(),
+                prime_threads_cpus: prime_threads_cpus.clone(),
+                prime_threads_prefixes: prime_threads_prefixes.clone(),
+                track_top_x_threads,
+                ideal_processor_rules: ideal_processor_rules.clone(),
+                prime_placement,
+                prime_pin_mode,
+                thread_power_throttling,
+                condition: condition.clone(),
+                profile: profile.map(str::to_string),
+            };
+            result
+                .thread_level_configs
+                .entry(grade)
+                .or_default()
+                .insert(name.clone(), thread_level_config);
+            result.thread_level_configs_count += 1;
+        }
+
+        if !process_level_valid && !thread_level_valid {
+            result.warnings.push(format!("No valid rules(all none/0) for process '{}'", name));
+        }
+    }
+    result.process_rules_count += members.len();
+}
+
+/// Reads the config file with `profile` as the active profile.
+///
+/// Global lines are parsed first and the active profile block second, so its constants, aliases and
+/// rules override global ones regardless of where the block sits in the file.
+pub fn read_config<P: AsRef<Path>>(path: P, profile: Option<&str>) -> ConfigResult {
+    read_config_as(path, profile, MachineInfo::current())
+}
+
+/// Reads the config as it would be read on `machine`, which selects the `[if ...]` blocks to use.
+pub fn read_config_as<P: AsRef<Path>>(path: P, profile: Option<&str>, machine: &MachineInfo) -> ConfigResult {
+    let mut result = ConfigResult::default();
+
+    let path = path.as_ref();
+    let mut lines: Vec<String> = Vec::new();
+    if path.is_dir() {
+        // conf.d mode: every *.ini of the directory in name order, as if listed with @INCLUDE_DIR
+        result.watched_dirs.push(path.to_path_buf());
+        match list_config_dir(path) {
+            Ok(files) => {
+                for file in files {
+                    read_config_file(&file, &mut lines, &mut Vec::new(), &mut result);
+                }
+            }
+            Err(e) => {
+                result.errors.push(format!("Cannot read config directory: {}", e));
+                return result;
+            }
+        }
+    } else {
+        result.watched_files.push(path.to_path_buf());
+        if let Err(e) = File::open(path) {
+            result.errors.push(format!("Cannot open config file: {}", e));
+            return result;
+        }
+        read_config_file(path, &mut lines, &mut Vec::new(), &mut result);
+    }
+
+    let lines = select_machine_blocks(&lines, machine, &mut result);
+    let mut cpu_aliases: HashMap<String, List<[u32; CONSUMER_CPUS]>> = HashMap::default();
+    let profile = profile.map(str::to_lowercase);
+    let (global_lines, profile_lines) = split_profile_blocks(&lines, profile.as_deref(), &mut result);
+    parse_config_lines(&global_lines, None, &mut cpu_aliases, &mut result);
+    if let Some(profile) = profile {
+        if result.profiles.contains(&profile) {
+            parse_config_lines(&profile_lines, Some(&profile), &mut cpu_aliases, &mut result);
+            result.active_profile = Some(profile);
+        } else {
+            result.errors.push(format!(
+                "Unknown profile '{}' - declared profiles: {}",
+                profile,
+                if result.profiles.is_empty() {
+                    "none".to_string()
+                } else {
+                    result.profiles.join(", ")
+                }
+            ));
+        }
+    }
+    for group in result.groups.iter().filter(|group| !group.has_rule) {
+        if !result.groups.iter().any(|other| other.includes.contains(&group.name)) {
+            result.warnings.push(format!(
+                "Line {}: Group '{}' has no rule and is not referenced by another group",
+                group.line, group.name
+            ));
+        }
+    }
+    relabel_line_references(&mut result);
+    result.cpu_aliases = cpu_aliases;
+    result
+}
+
+/// Appends the lines of one config file to `lines`, expanding include directives in place.
+///
+/// `@INCLUDE = file` fails when the file is missing, `@INCLUDE_IF_EXISTS = file` skips it silently and
+/// `@INCLUDE_DIR = dir` includes every `*.ini` of the directory in name order. Paths are relative to the
+/// including file and may use `%VAR%` environment variables, e.g. `machines/%COMPUTERNAME%.ini`.
+/// Directive lines are left blank so the combined line numbers can be mapped back through `line_sources`.
+fn read_config_file(path: &Path, lines: &mut Vec<String>, stack: &mut Vec<PathBuf>, result: &mut ConfigResult) {
+    let file_lines: Vec<String> = match File::open(path) {
+        Ok(file) => BufReader::new(file).lines().map_while(Result::ok).collect(),
+        Err(e) => {
+            result.errors.push(format!("Cannot open config file '{}': {}", path.display(), e));
+            return;
+        }
+    };
+    let canonical = path.canonicalize().unwrap_or_else(|_| path.to_path_buf());
+    let file_index = result.config_files.len();
+    result.config_files.push(path.to_path_buf());
+    stack.push(canonical);
+
+    for (i, line) in file_lines.into_iter().enumerate() {
+        result.line_sources.push((file_index, i + 1));
+        let trimmed = line.trim();
+        let directive = trimmed
+            .strip_prefix('@')
+            .and_then(|rest| rest.split_once('='))
+            .and_then(|(key, value)| {
+                let key = key.trim().to_uppercase();
+                matches!(key.as_str(), "INCLUDE" | "INCLUDE_IF_EXISTS" | "INCLUDE_DIR").then(|| (key, value.trim().to_string()))
+            });
+        let Some((key, value)) = directive else {
+            lines.push(line);
+            continue;
+        };
+        lines.push(String::new());
+        let line_number = lines.len();
+        let target = path.parent().unwrap_or(Path::new("")).join(expand_env_vars(&value));
+        if key != "INCLUDE_DIR" {
+            // a missing target is watched too, creating it later triggers a reload
+            result.watched_files.push(target.clone());
+        }
+
+        let included: Vec<PathBuf> = match key.as_str() {
+            "INCLUDE_DIR" => {
+                result.watched_dirs.push(target.clone());
+                match list_config_dir(&target) {
+                    Ok(files) => files,
+                    Err(e) => {
+                        result.errors.push(format!(
+                            "Line {}: Cannot read include directory '{}': {}",
+                            line_number,
+                            target.display(),
+                            e
+                        ));
+                        continue;
+                    }
+                }
+            }
+            _ if target.is_file() => vec![target],
+            "INCLUDE" => {
+                result
+                    .errors
+                    .push(format!("Line {}: Included file '{}' not found", line_number, target.display()));
+                continue;
+            }
+            _ => continue,
+        };
+        for file in included {
+            let canonical = file.canonicalize().unwrap_or_else(|_| file.clone());
+            if stack.contains(&canonical) {
+                result.errors.push(format!(
+                    "Line {}: Include cycle - '{}' is already being read",
+                    line_number,
+                    file.display()
+                ));
+                continue;
+            }
+            read_config_file(&file, lines, stack, result);
+        }
+    }
+    // end-of-file marker, line 0 of the file
+    lines.push(String::new());
+    result.line_sources.push((file_index, 0));
+    stack.pop();
+}
+
+/// The `*.ini` files of a directory, sorted by name so layering order is predictable (`00-base.ini`, `50-machine.ini`, ...).
+fn list_config_dir(dir: &Path) -> std::io::Result<Vec<PathBuf>> {
+    let mut files: Vec<PathBuf> = read_dir(dir)?
+        .filter_map(|entry| entry.ok().map(|entry| entry.path()))
+        .filter(|path| path.is_file() && path.extension().is_some_and(|ext| ext.eq_ignore_ascii_case("ini")))
+        .collect();
+    files.sort();
+    Ok(files)
+}
+
+/// Replaces `%VAR%` with the environment variable, unknown variables are left as written.
+fn expand_env_vars(text: &str) -> String {
+    let mut expanded = String::new();
+    let mut rest = text;
+    while let Some(start) = rest.find('%') {
+        let Some(len) = rest[start + 1..].find('%') else {
+            break;
+        };
+        let name = &rest[start + 1..start + 1 + len];
+        expanded.push_str(&rest[..start]);
+        match std::env::var(name) {
+            Ok(value) if !name.is_empty() => expanded.push_str(&value),
+            _ => expanded.push_str(&rest[start..start + len + 2]),
+        }
+        rest = &rest[start + len + 2..];
+    }
+    expanded.push_str(rest);
+    expanded
+}
+
+/// Rewrites the combined line numbers in diagnostics and rule origins to `Line N [file]` once more than one file was read.
+fn relabel_line_references(result: &mut ConfigResult) {
+    if result.config_files.len() <= 1 {
+        return;
+    }
+    let relabel = |result: &ConfigResult, message: &str, prefix: &str| -> Option<String> {
+        let rest = message.strip_prefix(prefix)?;
+        let digits = rest.find(|c: char| !c.is_ascii_digit())?;
+        let location = result.line_label(rest[..digits].parse().ok()?);
+        Some(format!("{}{}{}", prefix, location, &rest[digits..]))
+    };
+    let errors: Vec<String> = result
+        .errors
+        .iter()
+        .map(|error| relabel(result, error, "Line ").unwrap_or_else(|| error.clone()))
+        .collect();
+    let warnings: Vec<String> = result
+        .warnings
+        .iter()
+        .map(|warning| relabel(result, warning, "Line ").unwrap_or_else(|| warning.clone()))
+        .collect();
+    let origins: Vec<(u32, String, String)> = result
+        .process_level_configs
+        .iter()
+        .flat_map(|(grade, graded)| graded.values().map(move |config| (*grade, config)))
+        .filter_map(|(grade, config)| Some((grade, config.name.clone(), relabel(result, &config.origin, "line ")?)))
+        .collect();
+    result.errors = errors;
+    result.warnings = warnings;
+    for (grade, name, origin) in origins {
+        if let Some(config) = result
+            .process_level_configs
+            .get_mut(&grade)
+            .and_then(|graded| graded.get_mut(&name))
+        {
+            config.origin = origin;
+        }
+    }
+}
+
+#[derive(Debug)]
+struct MachineBlock {
+    line_number: usize,
+    /// Whether the enclosing blocks are active.
+    parent_active: bool,
+    /// Whether the current branch is the one taken.
+    taking: bool,
+    /// Whether an earlier branch of this block was taken.
+    taken: bool,
+    seen_else: bool,
+}
+
+/// Keeps the lines of the `[if ...]`, `[elif ...]` or `[else]` branch matching `machine`, blocks end with `[endif]` and may nest.
+///
+/// Lines of other branches and the block headers are blanked so line numbers stay valid.
+/// A block has to be closed in the file it was opened in.
+fn select_machine_blocks(lines: &[String], machine: &MachineInfo, result: &mut ConfigResult) -> Vec<String> {
+    let mut selected = vec![String::new(); lines.len()];
+    let mut blocks: Vec<MachineBlock> = Vec::new();
+    let is_active = |blocks: &[MachineBlock]| blocks.last().is_none_or(|block| block.parent_active && block.taking);
+    let evaluate = |condition: &str, line_number: usize, result: &mut ConfigResult| match machine.matches(condition) {
+        Ok(matched) => matched,
+        Err(e) => {
+            result
+                .errors
+                .push(format!("Line {}: Invalid machine condition '{}': {}", line_number, condition, e));
+            false
+        }
+    };
+
+    for (i, line) in lines.iter().enumerate() {
+        let line_number = i + 1;
+        if let Some((file_index, 0)) = result.line_sources.get(i).copied() {
+            while let Some(block) = blocks.last()
+                && result.line_file(block.line_number) == file_index
+            {
+                result
+                    .errors
+                    .push(format!("Line {}: Unclosed [if] block - missing [endif]", block.line_number));
+                blocks.pop();
+            }
+        }
+        let trimmed = line.trim();
+        let lowered = trimmed.to_lowercase();
+        let header = |keyword: &str| {
+            lowered
+                .strip_prefix(keyword)
+                .and_then(|rest| rest.strip_suffix(']'))
+                .filter(|rest| rest.starts_with(char::is_whitespace))
+                .map(|_| trimmed[keyword.len()..trimmed.len() - 1].trim())
+        };
+
+        if let Some(condition) = header("[if") {
+            let parent_active = is_active(&blocks);
+            // evaluated even inside inactive branches so -validate reports every invalid condition
+            let taking = evaluate(condition, line_number, result) && parent_active;
+            blocks.push(MachineBlock {
+                line_number,
+                parent_active,
+                taking,
+                taken: taking,
+                seen_else: false,
+            });
+        } else if let Some(condition) = header("[elif") {
+            let Some(block) = blocks.last() else {
+                result.errors.push(format!("Line {}: [elif] without a matching [if]", line_number));
+                continue;
             };
-            result
-                .thread_level_configs
-                .entry(grade)
-                .or_default()
-                .insert(name.clone(), thread_level_config);
-            result.thread_level_configs_count += 1;
+            if block.seen_else {
+                result.errors.push(format!("Line {}: [elif] after [else]", line_number));
+            }
+            let pending = block.parent_active && !block.taken;
+            let taking = evaluate(condition, line_number, result) && pending;
+            if let Some(block) = blocks.last_mut() {
+                block.taking = taking;
+                block.taken |= taking;
+            }
+        } else if lowered == "[else]" {
+            let Some(block) = blocks.last_mut() else {
+                result.errors.push(format!("Line {}: [else] without a matching [if]", line_number));
+                continue;
+            };
+            if block.seen_else {
+                result.errors.push(format!("Line {}: Second [else] in one [if] block", line_number));
+            }
+            block.seen_else = true;
+            block.taking = !block.taken;
+            block.taken = true;
+        } else if lowered == "[endif]" {
+            if blocks.pop().is_none() {
+                result.errors.push(format!("Line {}: [endif] without a matching [if]", line_number));
+            }
+        } else if is_active(&blocks) {
+            selected[i] = line.clone();
         }
+    }
+    for block in blocks {
+        result
+            .errors
+            .push(format!("Line {}: Unclosed [if] block - missing [endif]", block.line_number));
+    }
+    selected
+}
 
-        if !process_level_valid && !thread_level_valid {
-            result.warnings.push(format!("No valid rules(all none/0) for process '{}'", name));
+/// Separates `[profile name]` ... `[end]` blocks from the global lines.
+///
+/// Both returned lists keep the original length (other lines blanked) so line numbers stay valid.
+/// The second list holds the lines of the `active` profile only.
+fn split_profile_blocks(lines: &[String], active: Option<&str>, result: &mut ConfigResult) -> (Vec<String>, Vec<String>) {
+    let mut global_lines = vec![String::new(); lines.len()];
+    let mut profile_lines = vec![String::new(); lines.len()];
+    let mut current: Option<(String, usize)> = None;
+    for (i, line) in lines.iter().enumerate() {
+        let trimmed = line.trim();
+        let lowered = trimmed.to_lowercase();
+        if let Some(header) = lowered.strip_prefix("[profile").and_then(|rest| rest.strip_suffix(']')) {
+            let (name, schedule) = match header.split_once("schedule=") {
+                Some((name, schedule)) => (name.trim().to_string(), Some(schedule.trim())),
+                None => (header.trim().to_string(), None),
+            };
+            if let Some(schedule) = schedule {
+                match Schedule::parse(schedule) {
+                    Ok(parsed) => {
+                        result.profile_schedules.insert(name.clone(), parsed);
+                    }
+                    Err(e) => result.errors.push(format!(
+                        "Line {}: Invalid schedule '{}' on profile '{}': {}",
+                        i + 1,
+                        schedule,
+                        name,
+                        e
+                    )),
+                }
+            }
+            if let Some((open, _)) = &current {
+                result.errors.push(format!(
+                    "Line {}: Profile '{}' starts before profile '{}' was closed with [end]",
+                    i + 1,
+                    name,
+                    open
+                ));
+            }
+            if name.is_empty() {
+                result.errors.push(format!("Line {}: Empty profile name", i + 1));
+            } else if result.profiles.contains(&name) {
+                result.errors.push(format!("Line {}: Profile '{}' declared twice", i + 1, name));
+            } else {
+                result.profiles.push(name.clone());
+            }
+            current = Some((name, i + 1));
+            continue;
+        }
+        if lowered == "[end]" {
+            if current.take().is_none() {
+                result
+                    .errors
+                    .push(format!("Line {}: [end] without a matching [profile name]", i + 1));
+            }
+            continue;
+        }
+        // profile blocks end with their file, lines included from inside a block still belong to it
+        if let Some((name, line_number)) = &current
+            && result.line_sources.get(i) == Some(&(result.line_file(*line_number), 0))
+        {
+            result
+                .errors
+                .push(format!("Line {}: Unclosed profile '{}' - missing [end]", line_number, name));
+            current = None;
+        }
+        match &current {
+            None => global_lines[i] = line.clone(),
+            Some((name, _)) if Some(name.as_str()) == active => profile_lines[i] = line.clone(),
+            Some(_) => {}
         }
     }
-    result.process_rules_count += members.len();
+    if let Some((name, line_number)) = current {
+        result
+            .errors
+            .push(format!("Line {}: Unclosed profile '{}' - missing [end]", line_number, name));
+    }
+    (global_lines, profile_lines)
 }
 
-pub fn read_config<P: AsRef<Path>>(path: P) -> ConfigResult {
-    let mut result = ConfigResult::default();
+/// Applies a `key = value` line of a group block: `enabled`, `owner`, `reason` or `ticket`.
+fn set_group_property(group: &mut ProcessGroup, key: &str, value: &str, line_number: usize, result: &mut ConfigResult) {
+    let value = value.trim();
+    match key {
+        "enabled" => match value.to_lowercase().as_str() {
+            "true" | "yes" | "on" | "1" => group.enabled = true,
+            "false" | "no" | "off" | "0" => group.enabled = false,
+            _ => result.errors.push(format!(
+                "Line {}: Invalid value '{}' for 'enabled' in group '{}' - use true or false",
+                line_number, value, group.name
+            )),
+        },
+        "owner" => group.owner = Some(value.to_string()),
+        "reason" => group.reason = Some(value.to_string()),
+        "ticket" => group.ticket = Some(value.to_string()),
+        _ => result.errors.push(format!(
+            "Line {}: Unknown group property '{}' in group '{}' - use enabled, owner, reason or ticket",
+            line_number, key, group.name
+        )),
+    }
+}
 
-    let file = match File::open(&path) {
-        Ok(f) => f,
-        Err(e) => {
-            result.errors.push(format!("Cannot open config file: {}", e));
-            return result;
+/// Removes the rules an earlier group of the same name gave its members, before that group is redefined.
+///
+/// Members whose rule was replaced after the earlier group keep their own rule.
+fn forget_group_rules(name: &str, result: &mut ConfigResult) {
+    let Some(earlier) = result.groups.iter().find(|known| known.name == name) else {
+        return;
+    };
+    let line = earlier.line;
+    for member in earlier.members.clone() {
+        if result.rule_lines.get(&member) != Some(&line) {
+            continue;
+        }
+        for graded in result.process_level_configs.values_mut() {
+            graded.remove(&member);
+        }
+        for graded in result.thread_level_configs.values_mut() {
+            graded.remove(&member);
+        }
+        result.rule_lines.remove(&member);
+        result.explanations.remove(&member);
+    }
+}
+
+/// Adds a parsed group, replacing an earlier group of the same name, and records which members got its rule.
+fn register_group(group: ProcessGroup, result: &mut ConfigResult) {
+    let index = match result.groups.iter().position(|known| known.name == group.name) {
+        Some(index) => {
+            let earlier = result.groups[index].line;
+            if result.line_file(earlier) == result.line_file(group.line) {
+                result.warnings.push(format!(
+                    "Line {}: Group '{}' redefined (first defined at line {})",
+                    group.line, group.name, earlier
+                ));
+            }
+            index
+        }
+        None => {
+            result.groups.push(group.clone());
+            result.groups.len() - 1
         }
     };
+    // members the new definition dropped no longer belong to the group
+    result.member_groups.retain(|_, known| *known != index);
+    if group.enabled && group.has_rule {
+        for member in &group.members {
+            result.member_groups.insert(member.clone(), index);
+        }
+    }
+    result.groups[index] = group;
+}
 
-    let reader = BufReader::new(file);
-    let mut cpu_aliases: HashMap<String, List<[u32; CONSUMER_CPUS]>> = HashMap::default();
-    let lines: Vec<String> = reader.lines().map_while(Result::ok).collect();
+fn parse_config_lines(
+    lines: &[String],
+    profile: Option<&str>,
+    cpu_aliases: &mut HashMap<String, List<[u32; CONSUMER_CPUS]>>,
+    result: &mut ConfigResult,
+) {
     let mut i = 0;
 
     while i < lines.len() {
@@ -771,7 +2902,8 @@ pub fn read_config<P: AsRef<Path>>(path: P) -> ConfigResult {
                     &line[1..eq_pos].trim().to_uppercase(),
                     line[eq_pos + 1..].trim(),
                     line_number,
-                    &mut result,
+                    cpu_aliases,
+                    result,
                 );
             } else {
                 result
@@ -782,14 +2914,34 @@ pub fn read_config<P: AsRef<Path>>(path: P) -> ConfigResult {
             continue;
         }
 
+        if let Some(definition) = line.strip_prefix('&') {
+            match definition.split_once('=') {
+                Some((name, value)) if !name.trim().is_empty() => {
+                    result.templates.insert(
+                        name.trim().to_lowercase(),
+                        RuleTemplate {
+                            parts: value.split(':').map(|part| part.trim().to_string()).collect(),
+                            line: line_number,
+                        },
+                    );
+                }
+                _ => result.errors.push(format!(
+                    "Line {}: Invalid template - expected '&name = priority:affinity:...' or '&name = &base:field=value'",
+                    line_number
+                )),
+            }
+            i += 1;
+            continue;
+        }
+
         if line.starts_with('*') {
             if let Some(eq_pos) = line.find('=') {
                 parse_alias(
                     &line[1..eq_pos].trim().to_lowercase(),
                     line[eq_pos + 1..].trim(),
                     line_number,
-                    &mut cpu_aliases,
-                    &mut result,
+                    cpu_aliases,
+                    result,
                 );
             } else {
                 result
@@ -816,7 +2968,7 @@ pub fn read_config<P: AsRef<Path>>(path: P) -> ConfigResult {
                 (members, suffix, i + 1)
             } else {
                 let first_content = line[brace_start + 1..].trim();
-                match collect_group_block(&lines, i + 1, first_content) {
+                match collect_group_block(lines, i + 1, first_content) {
                     Some((members, suffix, next)) => (members, suffix, next),
                     None => {
                         result
@@ -830,7 +2982,40 @@ pub fn read_config<P: AsRef<Path>>(path: P) -> ConfigResult {
 
             i = next_i;
 
-            if members.is_empty() {
+            let mut group = ProcessGroup {
+                name: group_label.clone(),
+                line: line_number,
+                members: Vec::new(),
+                includes: Vec::new(),
+                enabled: true,
+                owner: None,
+                reason: None,
+                ticket: None,
+                has_rule: rule_suffix.is_some(),
+                rule: Vec::new(),
+            };
+            for item in members {
+                if let Some((key, value)) = item.split_once('=') {
+                    set_group_property(&mut group, key, value, line_number, result);
+                } else if let Some(reference) = item.strip_prefix('@') {
+                    match result.groups.iter().find(|known| known.name == reference) {
+                        Some(known) if known.enabled => group.members.extend(known.members.iter().cloned()),
+                        Some(_) => {}
+                        None => result.errors.push(format!(
+                            "Line {}: Unknown group '@{}' in group '{}' - groups must be defined before they are referenced",
+                            line_number, reference, group_label
+                        )),
+                    }
+                    group.includes.push(reference.to_string());
+                } else {
+                    group.members.push(item);
+                }
+            }
+            let mut seen = HashSet::default();
+            group.members.retain(|member| seen.insert(member.clone()));
+            let members = group.members.clone();
+
+            if members.is_empty() && group.includes.is_empty() {
                 result
                     .warnings
                     .push(format!("Line {}: Group '{}' has no members", line_number, group_label));
@@ -840,18 +3025,29 @@ pub fn read_config<P: AsRef<Path>>(path: P) -> ConfigResult {
             result.groups_count += 1;
             result.group_members_count += members.len();
 
-            if let Some(suffix) = rule_suffix {
-                let rule_parts: Vec<&str> = suffix.split(':').collect();
-                parse_and_insert_rules(&members, &rule_parts, line_number, &cpu_aliases, &mut result);
-            } else {
-                result.errors.push(format!(
+            if !group_name.is_empty() {
+                forget_group_rules(&group_label, result);
+            }
+            match rule_suffix {
+                Some(suffix) if group.enabled && !members.is_empty() => {
+                    let rule_parts: Vec<&str> = suffix.split(':').collect();
+                    parse_and_insert_rules(&members, &group_label, &rule_parts, line_number, profile, cpu_aliases, result);
+                    group.rule = result.explanations.get(&members[0]).cloned().unwrap_or_default();
+                }
+                Some(_) => {}
+                // a named group without a rule only collects members for @references
+                None if !group_name.is_empty() => {}
+                None => result.errors.push(format!(
                     "Line {}: Group '{}' missing rule - use }}:priority:affinity,...",
                     line_number, group_label
-                ));
+                )),
             }
+            register_group(group, result);
         } else {
             let parts: Vec<&str> = line.split(':').collect();
-            if parts.len() < 3 {
+            let extends_template = parts.get(1).is_some_and(|part| part.trim().starts_with('&'));
+            let member_rule = result.member_group(&parts[0].trim().to_lowercase()).is_some();
+            if parts.len() < 3 && !extends_template && !member_rule {
                 result.errors.push(format!(
                     "Line {}: Too few fields - expected name:priority:affinity,...",
                     line_number
@@ -867,24 +3063,19 @@ pub fn read_config<P: AsRef<Path>>(path: P) -> ConfigResult {
                 continue;
             }
 
-            parse_and_insert_rules(&[name.to_lowercase()], &parts[1..], line_number, &cpu_aliases, &mut result);
+            let name = name.to_lowercase();
+            parse_and_insert_rules(
+                std::slice::from_ref(&name),
+                &name,
+                &parts[1..],
+                line_number,
+                profile,
+                cpu_aliases,
+                result,
+            );
             i += 1;
         }
     }
-    result
-}
-
-pub fn read_bleack_list<P: AsRef<Path>>(path: P) -> Result<Vec<String>> {
-    let file = File::open(path)?;
-    let reader = BufReader::new(file);
-    let result: Vec<String> = reader
-        .lines()
-        .map_while(Result::ok)
-        .map(|s| s.trim().to_lowercase())
-        .filter(|s| !s.is_empty() && !s.starts_with('#'))
-        .collect();
-    log!("{} blacklist items loaded", result.len());
-    Ok(result)
 }
 
 pub fn read_utf16le_file(path: &str) -> Result<String> {
@@ -1064,6 +3255,77 @@ pub fn convert(in_file: Option<String>, out_file: Option<String>) {
     log!("Converted {} to {}", in_path, out_path);
 }
 
+/// Writes one line per rule string, `name:rule` for a single member or a `grp_N { ... }:rule` block for several.
+///
+/// Members are sorted and deduplicated, blocks longer than a line are wrapped at 128 columns. Returns the number
+/// of single rules, grouped members and groups. Shared by `-autogroup` and `-capture`.
+pub fn push_grouped_rules(
+    rule_order: &[String],
+    rule_to_members: &mut HashMap<String, Vec<String>>,
+    output_lines: &mut Vec<String>,
+) -> (usize, usize, usize) {
+    let mut group_idx: usize = 0;
+    let mut single_count: usize = 0;
+    let mut group_count: usize = 0;
+    let mut grouped_member_count: usize = 0;
+
+    for rule_string in rule_order {
+        let members = match rule_to_members.get_mut(rule_string) {
+            Some(m) => m,
+            None => continue,
+        };
+
+        members.sort();
+        members.dedup();
+
+        if members.is_empty() {
+            continue;
+        }
+
+        if members.len() == 1 {
+            output_lines.push(format!("{}:{}", members[0], rule_string));
+            single_count += 1;
+        } else {
+            let group_name = format!("grp_{}", group_idx);
+            group_idx += 1;
+            group_count += 1;
+            grouped_member_count += members.len();
+
+            let members_inline = members.join(": ");
+            let single_line = format!("{} {{ {} }}:{}", group_name, members_inline, rule_string);
+            if single_line.len() < 128 {
+                output_lines.push(single_line);
+            } else {
+                output_lines.push(format!("{} {{", group_name));
+                const INDENT: &str = "    ";
+                let mut cur = String::from(INDENT);
+                let mut first = true;
+                for member in members.iter() {
+                    if first {
+                        cur.push_str(member);
+                        first = false;
+                    } else {
+                        let candidate = format!("{}: {}", cur, member);
+                        if candidate.len() < 128 {
+                            cur = candidate;
+                        } else {
+                            output_lines.push(cur);
+                            cur = format!("{}{}", INDENT, member);
+                        }
+                    }
+                }
+                if !first {
+                    output_lines.push(cur);
+                }
+                output_lines.push(format!("}}:{}", rule_string));
+            }
+        }
+
+        output_lines.push(String::new());
+    }
+    (single_count, grouped_member_count, group_count)
+}
+
 /// Auto-groups processes with identical rules to reduce config duplication.
 ///
 /// Reads config, identifies processes sharing the same rule settings,
@@ -1190,65 +3452,7 @@ pub fn sort_and_group_config(in_file: Option<String>, out_file: Option<String>)
     }
     output_lines.push(String::new());
 
-    let mut group_idx: usize = 0;
-    let mut single_count: usize = 0;
-    let mut group_count: usize = 0;
-    let mut grouped_member_count: usize = 0;
-
-    for rule_string in &rule_order {
-        let members = match rule_to_members.get_mut(rule_string) {
-            Some(m) => m,
-            None => continue,
-        };
-
-        members.sort();
-        members.dedup();
-
-        if members.is_empty() {
-            continue;
-        }
-
-        if members.len() == 1 {
-            output_lines.push(format!("{}:{}", members[0], rule_string));
-            single_count += 1;
-        } else {
-            let group_name = format!("grp_{}", group_idx);
-            group_idx += 1;
-            group_count += 1;
-            grouped_member_count += members.len();
-
-            let members_inline = members.join(": ");
-            let single_line = format!("{} {{ {} }}:{}", group_name, members_inline, rule_string);
-            if single_line.len() < 128 {
-                output_lines.push(single_line);
-            } else {
-                output_lines.push(format!("{} {{", group_name));
-                const INDENT: &str = "    ";
-                let mut cur = String::from(INDENT);
-                let mut first = true;
-                for member in members.iter() {
-                    if first {
-                        cur.push_str(member);
-                        first = false;
-                    } else {
-                        let candidate = format!("{}: {}", cur, member);
-                        if candidate.len() < 128 {
-                            cur = candidate;
-                        } else {
-                            output_lines.push(cur);
-                            cur = format!("{}{}", INDENT, member);
-                        }
-                    }
-                }
-                if !first {
-                    output_lines.push(cur);
-                }
-                output_lines.push(format!("}}:{}", rule_string));
-            }
-        }
-
-        output_lines.push(String::new());
-    }
+    let (single_count, grouped_member_count, group_count) = push_grouped_rules(&rule_order, &mut rule_to_members, &mut output_lines);
 
     while output_lines.last().map(|l: &String| l.trim().is_empty()).unwrap_or(false) {
         output_lines.pop();
@@ -1278,53 +3482,164 @@ pub fn sort_and_group_config(in_file: Option<String>, out_file: Option<String>)
     log!("Written to {}", out_path);
 }
 
-pub fn hotreload_blacklist(cli: &CliArgs, blacklist: &mut Vec<String>, last_blacklist_mod_time: &mut Option<std::time::SystemTime>) {
-    if let Some(ref blacklist_file) = cli.blacklist_file_name {
-        match metadata(blacklist_file) {
-            Err(_) => {
-                if last_blacklist_mod_time.is_some() {
-                    *last_blacklist_mod_time = None;
-                    log!("Blacklist file '{}' no longer accessible, clearing blacklist.", blacklist_file);
-                    blacklist.clear();
+/// Rule changes between two configs, by process name.
+///
+/// Rules are compared by their resolved settings and grade, so moving a rule or renaming an alias
+/// without changing its CPUs is not a change.
+#[derive(Debug, Default)]
+pub struct ConfigDiff {
+    pub added: Vec<String>,
+    pub removed: Vec<String>,
+    pub changed: Vec<String>,
+    /// Names whose process-level rule was added, removed or changed.
+    pub process_level: HashSet<String>,
+    /// Names whose thread-level rule was added, removed or changed.
+    pub thread_level: HashSet<String>,
+    pub constants_changed: bool,
+}
+
+impl ConfigDiff {
+    pub fn between(old: &ConfigResult, new: &ConfigResult) -> Self {
+        let mut diff = ConfigDiff {
+            constants_changed: old.constants != new.constants,
+            ..Default::default()
+        };
+        let process_rules = |configs: &ConfigResult| -> HashMap<String, (u32, ProcessLevelConfig)> {
+            let mut rules: HashMap<String, (u32, ProcessLevelConfig)> = HashMap::default();
+            for (grade, graded) in &configs.process_level_configs {
+                for (name, config) in graded {
+                    // origin carries the line number, which moves with unrelated edits
+                    let config = ProcessLevelConfig {
+                        origin: String::new(),
+                        ..config.clone()
+                    };
+                    rules.insert(name.clone(), (*grade, config));
                 }
             }
-            Ok(metadata) => {
-                if let Ok(mod_time) = metadata.modified()
-                    && Some(mod_time) != *last_blacklist_mod_time
-                {
-                    *last_blacklist_mod_time = Some(mod_time);
-                    log!("Blacklist file '{}' changed, reloading...", blacklist_file);
-                    *blacklist = read_bleack_list(blacklist_file).unwrap_or_default();
-                    log!("Blacklist reload complete: {} items loaded.", blacklist.len());
+            rules
+        };
+        let thread_rules = |configs: &ConfigResult| -> HashMap<String, (u32, ThreadLevelConfig)> {
+            let mut rules: HashMap<String, (u32, ThreadLevelConfig)> = HashMap::default();
+            for (grade, graded) in &configs.thread_level_configs {
+                for (name, config) in graded {
+                    rules.insert(name.clone(), (*grade, config.clone()));
                 }
             }
+            rules
+        };
+        let (old_process, new_process) = (process_rules(old), process_rules(new));
+        let (old_thread, new_thread) = (thread_rules(old), thread_rules(new));
+
+        let mut names: Vec<&String> = old_process
+            .keys()
+            .chain(new_process.keys())
+            .chain(old_thread.keys())
+            .chain(new_thread.keys())
+            .collect();
+        names.sort();
+        names.dedup();
+        for name in names {
+            let process_changed = old_process.get(name) != new_process.get(name);
+            let thread_changed = old_thread.get(name) != new_thread.get(name);
+            if !process_changed && !thread_changed {
+                continue;
+            }
+            let existed = old_process.contains_key(name) || old_thread.contains_key(name);
+            let exists = new_process.contains_key(name) || new_thread.contains_key(name);
+            match (existed, exists) {
+                (false, _) => diff.added.push(name.clone()),
+                (_, false) => diff.removed.push(name.clone()),
+                _ => diff.changed.push(name.clone()),
+            }
+            if process_changed {
+                diff.process_level.insert(name.clone());
+            }
+            if thread_changed {
+                diff.thread_level.insert(name.clone());
+            }
+        }
+        diff
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.added.is_empty() && self.removed.is_empty() && self.changed.is_empty() && !self.constants_changed
+    }
+
+    pub fn log(&self) {
+        if self.is_empty() {
+            log!("No rule changes.");
+            return;
+        }
+        for (label, names) in [("added", &self.added), ("removed", &self.removed), ("changed", &self.changed)] {
+            if !names.is_empty() {
+                log!("Rules {} ({}): {}", label, names.len(), names.join(", "));
+            }
+        }
+        if self.constants_changed {
+            log!("Scheduler constants changed, active streaks restart under the new thresholds.");
         }
     }
 }
 
+/// Reloads the config when a config file or include target changed, appeared or disappeared, and applies
+/// only the difference to the previous rules.
+///
+/// Processes whose process-level rule was removed or changed get their original state back, processes
+/// with a changed thread-level rule have their prime threads released and scheduler state reset, and only
+/// the affected processes are matched again.
+#[allow(clippy::too_many_arguments)]
 pub fn hotreload_config(
     cli: &CliArgs,
     configs: &mut ConfigResult,
-    last_config_mod_time: &mut Option<std::time::SystemTime>,
+    last_config_signature: &mut ConfigSignature,
+    pid_to_process: &HashMap<u32, ProcessEntry>,
     prime_core_scheduler: &mut PrimeThreadScheduler,
+    original_states: &mut OriginalStates,
     process_level_applied: &mut List<[u32; PIDS]>,
     full_process_level_match: &mut bool,
 ) {
-    if let Ok(metadata) = metadata(&cli.config_file_name)
-        && let Ok(mod_time) = metadata.modified()
-        && Some(mod_time) != *last_config_mod_time
-    {
-        *last_config_mod_time = Some(mod_time);
+    let signature = configs.signature();
+    if signature != *last_config_signature {
+        *last_config_signature = signature;
         log!("Configuration file '{}' changed, reloading...", cli.config_file_name);
-        let new_config_result = read_config(&cli.config_file_name);
+        let new_config_result = read_config(&cli.config_file_name, configs.active_profile.as_deref());
         if new_config_result.errors.is_empty() {
+            let diff = ConfigDiff::between(configs, &new_config_result);
             *configs = new_config_result;
+            // files included for the first time count from now on
+            *last_config_signature = configs.signature();
             (*configs).print_report();
-            prime_core_scheduler.constants = configs.constants.clone();
+            diff.log();
+
+            let thread_level_pids: List<[u32; PENDING]> = prime_core_scheduler
+                .pid_to_process_stats
+                .iter()
+                .filter(|(_, process_stats)| diff.thread_level.contains(&process_stats.process_name))
+                .map(|(pid, _)| *pid)
+                .collect();
+            for pid in thread_level_pids {
+                let mut result = ApplyConfigResult::new();
+                release_prime_threads(pid, prime_core_scheduler, &mut result);
+                prime_core_scheduler.drop_process_by_pid(&pid);
+                result.log_errors();
+                if !result.changes.is_empty() {
+                    log!("{:>5}::Reload: {}", pid, result.changes.join(", "));
+                }
+            }
+            original_states.restore_where(|name| diff.process_level.contains(name), "Reload");
+            configs.release_unused_jobs("Reload");
+            prime_core_scheduler.set_constants(&configs.constants);
             let total_rules = (*configs).total_rules();
             log!("Configuration reload complete: {} rules loaded.", total_rules);
-            process_level_applied.clear(); //reset process_level_applied on config reload
-            *full_process_level_match = true;
+            // only affected processes are matched again, the scheduler needs a process-level apply to track threads
+            process_level_applied.retain(|pid| {
+                pid_to_process
+                    .get(pid)
+                    .is_some_and(|process| !diff.process_level.contains(process.get_name()) && !diff.thread_level.contains(process.get_name()))
+            });
+            if !diff.process_level.is_empty() || !diff.thread_level.is_empty() {
+                *full_process_level_match = true;
+            }
         } else {
             log!(
                 "Configuration file '{}' has errors, keeping previous configuration.",
@@ -1336,3 +3651,48 @@ pub fn hotreload_config(
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn selector_offsets_and_plus_in_module_names() {
+        assert_eq!(ThreadSelector::parse("engine.dll+0x1A30").unwrap().to_string(), "engine.dll+0x1A30");
+        assert_eq!(
+            ThreadSelector::parse("engine.dll+0x1000-0x2000").unwrap().to_string(),
+            "engine.dll+0x1000-0x2000"
+        );
+        assert_eq!(ThreadSelector::parse("notepad++.exe").unwrap().to_string(), "notepad++.exe");
+        assert!(ThreadSelector::parse("engine.dll+0x2000-0x1000").is_err());
+    }
+
+    #[test]
+    fn disabled_group_redefinition_drops_the_earlier_rules() {
+        let lines: Vec<String> = [
+            "games { cs2.exe: valorant.exe: minecraft.exe }:high",
+            "other.exe:normal:0",
+            "games {",
+            "    cs2.exe: valorant.exe",
+            "    enabled = false",
+            "}:high",
+        ]
+        .iter()
+        .map(|line| line.to_string())
+        .collect();
+        let mut result = ConfigResult::default();
+        parse_config_lines(&lines, None, &mut HashMap::default(), &mut result);
+
+        assert!(result.errors.is_empty(), "{:?}", result.errors);
+        for member in ["cs2.exe", "valorant.exe", "minecraft.exe"] {
+            assert!(
+                !result.process_level_configs.values().any(|graded| graded.contains_key(member)),
+                "{} kept the rule of the disabled group",
+                member
+            );
+            assert!(!result.rule_lines.contains_key(member));
+            assert!(!result.member_groups.contains_key(member));
+        }
+        assert!(result.process_level_configs.values().any(|graded| graded.contains_key("other.exe")));
+    }
+}
@@ -0,0 +1,312 @@
+//! Behavior stats of processes discovered by `-find`, and the rule suggestions `-processlogs` derives from them.
+//!
+//! While `-find` runs, every process it has reported is sampled each loop from the process snapshot:
+//! CPU share, thread count, lifetime, whether it owns the foreground window and its IO volume. The
+//! cumulative stats are written to the `.find.log` as `stats <name> run=<start> key=value ...` lines every
+//! few minutes, when the last instance exits and on shutdown. `-processlogs` keeps the last line of every run
+//! and merges them across runs and log files, then classifies each process as a game, a background task or an updater, emitting a rule line in
+//! config syntax with the evidence in a comment above it.
+
+use crate::{
+    collections::HashMap,
+    logging::{FINDS_SET, log_to_find},
+    process::ProcessEntry,
+    winapi::{get_cpu_set_information, get_foreground_pid},
+};
+
+use std::{
+    fmt::Write,
+    time::{Duration, Instant, SystemTime, UNIX_EPOCH},
+};
+
+/// How often the cumulative stats of running processes are written to the find log.
+const FLUSH_INTERVAL: Duration = Duration::from_secs(10 * 60);
+
+/// Seconds between 1601-01-01 (FILETIME epoch) and 1970-01-01.
+const FILETIME_UNIX_OFFSET_SECS: i64 = 11_644_473_600;
+
+/// Cumulative behavior of all instances of one process name.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct ProcessBehavior {
+    pub samples: u32,
+    /// Sum of the per-sample CPU shares, percent of all logical processors.
+    pub cpu_share_sum: f64,
+    pub cpu_share_max: f64,
+    pub max_threads: u32,
+    /// Longest lifetime of an instance, seconds.
+    pub lifetime_secs: u64,
+    pub foreground_samples: u32,
+    pub io_bytes: u64,
+    pub instances: u32,
+}
+
+impl ProcessBehavior {
+    pub fn cpu_share_avg(&self) -> f64 {
+        if self.samples == 0 {
+            0.0
+        } else {
+            self.cpu_share_sum / self.samples as f64
+        }
+    }
+
+    /// Percent of samples in which the process owned the foreground window.
+    pub fn foreground_percent(&self) -> f64 {
+        if self.samples == 0 {
+            0.0
+        } else {
+            self.foreground_samples as f64 * 100.0 / self.samples as f64
+        }
+    }
+
+    /// The `stats` log line of a process, parsed back by [`ProcessBehavior::parse`].
+    ///
+    /// `run` tells runs apart that write to the same log file, the Unix time the run started.
+    pub fn format(&self, name: &str, run: u64) -> String {
+        format!(
+            "stats {} run={} samples={} cpu_avg={:.2} cpu_max={:.2} threads={} lifetime={} foreground={} io_mb={} instances={}",
+            name,
+            run,
+            self.samples,
+            self.cpu_share_avg(),
+            self.cpu_share_max,
+            self.max_threads,
+            self.lifetime_secs,
+            self.foreground_samples,
+            self.io_bytes / (1024 * 1024),
+            self.instances
+        )
+    }
+
+    /// Parses the text after `stats ` of a find log line into the process name, its run and its stats.
+    ///
+    /// Lines written without a run count as run 0.
+    pub fn parse(text: &str) -> Option<(String, u64, ProcessBehavior)> {
+        let mut words = text.split_whitespace();
+        let name = words.next()?.to_lowercase();
+        let mut run = 0;
+        let mut behavior = ProcessBehavior::default();
+        let mut cpu_avg = 0.0;
+        for word in words {
+            let (key, value) = word.split_once('=')?;
+            match key {
+                "run" => run = value.parse().ok()?,
+                "samples" => behavior.samples = value.parse().ok()?,
+                "cpu_avg" => cpu_avg = value.parse().ok()?,
+                "cpu_max" => behavior.cpu_share_max = value.parse().ok()?,
+                "threads" => behavior.max_threads = value.parse().ok()?,
+                "lifetime" => behavior.lifetime_secs = value.parse().ok()?,
+                "foreground" => behavior.foreground_samples = value.parse().ok()?,
+                "io_mb" => behavior.io_bytes = value.parse::<u64>().ok()? * 1024 * 1024,
+                "instances" => behavior.instances = value.parse().ok()?,
+                // keys of later versions are skipped
+                _ => {}
+            }
+        }
+        behavior.cpu_share_sum = cpu_avg * behavior.samples as f64;
+        Some((name, run, behavior))
+    }
+
+    /// Adds the stats of another run, sums for counters and maxima for peaks.
+    pub fn merge(&mut self, other: &ProcessBehavior) {
+        self.samples += other.samples;
+        self.cpu_share_sum += other.cpu_share_sum;
+        self.cpu_share_max = self.cpu_share_max.max(other.cpu_share_max);
+        self.max_threads = self.max_threads.max(other.max_threads);
+        self.lifetime_secs = self.lifetime_secs.max(other.lifetime_secs);
+        self.foreground_samples += other.foreground_samples;
+        self.io_bytes += other.io_bytes;
+        self.instances += other.instances;
+    }
+}
+
+/// Suggested rule category, in the order the suggestions are written.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SuggestedCategory {
+    Game,
+    Background,
+    Updater,
+}
+
+impl SuggestedCategory {
+    const TABLE: &'static [(Self, &'static str, &'static str)] = &[
+        // high priority only, CPU aliases differ per machine and are left to the user
+        (Self::Game, "game", "high:0:0:0:normal:none"),
+        (Self::Background, "background", "below normal:0:0:0:low:low:power=on"),
+        (Self::Updater, "updater", "idle:0:0:0:very low:very low:power=on"),
+    ];
+
+    pub fn as_str(&self) -> &'static str {
+        Self::TABLE
+            .iter()
+            .find(|(v, _, _)| v == self)
+            .map(|(_, name, _)| *name)
+            .unwrap_or("unknown")
+    }
+
+    /// The rule fields after `name:` suggested for the category.
+    pub fn rule(&self) -> &'static str {
+        Self::TABLE
+            .iter()
+            .find(|(v, _, _)| v == self)
+            .map(|(_, _, rule)| *rule)
+            .unwrap_or("none")
+    }
+
+    pub fn all() -> impl Iterator<Item = Self> {
+        Self::TABLE.iter().map(|(v, _, _)| *v)
+    }
+}
+
+/// Classifies a process by its stats, `None` when the evidence fits no category.
+///
+/// Updaters are named like one or run briefly in the background with notable IO; games own the
+/// foreground most of the time with real CPU load and many threads; background tasks stay out of the
+/// foreground and live long.
+pub fn suggest_category(name: &str, behavior: &ProcessBehavior) -> Option<SuggestedCategory> {
+    if behavior.samples == 0 {
+        return None;
+    }
+    let foreground = behavior.foreground_percent();
+    let updater_name = ["update", "setup", "install", "patch"].iter().any(|word| name.contains(word));
+    if updater_name || (foreground < 5.0 && behavior.lifetime_secs < 10 * 60 && behavior.io_bytes >= 50 * 1024 * 1024) {
+        return Some(SuggestedCategory::Updater);
+    }
+    if foreground >= 50.0 && (behavior.cpu_share_avg() >= 5.0 || behavior.cpu_share_max >= 20.0) && behavior.max_threads >= 16 {
+        return Some(SuggestedCategory::Game);
+    }
+    if foreground < 5.0 && behavior.lifetime_secs >= 30 * 60 {
+        return Some(SuggestedCategory::Background);
+    }
+    None
+}
+
+/// The evidence comment of a suggestion, e.g. `foreground 82% of 340 samples, cpu avg 12.5% max 38.0%, ...`.
+pub fn describe_evidence(behavior: &ProcessBehavior) -> String {
+    let mut evidence = format!(
+        "foreground {:.0}% of {} samples, cpu avg {:.1}% max {:.1}%, {} threads, alive {}",
+        behavior.foreground_percent(),
+        behavior.samples,
+        behavior.cpu_share_avg(),
+        behavior.cpu_share_max,
+        behavior.max_threads,
+        format_duration(behavior.lifetime_secs)
+    );
+    let _ = write!(
+        evidence,
+        ", io {} MB, {} instance(s)",
+        behavior.io_bytes / (1024 * 1024),
+        behavior.instances
+    );
+    evidence
+}
+
+fn format_duration(secs: u64) -> String {
+    match secs {
+        0..60 => format!("{}s", secs),
+        60..3600 => format!("{}m", secs / 60),
+        _ => format!("{}h{:02}m", secs / 3600, secs % 3600 / 60),
+    }
+}
+
+#[derive(Debug)]
+struct LiveProcess {
+    name: String,
+    create_time: i64,
+    last_cpu_time: i64,
+    last_io_bytes: u64,
+}
+
+/// Samples the processes reported by `-find` every loop.
+#[derive(Debug)]
+pub struct DiscoveryTracker {
+    live: HashMap<u32, LiveProcess>,
+    behaviors: HashMap<String, ProcessBehavior>,
+    last_sample: Option<Instant>,
+    last_flush: Instant,
+    /// Unix time this run started, tags its stats lines.
+    run: u64,
+}
+
+impl DiscoveryTracker {
+    pub fn new() -> Self {
+        Self {
+            live: HashMap::default(),
+            behaviors: HashMap::default(),
+            last_sample: None,
+            last_flush: Instant::now(),
+            run: SystemTime::now().duration_since(UNIX_EPOCH).unwrap_or_default().as_secs(),
+        }
+    }
+
+    /// Samples the running processes `-find` has reported, `is_known` skips names that got a rule or a blacklist entry since.
+    pub fn update<'a>(&mut self, processes: impl Iterator<Item = &'a ProcessEntry>, is_known: impl Fn(&str) -> bool) {
+        let now = Instant::now();
+        let elapsed = self.last_sample.map(|last| (now - last).as_nanos() as i64 / 100).unwrap_or(0);
+        self.last_sample = Some(now);
+        let cpu_count = get_cpu_set_information().lock().unwrap().len().max(1) as f64;
+        let foreground_pid = get_foreground_pid();
+        let now_filetime = filetime_now();
+
+        let mut seen: Vec<u32> = Vec::new();
+        {
+            let found = FINDS_SET.lock().unwrap();
+            for process in processes.filter(|process| found.contains(process.get_name()) && !is_known(process.get_name())) {
+                let pid = process.pid();
+                seen.push(pid);
+                let cpu_time = process.cpu_time();
+                let io_bytes = process.io_bytes();
+                let is_new = !self.live.contains_key(&pid);
+                let live = self.live.entry(pid).or_insert_with(|| LiveProcess {
+                    name: process.get_name().to_string(),
+                    create_time: process.create_time(),
+                    last_cpu_time: cpu_time,
+                    // IO before the first sample counts too, the counters are per process lifetime
+                    last_io_bytes: 0,
+                });
+                let behavior = self.behaviors.entry(live.name.clone()).or_default();
+                behavior.instances += is_new as u32;
+                // a new instance has no previous counters to take a delta from
+                if !is_new && elapsed > 0 {
+                    let share = (cpu_time - live.last_cpu_time).max(0) as f64 * 100.0 / (elapsed as f64 * cpu_count);
+                    behavior.samples += 1;
+                    behavior.cpu_share_sum += share;
+                    behavior.cpu_share_max = behavior.cpu_share_max.max(share);
+                    behavior.foreground_samples += (pid == foreground_pid) as u32;
+                }
+                behavior.max_threads = behavior.max_threads.max(process.thread_count());
+                behavior.io_bytes += io_bytes.saturating_sub(live.last_io_bytes);
+                behavior.lifetime_secs = behavior
+                    .lifetime_secs
+                    .max(((now_filetime - live.create_time) / 10_000_000).max(0) as u64);
+                live.last_cpu_time = cpu_time;
+                live.last_io_bytes = io_bytes;
+            }
+        }
+
+        let exited: Vec<u32> = self.live.keys().filter(|pid| !seen.contains(pid)).copied().collect();
+        for pid in exited {
+            if let Some(live) = self.live.remove(&pid)
+                && !self.live.values().any(|other| other.name == live.name)
+                && let Some(behavior) = self.behaviors.get(&live.name)
+            {
+                log_to_find(&behavior.format(&live.name, self.run));
+            }
+        }
+        if now - self.last_flush >= FLUSH_INTERVAL {
+            self.flush();
+        }
+    }
+
+    /// Writes the stats of every process seen so far to the find log, used periodically and on shutdown.
+    pub fn flush(&mut self) {
+        self.last_flush = Instant::now();
+        for (name, behavior) in &self.behaviors {
+            log_to_find(&behavior.format(name, self.run));
+        }
+    }
+}
+
+fn filetime_now() -> i64 {
+    let since_unix = SystemTime::now().duration_since(UNIX_EPOCH).unwrap_or_default();
+    (since_unix.as_secs() as i64 + FILETIME_UNIX_OFFSET_SECS) * 10_000_000 + since_unix.subsec_nanos() as i64 / 100
+}
@@ -0,0 +1,191 @@
+//! Drift detection for `-verify` mode.
+//!
+//! Once a process got its rule, the default mode never looks at it again, and
+//! `-continuous_process_level_apply` re-issues every setter on every grade tick. With `-verify` the
+//! process-level attributes of applied processes are read back on every grade tick instead; only the
+//! attributes that no longer match the rule are applied again, and every drift is counted per process.
+
+use crate::{
+    apply::{
+        ApplyConfigResult, apply_affinity, apply_io_priority, apply_memory_priority, apply_power_throttling, apply_priority,
+        apply_process_default_cpuset, capture_process_state,
+    },
+    collections::HashMap,
+    config::ProcessLevelConfig,
+    log,
+    priority::{IOPriority, MemoryPriority, PowerThrottling, ProcessPriority},
+    winapi::get_process_handle,
+};
+
+use ntapi::ntexapi::SYSTEM_THREAD_INFORMATION;
+
+/// Number of [`DriftAttribute`] variants.
+pub const DRIFT_ATTRIBUTES: usize = 6;
+
+/// A process-level attribute that can drift away from its rule.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DriftAttribute {
+    Priority,
+    Affinity,
+    CpuSet,
+    IoPriority,
+    MemoryPriority,
+    PowerThrottling,
+}
+
+impl DriftAttribute {
+    const TABLE: &'static [(Self, &'static str)] = &[
+        (Self::Priority, "priority"),
+        (Self::Affinity, "affinity"),
+        (Self::CpuSet, "cpuset"),
+        (Self::IoPriority, "io"),
+        (Self::MemoryPriority, "memory"),
+        (Self::PowerThrottling, "power"),
+    ];
+
+    pub fn as_str(&self) -> &'static str {
+        Self::TABLE
+            .iter()
+            .find(|(v, _)| v == self)
+            .map(|(_, name)| *name)
+            .unwrap_or("unknown")
+    }
+
+    fn index(&self) -> usize {
+        Self::TABLE.iter().position(|(v, _)| v == self).unwrap_or(0)
+    }
+}
+
+#[derive(Debug)]
+struct DriftCounters {
+    name: String,
+    counts: [u32; DRIFT_ATTRIBUTES],
+}
+
+fn log_exited(pid: u32, counters: &DriftCounters) {
+    log!("{:>5}::{}::Drift: exited after {}", pid, counters.name, counters.describe());
+}
+
+impl DriftCounters {
+    fn describe(&self) -> String {
+        DriftAttribute::TABLE
+            .iter()
+            .filter(|(attribute, _)| self.counts[attribute.index()] > 0)
+            .map(|(attribute, _)| format!("{} x{}", attribute.as_str(), self.counts[attribute.index()]))
+            .collect::<Vec<_>>()
+            .join(", ")
+    }
+}
+
+#[derive(Debug, Default)]
+pub struct DriftMonitor {
+    processes: HashMap<u32, DriftCounters>,
+}
+
+impl DriftMonitor {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Reads back the attributes of an applied process and re-applies only the drifted ones.
+    ///
+    /// Returns the re-apply result, changes prefixed with `Drift:` and followed by the process's drift counts.
+    pub fn verify<'a>(
+        &mut self,
+        pid: u32,
+        config: &ProcessLevelConfig,
+        threads: &impl Fn() -> &'a HashMap<u32, SYSTEM_THREAD_INFORMATION>,
+    ) -> ApplyConfigResult {
+        let mut result = ApplyConfigResult::new();
+        let Some(process_handle) = get_process_handle(pid, &config.name) else {
+            return result;
+        };
+        let drifted = capture_process_state(config, &process_handle).drifted(config);
+        if drifted.is_empty() {
+            return result;
+        }
+        let counters = self.processes.entry(pid).or_insert_with(|| DriftCounters {
+            name: config.name.clone(),
+            counts: [0; DRIFT_ATTRIBUTES],
+        });
+        for attribute in &drifted {
+            counters.counts[attribute.index()] += 1;
+        }
+
+        // every setter skips attributes the rule leaves alone, so clearing the others limits the re-apply
+        let targeted = ProcessLevelConfig {
+            priority: if drifted.contains(&DriftAttribute::Priority) {
+                config.priority
+            } else {
+                ProcessPriority::None
+            },
+            affinity_cpus: if drifted.contains(&DriftAttribute::Affinity) {
+                config.affinity_cpus.clone()
+            } else {
+                Default::default()
+            },
+            cpu_set_cpus: if drifted.contains(&DriftAttribute::CpuSet) {
+                config.cpu_set_cpus.clone()
+            } else {
+                Default::default()
+            },
+            io_priority: if drifted.contains(&DriftAttribute::IoPriority) {
+                config.io_priority
+            } else {
+                IOPriority::None
+            },
+            memory_priority: if drifted.contains(&DriftAttribute::MemoryPriority) {
+                config.memory_priority
+            } else {
+                MemoryPriority::None
+            },
+            power_throttling: if drifted.contains(&DriftAttribute::PowerThrottling) {
+                config.power_throttling
+            } else {
+                PowerThrottling::None
+            },
+            ..config.clone()
+        };
+        let mut current_mask: usize = 0;
+        apply_priority(pid, &targeted, false, &process_handle, &mut result);
+        apply_affinity(pid, &targeted, false, &mut current_mask, &process_handle, threads, &mut result);
+        apply_process_default_cpuset(pid, &targeted, false, &process_handle, threads, &mut result);
+        apply_io_priority(pid, &targeted, false, &process_handle, &mut result);
+        apply_memory_priority(pid, &targeted, false, &process_handle, &mut result);
+        apply_power_throttling(pid, &targeted, false, &process_handle, &mut result);
+        for change in result.changes.iter_mut() {
+            change.insert_str(0, "Drift: ");
+        }
+        result.add_change(format!("Drift counts: {}", counters.describe()));
+        result
+    }
+
+    /// Drops counters of exited processes, logging what drifted during their lifetime.
+    pub fn retain_alive(&mut self, pids_and_names: &[(u32, &str)]) {
+        self.processes.retain(|pid, counters| {
+            let alive = pids_and_names.iter().any(|(p, name)| p == pid && *name == counters.name);
+            if !alive {
+                log_exited(*pid, counters);
+            }
+            alive
+        });
+    }
+
+    /// Drops the counters of a process whose exit was reported by ETW.
+    pub fn drop_process(&mut self, pid: u32) {
+        if let Some(counters) = self.processes.remove(&pid) {
+            log_exited(pid, &counters);
+        }
+    }
+
+    /// Logs the drift counters of every running process, used on shutdown.
+    pub fn log_summary(&self) {
+        if self.processes.is_empty() {
+            return;
+        }
+        log!("Drift summary ({} processes):", self.processes.len());
+        for (pid, counters) in &self.processes {
+            log!("  {:>5}::{}: {}", pid, counters.name, counters.describe());
+        }
+    }
+}
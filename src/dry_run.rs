@@ -0,0 +1,141 @@
+//! `-dryrun` sampling and the `-report` file.
+//!
+//! A dry run queries the current values and logs `current -> desired` for every attribute that would
+//! change. Thread-level rules need cycle deltas and several consecutive selections before a thread is
+//! promoted, so a dry run with thread-level rules samples a few intervals (`-samples <n>`) and simulates
+//! the prime and ideal processor selection on the real thread counters. With `-report <file>` every
+//! sample's changes and a per-attribute summary are also written to a file.
+
+use crate::{cli::CliArgs, config::ConfigResult, get_local_time};
+
+use once_cell::sync::Lazy;
+use std::{fs::write, sync::Mutex};
+
+pub static DRY_RUN_REPORT: Lazy<Mutex<DryRunReport>> = Lazy::new(|| Mutex::new(DryRunReport::default()));
+
+/// Number of loops a dry run samples: `-samples`, or enough for a thread to pass `MIN_ACTIVE_STREAK`
+/// when there are thread-level rules.
+pub fn dry_run_samples(cli: &CliArgs, configs: &ConfigResult) -> u32 {
+    if let Some(samples) = cli.dry_run_samples {
+        return samples;
+    }
+    // thread-level rules of grade N are applied every N-th loop
+    let max_grade = configs
+        .thread_level_configs
+        .iter()
+        .filter(|(_, graded)| !graded.is_empty())
+        .map(|(grade, _)| *grade)
+        .max();
+    match max_grade {
+        None => 1,
+        // the first sample only records the cycle baseline
+        Some(grade) => (configs.constants.min_active_streak as u32 + 2) * grade.max(1),
+    }
+}
+
+#[derive(Debug, Default)]
+pub struct DryRunReport {
+    enabled: bool,
+    /// Changes of every sample as `(pid, process name, change)`.
+    samples: Vec<Vec<(u32, String, String)>>,
+}
+
+impl DryRunReport {
+    /// Starts collecting, changes recorded before are dropped.
+    pub fn enable(&mut self) {
+        self.enabled = true;
+        self.samples.clear();
+    }
+
+    pub fn begin_sample(&mut self) {
+        if self.enabled {
+            self.samples.push(Vec::new());
+        }
+    }
+
+    pub fn record(&mut self, pid: u32, name: &str, changes: &[String]) {
+        if !self.enabled {
+            return;
+        }
+        if self.samples.is_empty() {
+            self.samples.push(Vec::new());
+        }
+        if let Some(sample) = self.samples.last_mut() {
+            sample.extend(changes.iter().map(|change| (pid, name.to_string(), change.clone())));
+        }
+    }
+
+    /// Writes the samples and the number of changes per attribute to `path`.
+    pub fn write(&self, path: &str, config_file: &str) -> std::io::Result<()> {
+        let mut lines: Vec<String> = vec![
+            format!("# Dry run of {} on {}", config_file, get_local_time!().format("%Y-%m-%d %H:%M:%S")),
+            format!("# {} sample(s), nothing was changed", self.samples.len()),
+            String::new(),
+        ];
+        let mut summary: Vec<(&str, usize)> = Vec::new();
+        for (index, sample) in self.samples.iter().enumerate() {
+            lines.push(format!("[sample {}]", index + 1));
+            if sample.is_empty() {
+                lines.push("no changes".to_string());
+            }
+            for (pid, name, change) in sample {
+                lines.push(format!("{:>5}::{}::{}", pid, name, change));
+                let attribute = attribute_of(change);
+                match summary.iter_mut().find(|(known, _)| *known == attribute) {
+                    Some((_, count)) => *count += 1,
+                    None => summary.push((attribute, 1)),
+                }
+            }
+            lines.push(String::new());
+        }
+        lines.push("[summary]".to_string());
+        if summary.is_empty() {
+            lines.push("no changes".to_string());
+        }
+        for (attribute, count) in summary {
+            lines.push(format!("{}: {}", attribute, count));
+        }
+        lines.push(String::new());
+        write(path, lines.join("\n"))
+    }
+}
+
+/// The attribute a change line belongs to, `Priority: Normal -> high` is `Priority`.
+fn attribute_of(change: &str) -> &'static str {
+    const THREAD_CHANGES: &[(&str, &str)] = &[
+        ("(promoted", "Prime Promote"),
+        ("(demoted", "Prime Demote"),
+        ("(released", "Prime Release"),
+        ("(priority", "Thread Priority"),
+        ("ideal CPU", "Ideal Processor"),
+        ("restored ideal CPU", "Ideal Processor Restore"),
+    ];
+    const ATTRIBUTES: &[&str] = &[
+        "Priority",
+        "Affinity",
+        "CPU Set",
+        "IO Priority",
+        "Memory Priority",
+        "Power Throttling",
+        "Thread Power Throttling",
+        "Job Limits",
+        "Action",
+        "Reset Ideal Processors",
+    ];
+    if let Some(rest) = change.strip_prefix("Thread ")
+        && rest.starts_with(|c: char| c.is_ascii_digit())
+        && let Some((_, action)) = rest.split_once("-> ")
+    {
+        return THREAD_CHANGES
+            .iter()
+            .find(|(prefix, _)| action.starts_with(prefix))
+            .map(|(_, attribute)| *attribute)
+            .unwrap_or("Thread");
+    }
+    let head = change.split(':').next().unwrap_or_default();
+    ATTRIBUTES
+        .iter()
+        .find(|attribute| head == **attribute || head.starts_with(&format!("{} (", attribute)))
+        .copied()
+        .unwrap_or("Other")
+}
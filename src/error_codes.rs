@@ -1,70 +1,96 @@
-pub fn error_from_code_win32(code: u32) -> String {
-    match code {
-        0 => "SUCCESS".to_string(),
-        2 => "FILE_NOT_FOUND".to_string(),
-        5 => "ACCESS_DENIED".to_string(),
-        6 => "INVALID_HANDLE".to_string(),
-        8 => "NOT_ENOUGH_MEMORY".to_string(),
-        31 => "ERROR_GEN_FAILURE".to_string(),
-        87 => "INVALID_PARAMETER".to_string(),
-        122 => "INSUFFICIENT_BUFFER".to_string(),
-        126 => "MOD_NOT_FOUND".to_string(),
-        127 => "PROC_NOT_FOUND".to_string(),
-        193 => "BAD_EXE_FORMAT".to_string(),
-        565 => "TOO_MANY_THREADS".to_string(),
-        566 => "THREAD_NOT_IN_PROCESS".to_string(),
-        567 => "PAGEFILE_QUOTA_EXCEEDED".to_string(),
-        571 => "IO_PRIVILEGE_FAILED".to_string(),
-        577 => "INVALID_IMAGE_HASH".to_string(),
-        633 => "DRIVER_FAILED_SLEEP".to_string(),
-        998 => "NOACCESS".to_string(),
-        1003 => "CALLER_CANNOT_MAP_VIEW".to_string(),
-        1006 => "VOLUME_CHANGED".to_string(),
-        1007 => "FULLSCREEN_MODE".to_string(),
-        1008 => "INVALID_HANDLE_STATE".to_string(),
-        1058 => "SERVICE_DISABLED".to_string(),
-        1060 => "SERVICE_DOES_NOT_EXIST".to_string(),
-        1062 => "SERVICE_NOT_STARTED".to_string(),
-        1073 => "ALREADY_RUNNING".to_string(),
-        1314 => "PRIVILEGE_NOT_HELD".to_string(),
-        1330 => "INVALID_ACCOUNT_NAME".to_string(),
-        1331 => "LOGON_FAILURE".to_string(),
-        1332 => "ACCOUNT_RESTRICTION".to_string(),
-        1344 => "NO_LOGON_SERVERS".to_string(),
-        1346 => "RPC_AUTH_LEVEL_MISMATCH".to_string(),
-        1444 => "INVALID_THREAD_ID".to_string(),
-        1445 => "NON_MDICHILD_WINDOW".to_string(),
-        1450 => "NO_SYSTEM_RESOURCES".to_string(),
-        1460 => "TIMEOUT".to_string(),
-        1453 => "QUOTA_EXCEEDED".to_string(),
-        1455 => "PAGEFILE_TOO_SMALL".to_string(),
-        1500 => "EVT_INVALID_CHANNEL".to_string(),
-        1503 => "EVT_CHANNEL_ALREADY_EXISTS".to_string(),
+//! Names and classification of the Win32 and NTSTATUS codes in failure log lines.
+//!
+//! The name tables are generated from the constants of the windows crate by
+//! `scripts/generate_error_codes.sh`; rerun it after updating the crate.
 
-        _ => format!("WIN32_ERROR_CODE_0x{:08X}", code),
-    }
+use windows::Win32::Foundation::{
+    ERROR_ACCESS_DENIED, ERROR_CALL_NOT_IMPLEMENTED, ERROR_INVALID_FUNCTION, ERROR_INVALID_PARAMETER, ERROR_INVALID_THREAD_ID,
+    ERROR_NOT_SUPPORTED, ERROR_PRIVILEGE_NOT_HELD, ERROR_PROCESS_ABORTED, ERROR_THREAD_NOT_IN_PROCESS, STATUS_ACCESS_DENIED,
+    STATUS_INVALID_CID, STATUS_INVALID_INFO_CLASS, STATUS_INVALID_PARAMETER, STATUS_NOT_IMPLEMENTED, STATUS_NOT_SUPPORTED,
+    STATUS_PRIVILEGE_NOT_HELD, STATUS_PROCESS_IS_TERMINATING, STATUS_THREAD_IS_TERMINATING,
+};
+
+include!("error_codes_table.rs");
+
+/// Where an error code comes from, which decides the table its name is looked up in.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ErrorSource {
+    Win32,
+    NtStatus,
+    /// Detected by the service itself, e.g. an invalid cached handle; the code only tells such cases apart.
+    Internal,
 }
-pub fn error_from_ntstatus(status: i32) -> String {
-    match i32::cast_unsigned(status) {
-        0 => "STATUS_SUCCESS".to_string(),
-        1 => "STATUS_WAIT_1".to_string(),
 
-        0xC0000001 => "STATUS_UNSUCCESSFUL".to_string(),
-        0xC0000002 => "STATUS_NOT_IMPLEMENTED".to_string(),
-        0xC0000003 => "STATUS_INVALID_INFO_CLASS".to_string(),
-        0xC0000004 => "STATUS_INFO_LENGTH_MISMATCH".to_string(),
-        0xC0000008 => "STATUS_INVALID_HANDLE".to_string(),
-        0xC000000D => "STATUS_INVALID_PARAMETER".to_string(),
-        0xC0000017 => "STATUS_NO_MEMORY".to_string(),
-        0xC0000018 => "STATUS_CONFLICTING_ADDRESSES".to_string(),
-        0xC0000022 => "STATUS_ACCESS_DENIED".to_string(),
-        0xC0000023 => "STATUS_BUFFER_TOO_SMALL".to_string(),
-        0xC0000034 => "STATUS_OBJECT_NAME_NOT_FOUND".to_string(),
-        0xC000004B => "STATUS_THREAD_IS_TERMINATING".to_string(),
-        0xC0000061 => "STATUS_PRIVILEGE_NOT_HELD".to_string(),
-        0xC00000BB => "STATUS_NOT_SUPPORTED".to_string(),
-        0xC000010A => "STATUS_PROCESS_IS_TERMINATING".to_string(),
+/// What a failure means for the service, independent of the API that reported it.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ErrorKind {
+    AccessDenied,
+    /// The process or thread exited between the snapshot and the call.
+    ProcessGone,
+    InvalidParameter,
+    /// The API or information class is not available on this Windows version.
+    Unsupported,
+    Other,
+}
+
+impl ErrorKind {
+    /// The Win32 and NTSTATUS codes of each kind.
+    const TABLE: &'static [(Self, &'static [u32], &'static [i32])] = &[
+        (
+            Self::AccessDenied,
+            &[ERROR_ACCESS_DENIED.0, ERROR_PRIVILEGE_NOT_HELD.0],
+            &[STATUS_ACCESS_DENIED.0, STATUS_PRIVILEGE_NOT_HELD.0],
+        ),
+        (
+            Self::ProcessGone,
+            &[ERROR_PROCESS_ABORTED.0, ERROR_INVALID_THREAD_ID.0, ERROR_THREAD_NOT_IN_PROCESS.0],
+            &[
+                STATUS_PROCESS_IS_TERMINATING.0,
+                STATUS_THREAD_IS_TERMINATING.0,
+                STATUS_INVALID_CID.0,
+            ],
+        ),
+        (Self::InvalidParameter, &[ERROR_INVALID_PARAMETER.0], &[STATUS_INVALID_PARAMETER.0]),
+        (
+            Self::Unsupported,
+            &[ERROR_NOT_SUPPORTED.0, ERROR_CALL_NOT_IMPLEMENTED.0, ERROR_INVALID_FUNCTION.0],
+            &[STATUS_NOT_SUPPORTED.0, STATUS_NOT_IMPLEMENTED.0, STATUS_INVALID_INFO_CLASS.0],
+        ),
+        (Self::Other, &[], &[]),
+    ];
+
+    /// Classifies a code by its value alone, internal codes are always `Other`.
+    pub fn classify(source: ErrorSource, code: u32) -> Self {
+        Self::TABLE
+            .iter()
+            .find(|(_, win32, ntstatus)| match source {
+                ErrorSource::Win32 => win32.contains(&code),
+                ErrorSource::NtStatus => ntstatus.contains(&u32::cast_signed(code)),
+                ErrorSource::Internal => false,
+            })
+            .map(|(v, _, _)| *v)
+            .unwrap_or(Self::Other)
+    }
+}
 
-        _ => format!("NTSTATUS_0x{:08X}", status as u32),
+/// The name of a code, e.g. `ACCESS_DENIED` or `STATUS_PROCESS_IS_TERMINATING`.
+pub fn error_name(source: ErrorSource, code: u32) -> String {
+    let table = match source {
+        ErrorSource::Win32 => WIN32_ERROR_NAMES,
+        ErrorSource::NtStatus => NTSTATUS_NAMES,
+        ErrorSource::Internal => return format!("INTERNAL_{}", code),
+    };
+    match table.binary_search_by_key(&code, |(known, _)| *known) {
+        Ok(index) => table[index].1.to_string(),
+        Err(_) if source == ErrorSource::Win32 => format!("WIN32_ERROR_CODE_0x{:08X}", code),
+        Err(_) => format!("NTSTATUS_0x{:08X}", code),
     }
 }
+
+pub fn error_from_code_win32(code: u32) -> String {
+    error_name(ErrorSource::Win32, code)
+}
+
+pub fn error_from_ntstatus(status: i32) -> String {
+    error_name(ErrorSource::NtStatus, i32::cast_unsigned(status))
+}
@@ -0,0 +1,6320 @@
+// Generated by scripts/generate_error_codes.sh from windows 0.62.2, do not edit.
+
+/// Win32 error codes and their names without the `ERROR_` prefix, sorted by code.
+pub const WIN32_ERROR_NAMES: &[(u32, &str)] = &[
+    (0x00000000, "SUCCESS"),
+    (0x00000001, "INVALID_FUNCTION"),
+    (0x00000002, "FILE_NOT_FOUND"),
+    (0x00000003, "PATH_NOT_FOUND"),
+    (0x00000004, "TOO_MANY_OPEN_FILES"),
+    (0x00000005, "ACCESS_DENIED"),
+    (0x00000006, "INVALID_HANDLE"),
+    (0x00000007, "ARENA_TRASHED"),
+    (0x00000008, "NOT_ENOUGH_MEMORY"),
+    (0x00000009, "INVALID_BLOCK"),
+    (0x0000000A, "BAD_ENVIRONMENT"),
+    (0x0000000B, "BAD_FORMAT"),
+    (0x0000000C, "INVALID_ACCESS"),
+    (0x0000000D, "INVALID_DATA"),
+    (0x0000000E, "OUTOFMEMORY"),
+    (0x0000000F, "INVALID_DRIVE"),
+    (0x00000010, "CURRENT_DIRECTORY"),
+    (0x00000011, "NOT_SAME_DEVICE"),
+    (0x00000012, "NO_MORE_FILES"),
+    (0x00000013, "WRITE_PROTECT"),
+    (0x00000014, "BAD_UNIT"),
+    (0x00000015, "NOT_READY"),
+    (0x00000016, "BAD_COMMAND"),
+    (0x00000017, "CRC"),
+    (0x00000018, "BAD_LENGTH"),
+    (0x00000019, "SEEK"),
+    (0x0000001A, "NOT_DOS_DISK"),
+    (0x0000001B, "SECTOR_NOT_FOUND"),
+    (0x0000001C, "OUT_OF_PAPER"),
+    (0x0000001D, "WRITE_FAULT"),
+    (0x0000001E, "READ_FAULT"),
+    (0x0000001F, "GEN_FAILURE"),
+    (0x00000020, "SHARING_VIOLATION"),
+    (0x00000021, "LOCK_VIOLATION"),
+    (0x00000022, "WRONG_DISK"),
+    (0x00000024, "SHARING_BUFFER_EXCEEDED"),
+    (0x00000026, "HANDLE_EOF"),
+    (0x00000027, "HANDLE_DISK_FULL"),
+    (0x00000032, "NOT_SUPPORTED"),
+    (0x00000033, "REM_NOT_LIST"),
+    (0x00000034, "DUP_NAME"),
+    (0x00000035, "BAD_NETPATH"),
+    (0x00000036, "NETWORK_BUSY"),
+    (0x00000037, "DEV_NOT_EXIST"),
+    (0x00000038, "TOO_MANY_CMDS"),
+    (0x00000039, "ADAP_HDW_ERR"),
+    (0x0000003A, "BAD_NET_RESP"),
+    (0x0000003B, "UNEXP_NET_ERR"),
+    (0x0000003C, "BAD_REM_ADAP"),
+    (0x0000003D, "PRINTQ_FULL"),
+    (0x0000003E, "NO_SPOOL_SPACE"),
+    (0x0000003F, "PRINT_CANCELLED"),
+    (0x00000040, "NETNAME_DELETED"),
+    (0x00000041, "NETWORK_ACCESS_DENIED"),
+    (0x00000042, "BAD_DEV_TYPE"),
+    (0x00000043, "BAD_NET_NAME"),
+    (0x00000044, "TOO_MANY_NAMES"),
+    (0x00000045, "TOO_MANY_SESS"),
+    (0x00000046, "SHARING_PAUSED"),
+    (0x00000047, "REQ_NOT_ACCEP"),
+    (0x00000048, "REDIR_PAUSED"),
+    (0x00000050, "FILE_EXISTS"),
+    (0x00000052, "CANNOT_MAKE"),
+    (0x00000053, "FAIL_I24"),
+    (0x00000054, "OUT_OF_STRUCTURES"),
+    (0x00000055, "ALREADY_ASSIGNED"),
+    (0x00000056, "INVALID_PASSWORD"),
+    (0x00000057, "INVALID_PARAMETER"),
+    (0x00000058, "NET_WRITE_FAULT"),
+    (0x00000059, "NO_PROC_SLOTS"),
+    (0x00000064, "TOO_MANY_SEMAPHORES"),
+    (0x00000065, "EXCL_SEM_ALREADY_OWNED"),
+    (0x00000066, "SEM_IS_SET"),
+    (0x00000067, "TOO_MANY_SEM_REQUESTS"),
+    (0x00000068, "INVALID_AT_INTERRUPT_TIME"),
+    (0x00000069, "SEM_OWNER_DIED"),
+    (0x0000006A, "SEM_USER_LIMIT"),
+    (0x0000006B, "DISK_CHANGE"),
+    (0x0000006C, "DRIVE_LOCKED"),
+    (0x0000006D, "BROKEN_PIPE"),
+    (0x0000006E, "OPEN_FAILED"),
+    (0x0000006F, "BUFFER_OVERFLOW"),
+    (0x00000070, "DISK_FULL"),
+    (0x00000071, "NO_MORE_SEARCH_HANDLES"),
+    (0x00000072, "INVALID_TARGET_HANDLE"),
+    (0x00000075, "INVALID_CATEGORY"),
+    (0x00000076, "INVALID_VERIFY_SWITCH"),
+    (0x00000077, "BAD_DRIVER_LEVEL"),
+    (0x00000078, "CALL_NOT_IMPLEMENTED"),
+    (0x00000079, "SEM_TIMEOUT"),
+    (0x0000007A, "INSUFFICIENT_BUFFER"),
+    (0x0000007B, "INVALID_NAME"),
+    (0x0000007C, "INVALID_LEVEL"),
+    (0x0000007D, "NO_VOLUME_LABEL"),
+    (0x0000007E, "MOD_NOT_FOUND"),
+    (0x0000007F, "PROC_NOT_FOUND"),
+    (0x00000080, "WAIT_NO_CHILDREN"),
+    (0x00000081, "CHILD_NOT_COMPLETE"),
+    (0x00000082, "DIRECT_ACCESS_HANDLE"),
+    (0x00000083, "NEGATIVE_SEEK"),
+    (0x00000084, "SEEK_ON_DEVICE"),
+    (0x00000085, "IS_JOIN_TARGET"),
+    (0x00000086, "IS_JOINED"),
+    (0x00000087, "IS_SUBSTED"),
+    (0x00000088, "NOT_JOINED"),
+    (0x00000089, "NOT_SUBSTED"),
+    (0x0000008A, "JOIN_TO_JOIN"),
+    (0x0000008B, "SUBST_TO_SUBST"),
+    (0x0000008C, "JOIN_TO_SUBST"),
+    (0x0000008D, "SUBST_TO_JOIN"),
+    (0x0000008E, "BUSY_DRIVE"),
+    (0x0000008F, "SAME_DRIVE"),
+    (0x00000090, "DIR_NOT_ROOT"),
+    (0x00000091, "DIR_NOT_EMPTY"),
+    (0x00000092, "IS_SUBST_PATH"),
+    (0x00000093, "IS_JOIN_PATH"),
+    (0x00000094, "PATH_BUSY"),
+    (0x00000095, "IS_SUBST_TARGET"),
+    (0x00000096, "SYSTEM_TRACE"),
+    (0x00000097, "INVALID_EVENT_COUNT"),
+    (0x00000098, "TOO_MANY_MUXWAITERS"),
+    (0x00000099, "INVALID_LIST_FORMAT"),
+    (0x0000009A, "LABEL_TOO_LONG"),
+    (0x0000009B, "TOO_MANY_TCBS"),
+    (0x0000009C, "SIGNAL_REFUSED"),
+    (0x0000009D, "DISCARDED"),
+    (0x0000009E, "NOT_LOCKED"),
+    (0x0000009F, "BAD_THREADID_ADDR"),
+    (0x000000A0, "BAD_ARGUMENTS"),
+    (0x000000A1, "BAD_PATHNAME"),
+    (0x000000A2, "SIGNAL_PENDING"),
+    (0x000000A4, "MAX_THRDS_REACHED"),
+    (0x000000A7, "LOCK_FAILED"),
+    (0x000000AA, "BUSY"),
+    (0x000000AB, "DEVICE_SUPPORT_IN_PROGRESS"),
+    (0x000000AD, "CANCEL_VIOLATION"),
+    (0x000000AE, "ATOMIC_LOCKS_NOT_SUPPORTED"),
+    (0x000000B4, "INVALID_SEGMENT_NUMBER"),
+    (0x000000B6, "INVALID_ORDINAL"),
+    (0x000000B7, "ALREADY_EXISTS"),
+    (0x000000BA, "INVALID_FLAG_NUMBER"),
+    (0x000000BB, "SEM_NOT_FOUND"),
+    (0x000000BC, "INVALID_STARTING_CODESEG"),
+    (0x000000BD, "INVALID_STACKSEG"),
+    (0x000000BE, "INVALID_MODULETYPE"),
+    (0x000000BF, "INVALID_EXE_SIGNATURE"),
+    (0x000000C0, "EXE_MARKED_INVALID"),
+    (0x000000C1, "BAD_EXE_FORMAT"),
+    (0x000000C3, "INVALID_MINALLOCSIZE"),
+    (0x000000C4, "DYNLINK_FROM_INVALID_RING"),
+    (0x000000C5, "IOPL_NOT_ENABLED"),
+    (0x000000C6, "INVALID_SEGDPL"),
+    (0x000000C8, "RING2SEG_MUST_BE_MOVABLE"),
+    (0x000000C9, "RELOC_CHAIN_XEEDS_SEGLIM"),
+    (0x000000CA, "INFLOOP_IN_RELOC_CHAIN"),
+    (0x000000CB, "ENVVAR_NOT_FOUND"),
+    (0x000000CD, "NO_SIGNAL_SENT"),
+    (0x000000CE, "FILENAME_EXCED_RANGE"),
+    (0x000000CF, "RING2_STACK_IN_USE"),
+    (0x000000D0, "META_EXPANSION_TOO_LONG"),
+    (0x000000D1, "INVALID_SIGNAL_NUMBER"),
+    (0x000000D2, "THREAD_1_INACTIVE"),
+    (0x000000D4, "LOCKED"),
+    (0x000000D6, "TOO_MANY_MODULES"),
+    (0x000000D7, "NESTING_NOT_ALLOWED"),
+    (0x000000D8, "EXE_MACHINE_TYPE_MISMATCH"),
+    (0x000000D9, "EXE_CANNOT_MODIFY_SIGNED_BINARY"),
+    (0x000000DA, "EXE_CANNOT_MODIFY_STRONG_SIGNED_BINARY"),
+    (0x000000DC, "FILE_CHECKED_OUT"),
+    (0x000000DD, "CHECKOUT_REQUIRED"),
+    (0x000000DE, "BAD_FILE_TYPE"),
+    (0x000000DF, "FILE_TOO_LARGE"),
+    (0x000000E0, "FORMS_AUTH_REQUIRED"),
+    (0x000000E1, "VIRUS_INFECTED"),
+    (0x000000E2, "VIRUS_DELETED"),
+    (0x000000E5, "PIPE_LOCAL"),
+    (0x000000E6, "BAD_PIPE"),
+    (0x000000E7, "PIPE_BUSY"),
+    (0x000000E8, "NO_DATA"),
+    (0x000000E9, "PIPE_NOT_CONNECTED"),
+    (0x000000EA, "MORE_DATA"),
+    (0x000000EB, "NO_WORK_DONE"),
+    (0x000000F0, "VC_DISCONNECTED"),
+    (0x000000FE, "INVALID_EA_NAME"),
+    (0x000000FF, "EA_LIST_INCONSISTENT"),
+    (0x00000103, "NO_MORE_ITEMS"),
+    (0x0000010A, "CANNOT_COPY"),
+    (0x0000010B, "DIRECTORY"),
+    (0x00000113, "EAS_DIDNT_FIT"),
+    (0x00000114, "EA_FILE_CORRUPT"),
+    (0x00000115, "EA_TABLE_FULL"),
+    (0x00000116, "INVALID_EA_HANDLE"),
+    (0x0000011A, "EAS_NOT_SUPPORTED"),
+    (0x00000120, "NOT_OWNER"),
+    (0x0000012A, "TOO_MANY_POSTS"),
+    (0x0000012B, "PARTIAL_COPY"),
+    (0x0000012C, "OPLOCK_NOT_GRANTED"),
+    (0x0000012D, "INVALID_OPLOCK_PROTOCOL"),
+    (0x0000012E, "DISK_TOO_FRAGMENTED"),
+    (0x0000012F, "DELETE_PENDING"),
+    (0x00000130, "INCOMPATIBLE_WITH_GLOBAL_SHORT_NAME_REGISTRY_SETTING"),
+    (0x00000131, "SHORT_NAMES_NOT_ENABLED_ON_VOLUME"),
+    (0x00000132, "SECURITY_STREAM_IS_INCONSISTENT"),
+    (0x00000133, "INVALID_LOCK_RANGE"),
+    (0x00000134, "IMAGE_SUBSYSTEM_NOT_PRESENT"),
+    (0x00000135, "NOTIFICATION_GUID_ALREADY_DEFINED"),
+    (0x00000136, "INVALID_EXCEPTION_HANDLER"),
+    (0x00000137, "DUPLICATE_PRIVILEGES"),
+    (0x00000138, "NO_RANGES_PROCESSED"),
+    (0x00000139, "NOT_ALLOWED_ON_SYSTEM_FILE"),
+    (0x0000013A, "DISK_RESOURCES_EXHAUSTED"),
+    (0x0000013B, "INVALID_TOKEN"),
+    (0x0000013C, "DEVICE_FEATURE_NOT_SUPPORTED"),
+    (0x0000013D, "MR_MID_NOT_FOUND"),
+    (0x0000013E, "SCOPE_NOT_FOUND"),
+    (0x0000013F, "UNDEFINED_SCOPE"),
+    (0x00000140, "INVALID_CAP"),
+    (0x00000141, "DEVICE_UNREACHABLE"),
+    (0x00000142, "DEVICE_NO_RESOURCES"),
+    (0x00000143, "DATA_CHECKSUM_ERROR"),
+    (0x00000144, "INTERMIXED_KERNEL_EA_OPERATION"),
+    (0x00000146, "FILE_LEVEL_TRIM_NOT_SUPPORTED"),
+    (0x00000147, "OFFSET_ALIGNMENT_VIOLATION"),
+    (0x00000148, "INVALID_FIELD_IN_PARAMETER_LIST"),
+    (0x00000149, "OPERATION_IN_PROGRESS"),
+    (0x0000014A, "BAD_DEVICE_PATH"),
+    (0x0000014B, "TOO_MANY_DESCRIPTORS"),
+    (0x0000014C, "SCRUB_DATA_DISABLED"),
+    (0x0000014D, "NOT_REDUNDANT_STORAGE"),
+    (0x0000014E, "RESIDENT_FILE_NOT_SUPPORTED"),
+    (0x0000014F, "COMPRESSED_FILE_NOT_SUPPORTED"),
+    (0x00000150, "DIRECTORY_NOT_SUPPORTED"),
+    (0x00000151, "NOT_READ_FROM_COPY"),
+    (0x00000152, "FT_WRITE_FAILURE"),
+    (0x00000153, "FT_DI_SCAN_REQUIRED"),
+    (0x00000154, "INVALID_KERNEL_INFO_VERSION"),
+    (0x00000155, "INVALID_PEP_INFO_VERSION"),
+    (0x00000156, "OBJECT_NOT_EXTERNALLY_BACKED"),
+    (0x00000157, "EXTERNAL_BACKING_PROVIDER_UNKNOWN"),
+    (0x00000158, "COMPRESSION_NOT_BENEFICIAL"),
+    (0x00000159, "STORAGE_TOPOLOGY_ID_MISMATCH"),
+    (0x0000015A, "BLOCKED_BY_PARENTAL_CONTROLS"),
+    (0x0000015B, "BLOCK_TOO_MANY_REFERENCES"),
+    (0x0000015C, "MARKED_TO_DISALLOW_WRITES"),
+    (0x0000015D, "ENCLAVE_FAILURE"),
+    (0x0000015E, "FAIL_NOACTION_REBOOT"),
+    (0x0000015F, "FAIL_SHUTDOWN"),
+    (0x00000160, "FAIL_RESTART"),
+    (0x00000161, "MAX_SESSIONS_REACHED"),
+    (0x00000162, "NETWORK_ACCESS_DENIED_EDP"),
+    (0x00000163, "DEVICE_HINT_NAME_BUFFER_TOO_SMALL"),
+    (0x00000164, "EDP_POLICY_DENIES_OPERATION"),
+    (0x00000165, "EDP_DPL_POLICY_CANT_BE_SATISFIED"),
+    (0x00000166, "CLOUD_FILE_SYNC_ROOT_METADATA_CORRUPT"),
+    (0x00000167, "DEVICE_IN_MAINTENANCE"),
+    (0x00000168, "NOT_SUPPORTED_ON_DAX"),
+    (0x00000169, "DAX_MAPPING_EXISTS"),
+    (0x0000016A, "CLOUD_FILE_PROVIDER_NOT_RUNNING"),
+    (0x0000016B, "CLOUD_FILE_METADATA_CORRUPT"),
+    (0x0000016C, "CLOUD_FILE_METADATA_TOO_LARGE"),
+    (0x0000016D, "CLOUD_FILE_PROPERTY_BLOB_TOO_LARGE"),
+    (0x0000016E, "CLOUD_FILE_PROPERTY_BLOB_CHECKSUM_MISMATCH"),
+    (0x0000016F, "CHILD_PROCESS_BLOCKED"),
+    (0x00000170, "STORAGE_LOST_DATA_PERSISTENCE"),
+    (0x00000171, "FILE_SYSTEM_VIRTUALIZATION_UNAVAILABLE"),
+    (0x00000172, "FILE_SYSTEM_VIRTUALIZATION_METADATA_CORRUPT"),
+    (0x00000173, "FILE_SYSTEM_VIRTUALIZATION_BUSY"),
+    (0x00000174, "FILE_SYSTEM_VIRTUALIZATION_PROVIDER_UNKNOWN"),
+    (0x00000175, "GDI_HANDLE_LEAK"),
+    (0x00000176, "CLOUD_FILE_TOO_MANY_PROPERTY_BLOBS"),
+    (0x00000177, "CLOUD_FILE_PROPERTY_VERSION_NOT_SUPPORTED"),
+    (0x00000178, "NOT_A_CLOUD_FILE"),
+    (0x00000179, "CLOUD_FILE_NOT_IN_SYNC"),
+    (0x0000017A, "CLOUD_FILE_ALREADY_CONNECTED"),
+    (0x0000017B, "CLOUD_FILE_NOT_SUPPORTED"),
+    (0x0000017C, "CLOUD_FILE_INVALID_REQUEST"),
+    (0x0000017D, "CLOUD_FILE_READ_ONLY_VOLUME"),
+    (0x0000017E, "CLOUD_FILE_CONNECTED_PROVIDER_ONLY"),
+    (0x0000017F, "CLOUD_FILE_VALIDATION_FAILED"),
+    (0x00000180, "SMB1_NOT_AVAILABLE"),
+    (0x00000181, "FILE_SYSTEM_VIRTUALIZATION_INVALID_OPERATION"),
+    (0x00000182, "CLOUD_FILE_AUTHENTICATION_FAILED"),
+    (0x00000183, "CLOUD_FILE_INSUFFICIENT_RESOURCES"),
+    (0x00000184, "CLOUD_FILE_NETWORK_UNAVAILABLE"),
+    (0x00000185, "CLOUD_FILE_UNSUCCESSFUL"),
+    (0x00000186, "CLOUD_FILE_NOT_UNDER_SYNC_ROOT"),
+    (0x00000187, "CLOUD_FILE_IN_USE"),
+    (0x00000188, "CLOUD_FILE_PINNED"),
+    (0x00000189, "CLOUD_FILE_REQUEST_ABORTED"),
+    (0x0000018A, "CLOUD_FILE_PROPERTY_CORRUPT"),
+    (0x0000018B, "CLOUD_FILE_ACCESS_DENIED"),
+    (0x0000018C, "CLOUD_FILE_INCOMPATIBLE_HARDLINKS"),
+    (0x0000018D, "CLOUD_FILE_PROPERTY_LOCK_CONFLICT"),
+    (0x0000018E, "CLOUD_FILE_REQUEST_CANCELED"),
+    (0x0000018F, "EXTERNAL_SYSKEY_NOT_SUPPORTED"),
+    (0x00000190, "THREAD_MODE_ALREADY_BACKGROUND"),
+    (0x00000191, "THREAD_MODE_NOT_BACKGROUND"),
+    (0x00000192, "PROCESS_MODE_ALREADY_BACKGROUND"),
+    (0x00000193, "PROCESS_MODE_NOT_BACKGROUND"),
+    (0x00000194, "CLOUD_FILE_PROVIDER_TERMINATED"),
+    (0x00000195, "NOT_A_CLOUD_SYNC_ROOT"),
+    (0x00000196, "FILE_PROTECTED_UNDER_DPL"),
+    (0x00000197, "VOLUME_NOT_CLUSTER_ALIGNED"),
+    (0x00000198, "NO_PHYSICALLY_ALIGNED_FREE_SPACE_FOUND"),
+    (0x00000199, "APPX_FILE_NOT_ENCRYPTED"),
+    (0x0000019A, "RWRAW_ENCRYPTED_FILE_NOT_ENCRYPTED"),
+    (0x0000019B, "RWRAW_ENCRYPTED_INVALID_EDATAINFO_FILEOFFSET"),
+    (0x0000019C, "RWRAW_ENCRYPTED_INVALID_EDATAINFO_FILERANGE"),
+    (0x0000019D, "RWRAW_ENCRYPTED_INVALID_EDATAINFO_PARAMETER"),
+    (0x0000019E, "LINUX_SUBSYSTEM_NOT_PRESENT"),
+    (0x0000019F, "FT_READ_FAILURE"),
+    (0x000001A0, "STORAGE_RESERVE_ID_INVALID"),
+    (0x000001A1, "STORAGE_RESERVE_DOES_NOT_EXIST"),
+    (0x000001A2, "STORAGE_RESERVE_ALREADY_EXISTS"),
+    (0x000001A3, "STORAGE_RESERVE_NOT_EMPTY"),
+    (0x000001A4, "NOT_A_DAX_VOLUME"),
+    (0x000001A5, "NOT_DAX_MAPPABLE"),
+    (0x000001A6, "TIME_SENSITIVE_THREAD"),
+    (0x000001A7, "DPL_NOT_SUPPORTED_FOR_USER"),
+    (0x000001A8, "CASE_DIFFERING_NAMES_IN_DIR"),
+    (0x000001A9, "FILE_NOT_SUPPORTED"),
+    (0x000001AA, "CLOUD_FILE_REQUEST_TIMEOUT"),
+    (0x000001AB, "NO_TASK_QUEUE"),
+    (0x000001AC, "SRC_SRV_DLL_LOAD_FAILED"),
+    (0x000001AD, "NOT_SUPPORTED_WITH_BTT"),
+    (0x000001AE, "ENCRYPTION_DISABLED"),
+    (0x000001AF, "ENCRYPTING_METADATA_DISALLOWED"),
+    (0x000001B0, "CANT_CLEAR_ENCRYPTION_FLAG"),
+    (0x000001B1, "NO_SUCH_DEVICE"),
+    (0x000001B2, "CLOUD_FILE_DEHYDRATION_DISALLOWED"),
+    (0x000001B3, "FILE_SNAP_IN_PROGRESS"),
+    (0x000001B4, "FILE_SNAP_USER_SECTION_NOT_SUPPORTED"),
+    (0x000001B5, "FILE_SNAP_MODIFY_NOT_SUPPORTED"),
+    (0x000001B6, "FILE_SNAP_IO_NOT_COORDINATED"),
+    (0x000001B7, "FILE_SNAP_UNEXPECTED_ERROR"),
+    (0x000001B8, "FILE_SNAP_INVALID_PARAMETER"),
+    (0x000001B9, "UNSATISFIED_DEPENDENCIES"),
+    (0x000001BA, "CASE_SENSITIVE_PATH"),
+    (0x000001BB, "UNEXPECTED_NTCACHEMANAGER_ERROR"),
+    (0x000001BC, "LINUX_SUBSYSTEM_UPDATE_REQUIRED"),
+    (0x000001BD, "DLP_POLICY_WARNS_AGAINST_OPERATION"),
+    (0x000001BE, "DLP_POLICY_DENIES_OPERATION"),
+    (0x000001BF, "SECURITY_DENIES_OPERATION"),
+    (0x000001C0, "UNTRUSTED_MOUNT_POINT"),
+    (0x000001C1, "DLP_POLICY_SILENTLY_FAIL"),
+    (0x000001C2, "CAPAUTHZ_NOT_DEVUNLOCKED"),
+    (0x000001C3, "CAPAUTHZ_CHANGE_TYPE"),
+    (0x000001C4, "CAPAUTHZ_NOT_PROVISIONED"),
+    (0x000001C5, "CAPAUTHZ_NOT_AUTHORIZED"),
+    (0x000001C6, "CAPAUTHZ_NO_POLICY"),
+    (0x000001C7, "CAPAUTHZ_DB_CORRUPTED"),
+    (0x000001C8, "CAPAUTHZ_SCCD_INVALID_CATALOG"),
+    (0x000001C9, "CAPAUTHZ_SCCD_NO_AUTH_ENTITY"),
+    (0x000001CA, "CAPAUTHZ_SCCD_PARSE_ERROR"),
+    (0x000001CB, "CAPAUTHZ_SCCD_DEV_MODE_REQUIRED"),
+    (0x000001CC, "CAPAUTHZ_SCCD_NO_CAPABILITY_MATCH"),
+    (0x000001D6, "CIMFS_IMAGE_CORRUPT"),
+    (0x000001D7, "CIMFS_IMAGE_VERSION_NOT_SUPPORTED"),
+    (0x000001D8, "STORAGE_STACK_ACCESS_DENIED"),
+    (0x000001D9, "INSUFFICIENT_VIRTUAL_ADDR_RESOURCES"),
+    (0x000001DA, "INDEX_OUT_OF_BOUNDS"),
+    (0x000001DB, "CLOUD_FILE_US_MESSAGE_TIMEOUT"),
+    (0x000001DC, "NOT_A_DEV_VOLUME"),
+    (0x000001DD, "FS_GUID_MISMATCH"),
+    (0x000001DE, "CANT_ATTACH_TO_DEV_VOLUME"),
+    (0x000001DF, "INVALID_CONFIG_VALUE"),
+    (0x000001E0, "PNP_QUERY_REMOVE_DEVICE_TIMEOUT"),
+    (0x000001E1, "PNP_QUERY_REMOVE_RELATED_DEVICE_TIMEOUT"),
+    (0x000001E2, "PNP_QUERY_REMOVE_UNRELATED_DEVICE_TIMEOUT"),
+    (0x000001E3, "DEVICE_HARDWARE_ERROR"),
+    (0x000001E7, "INVALID_ADDRESS"),
+    (0x000001E8, "HAS_SYSTEM_CRITICAL_FILES"),
+    (0x000001E9, "ENCRYPTED_FILE_NOT_SUPPORTED"),
+    (0x000001EA, "SPARSE_FILE_NOT_SUPPORTED"),
+    (0x000001EB, "PAGEFILE_NOT_SUPPORTED"),
+    (0x000001EC, "VOLUME_NOT_SUPPORTED"),
+    (0x000001ED, "NOT_SUPPORTED_WITH_BYPASSIO"),
+    (0x000001EE, "NO_BYPASSIO_DRIVER_SUPPORT"),
+    (0x000001EF, "NOT_SUPPORTED_WITH_ENCRYPTION"),
+    (0x000001F0, "NOT_SUPPORTED_WITH_COMPRESSION"),
+    (0x000001F1, "NOT_SUPPORTED_WITH_REPLICATION"),
+    (0x000001F2, "NOT_SUPPORTED_WITH_DEDUPLICATION"),
+    (0x000001F3, "NOT_SUPPORTED_WITH_AUDITING"),
+    (0x000001F4, "USER_PROFILE_LOAD"),
+    (0x000001F5, "SESSION_KEY_TOO_SHORT"),
+    (0x000001F6, "ACCESS_DENIED_APPDATA"),
+    (0x000001F7, "NOT_SUPPORTED_WITH_MONITORING"),
+    (0x000001F8, "NOT_SUPPORTED_WITH_SNAPSHOT"),
+    (0x000001F9, "NOT_SUPPORTED_WITH_VIRTUALIZATION"),
+    (0x000001FA, "BYPASSIO_FLT_NOT_SUPPORTED"),
+    (0x000001FB, "DEVICE_RESET_REQUIRED"),
+    (0x000001FC, "VOLUME_WRITE_ACCESS_DENIED"),
+    (0x000001FD, "NOT_SUPPORTED_WITH_CACHED_HANDLE"),
+    (0x000001FE, "FS_METADATA_INCONSISTENT"),
+    (0x000001FF, "BLOCK_WEAK_REFERENCE_INVALID"),
+    (0x00000200, "BLOCK_SOURCE_WEAK_REFERENCE_INVALID"),
+    (0x00000201, "BLOCK_TARGET_WEAK_REFERENCE_INVALID"),
+    (0x00000202, "BLOCK_SHARED"),
+    (0x00000203, "VOLUME_UPGRADE_NOT_NEEDED"),
+    (0x00000204, "VOLUME_UPGRADE_PENDING"),
+    (0x00000205, "VOLUME_UPGRADE_DISABLED"),
+    (0x00000206, "VOLUME_UPGRADE_DISABLED_TILL_OS_DOWNGRADE_EXPIRED"),
+    (0x00000216, "ARITHMETIC_OVERFLOW"),
+    (0x00000217, "PIPE_CONNECTED"),
+    (0x00000218, "PIPE_LISTENING"),
+    (0x00000219, "VERIFIER_STOP"),
+    (0x0000021A, "ABIOS_ERROR"),
+    (0x0000021B, "WX86_WARNING"),
+    (0x0000021C, "WX86_ERROR"),
+    (0x0000021D, "TIMER_NOT_CANCELED"),
+    (0x0000021E, "UNWIND"),
+    (0x0000021F, "BAD_STACK"),
+    (0x00000220, "INVALID_UNWIND_TARGET"),
+    (0x00000221, "INVALID_PORT_ATTRIBUTES"),
+    (0x00000222, "PORT_MESSAGE_TOO_LONG"),
+    (0x00000223, "INVALID_QUOTA_LOWER"),
+    (0x00000224, "DEVICE_ALREADY_ATTACHED"),
+    (0x00000225, "INSTRUCTION_MISALIGNMENT"),
+    (0x00000226, "PROFILING_NOT_STARTED"),
+    (0x00000227, "PROFILING_NOT_STOPPED"),
+    (0x00000228, "COULD_NOT_INTERPRET"),
+    (0x00000229, "PROFILING_AT_LIMIT"),
+    (0x0000022A, "CANT_WAIT"),
+    (0x0000022B, "CANT_TERMINATE_SELF"),
+    (0x0000022C, "UNEXPECTED_MM_CREATE_ERR"),
+    (0x0000022D, "UNEXPECTED_MM_MAP_ERROR"),
+    (0x0000022E, "UNEXPECTED_MM_EXTEND_ERR"),
+    (0x0000022F, "BAD_FUNCTION_TABLE"),
+    (0x00000230, "NO_GUID_TRANSLATION"),
+    (0x00000231, "INVALID_LDT_SIZE"),
+    (0x00000233, "INVALID_LDT_OFFSET"),
+    (0x00000234, "INVALID_LDT_DESCRIPTOR"),
+    (0x00000235, "TOO_MANY_THREADS"),
+    (0x00000236, "THREAD_NOT_IN_PROCESS"),
+    (0x00000237, "PAGEFILE_QUOTA_EXCEEDED"),
+    (0x00000238, "LOGON_SERVER_CONFLICT"),
+    (0x00000239, "SYNCHRONIZATION_REQUIRED"),
+    (0x0000023A, "NET_OPEN_FAILED"),
+    (0x0000023B, "IO_PRIVILEGE_FAILED"),
+    (0x0000023C, "CONTROL_C_EXIT"),
+    (0x0000023D, "MISSING_SYSTEMFILE"),
+    (0x0000023E, "UNHANDLED_EXCEPTION"),
+    (0x0000023F, "APP_INIT_FAILURE"),
+    (0x00000240, "PAGEFILE_CREATE_FAILED"),
+    (0x00000241, "INVALID_IMAGE_HASH"),
+    (0x00000242, "NO_PAGEFILE"),
+    (0x00000243, "ILLEGAL_FLOAT_CONTEXT"),
+    (0x00000244, "NO_EVENT_PAIR"),
+    (0x00000245, "DOMAIN_CTRLR_CONFIG_ERROR"),
+    (0x00000246, "ILLEGAL_CHARACTER"),
+    (0x00000247, "UNDEFINED_CHARACTER"),
+    (0x00000248, "FLOPPY_VOLUME"),
+    (0x00000249, "BIOS_FAILED_TO_CONNECT_INTERRUPT"),
+    (0x0000024A, "BACKUP_CONTROLLER"),
+    (0x0000024B, "MUTANT_LIMIT_EXCEEDED"),
+    (0x0000024C, "FS_DRIVER_REQUIRED"),
+    (0x0000024D, "CANNOT_LOAD_REGISTRY_FILE"),
+    (0x0000024E, "DEBUG_ATTACH_FAILED"),
+    (0x0000024F, "SYSTEM_PROCESS_TERMINATED"),
+    (0x00000250, "DATA_NOT_ACCEPTED"),
+    (0x00000251, "VDM_HARD_ERROR"),
+    (0x00000252, "DRIVER_CANCEL_TIMEOUT"),
+    (0x00000253, "REPLY_MESSAGE_MISMATCH"),
+    (0x00000254, "LOST_WRITEBEHIND_DATA"),
+    (0x00000255, "CLIENT_SERVER_PARAMETERS_INVALID"),
+    (0x00000256, "NOT_TINY_STREAM"),
+    (0x00000257, "STACK_OVERFLOW_READ"),
+    (0x00000258, "CONVERT_TO_LARGE"),
+    (0x00000259, "FOUND_OUT_OF_SCOPE"),
+    (0x0000025A, "ALLOCATE_BUCKET"),
+    (0x0000025B, "MARSHALL_OVERFLOW"),
+    (0x0000025C, "INVALID_VARIANT"),
+    (0x0000025D, "BAD_COMPRESSION_BUFFER"),
+    (0x0000025E, "AUDIT_FAILED"),
+    (0x0000025F, "TIMER_RESOLUTION_NOT_SET"),
+    (0x00000260, "INSUFFICIENT_LOGON_INFO"),
+    (0x00000261, "BAD_DLL_ENTRYPOINT"),
+    (0x00000262, "BAD_SERVICE_ENTRYPOINT"),
+    (0x00000263, "IP_ADDRESS_CONFLICT1"),
+    (0x00000264, "IP_ADDRESS_CONFLICT2"),
+    (0x00000265, "REGISTRY_QUOTA_LIMIT"),
+    (0x00000266, "NO_CALLBACK_ACTIVE"),
+    (0x00000267, "PWD_TOO_SHORT"),
+    (0x00000268, "PWD_TOO_RECENT"),
+    (0x00000269, "PWD_HISTORY_CONFLICT"),
+    (0x0000026A, "UNSUPPORTED_COMPRESSION"),
+    (0x0000026B, "INVALID_HW_PROFILE"),
+    (0x0000026C, "INVALID_PLUGPLAY_DEVICE_PATH"),
+    (0x0000026D, "QUOTA_LIST_INCONSISTENT"),
+    (0x0000026E, "EVALUATION_EXPIRATION"),
+    (0x0000026F, "ILLEGAL_DLL_RELOCATION"),
+    (0x00000270, "DLL_INIT_FAILED_LOGOFF"),
+    (0x00000271, "VALIDATE_CONTINUE"),
+    (0x00000272, "NO_MORE_MATCHES"),
+    (0x00000273, "RANGE_LIST_CONFLICT"),
+    (0x00000274, "SERVER_SID_MISMATCH"),
+    (0x00000275, "CANT_ENABLE_DENY_ONLY"),
+    (0x00000276, "FLOAT_MULTIPLE_FAULTS"),
+    (0x00000277, "FLOAT_MULTIPLE_TRAPS"),
+    (0x00000278, "NOINTERFACE"),
+    (0x00000279, "DRIVER_FAILED_SLEEP"),
+    (0x0000027A, "CORRUPT_SYSTEM_FILE"),
+    (0x0000027B, "COMMITMENT_MINIMUM"),
+    (0x0000027C, "PNP_RESTART_ENUMERATION"),
+    (0x0000027D, "SYSTEM_IMAGE_BAD_SIGNATURE"),
+    (0x0000027E, "PNP_REBOOT_REQUIRED"),
+    (0x0000027F, "INSUFFICIENT_POWER"),
+    (0x00000280, "MULTIPLE_FAULT_VIOLATION"),
+    (0x00000281, "SYSTEM_SHUTDOWN"),
+    (0x00000282, "PORT_NOT_SET"),
+    (0x00000283, "DS_VERSION_CHECK_FAILURE"),
+    (0x00000284, "RANGE_NOT_FOUND"),
+    (0x00000286, "NOT_SAFE_MODE_DRIVER"),
+    (0x00000287, "FAILED_DRIVER_ENTRY"),
+    (0x00000288, "DEVICE_ENUMERATION_ERROR"),
+    (0x00000289, "MOUNT_POINT_NOT_RESOLVED"),
+    (0x0000028A, "INVALID_DEVICE_OBJECT_PARAMETER"),
+    (0x0000028B, "MCA_OCCURED"),
+    (0x0000028C, "DRIVER_DATABASE_ERROR"),
+    (0x0000028D, "SYSTEM_HIVE_TOO_LARGE"),
+    (0x0000028E, "DRIVER_FAILED_PRIOR_UNLOAD"),
+    (0x0000028F, "VOLSNAP_PREPARE_HIBERNATE"),
+    (0x00000290, "HIBERNATION_FAILURE"),
+    (0x00000291, "PWD_TOO_LONG"),
+    (0x00000299, "FILE_SYSTEM_LIMITATION"),
+    (0x0000029C, "ASSERTION_FAILURE"),
+    (0x0000029D, "ACPI_ERROR"),
+    (0x0000029E, "WOW_ASSERTION"),
+    (0x0000029F, "PNP_BAD_MPS_TABLE"),
+    (0x000002A0, "PNP_TRANSLATION_FAILED"),
+    (0x000002A1, "PNP_IRQ_TRANSLATION_FAILED"),
+    (0x000002A2, "PNP_INVALID_ID"),
+    (0x000002A3, "WAKE_SYSTEM_DEBUGGER"),
+    (0x000002A4, "HANDLES_CLOSED"),
+    (0x000002A5, "EXTRANEOUS_INFORMATION"),
+    (0x000002A6, "RXACT_COMMIT_NECESSARY"),
+    (0x000002A7, "MEDIA_CHECK"),
+    (0x000002A8, "GUID_SUBSTITUTION_MADE"),
+    (0x000002A9, "STOPPED_ON_SYMLINK"),
+    (0x000002AA, "LONGJUMP"),
+    (0x000002AB, "PLUGPLAY_QUERY_VETOED"),
+    (0x000002AC, "UNWIND_CONSOLIDATE"),
+    (0x000002AD, "REGISTRY_HIVE_RECOVERED"),
+    (0x000002AE, "DLL_MIGHT_BE_INSECURE"),
+    (0x000002AF, "DLL_MIGHT_BE_INCOMPATIBLE"),
+    (0x000002B0, "DBG_EXCEPTION_NOT_HANDLED"),
+    (0x000002B1, "DBG_REPLY_LATER"),
+    (0x000002B2, "DBG_UNABLE_TO_PROVIDE_HANDLE"),
+    (0x000002B3, "DBG_TERMINATE_THREAD"),
+    (0x000002B4, "DBG_TERMINATE_PROCESS"),
+    (0x000002B5, "DBG_CONTROL_C"),
+    (0x000002B6, "DBG_PRINTEXCEPTION_C"),
+    (0x000002B7, "DBG_RIPEXCEPTION"),
+    (0x000002B8, "DBG_CONTROL_BREAK"),
+    (0x000002B9, "DBG_COMMAND_EXCEPTION"),
+    (0x000002BA, "OBJECT_NAME_EXISTS"),
+    (0x000002BB, "THREAD_WAS_SUSPENDED"),
+    (0x000002BC, "IMAGE_NOT_AT_BASE"),
+    (0x000002BD, "RXACT_STATE_CREATED"),
+    (0x000002BE, "SEGMENT_NOTIFICATION"),
+    (0x000002BF, "BAD_CURRENT_DIRECTORY"),
+    (0x000002C0, "FT_READ_RECOVERY_FROM_BACKUP"),
+    (0x000002C1, "FT_WRITE_RECOVERY"),
+    (0x000002C2, "IMAGE_MACHINE_TYPE_MISMATCH"),
+    (0x000002C3, "RECEIVE_PARTIAL"),
+    (0x000002C4, "RECEIVE_EXPEDITED"),
+    (0x000002C5, "RECEIVE_PARTIAL_EXPEDITED"),
+    (0x000002C6, "EVENT_DONE"),
+    (0x000002C7, "EVENT_PENDING"),
+    (0x000002C8, "CHECKING_FILE_SYSTEM"),
+    (0x000002C9, "FATAL_APP_EXIT"),
+    (0x000002CA, "PREDEFINED_HANDLE"),
+    (0x000002CB, "WAS_UNLOCKED"),
+    (0x000002CC, "SERVICE_NOTIFICATION"),
+    (0x000002CD, "WAS_LOCKED"),
+    (0x000002CE, "LOG_HARD_ERROR"),
+    (0x000002CF, "ALREADY_WIN32"),
+    (0x000002D0, "IMAGE_MACHINE_TYPE_MISMATCH_EXE"),
+    (0x000002D1, "NO_YIELD_PERFORMED"),
+    (0x000002D2, "TIMER_RESUME_IGNORED"),
+    (0x000002D3, "ARBITRATION_UNHANDLED"),
+    (0x000002D4, "CARDBUS_NOT_SUPPORTED"),
+    (0x000002D5, "MP_PROCESSOR_MISMATCH"),
+    (0x000002D6, "HIBERNATED"),
+    (0x000002D7, "RESUME_HIBERNATION"),
+    (0x000002D8, "FIRMWARE_UPDATED"),
+    (0x000002D9, "DRIVERS_LEAKING_LOCKED_PAGES"),
+    (0x000002DA, "WAKE_SYSTEM"),
+    (0x000002DB, "WAIT_1"),
+    (0x000002DC, "WAIT_2"),
+    (0x000002DD, "WAIT_3"),
+    (0x000002DE, "WAIT_63"),
+    (0x000002DF, "ABANDONED_WAIT_0"),
+    (0x000002E0, "ABANDONED_WAIT_63"),
+    (0x000002E1, "USER_APC"),
+    (0x000002E2, "KERNEL_APC"),
+    (0x000002E3, "ALERTED"),
+    (0x000002E4, "ELEVATION_REQUIRED"),
+    (0x000002E5, "REPARSE"),
+    (0x000002E6, "OPLOCK_BREAK_IN_PROGRESS"),
+    (0x000002E7, "VOLUME_MOUNTED"),
+    (0x000002E8, "RXACT_COMMITTED"),
+    (0x000002E9, "NOTIFY_CLEANUP"),
+    (0x000002EA, "PRIMARY_TRANSPORT_CONNECT_FAILED"),
+    (0x000002EB, "PAGE_FAULT_TRANSITION"),
+    (0x000002EC, "PAGE_FAULT_DEMAND_ZERO"),
+    (0x000002ED, "PAGE_FAULT_COPY_ON_WRITE"),
+    (0x000002EE, "PAGE_FAULT_GUARD_PAGE"),
+    (0x000002EF, "PAGE_FAULT_PAGING_FILE"),
+    (0x000002F0, "CACHE_PAGE_LOCKED"),
+    (0x000002F1, "CRASH_DUMP"),
+    (0x000002F2, "BUFFER_ALL_ZEROS"),
+    (0x000002F3, "REPARSE_OBJECT"),
+    (0x000002F4, "RESOURCE_REQUIREMENTS_CHANGED"),
+    (0x000002F5, "TRANSLATION_COMPLETE"),
+    (0x000002F6, "NOTHING_TO_TERMINATE"),
+    (0x000002F7, "PROCESS_NOT_IN_JOB"),
+    (0x000002F8, "PROCESS_IN_JOB"),
+    (0x000002F9, "VOLSNAP_HIBERNATE_READY"),
+    (0x000002FA, "FSFILTER_OP_COMPLETED_SUCCESSFULLY"),
+    (0x000002FB, "INTERRUPT_VECTOR_ALREADY_CONNECTED"),
+    (0x000002FC, "INTERRUPT_STILL_CONNECTED"),
+    (0x000002FD, "WAIT_FOR_OPLOCK"),
+    (0x000002FE, "DBG_EXCEPTION_HANDLED"),
+    (0x000002FF, "DBG_CONTINUE"),
+    (0x00000300, "CALLBACK_POP_STACK"),
+    (0x00000301, "COMPRESSION_DISABLED"),
+    (0x00000302, "CANTFETCHBACKWARDS"),
+    (0x00000303, "CANTSCROLLBACKWARDS"),
+    (0x00000304, "ROWSNOTRELEASED"),
+    (0x00000305, "BAD_ACCESSOR_FLAGS"),
+    (0x00000306, "ERRORS_ENCOUNTERED"),
+    (0x00000307, "NOT_CAPABLE"),
+    (0x00000308, "REQUEST_OUT_OF_SEQUENCE"),
+    (0x00000309, "VERSION_PARSE_ERROR"),
+    (0x0000030A, "BADSTARTPOSITION"),
+    (0x0000030B, "MEMORY_HARDWARE"),
+    (0x0000030C, "DISK_REPAIR_DISABLED"),
+    (0x0000030D, "INSUFFICIENT_RESOURCE_FOR_SPECIFIED_SHARED_SECTION_SIZE"),
+    (0x0000030E, "SYSTEM_POWERSTATE_TRANSITION"),
+    (0x0000030F, "SYSTEM_POWERSTATE_COMPLEX_TRANSITION"),
+    (0x00000310, "MCA_EXCEPTION"),
+    (0x00000311, "ACCESS_AUDIT_BY_POLICY"),
+    (0x00000312, "ACCESS_DISABLED_NO_SAFER_UI_BY_POLICY"),
+    (0x00000313, "ABANDON_HIBERFILE"),
+    (0x00000314, "LOST_WRITEBEHIND_DATA_NETWORK_DISCONNECTED"),
+    (0x00000315, "LOST_WRITEBEHIND_DATA_NETWORK_SERVER_ERROR"),
+    (0x00000316, "LOST_WRITEBEHIND_DATA_LOCAL_DISK_ERROR"),
+    (0x00000317, "BAD_MCFG_TABLE"),
+    (0x00000318, "DISK_REPAIR_REDIRECTED"),
+    (0x00000319, "DISK_REPAIR_UNSUCCESSFUL"),
+    (0x0000031A, "CORRUPT_LOG_OVERFULL"),
+    (0x0000031B, "CORRUPT_LOG_CORRUPTED"),
+    (0x0000031C, "CORRUPT_LOG_UNAVAILABLE"),
+    (0x0000031D, "CORRUPT_LOG_DELETED_FULL"),
+    (0x0000031E, "CORRUPT_LOG_CLEARED"),
+    (0x0000031F, "ORPHAN_NAME_EXHAUSTED"),
+    (0x00000320, "OPLOCK_SWITCHED_TO_NEW_HANDLE"),
+    (0x00000321, "CANNOT_GRANT_REQUESTED_OPLOCK"),
+    (0x00000322, "CANNOT_BREAK_OPLOCK"),
+    (0x00000323, "OPLOCK_HANDLE_CLOSED"),
+    (0x00000324, "NO_ACE_CONDITION"),
+    (0x00000325, "INVALID_ACE_CONDITION"),
+    (0x00000326, "FILE_HANDLE_REVOKED"),
+    (0x00000327, "IMAGE_AT_DIFFERENT_BASE"),
+    (0x00000328, "ENCRYPTED_IO_NOT_POSSIBLE"),
+    (0x00000329, "FILE_METADATA_OPTIMIZATION_IN_PROGRESS"),
+    (0x0000032A, "QUOTA_ACTIVITY"),
+    (0x0000032B, "HANDLE_REVOKED"),
+    (0x0000032C, "CALLBACK_INVOKE_INLINE"),
+    (0x0000032D, "CPU_SET_INVALID"),
+    (0x0000032E, "ENCLAVE_NOT_TERMINATED"),
+    (0x0000032F, "ENCLAVE_VIOLATION"),
+    (0x00000330, "SERVER_TRANSPORT_CONFLICT"),
+    (0x00000331, "CERTIFICATE_VALIDATION_PREFERENCE_CONFLICT"),
+    (0x00000332, "FT_READ_FROM_COPY_FAILURE"),
+    (0x00000333, "SECTION_DIRECT_MAP_ONLY"),
+    (0x000003E2, "EA_ACCESS_DENIED"),
+    (0x000003E3, "OPERATION_ABORTED"),
+    (0x000003E4, "IO_INCOMPLETE"),
+    (0x000003E5, "IO_PENDING"),
+    (0x000003E6, "NOACCESS"),
+    (0x000003E7, "SWAPERROR"),
+    (0x000003E9, "STACK_OVERFLOW"),
+    (0x000003EA, "INVALID_MESSAGE"),
+    (0x000003EB, "CAN_NOT_COMPLETE"),
+    (0x000003EC, "INVALID_FLAGS"),
+    (0x000003ED, "UNRECOGNIZED_VOLUME"),
+    (0x000003EE, "FILE_INVALID"),
+    (0x000003EF, "FULLSCREEN_MODE"),
+    (0x000003F0, "NO_TOKEN"),
+    (0x000003F1, "BADDB"),
+    (0x000003F2, "BADKEY"),
+    (0x000003F3, "CANTOPEN"),
+    (0x000003F4, "CANTREAD"),
+    (0x000003F5, "CANTWRITE"),
+    (0x000003F6, "REGISTRY_RECOVERED"),
+    (0x000003F7, "REGISTRY_CORRUPT"),
+    (0x000003F8, "REGISTRY_IO_FAILED"),
+    (0x000003F9, "NOT_REGISTRY_FILE"),
+    (0x000003FA, "KEY_DELETED"),
+    (0x000003FB, "NO_LOG_SPACE"),
+    (0x000003FC, "KEY_HAS_CHILDREN"),
+    (0x000003FD, "CHILD_MUST_BE_VOLATILE"),
+    (0x000003FE, "NOTIFY_ENUM_DIR"),
+    (0x0000041B, "DEPENDENT_SERVICES_RUNNING"),
+    (0x0000041C, "INVALID_SERVICE_CONTROL"),
+    (0x0000041D, "SERVICE_REQUEST_TIMEOUT"),
+    (0x0000041E, "SERVICE_NO_THREAD"),
+    (0x0000041F, "SERVICE_DATABASE_LOCKED"),
+    (0x00000420, "SERVICE_ALREADY_RUNNING"),
+    (0x00000421, "INVALID_SERVICE_ACCOUNT"),
+    (0x00000422, "SERVICE_DISABLED"),
+    (0x00000423, "CIRCULAR_DEPENDENCY"),
+    (0x00000424, "SERVICE_DOES_NOT_EXIST"),
+    (0x00000425, "SERVICE_CANNOT_ACCEPT_CTRL"),
+    (0x00000426, "SERVICE_NOT_ACTIVE"),
+    (0x00000427, "FAILED_SERVICE_CONTROLLER_CONNECT"),
+    (0x00000428, "EXCEPTION_IN_SERVICE"),
+    (0x00000429, "DATABASE_DOES_NOT_EXIST"),
+    (0x0000042A, "SERVICE_SPECIFIC_ERROR"),
+    (0x0000042B, "PROCESS_ABORTED"),
+    (0x0000042C, "SERVICE_DEPENDENCY_FAIL"),
+    (0x0000042D, "SERVICE_LOGON_FAILED"),
+    (0x0000042E, "SERVICE_START_HANG"),
+    (0x0000042F, "INVALID_SERVICE_LOCK"),
+    (0x00000430, "SERVICE_MARKED_FOR_DELETE"),
+    (0x00000431, "SERVICE_EXISTS"),
+    (0x00000432, "ALREADY_RUNNING_LKG"),
+    (0x00000433, "SERVICE_DEPENDENCY_DELETED"),
+    (0x00000434, "BOOT_ALREADY_ACCEPTED"),
+    (0x00000435, "SERVICE_NEVER_STARTED"),
+    (0x00000436, "DUPLICATE_SERVICE_NAME"),
+    (0x00000437, "DIFFERENT_SERVICE_ACCOUNT"),
+    (0x00000438, "CANNOT_DETECT_DRIVER_FAILURE"),
+    (0x00000439, "CANNOT_DETECT_PROCESS_ABORT"),
+    (0x0000043A, "NO_RECOVERY_PROGRAM"),
+    (0x0000043B, "SERVICE_NOT_IN_EXE"),
+    (0x0000043C, "NOT_SAFEBOOT_SERVICE"),
+    (0x0000044C, "END_OF_MEDIA"),
+    (0x0000044D, "FILEMARK_DETECTED"),
+    (0x0000044E, "BEGINNING_OF_MEDIA"),
+    (0x0000044F, "SETMARK_DETECTED"),
+    (0x00000450, "NO_DATA_DETECTED"),
+    (0x00000451, "PARTITION_FAILURE"),
+    (0x00000452, "INVALID_BLOCK_LENGTH"),
+    (0x00000453, "DEVICE_NOT_PARTITIONED"),
+    (0x00000454, "UNABLE_TO_LOCK_MEDIA"),
+    (0x00000455, "UNABLE_TO_UNLOAD_MEDIA"),
+    (0x00000456, "MEDIA_CHANGED"),
+    (0x00000457, "BUS_RESET"),
+    (0x00000458, "NO_MEDIA_IN_DRIVE"),
+    (0x00000459, "NO_UNICODE_TRANSLATION"),
+    (0x0000045A, "DLL_INIT_FAILED"),
+    (0x0000045B, "SHUTDOWN_IN_PROGRESS"),
+    (0x0000045C, "NO_SHUTDOWN_IN_PROGRESS"),
+    (0x0000045D, "IO_DEVICE"),
+    (0x0000045E, "SERIAL_NO_DEVICE"),
+    (0x0000045F, "IRQ_BUSY"),
+    (0x00000460, "MORE_WRITES"),
+    (0x00000461, "COUNTER_TIMEOUT"),
+    (0x00000462, "FLOPPY_ID_MARK_NOT_FOUND"),
+    (0x00000463, "FLOPPY_WRONG_CYLINDER"),
+    (0x00000464, "FLOPPY_UNKNOWN_ERROR"),
+    (0x00000465, "FLOPPY_BAD_REGISTERS"),
+    (0x00000466, "DISK_RECALIBRATE_FAILED"),
+    (0x00000467, "DISK_OPERATION_FAILED"),
+    (0x00000468, "DISK_RESET_FAILED"),
+    (0x00000469, "EOM_OVERFLOW"),
+    (0x0000046A, "NOT_ENOUGH_SERVER_MEMORY"),
+    (0x0000046B, "POSSIBLE_DEADLOCK"),
+    (0x0000046C, "MAPPED_ALIGNMENT"),
+    (0x00000474, "SET_POWER_STATE_VETOED"),
+    (0x00000475, "SET_POWER_STATE_FAILED"),
+    (0x00000476, "TOO_MANY_LINKS"),
+    (0x0000047E, "OLD_WIN_VERSION"),
+    (0x0000047F, "APP_WRONG_OS"),
+    (0x00000480, "SINGLE_INSTANCE_APP"),
+    (0x00000481, "RMODE_APP"),
+    (0x00000482, "INVALID_DLL"),
+    (0x00000483, "NO_ASSOCIATION"),
+    (0x00000484, "DDE_FAIL"),
+    (0x00000485, "DLL_NOT_FOUND"),
+    (0x00000486, "NO_MORE_USER_HANDLES"),
+    (0x00000487, "MESSAGE_SYNC_ONLY"),
+    (0x00000488, "SOURCE_ELEMENT_EMPTY"),
+    (0x00000489, "DESTINATION_ELEMENT_FULL"),
+    (0x0000048A, "ILLEGAL_ELEMENT_ADDRESS"),
+    (0x0000048B, "MAGAZINE_NOT_PRESENT"),
+    (0x0000048C, "DEVICE_REINITIALIZATION_NEEDED"),
+    (0x0000048D, "DEVICE_REQUIRES_CLEANING"),
+    (0x0000048E, "DEVICE_DOOR_OPEN"),
+    (0x0000048F, "DEVICE_NOT_CONNECTED"),
+    (0x00000490, "NOT_FOUND"),
+    (0x00000491, "NO_MATCH"),
+    (0x00000492, "SET_NOT_FOUND"),
+    (0x00000493, "POINT_NOT_FOUND"),
+    (0x00000494, "NO_TRACKING_SERVICE"),
+    (0x00000495, "NO_VOLUME_ID"),
+    (0x00000497, "UNABLE_TO_REMOVE_REPLACED"),
+    (0x00000498, "UNABLE_TO_MOVE_REPLACEMENT"),
+    (0x00000499, "UNABLE_TO_MOVE_REPLACEMENT_2"),
+    (0x0000049A, "JOURNAL_DELETE_IN_PROGRESS"),
+    (0x0000049B, "JOURNAL_NOT_ACTIVE"),
+    (0x0000049C, "POTENTIAL_FILE_FOUND"),
+    (0x0000049D, "JOURNAL_ENTRY_DELETED"),
+    (0x000004A0, "PARTITION_TERMINATING"),
+    (0x000004A6, "SHUTDOWN_IS_SCHEDULED"),
+    (0x000004A7, "SHUTDOWN_USERS_LOGGED_ON"),
+    (0x000004A8, "SHUTDOWN_DISKS_NOT_IN_MAINTENANCE_MODE"),
+    (0x000004B0, "BAD_DEVICE"),
+    (0x000004B1, "CONNECTION_UNAVAIL"),
+    (0x000004B2, "DEVICE_ALREADY_REMEMBERED"),
+    (0x000004B3, "NO_NET_OR_BAD_PATH"),
+    (0x000004B4, "BAD_PROVIDER"),
+    (0x000004B5, "CANNOT_OPEN_PROFILE"),
+    (0x000004B6, "BAD_PROFILE"),
+    (0x000004B7, "NOT_CONTAINER"),
+    (0x000004B8, "EXTENDED_ERROR"),
+    (0x000004B9, "INVALID_GROUPNAME"),
+    (0x000004BA, "INVALID_COMPUTERNAME"),
+    (0x000004BB, "INVALID_EVENTNAME"),
+    (0x000004BC, "INVALID_DOMAINNAME"),
+    (0x000004BD, "INVALID_SERVICENAME"),
+    (0x000004BE, "INVALID_NETNAME"),
+    (0x000004BF, "INVALID_SHARENAME"),
+    (0x000004C0, "INVALID_PASSWORDNAME"),
+    (0x000004C1, "INVALID_MESSAGENAME"),
+    (0x000004C2, "INVALID_MESSAGEDEST"),
+    (0x000004C3, "SESSION_CREDENTIAL_CONFLICT"),
+    (0x000004C4, "REMOTE_SESSION_LIMIT_EXCEEDED"),
+    (0x000004C5, "DUP_DOMAINNAME"),
+    (0x000004C6, "NO_NETWORK"),
+    (0x000004C7, "CANCELLED"),
+    (0x000004C8, "USER_MAPPED_FILE"),
+    (0x000004C9, "CONNECTION_REFUSED"),
+    (0x000004CA, "GRACEFUL_DISCONNECT"),
+    (0x000004CB, "ADDRESS_ALREADY_ASSOCIATED"),
+    (0x000004CC, "ADDRESS_NOT_ASSOCIATED"),
+    (0x000004CD, "CONNECTION_INVALID"),
+    (0x000004CE, "CONNECTION_ACTIVE"),
+    (0x000004CF, "NETWORK_UNREACHABLE"),
+    (0x000004D0, "HOST_UNREACHABLE"),
+    (0x000004D1, "PROTOCOL_UNREACHABLE"),
+    (0x000004D2, "PORT_UNREACHABLE"),
+    (0x000004D3, "REQUEST_ABORTED"),
+    (0x000004D4, "CONNECTION_ABORTED"),
+    (0x000004D5, "RETRY"),
+    (0x000004D6, "CONNECTION_COUNT_LIMIT"),
+    (0x000004D7, "LOGIN_TIME_RESTRICTION"),
+    (0x000004D8, "LOGIN_WKSTA_RESTRICTION"),
+    (0x000004D9, "INCORRECT_ADDRESS"),
+    (0x000004DA, "ALREADY_REGISTERED"),
+    (0x000004DB, "SERVICE_NOT_FOUND"),
+    (0x000004DC, "NOT_AUTHENTICATED"),
+    (0x000004DD, "NOT_LOGGED_ON"),
+    (0x000004DE, "CONTINUE"),
+    (0x000004DF, "ALREADY_INITIALIZED"),
+    (0x000004E0, "NO_MORE_DEVICES"),
+    (0x000004E1, "NO_SUCH_SITE"),
+    (0x000004E2, "DOMAIN_CONTROLLER_EXISTS"),
+    (0x000004E3, "ONLY_IF_CONNECTED"),
+    (0x000004E4, "OVERRIDE_NOCHANGES"),
+    (0x000004E5, "BAD_USER_PROFILE"),
+    (0x000004E6, "NOT_SUPPORTED_ON_SBS"),
+    (0x000004E7, "SERVER_SHUTDOWN_IN_PROGRESS"),
+    (0x000004E8, "HOST_DOWN"),
+    (0x000004E9, "NON_ACCOUNT_SID"),
+    (0x000004EA, "NON_DOMAIN_SID"),
+    (0x000004EB, "APPHELP_BLOCK"),
+    (0x000004EC, "ACCESS_DISABLED_BY_POLICY"),
+    (0x000004ED, "REG_NAT_CONSUMPTION"),
+    (0x000004EE, "CSCSHARE_OFFLINE"),
+    (0x000004EF, "PKINIT_FAILURE"),
+    (0x000004F0, "SMARTCARD_SUBSYSTEM_FAILURE"),
+    (0x000004F1, "DOWNGRADE_DETECTED"),
+    (0x000004F7, "MACHINE_LOCKED"),
+    (0x000004F8, "SMB_GUEST_LOGON_BLOCKED"),
+    (0x000004F9, "CALLBACK_SUPPLIED_INVALID_DATA"),
+    (0x000004FA, "SYNC_FOREGROUND_REFRESH_REQUIRED"),
+    (0x000004FB, "DRIVER_BLOCKED"),
+    (0x000004FC, "INVALID_IMPORT_OF_NON_DLL"),
+    (0x000004FD, "ACCESS_DISABLED_WEBBLADE"),
+    (0x000004FE, "ACCESS_DISABLED_WEBBLADE_TAMPER"),
+    (0x000004FF, "RECOVERY_FAILURE"),
+    (0x00000500, "ALREADY_FIBER"),
+    (0x00000501, "ALREADY_THREAD"),
+    (0x00000502, "STACK_BUFFER_OVERRUN"),
+    (0x00000503, "PARAMETER_QUOTA_EXCEEDED"),
+    (0x00000504, "DEBUGGER_INACTIVE"),
+    (0x00000505, "DELAY_LOAD_FAILED"),
+    (0x00000506, "VDM_DISALLOWED"),
+    (0x00000507, "UNIDENTIFIED_ERROR"),
+    (0x00000508, "INVALID_CRUNTIME_PARAMETER"),
+    (0x00000509, "BEYOND_VDL"),
+    (0x0000050A, "INCOMPATIBLE_SERVICE_SID_TYPE"),
+    (0x0000050B, "DRIVER_PROCESS_TERMINATED"),
+    (0x0000050C, "IMPLEMENTATION_LIMIT"),
+    (0x0000050D, "PROCESS_IS_PROTECTED"),
+    (0x0000050E, "SERVICE_NOTIFY_CLIENT_LAGGING"),
+    (0x0000050F, "DISK_QUOTA_EXCEEDED"),
+    (0x00000510, "CONTENT_BLOCKED"),
+    (0x00000511, "INCOMPATIBLE_SERVICE_PRIVILEGE"),
+    (0x00000512, "APP_HANG"),
+    (0x00000513, "INVALID_LABEL"),
+    (0x00000514, "NOT_ALL_ASSIGNED"),
+    (0x00000515, "SOME_NOT_MAPPED"),
+    (0x00000516, "NO_QUOTAS_FOR_ACCOUNT"),
+    (0x00000517, "LOCAL_USER_SESSION_KEY"),
+    (0x00000518, "NULL_LM_PASSWORD"),
+    (0x00000519, "UNKNOWN_REVISION"),
+    (0x0000051A, "REVISION_MISMATCH"),
+    (0x0000051B, "INVALID_OWNER"),
+    (0x0000051C, "INVALID_PRIMARY_GROUP"),
+    (0x0000051D, "NO_IMPERSONATION_TOKEN"),
+    (0x0000051E, "CANT_DISABLE_MANDATORY"),
+    (0x0000051F, "NO_LOGON_SERVERS"),
+    (0x00000520, "NO_SUCH_LOGON_SESSION"),
+    (0x00000521, "NO_SUCH_PRIVILEGE"),
+    (0x00000522, "PRIVILEGE_NOT_HELD"),
+    (0x00000523, "INVALID_ACCOUNT_NAME"),
+    (0x00000524, "USER_EXISTS"),
+    (0x00000525, "NO_SUCH_USER"),
+    (0x00000526, "GROUP_EXISTS"),
+    (0x00000527, "NO_SUCH_GROUP"),
+    (0x00000528, "MEMBER_IN_GROUP"),
+    (0x00000529, "MEMBER_NOT_IN_GROUP"),
+    (0x0000052A, "LAST_ADMIN"),
+    (0x0000052B, "WRONG_PASSWORD"),
+    (0x0000052C, "ILL_FORMED_PASSWORD"),
+    (0x0000052D, "PASSWORD_RESTRICTION"),
+    (0x0000052E, "LOGON_FAILURE"),
+    (0x0000052F, "ACCOUNT_RESTRICTION"),
+    (0x00000530, "INVALID_LOGON_HOURS"),
+    (0x00000531, "INVALID_WORKSTATION"),
+    (0x00000532, "PASSWORD_EXPIRED"),
+    (0x00000533, "ACCOUNT_DISABLED"),
+    (0x00000534, "NONE_MAPPED"),
+    (0x00000535, "TOO_MANY_LUIDS_REQUESTED"),
+    (0x00000536, "LUIDS_EXHAUSTED"),
+    (0x00000537, "INVALID_SUB_AUTHORITY"),
+    (0x00000538, "INVALID_ACL"),
+    (0x00000539, "INVALID_SID"),
+    (0x0000053A, "INVALID_SECURITY_DESCR"),
+    (0x0000053C, "BAD_INHERITANCE_ACL"),
+    (0x0000053D, "SERVER_DISABLED"),
+    (0x0000053E, "SERVER_NOT_DISABLED"),
+    (0x0000053F, "INVALID_ID_AUTHORITY"),
+    (0x00000540, "ALLOTTED_SPACE_EXCEEDED"),
+    (0x00000541, "INVALID_GROUP_ATTRIBUTES"),
+    (0x00000542, "BAD_IMPERSONATION_LEVEL"),
+    (0x00000543, "CANT_OPEN_ANONYMOUS"),
+    (0x00000544, "BAD_VALIDATION_CLASS"),
+    (0x00000545, "BAD_TOKEN_TYPE"),
+    (0x00000546, "NO_SECURITY_ON_OBJECT"),
+    (0x00000547, "CANT_ACCESS_DOMAIN_INFO"),
+    (0x00000548, "INVALID_SERVER_STATE"),
+    (0x00000549, "INVALID_DOMAIN_STATE"),
+    (0x0000054A, "INVALID_DOMAIN_ROLE"),
+    (0x0000054B, "NO_SUCH_DOMAIN"),
+    (0x0000054C, "DOMAIN_EXISTS"),
+    (0x0000054D, "DOMAIN_LIMIT_EXCEEDED"),
+    (0x0000054E, "INTERNAL_DB_CORRUPTION"),
+    (0x0000054F, "INTERNAL_ERROR"),
+    (0x00000550, "GENERIC_NOT_MAPPED"),
+    (0x00000551, "BAD_DESCRIPTOR_FORMAT"),
+    (0x00000552, "NOT_LOGON_PROCESS"),
+    (0x00000553, "LOGON_SESSION_EXISTS"),
+    (0x00000554, "NO_SUCH_PACKAGE"),
+    (0x00000555, "BAD_LOGON_SESSION_STATE"),
+    (0x00000556, "LOGON_SESSION_COLLISION"),
+    (0x00000557, "INVALID_LOGON_TYPE"),
+    (0x00000558, "CANNOT_IMPERSONATE"),
+    (0x00000559, "RXACT_INVALID_STATE"),
+    (0x0000055A, "RXACT_COMMIT_FAILURE"),
+    (0x0000055B, "SPECIAL_ACCOUNT"),
+    (0x0000055C, "SPECIAL_GROUP"),
+    (0x0000055D, "SPECIAL_USER"),
+    (0x0000055E, "MEMBERS_PRIMARY_GROUP"),
+    (0x0000055F, "TOKEN_ALREADY_IN_USE"),
+    (0x00000560, "NO_SUCH_ALIAS"),
+    (0x00000561, "MEMBER_NOT_IN_ALIAS"),
+    (0x00000562, "MEMBER_IN_ALIAS"),
+    (0x00000563, "ALIAS_EXISTS"),
+    (0x00000564, "LOGON_NOT_GRANTED"),
+    (0x00000565, "TOO_MANY_SECRETS"),
+    (0x00000566, "SECRET_TOO_LONG"),
+    (0x00000567, "INTERNAL_DB_ERROR"),
+    (0x00000568, "TOO_MANY_CONTEXT_IDS"),
+    (0x00000569, "LOGON_TYPE_NOT_GRANTED"),
+    (0x0000056A, "NT_CROSS_ENCRYPTION_REQUIRED"),
+    (0x0000056B, "NO_SUCH_MEMBER"),
+    (0x0000056C, "INVALID_MEMBER"),
+    (0x0000056D, "TOO_MANY_SIDS"),
+    (0x0000056E, "LM_CROSS_ENCRYPTION_REQUIRED"),
+    (0x0000056F, "NO_INHERITANCE"),
+    (0x00000570, "FILE_CORRUPT"),
+    (0x00000571, "DISK_CORRUPT"),
+    (0x00000572, "NO_USER_SESSION_KEY"),
+    (0x00000573, "LICENSE_QUOTA_EXCEEDED"),
+    (0x00000574, "WRONG_TARGET_NAME"),
+    (0x00000575, "MUTUAL_AUTH_FAILED"),
+    (0x00000576, "TIME_SKEW"),
+    (0x00000577, "CURRENT_DOMAIN_NOT_ALLOWED"),
+    (0x00000578, "INVALID_WINDOW_HANDLE"),
+    (0x00000579, "INVALID_MENU_HANDLE"),
+    (0x0000057A, "INVALID_CURSOR_HANDLE"),
+    (0x0000057B, "INVALID_ACCEL_HANDLE"),
+    (0x0000057C, "INVALID_HOOK_HANDLE"),
+    (0x0000057D, "INVALID_DWP_HANDLE"),
+    (0x0000057E, "TLW_WITH_WSCHILD"),
+    (0x0000057F, "CANNOT_FIND_WND_CLASS"),
+    (0x00000580, "WINDOW_OF_OTHER_THREAD"),
+    (0x00000581, "HOTKEY_ALREADY_REGISTERED"),
+    (0x00000582, "CLASS_ALREADY_EXISTS"),
+    (0x00000583, "CLASS_DOES_NOT_EXIST"),
+    (0x00000584, "CLASS_HAS_WINDOWS"),
+    (0x00000585, "INVALID_INDEX"),
+    (0x00000586, "INVALID_ICON_HANDLE"),
+    (0x00000587, "PRIVATE_DIALOG_INDEX"),
+    (0x00000588, "LISTBOX_ID_NOT_FOUND"),
+    (0x00000589, "NO_WILDCARD_CHARACTERS"),
+    (0x0000058A, "CLIPBOARD_NOT_OPEN"),
+    (0x0000058B, "HOTKEY_NOT_REGISTERED"),
+    (0x0000058C, "WINDOW_NOT_DIALOG"),
+    (0x0000058D, "CONTROL_ID_NOT_FOUND"),
+    (0x0000058E, "INVALID_COMBOBOX_MESSAGE"),
+    (0x0000058F, "WINDOW_NOT_COMBOBOX"),
+    (0x00000590, "INVALID_EDIT_HEIGHT"),
+    (0x00000591, "DC_NOT_FOUND"),
+    (0x00000592, "INVALID_HOOK_FILTER"),
+    (0x00000593, "INVALID_FILTER_PROC"),
+    (0x00000594, "HOOK_NEEDS_HMOD"),
+    (0x00000595, "GLOBAL_ONLY_HOOK"),
+    (0x00000596, "JOURNAL_HOOK_SET"),
+    (0x00000597, "HOOK_NOT_INSTALLED"),
+    (0x00000598, "INVALID_LB_MESSAGE"),
+    (0x00000599, "SETCOUNT_ON_BAD_LB"),
+    (0x0000059A, "LB_WITHOUT_TABSTOPS"),
+    (0x0000059B, "DESTROY_OBJECT_OF_OTHER_THREAD"),
+    (0x0000059C, "CHILD_WINDOW_MENU"),
+    (0x0000059D, "NO_SYSTEM_MENU"),
+    (0x0000059E, "INVALID_MSGBOX_STYLE"),
+    (0x0000059F, "INVALID_SPI_VALUE"),
+    (0x000005A0, "SCREEN_ALREADY_LOCKED"),
+    (0x000005A1, "HWNDS_HAVE_DIFF_PARENT"),
+    (0x000005A2, "NOT_CHILD_WINDOW"),
+    (0x000005A3, "INVALID_GW_COMMAND"),
+    (0x000005A4, "INVALID_THREAD_ID"),
+    (0x000005A5, "NON_MDICHILD_WINDOW"),
+    (0x000005A6, "POPUP_ALREADY_ACTIVE"),
+    (0x000005A7, "NO_SCROLLBARS"),
+    (0x000005A8, "INVALID_SCROLLBAR_RANGE"),
+    (0x000005A9, "INVALID_SHOWWIN_COMMAND"),
+    (0x000005AA, "NO_SYSTEM_RESOURCES"),
+    (0x000005AB, "NONPAGED_SYSTEM_RESOURCES"),
+    (0x000005AC, "PAGED_SYSTEM_RESOURCES"),
+    (0x000005AD, "WORKING_SET_QUOTA"),
+    (0x000005AE, "PAGEFILE_QUOTA"),
+    (0x000005AF, "COMMITMENT_LIMIT"),
+    (0x000005B0, "MENU_ITEM_NOT_FOUND"),
+    (0x000005B1, "INVALID_KEYBOARD_HANDLE"),
+    (0x000005B2, "HOOK_TYPE_NOT_ALLOWED"),
+    (0x000005B3, "REQUIRES_INTERACTIVE_WINDOWSTATION"),
+    (0x000005B4, "TIMEOUT"),
+    (0x000005B5, "INVALID_MONITOR_HANDLE"),
+    (0x000005B6, "INCORRECT_SIZE"),
+    (0x000005B7, "SYMLINK_CLASS_DISABLED"),
+    (0x000005B8, "SYMLINK_NOT_SUPPORTED"),
+    (0x000005B9, "XML_PARSE_ERROR"),
+    (0x000005BA, "XMLDSIG_ERROR"),
+    (0x000005BB, "RESTART_APPLICATION"),
+    (0x000005BC, "WRONG_COMPARTMENT"),
+    (0x000005BD, "AUTHIP_FAILURE"),
+    (0x000005BE, "NO_NVRAM_RESOURCES"),
+    (0x000005BF, "NOT_GUI_PROCESS"),
+    (0x000005DC, "EVENTLOG_FILE_CORRUPT"),
+    (0x000005DD, "EVENTLOG_CANT_START"),
+    (0x000005DE, "LOG_FILE_FULL"),
+    (0x000005DF, "EVENTLOG_FILE_CHANGED"),
+    (0x000005E0, "CONTAINER_ASSIGNED"),
+    (0x000005E1, "JOB_NO_CONTAINER"),
+    (0x0000060E, "INVALID_TASK_NAME"),
+    (0x0000060F, "INVALID_TASK_INDEX"),
+    (0x00000610, "THREAD_ALREADY_IN_TASK"),
+    (0x00000641, "INSTALL_SERVICE_FAILURE"),
+    (0x00000642, "INSTALL_USEREXIT"),
+    (0x00000643, "INSTALL_FAILURE"),
+    (0x00000644, "INSTALL_SUSPEND"),
+    (0x00000645, "UNKNOWN_PRODUCT"),
+    (0x00000646, "UNKNOWN_FEATURE"),
+    (0x00000647, "UNKNOWN_COMPONENT"),
+    (0x00000648, "UNKNOWN_PROPERTY"),
+    (0x00000649, "INVALID_HANDLE_STATE"),
+    (0x0000064A, "BAD_CONFIGURATION"),
+    (0x0000064B, "INDEX_ABSENT"),
+    (0x0000064C, "INSTALL_SOURCE_ABSENT"),
+    (0x0000064D, "INSTALL_PACKAGE_VERSION"),
+    (0x0000064E, "PRODUCT_UNINSTALLED"),
+    (0x0000064F, "BAD_QUERY_SYNTAX"),
+    (0x00000650, "INVALID_FIELD"),
+    (0x00000651, "DEVICE_REMOVED"),
+    (0x00000652, "INSTALL_ALREADY_RUNNING"),
+    (0x00000653, "INSTALL_PACKAGE_OPEN_FAILED"),
+    (0x00000654, "INSTALL_PACKAGE_INVALID"),
+    (0x00000655, "INSTALL_UI_FAILURE"),
+    (0x00000656, "INSTALL_LOG_FAILURE"),
+    (0x00000657, "INSTALL_LANGUAGE_UNSUPPORTED"),
+    (0x00000658, "INSTALL_TRANSFORM_FAILURE"),
+    (0x00000659, "INSTALL_PACKAGE_REJECTED"),
+    (0x0000065A, "FUNCTION_NOT_CALLED"),
+    (0x0000065B, "FUNCTION_FAILED"),
+    (0x0000065C, "INVALID_TABLE"),
+    (0x0000065D, "DATATYPE_MISMATCH"),
+    (0x0000065E, "UNSUPPORTED_TYPE"),
+    (0x0000065F, "CREATE_FAILED"),
+    (0x00000660, "INSTALL_TEMP_UNWRITABLE"),
+    (0x00000661, "INSTALL_PLATFORM_UNSUPPORTED"),
+    (0x00000662, "INSTALL_NOTUSED"),
+    (0x00000663, "PATCH_PACKAGE_OPEN_FAILED"),
+    (0x00000664, "PATCH_PACKAGE_INVALID"),
+    (0x00000665, "PATCH_PACKAGE_UNSUPPORTED"),
+    (0x00000666, "PRODUCT_VERSION"),
+    (0x00000667, "INVALID_COMMAND_LINE"),
+    (0x00000668, "INSTALL_REMOTE_DISALLOWED"),
+    (0x00000669, "SUCCESS_REBOOT_INITIATED"),
+    (0x0000066A, "PATCH_TARGET_NOT_FOUND"),
+    (0x0000066B, "PATCH_PACKAGE_REJECTED"),
+    (0x0000066C, "INSTALL_TRANSFORM_REJECTED"),
+    (0x0000066D, "INSTALL_REMOTE_PROHIBITED"),
+    (0x0000066E, "PATCH_REMOVAL_UNSUPPORTED"),
+    (0x0000066F, "UNKNOWN_PATCH"),
+    (0x00000670, "PATCH_NO_SEQUENCE"),
+    (0x00000671, "PATCH_REMOVAL_DISALLOWED"),
+    (0x00000672, "INVALID_PATCH_XML"),
+    (0x00000673, "PATCH_MANAGED_ADVERTISED_PRODUCT"),
+    (0x00000674, "INSTALL_SERVICE_SAFEBOOT"),
+    (0x00000675, "FAIL_FAST_EXCEPTION"),
+    (0x00000676, "INSTALL_REJECTED"),
+    (0x00000677, "DYNAMIC_CODE_BLOCKED"),
+    (0x00000678, "NOT_SAME_OBJECT"),
+    (0x00000679, "STRICT_CFG_VIOLATION"),
+    (0x0000067C, "SET_CONTEXT_DENIED"),
+    (0x0000067D, "CROSS_PARTITION_VIOLATION"),
+    (0x0000067E, "RETURN_ADDRESS_HIJACK_ATTEMPT"),
+    (0x000006F8, "INVALID_USER_BUFFER"),
+    (0x000006F9, "UNRECOGNIZED_MEDIA"),
+    (0x000006FA, "NO_TRUST_LSA_SECRET"),
+    (0x000006FB, "NO_TRUST_SAM_ACCOUNT"),
+    (0x000006FC, "TRUSTED_DOMAIN_FAILURE"),
+    (0x000006FD, "TRUSTED_RELATIONSHIP_FAILURE"),
+    (0x000006FE, "TRUST_FAILURE"),
+    (0x00000700, "NETLOGON_NOT_STARTED"),
+    (0x00000701, "ACCOUNT_EXPIRED"),
+    (0x00000702, "REDIRECTOR_HAS_OPEN_HANDLES"),
+    (0x00000703, "PRINTER_DRIVER_ALREADY_INSTALLED"),
+    (0x00000704, "UNKNOWN_PORT"),
+    (0x00000705, "UNKNOWN_PRINTER_DRIVER"),
+    (0x00000706, "UNKNOWN_PRINTPROCESSOR"),
+    (0x00000707, "INVALID_SEPARATOR_FILE"),
+    (0x00000708, "INVALID_PRIORITY"),
+    (0x00000709, "INVALID_PRINTER_NAME"),
+    (0x0000070A, "PRINTER_ALREADY_EXISTS"),
+    (0x0000070B, "INVALID_PRINTER_COMMAND"),
+    (0x0000070C, "INVALID_DATATYPE"),
+    (0x0000070D, "INVALID_ENVIRONMENT"),
+    (0x0000070F, "NOLOGON_INTERDOMAIN_TRUST_ACCOUNT"),
+    (0x00000710, "NOLOGON_WORKSTATION_TRUST_ACCOUNT"),
+    (0x00000711, "NOLOGON_SERVER_TRUST_ACCOUNT"),
+    (0x00000712, "DOMAIN_TRUST_INCONSISTENT"),
+    (0x00000713, "SERVER_HAS_OPEN_HANDLES"),
+    (0x00000714, "RESOURCE_DATA_NOT_FOUND"),
+    (0x00000715, "RESOURCE_TYPE_NOT_FOUND"),
+    (0x00000716, "RESOURCE_NAME_NOT_FOUND"),
+    (0x00000717, "RESOURCE_LANG_NOT_FOUND"),
+    (0x00000718, "NOT_ENOUGH_QUOTA"),
+    (0x0000076D, "INVALID_TIME"),
+    (0x0000076E, "INVALID_FORM_NAME"),
+    (0x0000076F, "INVALID_FORM_SIZE"),
+    (0x00000770, "ALREADY_WAITING"),
+    (0x00000771, "PRINTER_DELETED"),
+    (0x00000772, "INVALID_PRINTER_STATE"),
+    (0x00000773, "PASSWORD_MUST_CHANGE"),
+    (0x00000774, "DOMAIN_CONTROLLER_NOT_FOUND"),
+    (0x00000775, "ACCOUNT_LOCKED_OUT"),
+    (0x0000077F, "NO_SITENAME"),
+    (0x00000780, "CANT_ACCESS_FILE"),
+    (0x00000781, "CANT_RESOLVE_FILENAME"),
+    (0x0000078A, "KM_DRIVER_BLOCKED"),
+    (0x0000078B, "CONTEXT_EXPIRED"),
+    (0x0000078C, "PER_USER_TRUST_QUOTA_EXCEEDED"),
+    (0x0000078D, "ALL_USER_TRUST_QUOTA_EXCEEDED"),
+    (0x0000078E, "USER_DELETE_TRUST_QUOTA_EXCEEDED"),
+    (0x0000078F, "AUTHENTICATION_FIREWALL_FAILED"),
+    (0x00000790, "REMOTE_PRINT_CONNECTIONS_BLOCKED"),
+    (0x00000791, "NTLM_BLOCKED"),
+    (0x00000792, "PASSWORD_CHANGE_REQUIRED"),
+    (0x00000793, "LOST_MODE_LOGON_RESTRICTION"),
+    (0x000007D0, "INVALID_PIXEL_FORMAT"),
+    (0x000007D1, "BAD_DRIVER"),
+    (0x000007D2, "INVALID_WINDOW_STYLE"),
+    (0x000007D3, "METAFILE_NOT_SUPPORTED"),
+    (0x000007D4, "TRANSFORM_NOT_SUPPORTED"),
+    (0x000007D5, "CLIPPING_NOT_SUPPORTED"),
+    (0x000007DA, "INVALID_CMM"),
+    (0x000007DB, "INVALID_PROFILE"),
+    (0x000007DC, "TAG_NOT_FOUND"),
+    (0x000007DD, "TAG_NOT_PRESENT"),
+    (0x000007DE, "DUPLICATE_TAG"),
+    (0x000007DF, "PROFILE_NOT_ASSOCIATED_WITH_DEVICE"),
+    (0x000007E0, "PROFILE_NOT_FOUND"),
+    (0x000007E1, "INVALID_COLORSPACE"),
+    (0x000007E2, "ICM_NOT_ENABLED"),
+    (0x000007E3, "DELETING_ICM_XFORM"),
+    (0x000007E4, "INVALID_TRANSFORM"),
+    (0x000007E5, "COLORSPACE_MISMATCH"),
+    (0x000007E6, "INVALID_COLORINDEX"),
+    (0x000007E7, "PROFILE_DOES_NOT_MATCH_DEVICE"),
+    (0x0000083C, "CONNECTED_OTHER_PASSWORD"),
+    (0x0000083D, "CONNECTED_OTHER_PASSWORD_DEFAULT"),
+    (0x0000089A, "BAD_USERNAME"),
+    (0x000008CA, "NOT_CONNECTED"),
+    (0x00000961, "OPEN_FILES"),
+    (0x00000962, "ACTIVE_CONNECTIONS"),
+    (0x00000964, "DEVICE_IN_USE"),
+    (0x00000BB8, "UNKNOWN_PRINT_MONITOR"),
+    (0x00000BB9, "PRINTER_DRIVER_IN_USE"),
+    (0x00000BBA, "SPOOL_FILE_NOT_FOUND"),
+    (0x00000BBB, "SPL_NO_STARTDOC"),
+    (0x00000BBC, "SPL_NO_ADDJOB"),
+    (0x00000BBD, "PRINT_PROCESSOR_ALREADY_INSTALLED"),
+    (0x00000BBE, "PRINT_MONITOR_ALREADY_INSTALLED"),
+    (0x00000BBF, "INVALID_PRINT_MONITOR"),
+    (0x00000BC0, "PRINT_MONITOR_IN_USE"),
+    (0x00000BC1, "PRINTER_HAS_JOBS_QUEUED"),
+    (0x00000BC2, "SUCCESS_REBOOT_REQUIRED"),
+    (0x00000BC3, "SUCCESS_RESTART_REQUIRED"),
+    (0x00000BC4, "PRINTER_NOT_FOUND"),
+    (0x00000BC5, "PRINTER_DRIVER_WARNED"),
+    (0x00000BC6, "PRINTER_DRIVER_BLOCKED"),
+    (0x00000BC7, "PRINTER_DRIVER_PACKAGE_IN_USE"),
+    (0x00000BC8, "CORE_DRIVER_PACKAGE_NOT_FOUND"),
+    (0x00000BC9, "FAIL_REBOOT_REQUIRED"),
+    (0x00000BCA, "FAIL_REBOOT_INITIATED"),
+    (0x00000BCB, "PRINTER_DRIVER_DOWNLOAD_NEEDED"),
+    (0x00000BCC, "PRINT_JOB_RESTART_REQUIRED"),
+    (0x00000BCD, "INVALID_PRINTER_DRIVER_MANIFEST"),
+    (0x00000BCE, "PRINTER_NOT_SHAREABLE"),
+    (0x00000BCF, "SERVER_SERVICE_CALL_REQUIRES_SMB1"),
+    (0x00000BD0, "NETWORK_AUTHENTICATION_PROMPT_CANCELED"),
+    (0x00000BEA, "REQUEST_PAUSED"),
+    (0x00000BF4, "APPEXEC_CONDITION_NOT_SATISFIED"),
+    (0x00000BF5, "APPEXEC_HANDLE_INVALIDATED"),
+    (0x00000BF6, "APPEXEC_INVALID_HOST_GENERATION"),
+    (0x00000BF7, "APPEXEC_UNEXPECTED_PROCESS_REGISTRATION"),
+    (0x00000BF8, "APPEXEC_INVALID_HOST_STATE"),
+    (0x00000BF9, "APPEXEC_NO_DONOR"),
+    (0x00000BFA, "APPEXEC_HOST_ID_MISMATCH"),
+    (0x00000BFB, "APPEXEC_UNKNOWN_USER"),
+    (0x00000BFC, "APPEXEC_APP_COMPAT_BLOCK"),
+    (0x00000BFD, "APPEXEC_CALLER_WAIT_TIMEOUT"),
+    (0x00000BFE, "APPEXEC_CALLER_WAIT_TIMEOUT_TERMINATION"),
+    (0x00000BFF, "APPEXEC_CALLER_WAIT_TIMEOUT_LICENSING"),
+    (0x00000C00, "APPEXEC_CALLER_WAIT_TIMEOUT_RESOURCES"),
+    (0x00000C08, "VRF_VOLATILE_CFG_AND_IO_ENABLED"),
+    (0x00000C09, "VRF_VOLATILE_NOT_STOPPABLE"),
+    (0x00000C0A, "VRF_VOLATILE_SAFE_MODE"),
+    (0x00000C0B, "VRF_VOLATILE_NOT_RUNNABLE_SYSTEM"),
+    (0x00000C0C, "VRF_VOLATILE_NOT_SUPPORTED_RULECLASS"),
+    (0x00000C0D, "VRF_VOLATILE_PROTECTED_DRIVER"),
+    (0x00000C0E, "VRF_VOLATILE_NMI_REGISTERED"),
+    (0x00000C0F, "VRF_VOLATILE_SETTINGS_CONFLICT"),
+    (0x00000C76, "DIF_IOCALLBACK_NOT_REPLACED"),
+    (0x00000C77, "DIF_LIVEDUMP_LIMIT_EXCEEDED"),
+    (0x00000C78, "DIF_VOLATILE_SECTION_NOT_LOCKED"),
+    (0x00000C79, "DIF_VOLATILE_DRIVER_HOTPATCHED"),
+    (0x00000C7A, "DIF_VOLATILE_INVALID_INFO"),
+    (0x00000C7B, "DIF_VOLATILE_DRIVER_IS_NOT_RUNNING"),
+    (0x00000C7C, "DIF_VOLATILE_PLUGIN_IS_NOT_RUNNING"),
+    (0x00000C7D, "DIF_VOLATILE_PLUGIN_CHANGE_NOT_ALLOWED"),
+    (0x00000C7E, "DIF_VOLATILE_NOT_ALLOWED"),
+    (0x00000C7F, "DIF_BINDING_API_NOT_FOUND"),
+    (0x00000F6E, "IO_REISSUE_AS_CACHED"),
+    (0x00000FA0, "WINS_INTERNAL"),
+    (0x00000FA1, "CAN_NOT_DEL_LOCAL_WINS"),
+    (0x00000FA2, "STATIC_INIT"),
+    (0x00000FA3, "INC_BACKUP"),
+    (0x00000FA4, "FULL_BACKUP"),
+    (0x00000FA5, "REC_NON_EXISTENT"),
+    (0x00000FA6, "RPL_NOT_ALLOWED"),
+    (0x00001004, "DHCP_ADDRESS_CONFLICT"),
+    (0x00001068, "WMI_GUID_NOT_FOUND"),
+    (0x00001069, "WMI_INSTANCE_NOT_FOUND"),
+    (0x0000106A, "WMI_ITEMID_NOT_FOUND"),
+    (0x0000106B, "WMI_TRY_AGAIN"),
+    (0x0000106C, "WMI_DP_NOT_FOUND"),
+    (0x0000106D, "WMI_UNRESOLVED_INSTANCE_REF"),
+    (0x0000106E, "WMI_ALREADY_ENABLED"),
+    (0x0000106F, "WMI_GUID_DISCONNECTED"),
+    (0x00001070, "WMI_SERVER_UNAVAILABLE"),
+    (0x00001071, "WMI_DP_FAILED"),
+    (0x00001072, "WMI_INVALID_MOF"),
+    (0x00001073, "WMI_INVALID_REGINFO"),
+    (0x00001074, "WMI_ALREADY_DISABLED"),
+    (0x00001075, "WMI_READ_ONLY"),
+    (0x00001076, "WMI_SET_FAILURE"),
+    (0x0000109A, "NOT_APPCONTAINER"),
+    (0x0000109B, "APPCONTAINER_REQUIRED"),
+    (0x0000109C, "NOT_SUPPORTED_IN_APPCONTAINER"),
+    (0x0000109D, "INVALID_PACKAGE_SID_LENGTH"),
+    (0x000010CC, "INVALID_MEDIA"),
+    (0x000010CD, "INVALID_LIBRARY"),
+    (0x000010CE, "INVALID_MEDIA_POOL"),
+    (0x000010CF, "DRIVE_MEDIA_MISMATCH"),
+    (0x000010D0, "MEDIA_OFFLINE"),
+    (0x000010D1, "LIBRARY_OFFLINE"),
+    (0x000010D2, "EMPTY"),
+    (0x000010D3, "NOT_EMPTY"),
+    (0x000010D4, "MEDIA_UNAVAILABLE"),
+    (0x000010D5, "RESOURCE_DISABLED"),
+    (0x000010D6, "INVALID_CLEANER"),
+    (0x000010D7, "UNABLE_TO_CLEAN"),
+    (0x000010D8, "OBJECT_NOT_FOUND"),
+    (0x000010D9, "DATABASE_FAILURE"),
+    (0x000010DA, "DATABASE_FULL"),
+    (0x000010DB, "MEDIA_INCOMPATIBLE"),
+    (0x000010DC, "RESOURCE_NOT_PRESENT"),
+    (0x000010DD, "INVALID_OPERATION"),
+    (0x000010DE, "MEDIA_NOT_AVAILABLE"),
+    (0x000010DF, "DEVICE_NOT_AVAILABLE"),
+    (0x000010E0, "REQUEST_REFUSED"),
+    (0x000010E1, "INVALID_DRIVE_OBJECT"),
+    (0x000010E2, "LIBRARY_FULL"),
+    (0x000010E3, "MEDIUM_NOT_ACCESSIBLE"),
+    (0x000010E4, "UNABLE_TO_LOAD_MEDIUM"),
+    (0x000010E5, "UNABLE_TO_INVENTORY_DRIVE"),
+    (0x000010E6, "UNABLE_TO_INVENTORY_SLOT"),
+    (0x000010E7, "UNABLE_TO_INVENTORY_TRANSPORT"),
+    (0x000010E8, "TRANSPORT_FULL"),
+    (0x000010E9, "CONTROLLING_IEPORT"),
+    (0x000010EA, "UNABLE_TO_EJECT_MOUNTED_MEDIA"),
+    (0x000010EB, "CLEANER_SLOT_SET"),
+    (0x000010EC, "CLEANER_SLOT_NOT_SET"),
+    (0x000010ED, "CLEANER_CARTRIDGE_SPENT"),
+    (0x000010EE, "UNEXPECTED_OMID"),
+    (0x000010EF, "CANT_DELETE_LAST_ITEM"),
+    (0x000010F0, "MESSAGE_EXCEEDS_MAX_SIZE"),
+    (0x000010F1, "VOLUME_CONTAINS_SYS_FILES"),
+    (0x000010F2, "INDIGENOUS_TYPE"),
+    (0x000010F3, "NO_SUPPORTING_DRIVES"),
+    (0x000010F4, "CLEANER_CARTRIDGE_INSTALLED"),
+    (0x000010F5, "IEPORT_FULL"),
+    (0x000010FE, "FILE_OFFLINE"),
+    (0x000010FF, "REMOTE_STORAGE_NOT_ACTIVE"),
+    (0x00001100, "REMOTE_STORAGE_MEDIA_ERROR"),
+    (0x00001126, "NOT_A_REPARSE_POINT"),
+    (0x00001127, "REPARSE_ATTRIBUTE_CONFLICT"),
+    (0x00001128, "INVALID_REPARSE_DATA"),
+    (0x00001129, "REPARSE_TAG_INVALID"),
+    (0x0000112A, "REPARSE_TAG_MISMATCH"),
+    (0x0000112B, "REPARSE_POINT_ENCOUNTERED"),
+    (0x00001130, "APP_DATA_NOT_FOUND"),
+    (0x00001131, "APP_DATA_EXPIRED"),
+    (0x00001132, "APP_DATA_CORRUPT"),
+    (0x00001133, "APP_DATA_LIMIT_EXCEEDED"),
+    (0x00001134, "APP_DATA_REBOOT_REQUIRED"),
+    (0x00001144, "SECUREBOOT_ROLLBACK_DETECTED"),
+    (0x00001145, "SECUREBOOT_POLICY_VIOLATION"),
+    (0x00001146, "SECUREBOOT_INVALID_POLICY"),
+    (0x00001147, "SECUREBOOT_POLICY_PUBLISHER_NOT_FOUND"),
+    (0x00001148, "SECUREBOOT_POLICY_NOT_SIGNED"),
+    (0x00001149, "SECUREBOOT_NOT_ENABLED"),
+    (0x0000114A, "SECUREBOOT_FILE_REPLACED"),
+    (0x0000114B, "SECUREBOOT_POLICY_NOT_AUTHORIZED"),
+    (0x0000114C, "SECUREBOOT_POLICY_UNKNOWN"),
+    (0x0000114D, "SECUREBOOT_POLICY_MISSING_ANTIROLLBACKVERSION"),
+    (0x0000114E, "SECUREBOOT_PLATFORM_ID_MISMATCH"),
+    (0x0000114F, "SECUREBOOT_POLICY_ROLLBACK_DETECTED"),
+    (0x00001150, "SECUREBOOT_POLICY_UPGRADE_MISMATCH"),
+    (0x00001151, "SECUREBOOT_REQUIRED_POLICY_FILE_MISSING"),
+    (0x00001152, "SECUREBOOT_NOT_BASE_POLICY"),
+    (0x00001153, "SECUREBOOT_NOT_SUPPLEMENTAL_POLICY"),
+    (0x00001158, "OFFLOAD_READ_FLT_NOT_SUPPORTED"),
+    (0x00001159, "OFFLOAD_WRITE_FLT_NOT_SUPPORTED"),
+    (0x0000115A, "OFFLOAD_READ_FILE_NOT_SUPPORTED"),
+    (0x0000115B, "OFFLOAD_WRITE_FILE_NOT_SUPPORTED"),
+    (0x0000115C, "ALREADY_HAS_STREAM_ID"),
+    (0x0000115D, "SMR_GARBAGE_COLLECTION_REQUIRED"),
+    (0x0000115E, "WOF_WIM_HEADER_CORRUPT"),
+    (0x0000115F, "WOF_WIM_RESOURCE_TABLE_CORRUPT"),
+    (0x00001160, "WOF_FILE_RESOURCE_TABLE_CORRUPT"),
+    (0x00001161, "OBJECT_IS_IMMUTABLE"),
+    (0x00001194, "VOLUME_NOT_SIS_ENABLED"),
+    (0x000011C6, "SYSTEM_INTEGRITY_ROLLBACK_DETECTED"),
+    (0x000011C7, "SYSTEM_INTEGRITY_POLICY_VIOLATION"),
+    (0x000011C8, "SYSTEM_INTEGRITY_INVALID_POLICY"),
+    (0x000011C9, "SYSTEM_INTEGRITY_POLICY_NOT_SIGNED"),
+    (0x000011CA, "SYSTEM_INTEGRITY_TOO_MANY_POLICIES"),
+    (0x000011CB, "SYSTEM_INTEGRITY_SUPPLEMENTAL_POLICY_NOT_AUTHORIZED"),
+    (0x000011CC, "SYSTEM_INTEGRITY_REPUTATION_MALICIOUS"),
+    (0x000011CD, "SYSTEM_INTEGRITY_REPUTATION_PUA"),
+    (0x000011CE, "SYSTEM_INTEGRITY_REPUTATION_DANGEROUS_EXT"),
+    (0x000011CF, "SYSTEM_INTEGRITY_REPUTATION_OFFLINE"),
+    (0x000011D0, "VSM_NOT_INITIALIZED"),
+    (0x000011D1, "VSM_DMA_PROTECTION_NOT_IN_USE"),
+    (0x000011DA, "PLATFORM_MANIFEST_NOT_AUTHORIZED"),
+    (0x000011DB, "PLATFORM_MANIFEST_INVALID"),
+    (0x000011DC, "PLATFORM_MANIFEST_FILE_NOT_AUTHORIZED"),
+    (0x000011DD, "PLATFORM_MANIFEST_CATALOG_NOT_AUTHORIZED"),
+    (0x000011DE, "PLATFORM_MANIFEST_BINARY_ID_NOT_FOUND"),
+    (0x000011DF, "PLATFORM_MANIFEST_NOT_ACTIVE"),
+    (0x000011E0, "PLATFORM_MANIFEST_NOT_SIGNED"),
+    (0x000011E4, "SYSTEM_INTEGRITY_REPUTATION_UNFRIENDLY_FILE"),
+    (0x000011E5, "SYSTEM_INTEGRITY_REPUTATION_UNATTAINABLE"),
+    (0x000011E6, "SYSTEM_INTEGRITY_REPUTATION_EXPLICIT_DENY_FILE"),
+    (0x00001389, "DEPENDENT_RESOURCE_EXISTS"),
+    (0x0000138A, "DEPENDENCY_NOT_FOUND"),
+    (0x0000138B, "DEPENDENCY_ALREADY_EXISTS"),
+    (0x0000138C, "RESOURCE_NOT_ONLINE"),
+    (0x0000138D, "HOST_NODE_NOT_AVAILABLE"),
+    (0x0000138E, "RESOURCE_NOT_AVAILABLE"),
+    (0x0000138F, "RESOURCE_NOT_FOUND"),
+    (0x00001390, "SHUTDOWN_CLUSTER"),
+    (0x00001391, "CANT_EVICT_ACTIVE_NODE"),
+    (0x00001392, "OBJECT_ALREADY_EXISTS"),
+    (0x00001393, "OBJECT_IN_LIST"),
+    (0x00001394, "GROUP_NOT_AVAILABLE"),
+    (0x00001395, "GROUP_NOT_FOUND"),
+    (0x00001396, "GROUP_NOT_ONLINE"),
+    (0x00001397, "HOST_NODE_NOT_RESOURCE_OWNER"),
+    (0x00001398, "HOST_NODE_NOT_GROUP_OWNER"),
+    (0x00001399, "RESMON_CREATE_FAILED"),
+    (0x0000139A, "RESMON_ONLINE_FAILED"),
+    (0x0000139B, "RESOURCE_ONLINE"),
+    (0x0000139C, "QUORUM_RESOURCE"),
+    (0x0000139D, "NOT_QUORUM_CAPABLE"),
+    (0x0000139E, "CLUSTER_SHUTTING_DOWN"),
+    (0x0000139F, "INVALID_STATE"),
+    (0x000013A0, "RESOURCE_PROPERTIES_STORED"),
+    (0x000013A1, "NOT_QUORUM_CLASS"),
+    (0x000013A2, "CORE_RESOURCE"),
+    (0x000013A3, "QUORUM_RESOURCE_ONLINE_FAILED"),
+    (0x000013A4, "QUORUMLOG_OPEN_FAILED"),
+    (0x000013A5, "CLUSTERLOG_CORRUPT"),
+    (0x000013A6, "CLUSTERLOG_RECORD_EXCEEDS_MAXSIZE"),
+    (0x000013A7, "CLUSTERLOG_EXCEEDS_MAXSIZE"),
+    (0x000013A8, "CLUSTERLOG_CHKPOINT_NOT_FOUND"),
+    (0x000013A9, "CLUSTERLOG_NOT_ENOUGH_SPACE"),
+    (0x000013AA, "QUORUM_OWNER_ALIVE"),
+    (0x000013AB, "NETWORK_NOT_AVAILABLE"),
+    (0x000013AC, "NODE_NOT_AVAILABLE"),
+    (0x000013AD, "ALL_NODES_NOT_AVAILABLE"),
+    (0x000013AE, "RESOURCE_FAILED"),
+    (0x000013AF, "CLUSTER_INVALID_NODE"),
+    (0x000013B0, "CLUSTER_NODE_EXISTS"),
+    (0x000013B1, "CLUSTER_JOIN_IN_PROGRESS"),
+    (0x000013B2, "CLUSTER_NODE_NOT_FOUND"),
+    (0x000013B3, "CLUSTER_LOCAL_NODE_NOT_FOUND"),
+    (0x000013B4, "CLUSTER_NETWORK_EXISTS"),
+    (0x000013B5, "CLUSTER_NETWORK_NOT_FOUND"),
+    (0x000013B6, "CLUSTER_NETINTERFACE_EXISTS"),
+    (0x000013B7, "CLUSTER_NETINTERFACE_NOT_FOUND"),
+    (0x000013B8, "CLUSTER_INVALID_REQUEST"),
+    (0x000013B9, "CLUSTER_INVALID_NETWORK_PROVIDER"),
+    (0x000013BA, "CLUSTER_NODE_DOWN"),
+    (0x000013BB, "CLUSTER_NODE_UNREACHABLE"),
+    (0x000013BC, "CLUSTER_NODE_NOT_MEMBER"),
+    (0x000013BD, "CLUSTER_JOIN_NOT_IN_PROGRESS"),
+    (0x000013BE, "CLUSTER_INVALID_NETWORK"),
+    (0x000013C0, "CLUSTER_NODE_UP"),
+    (0x000013C1, "CLUSTER_IPADDR_IN_USE"),
+    (0x000013C2, "CLUSTER_NODE_NOT_PAUSED"),
+    (0x000013C3, "CLUSTER_NO_SECURITY_CONTEXT"),
+    (0x000013C4, "CLUSTER_NETWORK_NOT_INTERNAL"),
+    (0x000013C5, "CLUSTER_NODE_ALREADY_UP"),
+    (0x000013C6, "CLUSTER_NODE_ALREADY_DOWN"),
+    (0x000013C7, "CLUSTER_NETWORK_ALREADY_ONLINE"),
+    (0x000013C8, "CLUSTER_NETWORK_ALREADY_OFFLINE"),
+    (0x000013C9, "CLUSTER_NODE_ALREADY_MEMBER"),
+    (0x000013CA, "CLUSTER_LAST_INTERNAL_NETWORK"),
+    (0x000013CB, "CLUSTER_NETWORK_HAS_DEPENDENTS"),
+    (0x000013CC, "INVALID_OPERATION_ON_QUORUM"),
+    (0x000013CD, "DEPENDENCY_NOT_ALLOWED"),
+    (0x000013CE, "CLUSTER_NODE_PAUSED"),
+    (0x000013CF, "NODE_CANT_HOST_RESOURCE"),
+    (0x000013D0, "CLUSTER_NODE_NOT_READY"),
+    (0x000013D1, "CLUSTER_NODE_SHUTTING_DOWN"),
+    (0x000013D2, "CLUSTER_JOIN_ABORTED"),
+    (0x000013D3, "CLUSTER_INCOMPATIBLE_VERSIONS"),
+    (0x000013D4, "CLUSTER_MAXNUM_OF_RESOURCES_EXCEEDED"),
+    (0x000013D5, "CLUSTER_SYSTEM_CONFIG_CHANGED"),
+    (0x000013D6, "CLUSTER_RESOURCE_TYPE_NOT_FOUND"),
+    (0x000013D7, "CLUSTER_RESTYPE_NOT_SUPPORTED"),
+    (0x000013D8, "CLUSTER_RESNAME_NOT_FOUND"),
+    (0x000013D9, "CLUSTER_NO_RPC_PACKAGES_REGISTERED"),
+    (0x000013DA, "CLUSTER_OWNER_NOT_IN_PREFLIST"),
+    (0x000013DB, "CLUSTER_DATABASE_SEQMISMATCH"),
+    (0x000013DC, "RESMON_INVALID_STATE"),
+    (0x000013DD, "CLUSTER_GUM_NOT_LOCKER"),
+    (0x000013DE, "QUORUM_DISK_NOT_FOUND"),
+    (0x000013DF, "DATABASE_BACKUP_CORRUPT"),
+    (0x000013E0, "CLUSTER_NODE_ALREADY_HAS_DFS_ROOT"),
+    (0x000013E1, "RESOURCE_PROPERTY_UNCHANGEABLE"),
+    (0x000013E2, "NO_ADMIN_ACCESS_POINT"),
+    (0x00001702, "CLUSTER_MEMBERSHIP_INVALID_STATE"),
+    (0x00001703, "CLUSTER_QUORUMLOG_NOT_FOUND"),
+    (0x00001704, "CLUSTER_MEMBERSHIP_HALT"),
+    (0x00001705, "CLUSTER_INSTANCE_ID_MISMATCH"),
+    (0x00001706, "CLUSTER_NETWORK_NOT_FOUND_FOR_IP"),
+    (0x00001707, "CLUSTER_PROPERTY_DATA_TYPE_MISMATCH"),
+    (0x00001708, "CLUSTER_EVICT_WITHOUT_CLEANUP"),
+    (0x00001709, "CLUSTER_PARAMETER_MISMATCH"),
+    (0x0000170A, "NODE_CANNOT_BE_CLUSTERED"),
+    (0x0000170B, "CLUSTER_WRONG_OS_VERSION"),
+    (0x0000170C, "CLUSTER_CANT_CREATE_DUP_CLUSTER_NAME"),
+    (0x0000170D, "CLUSCFG_ALREADY_COMMITTED"),
+    (0x0000170E, "CLUSCFG_ROLLBACK_FAILED"),
+    (0x0000170F, "CLUSCFG_SYSTEM_DISK_DRIVE_LETTER_CONFLICT"),
+    (0x00001710, "CLUSTER_OLD_VERSION"),
+    (0x00001711, "CLUSTER_MISMATCHED_COMPUTER_ACCT_NAME"),
+    (0x00001712, "CLUSTER_NO_NET_ADAPTERS"),
+    (0x00001713, "CLUSTER_POISONED"),
+    (0x00001714, "CLUSTER_GROUP_MOVING"),
+    (0x00001715, "CLUSTER_RESOURCE_TYPE_BUSY"),
+    (0x00001716, "RESOURCE_CALL_TIMED_OUT"),
+    (0x00001717, "INVALID_CLUSTER_IPV6_ADDRESS"),
+    (0x00001718, "CLUSTER_INTERNAL_INVALID_FUNCTION"),
+    (0x00001719, "CLUSTER_PARAMETER_OUT_OF_BOUNDS"),
+    (0x0000171A, "CLUSTER_PARTIAL_SEND"),
+    (0x0000171B, "CLUSTER_REGISTRY_INVALID_FUNCTION"),
+    (0x0000171C, "CLUSTER_INVALID_STRING_TERMINATION"),
+    (0x0000171D, "CLUSTER_INVALID_STRING_FORMAT"),
+    (0x0000171E, "CLUSTER_DATABASE_TRANSACTION_IN_PROGRESS"),
+    (0x0000171F, "CLUSTER_DATABASE_TRANSACTION_NOT_IN_PROGRESS"),
+    (0x00001720, "CLUSTER_NULL_DATA"),
+    (0x00001721, "CLUSTER_PARTIAL_READ"),
+    (0x00001722, "CLUSTER_PARTIAL_WRITE"),
+    (0x00001723, "CLUSTER_CANT_DESERIALIZE_DATA"),
+    (0x00001724, "DEPENDENT_RESOURCE_PROPERTY_CONFLICT"),
+    (0x00001725, "CLUSTER_NO_QUORUM"),
+    (0x00001726, "CLUSTER_INVALID_IPV6_NETWORK"),
+    (0x00001727, "CLUSTER_INVALID_IPV6_TUNNEL_NETWORK"),
+    (0x00001728, "QUORUM_NOT_ALLOWED_IN_THIS_GROUP"),
+    (0x00001729, "DEPENDENCY_TREE_TOO_COMPLEX"),
+    (0x0000172A, "EXCEPTION_IN_RESOURCE_CALL"),
+    (0x0000172B, "CLUSTER_RHS_FAILED_INITIALIZATION"),
+    (0x0000172C, "CLUSTER_NOT_INSTALLED"),
+    (0x0000172D, "CLUSTER_RESOURCES_MUST_BE_ONLINE_ON_THE_SAME_NODE"),
+    (0x0000172E, "CLUSTER_MAX_NODES_IN_CLUSTER"),
+    (0x0000172F, "CLUSTER_TOO_MANY_NODES"),
+    (0x00001730, "CLUSTER_OBJECT_ALREADY_USED"),
+    (0x00001731, "NONCORE_GROUPS_FOUND"),
+    (0x00001732, "FILE_SHARE_RESOURCE_CONFLICT"),
+    (0x00001733, "CLUSTER_EVICT_INVALID_REQUEST"),
+    (0x00001734, "CLUSTER_SINGLETON_RESOURCE"),
+    (0x00001735, "CLUSTER_GROUP_SINGLETON_RESOURCE"),
+    (0x00001736, "CLUSTER_RESOURCE_PROVIDER_FAILED"),
+    (0x00001737, "CLUSTER_RESOURCE_CONFIGURATION_ERROR"),
+    (0x00001738, "CLUSTER_GROUP_BUSY"),
+    (0x00001739, "CLUSTER_NOT_SHARED_VOLUME"),
+    (0x0000173A, "CLUSTER_INVALID_SECURITY_DESCRIPTOR"),
+    (0x0000173B, "CLUSTER_SHARED_VOLUMES_IN_USE"),
+    (0x0000173C, "CLUSTER_USE_SHARED_VOLUMES_API"),
+    (0x0000173D, "CLUSTER_BACKUP_IN_PROGRESS"),
+    (0x0000173E, "NON_CSV_PATH"),
+    (0x0000173F, "CSV_VOLUME_NOT_LOCAL"),
+    (0x00001740, "CLUSTER_WATCHDOG_TERMINATING"),
+    (0x00001741, "CLUSTER_RESOURCE_VETOED_MOVE_INCOMPATIBLE_NODES"),
+    (0x00001742, "CLUSTER_INVALID_NODE_WEIGHT"),
+    (0x00001743, "CLUSTER_RESOURCE_VETOED_CALL"),
+    (0x00001744, "RESMON_SYSTEM_RESOURCES_LACKING"),
+    (0x00001745, "CLUSTER_RESOURCE_VETOED_MOVE_NOT_ENOUGH_RESOURCES_ON_DESTINATION"),
+    (0x00001746, "CLUSTER_RESOURCE_VETOED_MOVE_NOT_ENOUGH_RESOURCES_ON_SOURCE"),
+    (0x00001747, "CLUSTER_GROUP_QUEUED"),
+    (0x00001748, "CLUSTER_RESOURCE_LOCKED_STATUS"),
+    (0x00001749, "CLUSTER_SHARED_VOLUME_FAILOVER_NOT_ALLOWED"),
+    (0x0000174A, "CLUSTER_NODE_DRAIN_IN_PROGRESS"),
+    (0x0000174B, "CLUSTER_DISK_NOT_CONNECTED"),
+    (0x0000174C, "DISK_NOT_CSV_CAPABLE"),
+    (0x0000174D, "RESOURCE_NOT_IN_AVAILABLE_STORAGE"),
+    (0x0000174E, "CLUSTER_SHARED_VOLUME_REDIRECTED"),
+    (0x0000174F, "CLUSTER_SHARED_VOLUME_NOT_REDIRECTED"),
+    (0x00001750, "CLUSTER_CANNOT_RETURN_PROPERTIES"),
+    (0x00001751, "CLUSTER_RESOURCE_CONTAINS_UNSUPPORTED_DIFF_AREA_FOR_SHARED_VOLUMES"),
+    (0x00001752, "CLUSTER_RESOURCE_IS_IN_MAINTENANCE_MODE"),
+    (0x00001753, "CLUSTER_AFFINITY_CONFLICT"),
+    (0x00001754, "CLUSTER_RESOURCE_IS_REPLICA_VIRTUAL_MACHINE"),
+    (0x00001755, "CLUSTER_UPGRADE_INCOMPATIBLE_VERSIONS"),
+    (0x00001756, "CLUSTER_UPGRADE_FIX_QUORUM_NOT_SUPPORTED"),
+    (0x00001757, "CLUSTER_UPGRADE_RESTART_REQUIRED"),
+    (0x00001758, "CLUSTER_UPGRADE_IN_PROGRESS"),
+    (0x00001759, "CLUSTER_UPGRADE_INCOMPLETE"),
+    (0x0000175A, "CLUSTER_NODE_IN_GRACE_PERIOD"),
+    (0x0000175B, "CLUSTER_CSV_IO_PAUSE_TIMEOUT"),
+    (0x0000175C, "NODE_NOT_ACTIVE_CLUSTER_MEMBER"),
+    (0x0000175D, "CLUSTER_RESOURCE_NOT_MONITORED"),
+    (0x0000175E, "CLUSTER_RESOURCE_DOES_NOT_SUPPORT_UNMONITORED"),
+    (0x0000175F, "CLUSTER_RESOURCE_IS_REPLICATED"),
+    (0x00001760, "CLUSTER_NODE_ISOLATED"),
+    (0x00001761, "CLUSTER_NODE_QUARANTINED"),
+    (0x00001762, "CLUSTER_DATABASE_UPDATE_CONDITION_FAILED"),
+    (0x00001763, "CLUSTER_SPACE_DEGRADED"),
+    (0x00001764, "CLUSTER_TOKEN_DELEGATION_NOT_SUPPORTED"),
+    (0x00001765, "CLUSTER_CSV_INVALID_HANDLE"),
+    (0x00001766, "CLUSTER_CSV_SUPPORTED_ONLY_ON_COORDINATOR"),
+    (0x00001767, "GROUPSET_NOT_AVAILABLE"),
+    (0x00001768, "GROUPSET_NOT_FOUND"),
+    (0x00001769, "GROUPSET_CANT_PROVIDE"),
+    (0x0000176A, "CLUSTER_FAULT_DOMAIN_PARENT_NOT_FOUND"),
+    (0x0000176B, "CLUSTER_FAULT_DOMAIN_INVALID_HIERARCHY"),
+    (0x0000176C, "CLUSTER_FAULT_DOMAIN_FAILED_S2D_VALIDATION"),
+    (0x0000176D, "CLUSTER_FAULT_DOMAIN_S2D_CONNECTIVITY_LOSS"),
+    (0x0000176E, "CLUSTER_INVALID_INFRASTRUCTURE_FILESERVER_NAME"),
+    (0x0000176F, "CLUSTERSET_MANAGEMENT_CLUSTER_UNREACHABLE"),
+    (0x00001770, "ENCRYPTION_FAILED"),
+    (0x00001771, "DECRYPTION_FAILED"),
+    (0x00001772, "FILE_ENCRYPTED"),
+    (0x00001773, "NO_RECOVERY_POLICY"),
+    (0x00001774, "NO_EFS"),
+    (0x00001775, "WRONG_EFS"),
+    (0x00001776, "NO_USER_KEYS"),
+    (0x00001777, "FILE_NOT_ENCRYPTED"),
+    (0x00001778, "NOT_EXPORT_FORMAT"),
+    (0x00001779, "FILE_READ_ONLY"),
+    (0x0000177A, "DIR_EFS_DISALLOWED"),
+    (0x0000177B, "EFS_SERVER_NOT_TRUSTED"),
+    (0x0000177C, "BAD_RECOVERY_POLICY"),
+    (0x0000177D, "EFS_ALG_BLOB_TOO_BIG"),
+    (0x0000177E, "VOLUME_NOT_SUPPORT_EFS"),
+    (0x0000177F, "EFS_DISABLED"),
+    (0x00001780, "EFS_VERSION_NOT_SUPPORT"),
+    (0x00001781, "CS_ENCRYPTION_INVALID_SERVER_RESPONSE"),
+    (0x00001782, "CS_ENCRYPTION_UNSUPPORTED_SERVER"),
+    (0x00001783, "CS_ENCRYPTION_EXISTING_ENCRYPTED_FILE"),
+    (0x00001784, "CS_ENCRYPTION_NEW_ENCRYPTED_FILE"),
+    (0x00001785, "CS_ENCRYPTION_FILE_NOT_CSE"),
+    (0x00001786, "ENCRYPTION_POLICY_DENIES_OPERATION"),
+    (0x00001787, "WIP_ENCRYPTION_FAILED"),
+    (0x000017E6, "NO_BROWSER_SERVERS_FOUND"),
+    (0x0000186A, "CLUSTER_OBJECT_IS_CLUSTER_SET_VM"),
+    (0x000019C8, "LOG_SECTOR_INVALID"),
+    (0x000019C9, "LOG_SECTOR_PARITY_INVALID"),
+    (0x000019CA, "LOG_SECTOR_REMAPPED"),
+    (0x000019CB, "LOG_BLOCK_INCOMPLETE"),
+    (0x000019CC, "LOG_INVALID_RANGE"),
+    (0x000019CD, "LOG_BLOCKS_EXHAUSTED"),
+    (0x000019CE, "LOG_READ_CONTEXT_INVALID"),
+    (0x000019CF, "LOG_RESTART_INVALID"),
+    (0x000019D0, "LOG_BLOCK_VERSION"),
+    (0x000019D1, "LOG_BLOCK_INVALID"),
+    (0x000019D2, "LOG_READ_MODE_INVALID"),
+    (0x000019D3, "LOG_NO_RESTART"),
+    (0x000019D4, "LOG_METADATA_CORRUPT"),
+    (0x000019D5, "LOG_METADATA_INVALID"),
+    (0x000019D6, "LOG_METADATA_INCONSISTENT"),
+    (0x000019D7, "LOG_RESERVATION_INVALID"),
+    (0x000019D8, "LOG_CANT_DELETE"),
+    (0x000019D9, "LOG_CONTAINER_LIMIT_EXCEEDED"),
+    (0x000019DA, "LOG_START_OF_LOG"),
+    (0x000019DB, "LOG_POLICY_ALREADY_INSTALLED"),
+    (0x000019DC, "LOG_POLICY_NOT_INSTALLED"),
+    (0x000019DD, "LOG_POLICY_INVALID"),
+    (0x000019DE, "LOG_POLICY_CONFLICT"),
+    (0x000019DF, "LOG_PINNED_ARCHIVE_TAIL"),
+    (0x000019E0, "LOG_RECORD_NONEXISTENT"),
+    (0x000019E1, "LOG_RECORDS_RESERVED_INVALID"),
+    (0x000019E2, "LOG_SPACE_RESERVED_INVALID"),
+    (0x000019E3, "LOG_TAIL_INVALID"),
+    (0x000019E4, "LOG_FULL"),
+    (0x000019E5, "COULD_NOT_RESIZE_LOG"),
+    (0x000019E6, "LOG_MULTIPLEXED"),
+    (0x000019E7, "LOG_DEDICATED"),
+    (0x000019E8, "LOG_ARCHIVE_NOT_IN_PROGRESS"),
+    (0x000019E9, "LOG_ARCHIVE_IN_PROGRESS"),
+    (0x000019EA, "LOG_EPHEMERAL"),
+    (0x000019EB, "LOG_NOT_ENOUGH_CONTAINERS"),
+    (0x000019EC, "LOG_CLIENT_ALREADY_REGISTERED"),
+    (0x000019ED, "LOG_CLIENT_NOT_REGISTERED"),
+    (0x000019EE, "LOG_FULL_HANDLER_IN_PROGRESS"),
+    (0x000019EF, "LOG_CONTAINER_READ_FAILED"),
+    (0x000019F0, "LOG_CONTAINER_WRITE_FAILED"),
+    (0x000019F1, "LOG_CONTAINER_OPEN_FAILED"),
+    (0x000019F2, "LOG_CONTAINER_STATE_INVALID"),
+    (0x000019F3, "LOG_STATE_INVALID"),
+    (0x000019F4, "LOG_PINNED"),
+    (0x000019F5, "LOG_METADATA_FLUSH_FAILED"),
+    (0x000019F6, "LOG_INCONSISTENT_SECURITY"),
+    (0x000019F7, "LOG_APPENDED_FLUSH_FAILED"),
+    (0x000019F8, "LOG_PINNED_RESERVATION"),
+    (0x00001A2C, "INVALID_TRANSACTION"),
+    (0x00001A2D, "TRANSACTION_NOT_ACTIVE"),
+    (0x00001A2E, "TRANSACTION_REQUEST_NOT_VALID"),
+    (0x00001A2F, "TRANSACTION_NOT_REQUESTED"),
+    (0x00001A30, "TRANSACTION_ALREADY_ABORTED"),
+    (0x00001A31, "TRANSACTION_ALREADY_COMMITTED"),
+    (0x00001A32, "TM_INITIALIZATION_FAILED"),
+    (0x00001A33, "RESOURCEMANAGER_READ_ONLY"),
+    (0x00001A34, "TRANSACTION_NOT_JOINED"),
+    (0x00001A35, "TRANSACTION_SUPERIOR_EXISTS"),
+    (0x00001A36, "CRM_PROTOCOL_ALREADY_EXISTS"),
+    (0x00001A37, "TRANSACTION_PROPAGATION_FAILED"),
+    (0x00001A38, "CRM_PROTOCOL_NOT_FOUND"),
+    (0x00001A39, "TRANSACTION_INVALID_MARSHALL_BUFFER"),
+    (0x00001A3A, "CURRENT_TRANSACTION_NOT_VALID"),
+    (0x00001A3B, "TRANSACTION_NOT_FOUND"),
+    (0x00001A3C, "RESOURCEMANAGER_NOT_FOUND"),
+    (0x00001A3D, "ENLISTMENT_NOT_FOUND"),
+    (0x00001A3E, "TRANSACTIONMANAGER_NOT_FOUND"),
+    (0x00001A3F, "TRANSACTIONMANAGER_NOT_ONLINE"),
+    (0x00001A40, "TRANSACTIONMANAGER_RECOVERY_NAME_COLLISION"),
+    (0x00001A41, "TRANSACTION_NOT_ROOT"),
+    (0x00001A42, "TRANSACTION_OBJECT_EXPIRED"),
+    (0x00001A43, "TRANSACTION_RESPONSE_NOT_ENLISTED"),
+    (0x00001A44, "TRANSACTION_RECORD_TOO_LONG"),
+    (0x00001A45, "IMPLICIT_TRANSACTION_NOT_SUPPORTED"),
+    (0x00001A46, "TRANSACTION_INTEGRITY_VIOLATED"),
+    (0x00001A47, "TRANSACTIONMANAGER_IDENTITY_MISMATCH"),
+    (0x00001A48, "RM_CANNOT_BE_FROZEN_FOR_SNAPSHOT"),
+    (0x00001A49, "TRANSACTION_MUST_WRITETHROUGH"),
+    (0x00001A4A, "TRANSACTION_NO_SUPERIOR"),
+    (0x00001A4B, "HEURISTIC_DAMAGE_POSSIBLE"),
+    (0x00001A90, "TRANSACTIONAL_CONFLICT"),
+    (0x00001A91, "RM_NOT_ACTIVE"),
+    (0x00001A92, "RM_METADATA_CORRUPT"),
+    (0x00001A93, "DIRECTORY_NOT_RM"),
+    (0x00001A95, "TRANSACTIONS_UNSUPPORTED_REMOTE"),
+    (0x00001A96, "LOG_RESIZE_INVALID_SIZE"),
+    (0x00001A97, "OBJECT_NO_LONGER_EXISTS"),
+    (0x00001A98, "STREAM_MINIVERSION_NOT_FOUND"),
+    (0x00001A99, "STREAM_MINIVERSION_NOT_VALID"),
+    (0x00001A9A, "MINIVERSION_INACCESSIBLE_FROM_SPECIFIED_TRANSACTION"),
+    (0x00001A9B, "CANT_OPEN_MINIVERSION_WITH_MODIFY_INTENT"),
+    (0x00001A9C, "CANT_CREATE_MORE_STREAM_MINIVERSIONS"),
+    (0x00001A9E, "REMOTE_FILE_VERSION_MISMATCH"),
+    (0x00001A9F, "HANDLE_NO_LONGER_VALID"),
+    (0x00001AA0, "NO_TXF_METADATA"),
+    (0x00001AA1, "LOG_CORRUPTION_DETECTED"),
+    (0x00001AA2, "CANT_RECOVER_WITH_HANDLE_OPEN"),
+    (0x00001AA3, "RM_DISCONNECTED"),
+    (0x00001AA4, "ENLISTMENT_NOT_SUPERIOR"),
+    (0x00001AA5, "RECOVERY_NOT_NEEDED"),
+    (0x00001AA6, "RM_ALREADY_STARTED"),
+    (0x00001AA7, "FILE_IDENTITY_NOT_PERSISTENT"),
+    (0x00001AA8, "CANT_BREAK_TRANSACTIONAL_DEPENDENCY"),
+    (0x00001AA9, "CANT_CROSS_RM_BOUNDARY"),
+    (0x00001AAA, "TXF_DIR_NOT_EMPTY"),
+    (0x00001AAB, "INDOUBT_TRANSACTIONS_EXIST"),
+    (0x00001AAC, "TM_VOLATILE"),
+    (0x00001AAD, "ROLLBACK_TIMER_EXPIRED"),
+    (0x00001AAE, "TXF_ATTRIBUTE_CORRUPT"),
+    (0x00001AAF, "EFS_NOT_ALLOWED_IN_TRANSACTION"),
+    (0x00001AB0, "TRANSACTIONAL_OPEN_NOT_ALLOWED"),
+    (0x00001AB1, "LOG_GROWTH_FAILED"),
+    (0x00001AB2, "TRANSACTED_MAPPING_UNSUPPORTED_REMOTE"),
+    (0x00001AB3, "TXF_METADATA_ALREADY_PRESENT"),
+    (0x00001AB4, "TRANSACTION_SCOPE_CALLBACKS_NOT_SET"),
+    (0x00001AB5, "TRANSACTION_REQUIRED_PROMOTION"),
+    (0x00001AB6, "CANNOT_EXECUTE_FILE_IN_TRANSACTION"),
+    (0x00001AB7, "TRANSACTIONS_NOT_FROZEN"),
+    (0x00001AB8, "TRANSACTION_FREEZE_IN_PROGRESS"),
+    (0x00001AB9, "NOT_SNAPSHOT_VOLUME"),
+    (0x00001ABA, "NO_SAVEPOINT_WITH_OPEN_FILES"),
+    (0x00001ABB, "DATA_LOST_REPAIR"),
+    (0x00001ABC, "SPARSE_NOT_ALLOWED_IN_TRANSACTION"),
+    (0x00001ABD, "TM_IDENTITY_MISMATCH"),
+    (0x00001ABE, "FLOATED_SECTION"),
+    (0x00001ABF, "CANNOT_ACCEPT_TRANSACTED_WORK"),
+    (0x00001AC0, "CANNOT_ABORT_TRANSACTIONS"),
+    (0x00001AC1, "BAD_CLUSTERS"),
+    (0x00001AC2, "COMPRESSION_NOT_ALLOWED_IN_TRANSACTION"),
+    (0x00001AC3, "VOLUME_DIRTY"),
+    (0x00001AC4, "NO_LINK_TRACKING_IN_TRANSACTION"),
+    (0x00001AC5, "OPERATION_NOT_SUPPORTED_IN_TRANSACTION"),
+    (0x00001AC6, "EXPIRED_HANDLE"),
+    (0x00001AC7, "TRANSACTION_NOT_ENLISTED"),
+    (0x00001B59, "CTX_WINSTATION_NAME_INVALID"),
+    (0x00001B5A, "CTX_INVALID_PD"),
+    (0x00001B5B, "CTX_PD_NOT_FOUND"),
+    (0x00001B5C, "CTX_WD_NOT_FOUND"),
+    (0x00001B5D, "CTX_CANNOT_MAKE_EVENTLOG_ENTRY"),
+    (0x00001B5E, "CTX_SERVICE_NAME_COLLISION"),
+    (0x00001B5F, "CTX_CLOSE_PENDING"),
+    (0x00001B60, "CTX_NO_OUTBUF"),
+    (0x00001B61, "CTX_MODEM_INF_NOT_FOUND"),
+    (0x00001B62, "CTX_INVALID_MODEMNAME"),
+    (0x00001B63, "CTX_MODEM_RESPONSE_ERROR"),
+    (0x00001B64, "CTX_MODEM_RESPONSE_TIMEOUT"),
+    (0x00001B65, "CTX_MODEM_RESPONSE_NO_CARRIER"),
+    (0x00001B66, "CTX_MODEM_RESPONSE_NO_DIALTONE"),
+    (0x00001B67, "CTX_MODEM_RESPONSE_BUSY"),
+    (0x00001B68, "CTX_MODEM_RESPONSE_VOICE"),
+    (0x00001B69, "CTX_TD_ERROR"),
+    (0x00001B6E, "CTX_WINSTATION_NOT_FOUND"),
+    (0x00001B6F, "CTX_WINSTATION_ALREADY_EXISTS"),
+    (0x00001B70, "CTX_WINSTATION_BUSY"),
+    (0x00001B71, "CTX_BAD_VIDEO_MODE"),
+    (0x00001B7B, "CTX_GRAPHICS_INVALID"),
+    (0x00001B7D, "CTX_LOGON_DISABLED"),
+    (0x00001B7E, "CTX_NOT_CONSOLE"),
+    (0x00001B80, "CTX_CLIENT_QUERY_TIMEOUT"),
+    (0x00001B81, "CTX_CONSOLE_DISCONNECT"),
+    (0x00001B82, "CTX_CONSOLE_CONNECT"),
+    (0x00001B84, "CTX_SHADOW_DENIED"),
+    (0x00001B85, "CTX_WINSTATION_ACCESS_DENIED"),
+    (0x00001B89, "CTX_INVALID_WD"),
+    (0x00001B8A, "CTX_SHADOW_INVALID"),
+    (0x00001B8B, "CTX_SHADOW_DISABLED"),
+    (0x00001B8C, "CTX_CLIENT_LICENSE_IN_USE"),
+    (0x00001B8D, "CTX_CLIENT_LICENSE_NOT_SET"),
+    (0x00001B8E, "CTX_LICENSE_NOT_AVAILABLE"),
+    (0x00001B8F, "CTX_LICENSE_CLIENT_INVALID"),
+    (0x00001B90, "CTX_LICENSE_EXPIRED"),
+    (0x00001B91, "CTX_SHADOW_NOT_RUNNING"),
+    (0x00001B92, "CTX_SHADOW_ENDED_BY_MODE_CHANGE"),
+    (0x00001B93, "ACTIVATION_COUNT_EXCEEDED"),
+    (0x00001B94, "CTX_WINSTATIONS_DISABLED"),
+    (0x00001B95, "CTX_ENCRYPTION_LEVEL_REQUIRED"),
+    (0x00001B96, "CTX_SESSION_IN_USE"),
+    (0x00001B97, "CTX_NO_FORCE_LOGOFF"),
+    (0x00001B98, "CTX_ACCOUNT_RESTRICTION"),
+    (0x00001B99, "RDP_PROTOCOL_ERROR"),
+    (0x00001B9A, "CTX_CDM_CONNECT"),
+    (0x00001B9B, "CTX_CDM_DISCONNECT"),
+    (0x00001B9C, "CTX_SECURITY_LAYER_ERROR"),
+    (0x00001B9D, "TS_INCOMPATIBLE_SESSIONS"),
+    (0x00001B9E, "TS_VIDEO_SUBSYSTEM_ERROR"),
+    (0x00002008, "DS_NOT_INSTALLED"),
+    (0x00002009, "DS_MEMBERSHIP_EVALUATED_LOCALLY"),
+    (0x0000200A, "DS_NO_ATTRIBUTE_OR_VALUE"),
+    (0x0000200B, "DS_INVALID_ATTRIBUTE_SYNTAX"),
+    (0x0000200C, "DS_ATTRIBUTE_TYPE_UNDEFINED"),
+    (0x0000200D, "DS_ATTRIBUTE_OR_VALUE_EXISTS"),
+    (0x0000200E, "DS_BUSY"),
+    (0x0000200F, "DS_UNAVAILABLE"),
+    (0x00002010, "DS_NO_RIDS_ALLOCATED"),
+    (0x00002011, "DS_NO_MORE_RIDS"),
+    (0x00002012, "DS_INCORRECT_ROLE_OWNER"),
+    (0x00002013, "DS_RIDMGR_INIT_ERROR"),
+    (0x00002014, "DS_OBJ_CLASS_VIOLATION"),
+    (0x00002015, "DS_CANT_ON_NON_LEAF"),
+    (0x00002016, "DS_CANT_ON_RDN"),
+    (0x00002017, "DS_CANT_MOD_OBJ_CLASS"),
+    (0x00002018, "DS_CROSS_DOM_MOVE_ERROR"),
+    (0x00002019, "DS_GC_NOT_AVAILABLE"),
+    (0x0000201A, "SHARED_POLICY"),
+    (0x0000201B, "POLICY_OBJECT_NOT_FOUND"),
+    (0x0000201C, "POLICY_ONLY_IN_DS"),
+    (0x0000201D, "PROMOTION_ACTIVE"),
+    (0x0000201E, "NO_PROMOTION_ACTIVE"),
+    (0x00002020, "DS_OPERATIONS_ERROR"),
+    (0x00002021, "DS_PROTOCOL_ERROR"),
+    (0x00002022, "DS_TIMELIMIT_EXCEEDED"),
+    (0x00002023, "DS_SIZELIMIT_EXCEEDED"),
+    (0x00002024, "DS_ADMIN_LIMIT_EXCEEDED"),
+    (0x00002025, "DS_COMPARE_FALSE"),
+    (0x00002026, "DS_COMPARE_TRUE"),
+    (0x00002027, "DS_AUTH_METHOD_NOT_SUPPORTED"),
+    (0x00002028, "DS_STRONG_AUTH_REQUIRED"),
+    (0x00002029, "DS_INAPPROPRIATE_AUTH"),
+    (0x0000202A, "DS_AUTH_UNKNOWN"),
+    (0x0000202B, "DS_REFERRAL"),
+    (0x0000202C, "DS_UNAVAILABLE_CRIT_EXTENSION"),
+    (0x0000202D, "DS_CONFIDENTIALITY_REQUIRED"),
+    (0x0000202E, "DS_INAPPROPRIATE_MATCHING"),
+    (0x0000202F, "DS_CONSTRAINT_VIOLATION"),
+    (0x00002030, "DS_NO_SUCH_OBJECT"),
+    (0x00002031, "DS_ALIAS_PROBLEM"),
+    (0x00002032, "DS_INVALID_DN_SYNTAX"),
+    (0x00002033, "DS_IS_LEAF"),
+    (0x00002034, "DS_ALIAS_DEREF_PROBLEM"),
+    (0x00002035, "DS_UNWILLING_TO_PERFORM"),
+    (0x00002036, "DS_LOOP_DETECT"),
+    (0x00002037, "DS_NAMING_VIOLATION"),
+    (0x00002038, "DS_OBJECT_RESULTS_TOO_LARGE"),
+    (0x00002039, "DS_AFFECTS_MULTIPLE_DSAS"),
+    (0x0000203A, "DS_SERVER_DOWN"),
+    (0x0000203B, "DS_LOCAL_ERROR"),
+    (0x0000203C, "DS_ENCODING_ERROR"),
+    (0x0000203D, "DS_DECODING_ERROR"),
+    (0x0000203E, "DS_FILTER_UNKNOWN"),
+    (0x0000203F, "DS_PARAM_ERROR"),
+    (0x00002040, "DS_NOT_SUPPORTED"),
+    (0x00002041, "DS_NO_RESULTS_RETURNED"),
+    (0x00002042, "DS_CONTROL_NOT_FOUND"),
+    (0x00002043, "DS_CLIENT_LOOP"),
+    (0x00002044, "DS_REFERRAL_LIMIT_EXCEEDED"),
+    (0x00002045, "DS_SORT_CONTROL_MISSING"),
+    (0x00002046, "DS_OFFSET_RANGE_ERROR"),
+    (0x00002047, "DS_RIDMGR_DISABLED"),
+    (0x0000206D, "DS_ROOT_MUST_BE_NC"),
+    (0x0000206E, "DS_ADD_REPLICA_INHIBITED"),
+    (0x0000206F, "DS_ATT_NOT_DEF_IN_SCHEMA"),
+    (0x00002070, "DS_MAX_OBJ_SIZE_EXCEEDED"),
+    (0x00002071, "DS_OBJ_STRING_NAME_EXISTS"),
+    (0x00002072, "DS_NO_RDN_DEFINED_IN_SCHEMA"),
+    (0x00002073, "DS_RDN_DOESNT_MATCH_SCHEMA"),
+    (0x00002074, "DS_NO_REQUESTED_ATTS_FOUND"),
+    (0x00002075, "DS_USER_BUFFER_TO_SMALL"),
+    (0x00002076, "DS_ATT_IS_NOT_ON_OBJ"),
+    (0x00002077, "DS_ILLEGAL_MOD_OPERATION"),
+    (0x00002078, "DS_OBJ_TOO_LARGE"),
+    (0x00002079, "DS_BAD_INSTANCE_TYPE"),
+    (0x0000207A, "DS_MASTERDSA_REQUIRED"),
+    (0x0000207B, "DS_OBJECT_CLASS_REQUIRED"),
+    (0x0000207C, "DS_MISSING_REQUIRED_ATT"),
+    (0x0000207D, "DS_ATT_NOT_DEF_FOR_CLASS"),
+    (0x0000207E, "DS_ATT_ALREADY_EXISTS"),
+    (0x00002080, "DS_CANT_ADD_ATT_VALUES"),
+    (0x00002081, "DS_SINGLE_VALUE_CONSTRAINT"),
+    (0x00002082, "DS_RANGE_CONSTRAINT"),
+    (0x00002083, "DS_ATT_VAL_ALREADY_EXISTS"),
+    (0x00002084, "DS_CANT_REM_MISSING_ATT"),
+    (0x00002085, "DS_CANT_REM_MISSING_ATT_VAL"),
+    (0x00002086, "DS_ROOT_CANT_BE_SUBREF"),
+    (0x00002087, "DS_NO_CHAINING"),
+    (0x00002088, "DS_NO_CHAINED_EVAL"),
+    (0x00002089, "DS_NO_PARENT_OBJECT"),
+    (0x0000208A, "DS_PARENT_IS_AN_ALIAS"),
+    (0x0000208B, "DS_CANT_MIX_MASTER_AND_REPS"),
+    (0x0000208C, "DS_CHILDREN_EXIST"),
+    (0x0000208D, "DS_OBJ_NOT_FOUND"),
+    (0x0000208E, "DS_ALIASED_OBJ_MISSING"),
+    (0x0000208F, "DS_BAD_NAME_SYNTAX"),
+    (0x00002090, "DS_ALIAS_POINTS_TO_ALIAS"),
+    (0x00002091, "DS_CANT_DEREF_ALIAS"),
+    (0x00002092, "DS_OUT_OF_SCOPE"),
+    (0x00002093, "DS_OBJECT_BEING_REMOVED"),
+    (0x00002094, "DS_CANT_DELETE_DSA_OBJ"),
+    (0x00002095, "DS_GENERIC_ERROR"),
+    (0x00002096, "DS_DSA_MUST_BE_INT_MASTER"),
+    (0x00002097, "DS_CLASS_NOT_DSA"),
+    (0x00002098, "DS_INSUFF_ACCESS_RIGHTS"),
+    (0x00002099, "DS_ILLEGAL_SUPERIOR"),
+    (0x0000209A, "DS_ATTRIBUTE_OWNED_BY_SAM"),
+    (0x0000209B, "DS_NAME_TOO_MANY_PARTS"),
+    (0x0000209C, "DS_NAME_TOO_LONG"),
+    (0x0000209D, "DS_NAME_VALUE_TOO_LONG"),
+    (0x0000209E, "DS_NAME_UNPARSEABLE"),
+    (0x0000209F, "DS_NAME_TYPE_UNKNOWN"),
+    (0x000020A0, "DS_NOT_AN_OBJECT"),
+    (0x000020A1, "DS_SEC_DESC_TOO_SHORT"),
+    (0x000020A2, "DS_SEC_DESC_INVALID"),
+    (0x000020A3, "DS_NO_DELETED_NAME"),
+    (0x000020A4, "DS_SUBREF_MUST_HAVE_PARENT"),
+    (0x000020A5, "DS_NCNAME_MUST_BE_NC"),
+    (0x000020A6, "DS_CANT_ADD_SYSTEM_ONLY"),
+    (0x000020A7, "DS_CLASS_MUST_BE_CONCRETE"),
+    (0x000020A8, "DS_INVALID_DMD"),
+    (0x000020A9, "DS_OBJ_GUID_EXISTS"),
+    (0x000020AA, "DS_NOT_ON_BACKLINK"),
+    (0x000020AB, "DS_NO_CROSSREF_FOR_NC"),
+    (0x000020AC, "DS_SHUTTING_DOWN"),
+    (0x000020AD, "DS_UNKNOWN_OPERATION"),
+    (0x000020AE, "DS_INVALID_ROLE_OWNER"),
+    (0x000020AF, "DS_COULDNT_CONTACT_FSMO"),
+    (0x000020B0, "DS_CROSS_NC_DN_RENAME"),
+    (0x000020B1, "DS_CANT_MOD_SYSTEM_ONLY"),
+    (0x000020B2, "DS_REPLICATOR_ONLY"),
+    (0x000020B3, "DS_OBJ_CLASS_NOT_DEFINED"),
+    (0x000020B4, "DS_OBJ_CLASS_NOT_SUBCLASS"),
+    (0x000020B5, "DS_NAME_REFERENCE_INVALID"),
+    (0x000020B6, "DS_CROSS_REF_EXISTS"),
+    (0x000020B7, "DS_CANT_DEL_MASTER_CROSSREF"),
+    (0x000020B8, "DS_SUBTREE_NOTIFY_NOT_NC_HEAD"),
+    (0x000020B9, "DS_NOTIFY_FILTER_TOO_COMPLEX"),
+    (0x000020BA, "DS_DUP_RDN"),
+    (0x000020BB, "DS_DUP_OID"),
+    (0x000020BC, "DS_DUP_MAPI_ID"),
+    (0x000020BD, "DS_DUP_SCHEMA_ID_GUID"),
+    (0x000020BE, "DS_DUP_LDAP_DISPLAY_NAME"),
+    (0x000020BF, "DS_SEMANTIC_ATT_TEST"),
+    (0x000020C0, "DS_SYNTAX_MISMATCH"),
+    (0x000020C1, "DS_EXISTS_IN_MUST_HAVE"),
+    (0x000020C2, "DS_EXISTS_IN_MAY_HAVE"),
+    (0x000020C3, "DS_NONEXISTENT_MAY_HAVE"),
+    (0x000020C4, "DS_NONEXISTENT_MUST_HAVE"),
+    (0x000020C5, "DS_AUX_CLS_TEST_FAIL"),
+    (0x000020C6, "DS_NONEXISTENT_POSS_SUP"),
+    (0x000020C7, "DS_SUB_CLS_TEST_FAIL"),
+    (0x000020C8, "DS_BAD_RDN_ATT_ID_SYNTAX"),
+    (0x000020C9, "DS_EXISTS_IN_AUX_CLS"),
+    (0x000020CA, "DS_EXISTS_IN_SUB_CLS"),
+    (0x000020CB, "DS_EXISTS_IN_POSS_SUP"),
+    (0x000020CC, "DS_RECALCSCHEMA_FAILED"),
+    (0x000020CD, "DS_TREE_DELETE_NOT_FINISHED"),
+    (0x000020CE, "DS_CANT_DELETE"),
+    (0x000020CF, "DS_ATT_SCHEMA_REQ_ID"),
+    (0x000020D0, "DS_BAD_ATT_SCHEMA_SYNTAX"),
+    (0x000020D1, "DS_CANT_CACHE_ATT"),
+    (0x000020D2, "DS_CANT_CACHE_CLASS"),
+    (0x000020D3, "DS_CANT_REMOVE_ATT_CACHE"),
+    (0x000020D4, "DS_CANT_REMOVE_CLASS_CACHE"),
+    (0x000020D5, "DS_CANT_RETRIEVE_DN"),
+    (0x000020D6, "DS_MISSING_SUPREF"),
+    (0x000020D7, "DS_CANT_RETRIEVE_INSTANCE"),
+    (0x000020D8, "DS_CODE_INCONSISTENCY"),
+    (0x000020D9, "DS_DATABASE_ERROR"),
+    (0x000020DA, "DS_GOVERNSID_MISSING"),
+    (0x000020DB, "DS_MISSING_EXPECTED_ATT"),
+    (0x000020DC, "DS_NCNAME_MISSING_CR_REF"),
+    (0x000020DD, "DS_SECURITY_CHECKING_ERROR"),
+    (0x000020DE, "DS_SCHEMA_NOT_LOADED"),
+    (0x000020DF, "DS_SCHEMA_ALLOC_FAILED"),
+    (0x000020E0, "DS_ATT_SCHEMA_REQ_SYNTAX"),
+    (0x000020E1, "DS_GCVERIFY_ERROR"),
+    (0x000020E2, "DS_DRA_SCHEMA_MISMATCH"),
+    (0x000020E3, "DS_CANT_FIND_DSA_OBJ"),
+    (0x000020E4, "DS_CANT_FIND_EXPECTED_NC"),
+    (0x000020E5, "DS_CANT_FIND_NC_IN_CACHE"),
+    (0x000020E6, "DS_CANT_RETRIEVE_CHILD"),
+    (0x000020E7, "DS_SECURITY_ILLEGAL_MODIFY"),
+    (0x000020E8, "DS_CANT_REPLACE_HIDDEN_REC"),
+    (0x000020E9, "DS_BAD_HIERARCHY_FILE"),
+    (0x000020EA, "DS_BUILD_HIERARCHY_TABLE_FAILED"),
+    (0x000020EB, "DS_CONFIG_PARAM_MISSING"),
+    (0x000020EC, "DS_COUNTING_AB_INDICES_FAILED"),
+    (0x000020ED, "DS_HIERARCHY_TABLE_MALLOC_FAILED"),
+    (0x000020EE, "DS_INTERNAL_FAILURE"),
+    (0x000020EF, "DS_UNKNOWN_ERROR"),
+    (0x000020F0, "DS_ROOT_REQUIRES_CLASS_TOP"),
+    (0x000020F1, "DS_REFUSING_FSMO_ROLES"),
+    (0x000020F2, "DS_MISSING_FSMO_SETTINGS"),
+    (0x000020F3, "DS_UNABLE_TO_SURRENDER_ROLES"),
+    (0x000020F4, "DS_DRA_GENERIC"),
+    (0x000020F5, "DS_DRA_INVALID_PARAMETER"),
+    (0x000020F6, "DS_DRA_BUSY"),
+    (0x000020F7, "DS_DRA_BAD_DN"),
+    (0x000020F8, "DS_DRA_BAD_NC"),
+    (0x000020F9, "DS_DRA_DN_EXISTS"),
+    (0x000020FA, "DS_DRA_INTERNAL_ERROR"),
+    (0x000020FB, "DS_DRA_INCONSISTENT_DIT"),
+    (0x000020FC, "DS_DRA_CONNECTION_FAILED"),
+    (0x000020FD, "DS_DRA_BAD_INSTANCE_TYPE"),
+    (0x000020FE, "DS_DRA_OUT_OF_MEM"),
+    (0x000020FF, "DS_DRA_MAIL_PROBLEM"),
+    (0x00002100, "DS_DRA_REF_ALREADY_EXISTS"),
+    (0x00002101, "DS_DRA_REF_NOT_FOUND"),
+    (0x00002102, "DS_DRA_OBJ_IS_REP_SOURCE"),
+    (0x00002103, "DS_DRA_DB_ERROR"),
+    (0x00002104, "DS_DRA_NO_REPLICA"),
+    (0x00002105, "DS_DRA_ACCESS_DENIED"),
+    (0x00002106, "DS_DRA_NOT_SUPPORTED"),
+    (0x00002107, "DS_DRA_RPC_CANCELLED"),
+    (0x00002108, "DS_DRA_SOURCE_DISABLED"),
+    (0x00002109, "DS_DRA_SINK_DISABLED"),
+    (0x0000210A, "DS_DRA_NAME_COLLISION"),
+    (0x0000210B, "DS_DRA_SOURCE_REINSTALLED"),
+    (0x0000210C, "DS_DRA_MISSING_PARENT"),
+    (0x0000210D, "DS_DRA_PREEMPTED"),
+    (0x0000210E, "DS_DRA_ABANDON_SYNC"),
+    (0x0000210F, "DS_DRA_SHUTDOWN"),
+    (0x00002110, "DS_DRA_INCOMPATIBLE_PARTIAL_SET"),
+    (0x00002111, "DS_DRA_SOURCE_IS_PARTIAL_REPLICA"),
+    (0x00002112, "DS_DRA_EXTN_CONNECTION_FAILED"),
+    (0x00002113, "DS_INSTALL_SCHEMA_MISMATCH"),
+    (0x00002114, "DS_DUP_LINK_ID"),
+    (0x00002115, "DS_NAME_ERROR_RESOLVING"),
+    (0x00002116, "DS_NAME_ERROR_NOT_FOUND"),
+    (0x00002117, "DS_NAME_ERROR_NOT_UNIQUE"),
+    (0x00002118, "DS_NAME_ERROR_NO_MAPPING"),
+    (0x00002119, "DS_NAME_ERROR_DOMAIN_ONLY"),
+    (0x0000211A, "DS_NAME_ERROR_NO_SYNTACTICAL_MAPPING"),
+    (0x0000211B, "DS_CONSTRUCTED_ATT_MOD"),
+    (0x0000211C, "DS_WRONG_OM_OBJ_CLASS"),
+    (0x0000211D, "DS_DRA_REPL_PENDING"),
+    (0x0000211E, "DS_DS_REQUIRED"),
+    (0x0000211F, "DS_INVALID_LDAP_DISPLAY_NAME"),
+    (0x00002120, "DS_NON_BASE_SEARCH"),
+    (0x00002121, "DS_CANT_RETRIEVE_ATTS"),
+    (0x00002122, "DS_BACKLINK_WITHOUT_LINK"),
+    (0x00002123, "DS_EPOCH_MISMATCH"),
+    (0x00002124, "DS_SRC_NAME_MISMATCH"),
+    (0x00002125, "DS_SRC_AND_DST_NC_IDENTICAL"),
+    (0x00002126, "DS_DST_NC_MISMATCH"),
+    (0x00002127, "DS_NOT_AUTHORITIVE_FOR_DST_NC"),
+    (0x00002128, "DS_SRC_GUID_MISMATCH"),
+    (0x00002129, "DS_CANT_MOVE_DELETED_OBJECT"),
+    (0x0000212A, "DS_PDC_OPERATION_IN_PROGRESS"),
+    (0x0000212B, "DS_CROSS_DOMAIN_CLEANUP_REQD"),
+    (0x0000212C, "DS_ILLEGAL_XDOM_MOVE_OPERATION"),
+    (0x0000212D, "DS_CANT_WITH_ACCT_GROUP_MEMBERSHPS"),
+    (0x0000212E, "DS_NC_MUST_HAVE_NC_PARENT"),
+    (0x0000212F, "DS_CR_IMPOSSIBLE_TO_VALIDATE"),
+    (0x00002130, "DS_DST_DOMAIN_NOT_NATIVE"),
+    (0x00002131, "DS_MISSING_INFRASTRUCTURE_CONTAINER"),
+    (0x00002132, "DS_CANT_MOVE_ACCOUNT_GROUP"),
+    (0x00002133, "DS_CANT_MOVE_RESOURCE_GROUP"),
+    (0x00002134, "DS_INVALID_SEARCH_FLAG"),
+    (0x00002135, "DS_NO_TREE_DELETE_ABOVE_NC"),
+    (0x00002136, "DS_COULDNT_LOCK_TREE_FOR_DELETE"),
+    (0x00002137, "DS_COULDNT_IDENTIFY_OBJECTS_FOR_TREE_DELETE"),
+    (0x00002138, "DS_SAM_INIT_FAILURE"),
+    (0x00002139, "DS_SENSITIVE_GROUP_VIOLATION"),
+    (0x0000213A, "DS_CANT_MOD_PRIMARYGROUPID"),
+    (0x0000213B, "DS_ILLEGAL_BASE_SCHEMA_MOD"),
+    (0x0000213C, "DS_NONSAFE_SCHEMA_CHANGE"),
+    (0x0000213D, "DS_SCHEMA_UPDATE_DISALLOWED"),
+    (0x0000213E, "DS_CANT_CREATE_UNDER_SCHEMA"),
+    (0x0000213F, "DS_INSTALL_NO_SRC_SCH_VERSION"),
+    (0x00002140, "DS_INSTALL_NO_SCH_VERSION_IN_INIFILE"),
+    (0x00002141, "DS_INVALID_GROUP_TYPE"),
+    (0x00002142, "DS_NO_NEST_GLOBALGROUP_IN_MIXEDDOMAIN"),
+    (0x00002143, "DS_NO_NEST_LOCALGROUP_IN_MIXEDDOMAIN"),
+    (0x00002144, "DS_GLOBAL_CANT_HAVE_LOCAL_MEMBER"),
+    (0x00002145, "DS_GLOBAL_CANT_HAVE_UNIVERSAL_MEMBER"),
+    (0x00002146, "DS_UNIVERSAL_CANT_HAVE_LOCAL_MEMBER"),
+    (0x00002147, "DS_GLOBAL_CANT_HAVE_CROSSDOMAIN_MEMBER"),
+    (0x00002148, "DS_LOCAL_CANT_HAVE_CROSSDOMAIN_LOCAL_MEMBER"),
+    (0x00002149, "DS_HAVE_PRIMARY_MEMBERS"),
+    (0x0000214A, "DS_STRING_SD_CONVERSION_FAILED"),
+    (0x0000214B, "DS_NAMING_MASTER_GC"),
+    (0x0000214C, "DS_DNS_LOOKUP_FAILURE"),
+    (0x0000214D, "DS_COULDNT_UPDATE_SPNS"),
+    (0x0000214E, "DS_CANT_RETRIEVE_SD"),
+    (0x0000214F, "DS_KEY_NOT_UNIQUE"),
+    (0x00002150, "DS_WRONG_LINKED_ATT_SYNTAX"),
+    (0x00002151, "DS_SAM_NEED_BOOTKEY_PASSWORD"),
+    (0x00002152, "DS_SAM_NEED_BOOTKEY_FLOPPY"),
+    (0x00002153, "DS_CANT_START"),
+    (0x00002154, "DS_INIT_FAILURE"),
+    (0x00002155, "DS_NO_PKT_PRIVACY_ON_CONNECTION"),
+    (0x00002156, "DS_SOURCE_DOMAIN_IN_FOREST"),
+    (0x00002157, "DS_DESTINATION_DOMAIN_NOT_IN_FOREST"),
+    (0x00002158, "DS_DESTINATION_AUDITING_NOT_ENABLED"),
+    (0x00002159, "DS_CANT_FIND_DC_FOR_SRC_DOMAIN"),
+    (0x0000215A, "DS_SRC_OBJ_NOT_GROUP_OR_USER"),
+    (0x0000215B, "DS_SRC_SID_EXISTS_IN_FOREST"),
+    (0x0000215C, "DS_SRC_AND_DST_OBJECT_CLASS_MISMATCH"),
+    (0x0000215D, "SAM_INIT_FAILURE"),
+    (0x0000215E, "DS_DRA_SCHEMA_INFO_SHIP"),
+    (0x0000215F, "DS_DRA_SCHEMA_CONFLICT"),
+    (0x00002160, "DS_DRA_EARLIER_SCHEMA_CONFLICT"),
+    (0x00002161, "DS_DRA_OBJ_NC_MISMATCH"),
+    (0x00002162, "DS_NC_STILL_HAS_DSAS"),
+    (0x00002163, "DS_GC_REQUIRED"),
+    (0x00002164, "DS_LOCAL_MEMBER_OF_LOCAL_ONLY"),
+    (0x00002165, "DS_NO_FPO_IN_UNIVERSAL_GROUPS"),
+    (0x00002166, "DS_CANT_ADD_TO_GC"),
+    (0x00002167, "DS_NO_CHECKPOINT_WITH_PDC"),
+    (0x00002168, "DS_SOURCE_AUDITING_NOT_ENABLED"),
+    (0x00002169, "DS_CANT_CREATE_IN_NONDOMAIN_NC"),
+    (0x0000216A, "DS_INVALID_NAME_FOR_SPN"),
+    (0x0000216B, "DS_FILTER_USES_CONTRUCTED_ATTRS"),
+    (0x0000216C, "DS_UNICODEPWD_NOT_IN_QUOTES"),
+    (0x0000216D, "DS_MACHINE_ACCOUNT_QUOTA_EXCEEDED"),
+    (0x0000216E, "DS_MUST_BE_RUN_ON_DST_DC"),
+    (0x0000216F, "DS_SRC_DC_MUST_BE_SP4_OR_GREATER"),
+    (0x00002170, "DS_CANT_TREE_DELETE_CRITICAL_OBJ"),
+    (0x00002171, "DS_INIT_FAILURE_CONSOLE"),
+    (0x00002172, "DS_SAM_INIT_FAILURE_CONSOLE"),
+    (0x00002173, "DS_FOREST_VERSION_TOO_HIGH"),
+    (0x00002174, "DS_DOMAIN_VERSION_TOO_HIGH"),
+    (0x00002175, "DS_FOREST_VERSION_TOO_LOW"),
+    (0x00002176, "DS_DOMAIN_VERSION_TOO_LOW"),
+    (0x00002177, "DS_INCOMPATIBLE_VERSION"),
+    (0x00002178, "DS_LOW_DSA_VERSION"),
+    (0x00002179, "DS_NO_BEHAVIOR_VERSION_IN_MIXEDDOMAIN"),
+    (0x0000217A, "DS_NOT_SUPPORTED_SORT_ORDER"),
+    (0x0000217B, "DS_NAME_NOT_UNIQUE"),
+    (0x0000217C, "DS_MACHINE_ACCOUNT_CREATED_PRENT4"),
+    (0x0000217D, "DS_OUT_OF_VERSION_STORE"),
+    (0x0000217E, "DS_INCOMPATIBLE_CONTROLS_USED"),
+    (0x0000217F, "DS_NO_REF_DOMAIN"),
+    (0x00002180, "DS_RESERVED_LINK_ID"),
+    (0x00002181, "DS_LINK_ID_NOT_AVAILABLE"),
+    (0x00002182, "DS_AG_CANT_HAVE_UNIVERSAL_MEMBER"),
+    (0x00002183, "DS_MODIFYDN_DISALLOWED_BY_INSTANCE_TYPE"),
+    (0x00002184, "DS_NO_OBJECT_MOVE_IN_SCHEMA_NC"),
+    (0x00002185, "DS_MODIFYDN_DISALLOWED_BY_FLAG"),
+    (0x00002186, "DS_MODIFYDN_WRONG_GRANDPARENT"),
+    (0x00002187, "DS_NAME_ERROR_TRUST_REFERRAL"),
+    (0x00002188, "NOT_SUPPORTED_ON_STANDARD_SERVER"),
+    (0x00002189, "DS_CANT_ACCESS_REMOTE_PART_OF_AD"),
+    (0x0000218A, "DS_CR_IMPOSSIBLE_TO_VALIDATE_V2"),
+    (0x0000218B, "DS_THREAD_LIMIT_EXCEEDED"),
+    (0x0000218C, "DS_NOT_CLOSEST"),
+    (0x0000218D, "DS_CANT_DERIVE_SPN_WITHOUT_SERVER_REF"),
+    (0x0000218E, "DS_SINGLE_USER_MODE_FAILED"),
+    (0x0000218F, "DS_NTDSCRIPT_SYNTAX_ERROR"),
+    (0x00002190, "DS_NTDSCRIPT_PROCESS_ERROR"),
+    (0x00002191, "DS_DIFFERENT_REPL_EPOCHS"),
+    (0x00002192, "DS_DRS_EXTENSIONS_CHANGED"),
+    (0x00002193, "DS_REPLICA_SET_CHANGE_NOT_ALLOWED_ON_DISABLED_CR"),
+    (0x00002194, "DS_NO_MSDS_INTID"),
+    (0x00002195, "DS_DUP_MSDS_INTID"),
+    (0x00002196, "DS_EXISTS_IN_RDNATTID"),
+    (0x00002197, "DS_AUTHORIZATION_FAILED"),
+    (0x00002198, "DS_INVALID_SCRIPT"),
+    (0x00002199, "DS_REMOTE_CROSSREF_OP_FAILED"),
+    (0x0000219A, "DS_CROSS_REF_BUSY"),
+    (0x0000219B, "DS_CANT_DERIVE_SPN_FOR_DELETED_DOMAIN"),
+    (0x0000219C, "DS_CANT_DEMOTE_WITH_WRITEABLE_NC"),
+    (0x0000219D, "DS_DUPLICATE_ID_FOUND"),
+    (0x0000219E, "DS_INSUFFICIENT_ATTR_TO_CREATE_OBJECT"),
+    (0x0000219F, "DS_GROUP_CONVERSION_ERROR"),
+    (0x000021A0, "DS_CANT_MOVE_APP_BASIC_GROUP"),
+    (0x000021A1, "DS_CANT_MOVE_APP_QUERY_GROUP"),
+    (0x000021A2, "DS_ROLE_NOT_VERIFIED"),
+    (0x000021A3, "DS_WKO_CONTAINER_CANNOT_BE_SPECIAL"),
+    (0x000021A4, "DS_DOMAIN_RENAME_IN_PROGRESS"),
+    (0x000021A5, "DS_EXISTING_AD_CHILD_NC"),
+    (0x000021A6, "DS_REPL_LIFETIME_EXCEEDED"),
+    (0x000021A7, "DS_DISALLOWED_IN_SYSTEM_CONTAINER"),
+    (0x000021A8, "DS_LDAP_SEND_QUEUE_FULL"),
+    (0x000021A9, "DS_DRA_OUT_SCHEDULE_WINDOW"),
+    (0x000021AA, "DS_POLICY_NOT_KNOWN"),
+    (0x000021AB, "NO_SITE_SETTINGS_OBJECT"),
+    (0x000021AC, "NO_SECRETS"),
+    (0x000021AD, "NO_WRITABLE_DC_FOUND"),
+    (0x000021AE, "DS_NO_SERVER_OBJECT"),
+    (0x000021AF, "DS_NO_NTDSA_OBJECT"),
+    (0x000021B0, "DS_NON_ASQ_SEARCH"),
+    (0x000021B1, "DS_AUDIT_FAILURE"),
+    (0x000021B2, "DS_INVALID_SEARCH_FLAG_SUBTREE"),
+    (0x000021B3, "DS_INVALID_SEARCH_FLAG_TUPLE"),
+    (0x000021B4, "DS_HIERARCHY_TABLE_TOO_DEEP"),
+    (0x000021B5, "DS_DRA_CORRUPT_UTD_VECTOR"),
+    (0x000021B6, "DS_DRA_SECRETS_DENIED"),
+    (0x000021B7, "DS_RESERVED_MAPI_ID"),
+    (0x000021B8, "DS_MAPI_ID_NOT_AVAILABLE"),
+    (0x000021B9, "DS_DRA_MISSING_KRBTGT_SECRET"),
+    (0x000021BA, "DS_DOMAIN_NAME_EXISTS_IN_FOREST"),
+    (0x000021BB, "DS_FLAT_NAME_EXISTS_IN_FOREST"),
+    (0x000021BC, "INVALID_USER_PRINCIPAL_NAME"),
+    (0x000021BD, "DS_OID_MAPPED_GROUP_CANT_HAVE_MEMBERS"),
+    (0x000021BE, "DS_OID_NOT_FOUND"),
+    (0x000021BF, "DS_DRA_RECYCLED_TARGET"),
+    (0x000021C0, "DS_DISALLOWED_NC_REDIRECT"),
+    (0x000021C1, "DS_HIGH_ADLDS_FFL"),
+    (0x000021C2, "DS_HIGH_DSA_VERSION"),
+    (0x000021C3, "DS_LOW_ADLDS_FFL"),
+    (0x000021C4, "DOMAIN_SID_SAME_AS_LOCAL_WORKSTATION"),
+    (0x000021C5, "DS_UNDELETE_SAM_VALIDATION_FAILED"),
+    (0x000021C6, "INCORRECT_ACCOUNT_TYPE"),
+    (0x000021C7, "DS_SPN_VALUE_NOT_UNIQUE_IN_FOREST"),
+    (0x000021C8, "DS_UPN_VALUE_NOT_UNIQUE_IN_FOREST"),
+    (0x000021C9, "DS_MISSING_FOREST_TRUST"),
+    (0x000021CA, "DS_VALUE_KEY_NOT_UNIQUE"),
+    (0x000021CB, "WEAK_WHFBKEY_BLOCKED"),
+    (0x000021CC, "DS_PER_ATTRIBUTE_AUTHZ_FAILED_DURING_ADD"),
+    (0x000021CD, "LOCAL_POLICY_MODIFICATION_NOT_SUPPORTED"),
+    (0x000021CE, "POLICY_CONTROLLED_ACCOUNT"),
+    (0x000021CF, "LAPS_LEGACY_SCHEMA_MISSING"),
+    (0x000021D0, "LAPS_SCHEMA_MISSING"),
+    (0x000021D1, "LAPS_ENCRYPTION_REQUIRES_2016_DFL"),
+    (0x00002328, "DNS_ERROR_MASK"),
+    (0x00002329, "DNS_ERROR_RCODE_FORMAT_ERROR"),
+    (0x0000232A, "DNS_ERROR_RCODE_SERVER_FAILURE"),
+    (0x0000232B, "DNS_ERROR_RCODE_NAME_ERROR"),
+    (0x0000232C, "DNS_ERROR_RCODE_NOT_IMPLEMENTED"),
+    (0x0000232D, "DNS_ERROR_RCODE_REFUSED"),
+    (0x0000232E, "DNS_ERROR_RCODE_YXDOMAIN"),
+    (0x0000232F, "DNS_ERROR_RCODE_YXRRSET"),
+    (0x00002330, "DNS_ERROR_RCODE_NXRRSET"),
+    (0x00002331, "DNS_ERROR_RCODE_NOTAUTH"),
+    (0x00002332, "DNS_ERROR_RCODE_NOTZONE"),
+    (0x00002338, "DNS_ERROR_RCODE_BADSIG"),
+    (0x00002339, "DNS_ERROR_RCODE_BADKEY"),
+    (0x0000233A, "DNS_ERROR_RCODE_BADTIME"),
+    (0x0000238C, "DNS_ERROR_DNSSEC_BASE"),
+    (0x0000238D, "DNS_ERROR_KEYMASTER_REQUIRED"),
+    (0x0000238E, "DNS_ERROR_NOT_ALLOWED_ON_SIGNED_ZONE"),
+    (0x0000238F, "DNS_ERROR_NSEC3_INCOMPATIBLE_WITH_RSA_SHA1"),
+    (0x00002390, "DNS_ERROR_NOT_ENOUGH_SIGNING_KEY_DESCRIPTORS"),
+    (0x00002391, "DNS_ERROR_UNSUPPORTED_ALGORITHM"),
+    (0x00002392, "DNS_ERROR_INVALID_KEY_SIZE"),
+    (0x00002393, "DNS_ERROR_SIGNING_KEY_NOT_ACCESSIBLE"),
+    (0x00002394, "DNS_ERROR_KSP_DOES_NOT_SUPPORT_PROTECTION"),
+    (0x00002395, "DNS_ERROR_UNEXPECTED_DATA_PROTECTION_ERROR"),
+    (0x00002396, "DNS_ERROR_UNEXPECTED_CNG_ERROR"),
+    (0x00002397, "DNS_ERROR_UNKNOWN_SIGNING_PARAMETER_VERSION"),
+    (0x00002398, "DNS_ERROR_KSP_NOT_ACCESSIBLE"),
+    (0x00002399, "DNS_ERROR_TOO_MANY_SKDS"),
+    (0x0000239A, "DNS_ERROR_INVALID_ROLLOVER_PERIOD"),
+    (0x0000239B, "DNS_ERROR_INVALID_INITIAL_ROLLOVER_OFFSET"),
+    (0x0000239C, "DNS_ERROR_ROLLOVER_IN_PROGRESS"),
+    (0x0000239D, "DNS_ERROR_STANDBY_KEY_NOT_PRESENT"),
+    (0x0000239E, "DNS_ERROR_NOT_ALLOWED_ON_ZSK"),
+    (0x0000239F, "DNS_ERROR_NOT_ALLOWED_ON_ACTIVE_SKD"),
+    (0x000023A0, "DNS_ERROR_ROLLOVER_ALREADY_QUEUED"),
+    (0x000023A1, "DNS_ERROR_NOT_ALLOWED_ON_UNSIGNED_ZONE"),
+    (0x000023A2, "DNS_ERROR_BAD_KEYMASTER"),
+    (0x000023A3, "DNS_ERROR_INVALID_SIGNATURE_VALIDITY_PERIOD"),
+    (0x000023A4, "DNS_ERROR_INVALID_NSEC3_ITERATION_COUNT"),
+    (0x000023A5, "DNS_ERROR_DNSSEC_IS_DISABLED"),
+    (0x000023A6, "DNS_ERROR_INVALID_XML"),
+    (0x000023A7, "DNS_ERROR_NO_VALID_TRUST_ANCHORS"),
+    (0x000023A8, "DNS_ERROR_ROLLOVER_NOT_POKEABLE"),
+    (0x000023A9, "DNS_ERROR_NSEC3_NAME_COLLISION"),
+    (0x000023AA, "DNS_ERROR_NSEC_INCOMPATIBLE_WITH_NSEC3_RSA_SHA1"),
+    (0x0000251C, "DNS_ERROR_PACKET_FMT_BASE"),
+    (0x0000251E, "DNS_ERROR_BAD_PACKET"),
+    (0x0000251F, "DNS_ERROR_NO_PACKET"),
+    (0x00002520, "DNS_ERROR_RCODE"),
+    (0x00002521, "DNS_ERROR_UNSECURE_PACKET"),
+    (0x0000254E, "DNS_ERROR_GENERAL_API_BASE"),
+    (0x0000254F, "DNS_ERROR_INVALID_TYPE"),
+    (0x00002550, "DNS_ERROR_INVALID_IP_ADDRESS"),
+    (0x00002551, "DNS_ERROR_INVALID_PROPERTY"),
+    (0x00002552, "DNS_ERROR_TRY_AGAIN_LATER"),
+    (0x00002553, "DNS_ERROR_NOT_UNIQUE"),
+    (0x00002554, "DNS_ERROR_NON_RFC_NAME"),
+    (0x00002558, "DNS_ERROR_INVALID_NAME_CHAR"),
+    (0x00002559, "DNS_ERROR_NUMERIC_NAME"),
+    (0x0000255A, "DNS_ERROR_NOT_ALLOWED_ON_ROOT_SERVER"),
+    (0x0000255B, "DNS_ERROR_NOT_ALLOWED_UNDER_DELEGATION"),
+    (0x0000255C, "DNS_ERROR_CANNOT_FIND_ROOT_HINTS"),
+    (0x0000255D, "DNS_ERROR_INCONSISTENT_ROOT_HINTS"),
+    (0x0000255E, "DNS_ERROR_DWORD_VALUE_TOO_SMALL"),
+    (0x0000255F, "DNS_ERROR_DWORD_VALUE_TOO_LARGE"),
+    (0x00002560, "DNS_ERROR_BACKGROUND_LOADING"),
+    (0x00002561, "DNS_ERROR_NOT_ALLOWED_ON_RODC"),
+    (0x00002562, "DNS_ERROR_NOT_ALLOWED_UNDER_DNAME"),
+    (0x00002563, "DNS_ERROR_DELEGATION_REQUIRED"),
+    (0x00002564, "DNS_ERROR_INVALID_POLICY_TABLE"),
+    (0x00002565, "DNS_ERROR_ADDRESS_REQUIRED"),
+    (0x00002580, "DNS_ERROR_ZONE_BASE"),
+    (0x00002581, "DNS_ERROR_ZONE_DOES_NOT_EXIST"),
+    (0x00002582, "DNS_ERROR_NO_ZONE_INFO"),
+    (0x00002583, "DNS_ERROR_INVALID_ZONE_OPERATION"),
+    (0x00002584, "DNS_ERROR_ZONE_CONFIGURATION_ERROR"),
+    (0x00002585, "DNS_ERROR_ZONE_HAS_NO_SOA_RECORD"),
+    (0x00002586, "DNS_ERROR_ZONE_HAS_NO_NS_RECORDS"),
+    (0x00002587, "DNS_ERROR_ZONE_LOCKED"),
+    (0x00002588, "DNS_ERROR_ZONE_CREATION_FAILED"),
+    (0x00002589, "DNS_ERROR_ZONE_ALREADY_EXISTS"),
+    (0x0000258A, "DNS_ERROR_AUTOZONE_ALREADY_EXISTS"),
+    (0x0000258B, "DNS_ERROR_INVALID_ZONE_TYPE"),
+    (0x0000258C, "DNS_ERROR_SECONDARY_REQUIRES_MASTER_IP"),
+    (0x0000258D, "DNS_ERROR_ZONE_NOT_SECONDARY"),
+    (0x0000258E, "DNS_ERROR_NEED_SECONDARY_ADDRESSES"),
+    (0x0000258F, "DNS_ERROR_WINS_INIT_FAILED"),
+    (0x00002590, "DNS_ERROR_NEED_WINS_SERVERS"),
+    (0x00002591, "DNS_ERROR_NBSTAT_INIT_FAILED"),
+    (0x00002592, "DNS_ERROR_SOA_DELETE_INVALID"),
+    (0x00002593, "DNS_ERROR_FORWARDER_ALREADY_EXISTS"),
+    (0x00002594, "DNS_ERROR_ZONE_REQUIRES_MASTER_IP"),
+    (0x00002595, "DNS_ERROR_ZONE_IS_SHUTDOWN"),
+    (0x00002596, "DNS_ERROR_ZONE_LOCKED_FOR_SIGNING"),
+    (0x000025B2, "DNS_ERROR_DATAFILE_BASE"),
+    (0x000025B3, "DNS_ERROR_PRIMARY_REQUIRES_DATAFILE"),
+    (0x000025B4, "DNS_ERROR_INVALID_DATAFILE_NAME"),
+    (0x000025B5, "DNS_ERROR_DATAFILE_OPEN_FAILURE"),
+    (0x000025B6, "DNS_ERROR_FILE_WRITEBACK_FAILED"),
+    (0x000025B7, "DNS_ERROR_DATAFILE_PARSING"),
+    (0x000025E4, "DNS_ERROR_DATABASE_BASE"),
+    (0x000025E5, "DNS_ERROR_RECORD_DOES_NOT_EXIST"),
+    (0x000025E6, "DNS_ERROR_RECORD_FORMAT"),
+    (0x000025E7, "DNS_ERROR_NODE_CREATION_FAILED"),
+    (0x000025E8, "DNS_ERROR_UNKNOWN_RECORD_TYPE"),
+    (0x000025E9, "DNS_ERROR_RECORD_TIMED_OUT"),
+    (0x000025EA, "DNS_ERROR_NAME_NOT_IN_ZONE"),
+    (0x000025EB, "DNS_ERROR_CNAME_LOOP"),
+    (0x000025EC, "DNS_ERROR_NODE_IS_CNAME"),
+    (0x000025ED, "DNS_ERROR_CNAME_COLLISION"),
+    (0x000025EE, "DNS_ERROR_RECORD_ONLY_AT_ZONE_ROOT"),
+    (0x000025EF, "DNS_ERROR_RECORD_ALREADY_EXISTS"),
+    (0x000025F0, "DNS_ERROR_SECONDARY_DATA"),
+    (0x000025F1, "DNS_ERROR_NO_CREATE_CACHE_DATA"),
+    (0x000025F2, "DNS_ERROR_NAME_DOES_NOT_EXIST"),
+    (0x000025F5, "DNS_ERROR_DS_UNAVAILABLE"),
+    (0x000025F6, "DNS_ERROR_DS_ZONE_ALREADY_EXISTS"),
+    (0x000025F7, "DNS_ERROR_NO_BOOTFILE_IF_DS_ZONE"),
+    (0x000025F8, "DNS_ERROR_NODE_IS_DNAME"),
+    (0x000025F9, "DNS_ERROR_DNAME_COLLISION"),
+    (0x000025FA, "DNS_ERROR_ALIAS_LOOP"),
+    (0x00002616, "DNS_ERROR_OPERATION_BASE"),
+    (0x00002618, "DNS_ERROR_AXFR"),
+    (0x00002648, "DNS_ERROR_SECURE_BASE"),
+    (0x0000267A, "DNS_ERROR_SETUP_BASE"),
+    (0x0000267B, "DNS_ERROR_NO_TCPIP"),
+    (0x0000267C, "DNS_ERROR_NO_DNS_SERVERS"),
+    (0x000026AC, "DNS_ERROR_DP_BASE"),
+    (0x000026AD, "DNS_ERROR_DP_DOES_NOT_EXIST"),
+    (0x000026AE, "DNS_ERROR_DP_ALREADY_EXISTS"),
+    (0x000026AF, "DNS_ERROR_DP_NOT_ENLISTED"),
+    (0x000026B0, "DNS_ERROR_DP_ALREADY_ENLISTED"),
+    (0x000026B1, "DNS_ERROR_DP_NOT_AVAILABLE"),
+    (0x000026B2, "DNS_ERROR_DP_FSMO_ERROR"),
+    (0x000026B7, "DNS_ERROR_RRL_NOT_ENABLED"),
+    (0x000026B8, "DNS_ERROR_RRL_INVALID_WINDOW_SIZE"),
+    (0x000026B9, "DNS_ERROR_RRL_INVALID_IPV4_PREFIX"),
+    (0x000026BA, "DNS_ERROR_RRL_INVALID_IPV6_PREFIX"),
+    (0x000026BB, "DNS_ERROR_RRL_INVALID_TC_RATE"),
+    (0x000026BC, "DNS_ERROR_RRL_INVALID_LEAK_RATE"),
+    (0x000026BD, "DNS_ERROR_RRL_LEAK_RATE_LESSTHAN_TC_RATE"),
+    (0x000026C1, "DNS_ERROR_VIRTUALIZATION_INSTANCE_ALREADY_EXISTS"),
+    (0x000026C2, "DNS_ERROR_VIRTUALIZATION_INSTANCE_DOES_NOT_EXIST"),
+    (0x000026C3, "DNS_ERROR_VIRTUALIZATION_TREE_LOCKED"),
+    (0x000026C4, "DNS_ERROR_INVAILD_VIRTUALIZATION_INSTANCE_NAME"),
+    (0x000026C5, "DNS_ERROR_DEFAULT_VIRTUALIZATION_INSTANCE"),
+    (0x000026DF, "DNS_ERROR_ZONESCOPE_ALREADY_EXISTS"),
+    (0x000026E0, "DNS_ERROR_ZONESCOPE_DOES_NOT_EXIST"),
+    (0x000026E1, "DNS_ERROR_DEFAULT_ZONESCOPE"),
+    (0x000026E2, "DNS_ERROR_INVALID_ZONESCOPE_NAME"),
+    (0x000026E3, "DNS_ERROR_NOT_ALLOWED_WITH_ZONESCOPES"),
+    (0x000026E4, "DNS_ERROR_LOAD_ZONESCOPE_FAILED"),
+    (0x000026E5, "DNS_ERROR_ZONESCOPE_FILE_WRITEBACK_FAILED"),
+    (0x000026E6, "DNS_ERROR_INVALID_SCOPE_NAME"),
+    (0x000026E7, "DNS_ERROR_SCOPE_DOES_NOT_EXIST"),
+    (0x000026E8, "DNS_ERROR_DEFAULT_SCOPE"),
+    (0x000026E9, "DNS_ERROR_INVALID_SCOPE_OPERATION"),
+    (0x000026EA, "DNS_ERROR_SCOPE_LOCKED"),
+    (0x000026EB, "DNS_ERROR_SCOPE_ALREADY_EXISTS"),
+    (0x000026F3, "DNS_ERROR_POLICY_ALREADY_EXISTS"),
+    (0x000026F4, "DNS_ERROR_POLICY_DOES_NOT_EXIST"),
+    (0x000026F5, "DNS_ERROR_POLICY_INVALID_CRITERIA"),
+    (0x000026F6, "DNS_ERROR_POLICY_INVALID_SETTINGS"),
+    (0x000026F7, "DNS_ERROR_CLIENT_SUBNET_IS_ACCESSED"),
+    (0x000026F8, "DNS_ERROR_CLIENT_SUBNET_DOES_NOT_EXIST"),
+    (0x000026F9, "DNS_ERROR_CLIENT_SUBNET_ALREADY_EXISTS"),
+    (0x000026FA, "DNS_ERROR_SUBNET_DOES_NOT_EXIST"),
+    (0x000026FB, "DNS_ERROR_SUBNET_ALREADY_EXISTS"),
+    (0x000026FC, "DNS_ERROR_POLICY_LOCKED"),
+    (0x000026FD, "DNS_ERROR_POLICY_INVALID_WEIGHT"),
+    (0x000026FE, "DNS_ERROR_POLICY_INVALID_NAME"),
+    (0x000026FF, "DNS_ERROR_POLICY_MISSING_CRITERIA"),
+    (0x00002700, "DNS_ERROR_INVALID_CLIENT_SUBNET_NAME"),
+    (0x00002701, "DNS_ERROR_POLICY_PROCESSING_ORDER_INVALID"),
+    (0x00002702, "DNS_ERROR_POLICY_SCOPE_MISSING"),
+    (0x00002703, "DNS_ERROR_POLICY_SCOPE_NOT_ALLOWED"),
+    (0x00002704, "DNS_ERROR_SERVERSCOPE_IS_REFERENCED"),
+    (0x00002705, "DNS_ERROR_ZONESCOPE_IS_REFERENCED"),
+    (0x00002706, "DNS_ERROR_POLICY_INVALID_CRITERIA_CLIENT_SUBNET"),
+    (0x00002707, "DNS_ERROR_POLICY_INVALID_CRITERIA_TRANSPORT_PROTOCOL"),
+    (0x00002708, "DNS_ERROR_POLICY_INVALID_CRITERIA_NETWORK_PROTOCOL"),
+    (0x00002709, "DNS_ERROR_POLICY_INVALID_CRITERIA_INTERFACE"),
+    (0x0000270A, "DNS_ERROR_POLICY_INVALID_CRITERIA_FQDN"),
+    (0x0000270B, "DNS_ERROR_POLICY_INVALID_CRITERIA_QUERY_TYPE"),
+    (0x0000270C, "DNS_ERROR_POLICY_INVALID_CRITERIA_TIME_OF_DAY"),
+    (0x000032C8, "IPSEC_QM_POLICY_EXISTS"),
+    (0x000032C9, "IPSEC_QM_POLICY_NOT_FOUND"),
+    (0x000032CA, "IPSEC_QM_POLICY_IN_USE"),
+    (0x000032CB, "IPSEC_MM_POLICY_EXISTS"),
+    (0x000032CC, "IPSEC_MM_POLICY_NOT_FOUND"),
+    (0x000032CD, "IPSEC_MM_POLICY_IN_USE"),
+    (0x000032CE, "IPSEC_MM_FILTER_EXISTS"),
+    (0x000032CF, "IPSEC_MM_FILTER_NOT_FOUND"),
+    (0x000032D0, "IPSEC_TRANSPORT_FILTER_EXISTS"),
+    (0x000032D1, "IPSEC_TRANSPORT_FILTER_NOT_FOUND"),
+    (0x000032D2, "IPSEC_MM_AUTH_EXISTS"),
+    (0x000032D3, "IPSEC_MM_AUTH_NOT_FOUND"),
+    (0x000032D4, "IPSEC_MM_AUTH_IN_USE"),
+    (0x000032D5, "IPSEC_DEFAULT_MM_POLICY_NOT_FOUND"),
+    (0x000032D6, "IPSEC_DEFAULT_MM_AUTH_NOT_FOUND"),
+    (0x000032D7, "IPSEC_DEFAULT_QM_POLICY_NOT_FOUND"),
+    (0x000032D8, "IPSEC_TUNNEL_FILTER_EXISTS"),
+    (0x000032D9, "IPSEC_TUNNEL_FILTER_NOT_FOUND"),
+    (0x000032DA, "IPSEC_MM_FILTER_PENDING_DELETION"),
+    (0x000032DB, "IPSEC_TRANSPORT_FILTER_PENDING_DELETION"),
+    (0x000032DC, "IPSEC_TUNNEL_FILTER_PENDING_DELETION"),
+    (0x000032DD, "IPSEC_MM_POLICY_PENDING_DELETION"),
+    (0x000032DE, "IPSEC_MM_AUTH_PENDING_DELETION"),
+    (0x000032DF, "IPSEC_QM_POLICY_PENDING_DELETION"),
+    (0x000035E8, "IPSEC_IKE_NEG_STATUS_BEGIN"),
+    (0x000035E9, "IPSEC_IKE_AUTH_FAIL"),
+    (0x000035EA, "IPSEC_IKE_ATTRIB_FAIL"),
+    (0x000035EB, "IPSEC_IKE_NEGOTIATION_PENDING"),
+    (0x000035EC, "IPSEC_IKE_GENERAL_PROCESSING_ERROR"),
+    (0x000035ED, "IPSEC_IKE_TIMED_OUT"),
+    (0x000035EE, "IPSEC_IKE_NO_CERT"),
+    (0x000035EF, "IPSEC_IKE_SA_DELETED"),
+    (0x000035F0, "IPSEC_IKE_SA_REAPED"),
+    (0x000035F1, "IPSEC_IKE_MM_ACQUIRE_DROP"),
+    (0x000035F2, "IPSEC_IKE_QM_ACQUIRE_DROP"),
+    (0x000035F3, "IPSEC_IKE_QUEUE_DROP_MM"),
+    (0x000035F4, "IPSEC_IKE_QUEUE_DROP_NO_MM"),
+    (0x000035F5, "IPSEC_IKE_DROP_NO_RESPONSE"),
+    (0x000035F6, "IPSEC_IKE_MM_DELAY_DROP"),
+    (0x000035F7, "IPSEC_IKE_QM_DELAY_DROP"),
+    (0x000035F8, "IPSEC_IKE_ERROR"),
+    (0x000035F9, "IPSEC_IKE_CRL_FAILED"),
+    (0x000035FA, "IPSEC_IKE_INVALID_KEY_USAGE"),
+    (0x000035FB, "IPSEC_IKE_INVALID_CERT_TYPE"),
+    (0x000035FC, "IPSEC_IKE_NO_PRIVATE_KEY"),
+    (0x000035FD, "IPSEC_IKE_SIMULTANEOUS_REKEY"),
+    (0x000035FE, "IPSEC_IKE_DH_FAIL"),
+    (0x000035FF, "IPSEC_IKE_CRITICAL_PAYLOAD_NOT_RECOGNIZED"),
+    (0x00003600, "IPSEC_IKE_INVALID_HEADER"),
+    (0x00003601, "IPSEC_IKE_NO_POLICY"),
+    (0x00003602, "IPSEC_IKE_INVALID_SIGNATURE"),
+    (0x00003603, "IPSEC_IKE_KERBEROS_ERROR"),
+    (0x00003604, "IPSEC_IKE_NO_PUBLIC_KEY"),
+    (0x00003605, "IPSEC_IKE_PROCESS_ERR"),
+    (0x00003606, "IPSEC_IKE_PROCESS_ERR_SA"),
+    (0x00003607, "IPSEC_IKE_PROCESS_ERR_PROP"),
+    (0x00003608, "IPSEC_IKE_PROCESS_ERR_TRANS"),
+    (0x00003609, "IPSEC_IKE_PROCESS_ERR_KE"),
+    (0x0000360A, "IPSEC_IKE_PROCESS_ERR_ID"),
+    (0x0000360B, "IPSEC_IKE_PROCESS_ERR_CERT"),
+    (0x0000360C, "IPSEC_IKE_PROCESS_ERR_CERT_REQ"),
+    (0x0000360D, "IPSEC_IKE_PROCESS_ERR_HASH"),
+    (0x0000360E, "IPSEC_IKE_PROCESS_ERR_SIG"),
+    (0x0000360F, "IPSEC_IKE_PROCESS_ERR_NONCE"),
+    (0x00003610, "IPSEC_IKE_PROCESS_ERR_NOTIFY"),
+    (0x00003611, "IPSEC_IKE_PROCESS_ERR_DELETE"),
+    (0x00003612, "IPSEC_IKE_PROCESS_ERR_VENDOR"),
+    (0x00003613, "IPSEC_IKE_INVALID_PAYLOAD"),
+    (0x00003614, "IPSEC_IKE_LOAD_SOFT_SA"),
+    (0x00003615, "IPSEC_IKE_SOFT_SA_TORN_DOWN"),
+    (0x00003616, "IPSEC_IKE_INVALID_COOKIE"),
+    (0x00003617, "IPSEC_IKE_NO_PEER_CERT"),
+    (0x00003618, "IPSEC_IKE_PEER_CRL_FAILED"),
+    (0x00003619, "IPSEC_IKE_POLICY_CHANGE"),
+    (0x0000361A, "IPSEC_IKE_NO_MM_POLICY"),
+    (0x0000361B, "IPSEC_IKE_NOTCBPRIV"),
+    (0x0000361C, "IPSEC_IKE_SECLOADFAIL"),
+    (0x0000361D, "IPSEC_IKE_FAILSSPINIT"),
+    (0x0000361E, "IPSEC_IKE_FAILQUERYSSP"),
+    (0x0000361F, "IPSEC_IKE_SRVACQFAIL"),
+    (0x00003620, "IPSEC_IKE_SRVQUERYCRED"),
+    (0x00003621, "IPSEC_IKE_GETSPIFAIL"),
+    (0x00003622, "IPSEC_IKE_INVALID_FILTER"),
+    (0x00003623, "IPSEC_IKE_OUT_OF_MEMORY"),
+    (0x00003624, "IPSEC_IKE_ADD_UPDATE_KEY_FAILED"),
+    (0x00003625, "IPSEC_IKE_INVALID_POLICY"),
+    (0x00003626, "IPSEC_IKE_UNKNOWN_DOI"),
+    (0x00003627, "IPSEC_IKE_INVALID_SITUATION"),
+    (0x00003628, "IPSEC_IKE_DH_FAILURE"),
+    (0x00003629, "IPSEC_IKE_INVALID_GROUP"),
+    (0x0000362A, "IPSEC_IKE_ENCRYPT"),
+    (0x0000362B, "IPSEC_IKE_DECRYPT"),
+    (0x0000362C, "IPSEC_IKE_POLICY_MATCH"),
+    (0x0000362D, "IPSEC_IKE_UNSUPPORTED_ID"),
+    (0x0000362E, "IPSEC_IKE_INVALID_HASH"),
+    (0x0000362F, "IPSEC_IKE_INVALID_HASH_ALG"),
+    (0x00003630, "IPSEC_IKE_INVALID_HASH_SIZE"),
+    (0x00003631, "IPSEC_IKE_INVALID_ENCRYPT_ALG"),
+    (0x00003632, "IPSEC_IKE_INVALID_AUTH_ALG"),
+    (0x00003633, "IPSEC_IKE_INVALID_SIG"),
+    (0x00003634, "IPSEC_IKE_LOAD_FAILED"),
+    (0x00003635, "IPSEC_IKE_RPC_DELETE"),
+    (0x00003636, "IPSEC_IKE_BENIGN_REINIT"),
+    (0x00003637, "IPSEC_IKE_INVALID_RESPONDER_LIFETIME_NOTIFY"),
+    (0x00003638, "IPSEC_IKE_INVALID_MAJOR_VERSION"),
+    (0x00003639, "IPSEC_IKE_INVALID_CERT_KEYLEN"),
+    (0x0000363A, "IPSEC_IKE_MM_LIMIT"),
+    (0x0000363B, "IPSEC_IKE_NEGOTIATION_DISABLED"),
+    (0x0000363C, "IPSEC_IKE_QM_LIMIT"),
+    (0x0000363D, "IPSEC_IKE_MM_EXPIRED"),
+    (0x0000363E, "IPSEC_IKE_PEER_MM_ASSUMED_INVALID"),
+    (0x0000363F, "IPSEC_IKE_CERT_CHAIN_POLICY_MISMATCH"),
+    (0x00003640, "IPSEC_IKE_UNEXPECTED_MESSAGE_ID"),
+    (0x00003641, "IPSEC_IKE_INVALID_AUTH_PAYLOAD"),
+    (0x00003642, "IPSEC_IKE_DOS_COOKIE_SENT"),
+    (0x00003643, "IPSEC_IKE_SHUTTING_DOWN"),
+    (0x00003644, "IPSEC_IKE_CGA_AUTH_FAILED"),
+    (0x00003645, "IPSEC_IKE_PROCESS_ERR_NATOA"),
+    (0x00003646, "IPSEC_IKE_INVALID_MM_FOR_QM"),
+    (0x00003647, "IPSEC_IKE_QM_EXPIRED"),
+    (0x00003648, "IPSEC_IKE_TOO_MANY_FILTERS"),
+    (0x00003649, "IPSEC_IKE_NEG_STATUS_END"),
+    (0x0000364A, "IPSEC_IKE_KILL_DUMMY_NAP_TUNNEL"),
+    (0x0000364B, "IPSEC_IKE_INNER_IP_ASSIGNMENT_FAILURE"),
+    (0x0000364C, "IPSEC_IKE_REQUIRE_CP_PAYLOAD_MISSING"),
+    (0x0000364D, "IPSEC_KEY_MODULE_IMPERSONATION_NEGOTIATION_PENDING"),
+    (0x0000364E, "IPSEC_IKE_COEXISTENCE_SUPPRESS"),
+    (0x0000364F, "IPSEC_IKE_RATELIMIT_DROP"),
+    (0x00003650, "IPSEC_IKE_PEER_DOESNT_SUPPORT_MOBIKE"),
+    (0x00003651, "IPSEC_IKE_AUTHORIZATION_FAILURE"),
+    (0x00003652, "IPSEC_IKE_STRONG_CRED_AUTHORIZATION_FAILURE"),
+    (0x00003653, "IPSEC_IKE_AUTHORIZATION_FAILURE_WITH_OPTIONAL_RETRY"),
+    (0x00003654, "IPSEC_IKE_STRONG_CRED_AUTHORIZATION_AND_CERTMAP_FAILURE"),
+    (0x00003655, "IPSEC_IKE_NEG_STATUS_EXTENDED_END"),
+    (0x00003656, "IPSEC_BAD_SPI"),
+    (0x00003657, "IPSEC_SA_LIFETIME_EXPIRED"),
+    (0x00003658, "IPSEC_WRONG_SA"),
+    (0x00003659, "IPSEC_REPLAY_CHECK_FAILED"),
+    (0x0000365A, "IPSEC_INVALID_PACKET"),
+    (0x0000365B, "IPSEC_INTEGRITY_CHECK_FAILED"),
+    (0x0000365C, "IPSEC_CLEAR_TEXT_DROP"),
+    (0x0000365D, "IPSEC_AUTH_FIREWALL_DROP"),
+    (0x0000365E, "IPSEC_THROTTLE_DROP"),
+    (0x00003665, "IPSEC_DOSP_BLOCK"),
+    (0x00003666, "IPSEC_DOSP_RECEIVED_MULTICAST"),
+    (0x00003667, "IPSEC_DOSP_INVALID_PACKET"),
+    (0x00003668, "IPSEC_DOSP_STATE_LOOKUP_FAILED"),
+    (0x00003669, "IPSEC_DOSP_MAX_ENTRIES"),
+    (0x0000366A, "IPSEC_DOSP_KEYMOD_NOT_ALLOWED"),
+    (0x0000366B, "IPSEC_DOSP_NOT_INSTALLED"),
+    (0x0000366C, "IPSEC_DOSP_MAX_PER_IP_RATELIMIT_QUEUES"),
+    (0x000036B0, "SXS_SECTION_NOT_FOUND"),
+    (0x000036B1, "SXS_CANT_GEN_ACTCTX"),
+    (0x000036B2, "SXS_INVALID_ACTCTXDATA_FORMAT"),
+    (0x000036B3, "SXS_ASSEMBLY_NOT_FOUND"),
+    (0x000036B4, "SXS_MANIFEST_FORMAT_ERROR"),
+    (0x000036B5, "SXS_MANIFEST_PARSE_ERROR"),
+    (0x000036B6, "SXS_ACTIVATION_CONTEXT_DISABLED"),
+    (0x000036B7, "SXS_KEY_NOT_FOUND"),
+    (0x000036B8, "SXS_VERSION_CONFLICT"),
+    (0x000036B9, "SXS_WRONG_SECTION_TYPE"),
+    (0x000036BA, "SXS_THREAD_QUERIES_DISABLED"),
+    (0x000036BB, "SXS_PROCESS_DEFAULT_ALREADY_SET"),
+    (0x000036BC, "SXS_UNKNOWN_ENCODING_GROUP"),
+    (0x000036BD, "SXS_UNKNOWN_ENCODING"),
+    (0x000036BE, "SXS_INVALID_XML_NAMESPACE_URI"),
+    (0x000036BF, "SXS_ROOT_MANIFEST_DEPENDENCY_NOT_INSTALLED"),
+    (0x000036C0, "SXS_LEAF_MANIFEST_DEPENDENCY_NOT_INSTALLED"),
+    (0x000036C1, "SXS_INVALID_ASSEMBLY_IDENTITY_ATTRIBUTE"),
+    (0x000036C2, "SXS_MANIFEST_MISSING_REQUIRED_DEFAULT_NAMESPACE"),
+    (0x000036C3, "SXS_MANIFEST_INVALID_REQUIRED_DEFAULT_NAMESPACE"),
+    (0x000036C4, "SXS_PRIVATE_MANIFEST_CROSS_PATH_WITH_REPARSE_POINT"),
+    (0x000036C5, "SXS_DUPLICATE_DLL_NAME"),
+    (0x000036C6, "SXS_DUPLICATE_WINDOWCLASS_NAME"),
+    (0x000036C7, "SXS_DUPLICATE_CLSID"),
+    (0x000036C8, "SXS_DUPLICATE_IID"),
+    (0x000036C9, "SXS_DUPLICATE_TLBID"),
+    (0x000036CA, "SXS_DUPLICATE_PROGID"),
+    (0x000036CB, "SXS_DUPLICATE_ASSEMBLY_NAME"),
+    (0x000036CC, "SXS_FILE_HASH_MISMATCH"),
+    (0x000036CD, "SXS_POLICY_PARSE_ERROR"),
+    (0x000036CE, "SXS_XML_E_MISSINGQUOTE"),
+    (0x000036CF, "SXS_XML_E_COMMENTSYNTAX"),
+    (0x000036D0, "SXS_XML_E_BADSTARTNAMECHAR"),
+    (0x000036D1, "SXS_XML_E_BADNAMECHAR"),
+    (0x000036D2, "SXS_XML_E_BADCHARINSTRING"),
+    (0x000036D3, "SXS_XML_E_XMLDECLSYNTAX"),
+    (0x000036D4, "SXS_XML_E_BADCHARDATA"),
+    (0x000036D5, "SXS_XML_E_MISSINGWHITESPACE"),
+    (0x000036D6, "SXS_XML_E_EXPECTINGTAGEND"),
+    (0x000036D7, "SXS_XML_E_MISSINGSEMICOLON"),
+    (0x000036D8, "SXS_XML_E_UNBALANCEDPAREN"),
+    (0x000036D9, "SXS_XML_E_INTERNALERROR"),
+    (0x000036DA, "SXS_XML_E_UNEXPECTED_WHITESPACE"),
+    (0x000036DB, "SXS_XML_E_INCOMPLETE_ENCODING"),
+    (0x000036DC, "SXS_XML_E_MISSING_PAREN"),
+    (0x000036DD, "SXS_XML_E_EXPECTINGCLOSEQUOTE"),
+    (0x000036DE, "SXS_XML_E_MULTIPLE_COLONS"),
+    (0x000036DF, "SXS_XML_E_INVALID_DECIMAL"),
+    (0x000036E0, "SXS_XML_E_INVALID_HEXIDECIMAL"),
+    (0x000036E1, "SXS_XML_E_INVALID_UNICODE"),
+    (0x000036E2, "SXS_XML_E_WHITESPACEORQUESTIONMARK"),
+    (0x000036E3, "SXS_XML_E_UNEXPECTEDENDTAG"),
+    (0x000036E4, "SXS_XML_E_UNCLOSEDTAG"),
+    (0x000036E5, "SXS_XML_E_DUPLICATEATTRIBUTE"),
+    (0x000036E6, "SXS_XML_E_MULTIPLEROOTS"),
+    (0x000036E7, "SXS_XML_E_INVALIDATROOTLEVEL"),
+    (0x000036E8, "SXS_XML_E_BADXMLDECL"),
+    (0x000036E9, "SXS_XML_E_MISSINGROOT"),
+    (0x000036EA, "SXS_XML_E_UNEXPECTEDEOF"),
+    (0x000036EB, "SXS_XML_E_BADPEREFINSUBSET"),
+    (0x000036EC, "SXS_XML_E_UNCLOSEDSTARTTAG"),
+    (0x000036ED, "SXS_XML_E_UNCLOSEDENDTAG"),
+    (0x000036EE, "SXS_XML_E_UNCLOSEDSTRING"),
+    (0x000036EF, "SXS_XML_E_UNCLOSEDCOMMENT"),
+    (0x000036F0, "SXS_XML_E_UNCLOSEDDECL"),
+    (0x000036F1, "SXS_XML_E_UNCLOSEDCDATA"),
+    (0x000036F2, "SXS_XML_E_RESERVEDNAMESPACE"),
+    (0x000036F3, "SXS_XML_E_INVALIDENCODING"),
+    (0x000036F4, "SXS_XML_E_INVALIDSWITCH"),
+    (0x000036F5, "SXS_XML_E_BADXMLCASE"),
+    (0x000036F6, "SXS_XML_E_INVALID_STANDALONE"),
+    (0x000036F7, "SXS_XML_E_UNEXPECTED_STANDALONE"),
+    (0x000036F8, "SXS_XML_E_INVALID_VERSION"),
+    (0x000036F9, "SXS_XML_E_MISSINGEQUALS"),
+    (0x000036FA, "SXS_PROTECTION_RECOVERY_FAILED"),
+    (0x000036FB, "SXS_PROTECTION_PUBLIC_KEY_TOO_SHORT"),
+    (0x000036FC, "SXS_PROTECTION_CATALOG_NOT_VALID"),
+    (0x000036FD, "SXS_UNTRANSLATABLE_HRESULT"),
+    (0x000036FE, "SXS_PROTECTION_CATALOG_FILE_MISSING"),
+    (0x000036FF, "SXS_MISSING_ASSEMBLY_IDENTITY_ATTRIBUTE"),
+    (0x00003700, "SXS_INVALID_ASSEMBLY_IDENTITY_ATTRIBUTE_NAME"),
+    (0x00003701, "SXS_ASSEMBLY_MISSING"),
+    (0x00003702, "SXS_CORRUPT_ACTIVATION_STACK"),
+    (0x00003703, "SXS_CORRUPTION"),
+    (0x00003704, "SXS_EARLY_DEACTIVATION"),
+    (0x00003705, "SXS_INVALID_DEACTIVATION"),
+    (0x00003706, "SXS_MULTIPLE_DEACTIVATION"),
+    (0x00003707, "SXS_PROCESS_TERMINATION_REQUESTED"),
+    (0x00003708, "SXS_RELEASE_ACTIVATION_CONTEXT"),
+    (0x00003709, "SXS_SYSTEM_DEFAULT_ACTIVATION_CONTEXT_EMPTY"),
+    (0x0000370A, "SXS_INVALID_IDENTITY_ATTRIBUTE_VALUE"),
+    (0x0000370B, "SXS_INVALID_IDENTITY_ATTRIBUTE_NAME"),
+    (0x0000370C, "SXS_IDENTITY_DUPLICATE_ATTRIBUTE"),
+    (0x0000370D, "SXS_IDENTITY_PARSE_ERROR"),
+    (0x0000370E, "MALFORMED_SUBSTITUTION_STRING"),
+    (0x0000370F, "SXS_INCORRECT_PUBLIC_KEY_TOKEN"),
+    (0x00003710, "UNMAPPED_SUBSTITUTION_STRING"),
+    (0x00003711, "SXS_ASSEMBLY_NOT_LOCKED"),
+    (0x00003712, "SXS_COMPONENT_STORE_CORRUPT"),
+    (0x00003713, "ADVANCED_INSTALLER_FAILED"),
+    (0x00003714, "XML_ENCODING_MISMATCH"),
+    (0x00003715, "SXS_MANIFEST_IDENTITY_SAME_BUT_CONTENTS_DIFFERENT"),
+    (0x00003716, "SXS_IDENTITIES_DIFFERENT"),
+    (0x00003717, "SXS_ASSEMBLY_IS_NOT_A_DEPLOYMENT"),
+    (0x00003718, "SXS_FILE_NOT_PART_OF_ASSEMBLY"),
+    (0x00003719, "SXS_MANIFEST_TOO_BIG"),
+    (0x0000371A, "SXS_SETTING_NOT_REGISTERED"),
+    (0x0000371B, "SXS_TRANSACTION_CLOSURE_INCOMPLETE"),
+    (0x0000371C, "SMI_PRIMITIVE_INSTALLER_FAILED"),
+    (0x0000371D, "GENERIC_COMMAND_FAILED"),
+    (0x0000371E, "SXS_FILE_HASH_MISSING"),
+    (0x0000371F, "SXS_DUPLICATE_ACTIVATABLE_CLASS"),
+    (0x00003A98, "EVT_INVALID_CHANNEL_PATH"),
+    (0x00003A99, "EVT_INVALID_QUERY"),
+    (0x00003A9A, "EVT_PUBLISHER_METADATA_NOT_FOUND"),
+    (0x00003A9B, "EVT_EVENT_TEMPLATE_NOT_FOUND"),
+    (0x00003A9C, "EVT_INVALID_PUBLISHER_NAME"),
+    (0x00003A9D, "EVT_INVALID_EVENT_DATA"),
+    (0x00003A9F, "EVT_CHANNEL_NOT_FOUND"),
+    (0x00003AA0, "EVT_MALFORMED_XML_TEXT"),
+    (0x00003AA1, "EVT_SUBSCRIPTION_TO_DIRECT_CHANNEL"),
+    (0x00003AA2, "EVT_CONFIGURATION_ERROR"),
+    (0x00003AA3, "EVT_QUERY_RESULT_STALE"),
+    (0x00003AA4, "EVT_QUERY_RESULT_INVALID_POSITION"),
+    (0x00003AA5, "EVT_NON_VALIDATING_MSXML"),
+    (0x00003AA6, "EVT_FILTER_ALREADYSCOPED"),
+    (0x00003AA7, "EVT_FILTER_NOTELTSET"),
+    (0x00003AA8, "EVT_FILTER_INVARG"),
+    (0x00003AA9, "EVT_FILTER_INVTEST"),
+    (0x00003AAA, "EVT_FILTER_INVTYPE"),
+    (0x00003AAB, "EVT_FILTER_PARSEERR"),
+    (0x00003AAC, "EVT_FILTER_UNSUPPORTEDOP"),
+    (0x00003AAD, "EVT_FILTER_UNEXPECTEDTOKEN"),
+    (0x00003AAE, "EVT_INVALID_OPERATION_OVER_ENABLED_DIRECT_CHANNEL"),
+    (0x00003AAF, "EVT_INVALID_CHANNEL_PROPERTY_VALUE"),
+    (0x00003AB0, "EVT_INVALID_PUBLISHER_PROPERTY_VALUE"),
+    (0x00003AB1, "EVT_CHANNEL_CANNOT_ACTIVATE"),
+    (0x00003AB2, "EVT_FILTER_TOO_COMPLEX"),
+    (0x00003AB3, "EVT_MESSAGE_NOT_FOUND"),
+    (0x00003AB4, "EVT_MESSAGE_ID_NOT_FOUND"),
+    (0x00003AB5, "EVT_UNRESOLVED_VALUE_INSERT"),
+    (0x00003AB6, "EVT_UNRESOLVED_PARAMETER_INSERT"),
+    (0x00003AB7, "EVT_MAX_INSERTS_REACHED"),
+    (0x00003AB8, "EVT_EVENT_DEFINITION_NOT_FOUND"),
+    (0x00003AB9, "EVT_MESSAGE_LOCALE_NOT_FOUND"),
+    (0x00003ABA, "EVT_VERSION_TOO_OLD"),
+    (0x00003ABB, "EVT_VERSION_TOO_NEW"),
+    (0x00003ABC, "EVT_CANNOT_OPEN_CHANNEL_OF_QUERY"),
+    (0x00003ABD, "EVT_PUBLISHER_DISABLED"),
+    (0x00003ABE, "EVT_FILTER_OUT_OF_RANGE"),
+    (0x00003AE8, "EC_SUBSCRIPTION_CANNOT_ACTIVATE"),
+    (0x00003AE9, "EC_LOG_DISABLED"),
+    (0x00003AEA, "EC_CIRCULAR_FORWARDING"),
+    (0x00003AEB, "EC_CREDSTORE_FULL"),
+    (0x00003AEC, "EC_CRED_NOT_FOUND"),
+    (0x00003AED, "EC_NO_ACTIVE_CHANNEL"),
+    (0x00003AFC, "MUI_FILE_NOT_FOUND"),
+    (0x00003AFD, "MUI_INVALID_FILE"),
+    (0x00003AFE, "MUI_INVALID_RC_CONFIG"),
+    (0x00003AFF, "MUI_INVALID_LOCALE_NAME"),
+    (0x00003B00, "MUI_INVALID_ULTIMATEFALLBACK_NAME"),
+    (0x00003B01, "MUI_FILE_NOT_LOADED"),
+    (0x00003B02, "RESOURCE_ENUM_USER_STOP"),
+    (0x00003B03, "MUI_INTLSETTINGS_UILANG_NOT_INSTALLED"),
+    (0x00003B04, "MUI_INTLSETTINGS_INVALID_LOCALE_NAME"),
+    (0x00003B06, "MRM_RUNTIME_NO_DEFAULT_OR_NEUTRAL_RESOURCE"),
+    (0x00003B07, "MRM_INVALID_PRICONFIG"),
+    (0x00003B08, "MRM_INVALID_FILE_TYPE"),
+    (0x00003B09, "MRM_UNKNOWN_QUALIFIER"),
+    (0x00003B0A, "MRM_INVALID_QUALIFIER_VALUE"),
+    (0x00003B0B, "MRM_NO_CANDIDATE"),
+    (0x00003B0C, "MRM_NO_MATCH_OR_DEFAULT_CANDIDATE"),
+    (0x00003B0D, "MRM_RESOURCE_TYPE_MISMATCH"),
+    (0x00003B0E, "MRM_DUPLICATE_MAP_NAME"),
+    (0x00003B0F, "MRM_DUPLICATE_ENTRY"),
+    (0x00003B10, "MRM_INVALID_RESOURCE_IDENTIFIER"),
+    (0x00003B11, "MRM_FILEPATH_TOO_LONG"),
+    (0x00003B12, "MRM_UNSUPPORTED_DIRECTORY_TYPE"),
+    (0x00003B16, "MRM_INVALID_PRI_FILE"),
+    (0x00003B17, "MRM_NAMED_RESOURCE_NOT_FOUND"),
+    (0x00003B1F, "MRM_MAP_NOT_FOUND"),
+    (0x00003B20, "MRM_UNSUPPORTED_PROFILE_TYPE"),
+    (0x00003B21, "MRM_INVALID_QUALIFIER_OPERATOR"),
+    (0x00003B22, "MRM_INDETERMINATE_QUALIFIER_VALUE"),
+    (0x00003B23, "MRM_AUTOMERGE_ENABLED"),
+    (0x00003B24, "MRM_TOO_MANY_RESOURCES"),
+    (0x00003B25, "MRM_UNSUPPORTED_FILE_TYPE_FOR_MERGE"),
+    (0x00003B26, "MRM_UNSUPPORTED_FILE_TYPE_FOR_LOAD_UNLOAD_PRI_FILE"),
+    (0x00003B27, "MRM_NO_CURRENT_VIEW_ON_THREAD"),
+    (0x00003B28, "DIFFERENT_PROFILE_RESOURCE_MANAGER_EXIST"),
+    (0x00003B29, "OPERATION_NOT_ALLOWED_FROM_SYSTEM_COMPONENT"),
+    (0x00003B2A, "MRM_DIRECT_REF_TO_NON_DEFAULT_RESOURCE"),
+    (0x00003B2B, "MRM_GENERATION_COUNT_MISMATCH"),
+    (0x00003B2C, "PRI_MERGE_VERSION_MISMATCH"),
+    (0x00003B2D, "PRI_MERGE_MISSING_SCHEMA"),
+    (0x00003B2E, "PRI_MERGE_LOAD_FILE_FAILED"),
+    (0x00003B2F, "PRI_MERGE_ADD_FILE_FAILED"),
+    (0x00003B30, "PRI_MERGE_WRITE_FILE_FAILED"),
+    (0x00003B31, "PRI_MERGE_MULTIPLE_PACKAGE_FAMILIES_NOT_ALLOWED"),
+    (0x00003B32, "PRI_MERGE_MULTIPLE_MAIN_PACKAGES_NOT_ALLOWED"),
+    (0x00003B33, "PRI_MERGE_BUNDLE_PACKAGES_NOT_ALLOWED"),
+    (0x00003B34, "PRI_MERGE_MAIN_PACKAGE_REQUIRED"),
+    (0x00003B35, "PRI_MERGE_RESOURCE_PACKAGE_REQUIRED"),
+    (0x00003B36, "PRI_MERGE_INVALID_FILE_NAME"),
+    (0x00003B37, "MRM_PACKAGE_NOT_FOUND"),
+    (0x00003B38, "MRM_MISSING_DEFAULT_LANGUAGE"),
+    (0x00003B39, "MRM_SCOPE_ITEM_CONFLICT"),
+    (0x00003B60, "MCA_INVALID_CAPABILITIES_STRING"),
+    (0x00003B61, "MCA_INVALID_VCP_VERSION"),
+    (0x00003B62, "MCA_MONITOR_VIOLATES_MCCS_SPECIFICATION"),
+    (0x00003B63, "MCA_MCCS_VERSION_MISMATCH"),
+    (0x00003B64, "MCA_UNSUPPORTED_MCCS_VERSION"),
+    (0x00003B65, "MCA_INTERNAL_ERROR"),
+    (0x00003B66, "MCA_INVALID_TECHNOLOGY_TYPE_RETURNED"),
+    (0x00003B67, "MCA_UNSUPPORTED_COLOR_TEMPERATURE"),
+    (0x00003B92, "AMBIGUOUS_SYSTEM_DEVICE"),
+    (0x00003BC3, "SYSTEM_DEVICE_NOT_FOUND"),
+    (0x00003BC4, "HASH_NOT_SUPPORTED"),
+    (0x00003BC5, "HASH_NOT_PRESENT"),
+    (0x00003BD9, "SECONDARY_IC_PROVIDER_NOT_REGISTERED"),
+    (0x00003BDA, "GPIO_CLIENT_INFORMATION_INVALID"),
+    (0x00003BDB, "GPIO_VERSION_NOT_SUPPORTED"),
+    (0x00003BDC, "GPIO_INVALID_REGISTRATION_PACKET"),
+    (0x00003BDD, "GPIO_OPERATION_DENIED"),
+    (0x00003BDE, "GPIO_INCOMPATIBLE_CONNECT_MODE"),
+    (0x00003BDF, "GPIO_INTERRUPT_ALREADY_UNMASKED"),
+    (0x00003C28, "CANNOT_SWITCH_RUNLEVEL"),
+    (0x00003C29, "INVALID_RUNLEVEL_SETTING"),
+    (0x00003C2A, "RUNLEVEL_SWITCH_TIMEOUT"),
+    (0x00003C2B, "RUNLEVEL_SWITCH_AGENT_TIMEOUT"),
+    (0x00003C2C, "RUNLEVEL_SWITCH_IN_PROGRESS"),
+    (0x00003C2D, "SERVICES_FAILED_AUTOSTART"),
+    (0x00003C8D, "COM_TASK_STOP_PENDING"),
+    (0x00003CF0, "INSTALL_OPEN_PACKAGE_FAILED"),
+    (0x00003CF1, "INSTALL_PACKAGE_NOT_FOUND"),
+    (0x00003CF2, "INSTALL_INVALID_PACKAGE"),
+    (0x00003CF3, "INSTALL_RESOLVE_DEPENDENCY_FAILED"),
+    (0x00003CF4, "INSTALL_OUT_OF_DISK_SPACE"),
+    (0x00003CF5, "INSTALL_NETWORK_FAILURE"),
+    (0x00003CF6, "INSTALL_REGISTRATION_FAILURE"),
+    (0x00003CF7, "INSTALL_DEREGISTRATION_FAILURE"),
+    (0x00003CF8, "INSTALL_CANCEL"),
+    (0x00003CF9, "INSTALL_FAILED"),
+    (0x00003CFA, "REMOVE_FAILED"),
+    (0x00003CFB, "PACKAGE_ALREADY_EXISTS"),
+    (0x00003CFC, "NEEDS_REMEDIATION"),
+    (0x00003CFD, "INSTALL_PREREQUISITE_FAILED"),
+    (0x00003CFE, "PACKAGE_REPOSITORY_CORRUPTED"),
+    (0x00003CFF, "INSTALL_POLICY_FAILURE"),
+    (0x00003D00, "PACKAGE_UPDATING"),
+    (0x00003D01, "DEPLOYMENT_BLOCKED_BY_POLICY"),
+    (0x00003D02, "PACKAGES_IN_USE"),
+    (0x00003D03, "RECOVERY_FILE_CORRUPT"),
+    (0x00003D04, "INVALID_STAGED_SIGNATURE"),
+    (0x00003D05, "DELETING_EXISTING_APPLICATIONDATA_STORE_FAILED"),
+    (0x00003D06, "INSTALL_PACKAGE_DOWNGRADE"),
+    (0x00003D07, "SYSTEM_NEEDS_REMEDIATION"),
+    (0x00003D08, "APPX_INTEGRITY_FAILURE_CLR_NGEN"),
+    (0x00003D09, "RESILIENCY_FILE_CORRUPT"),
+    (0x00003D0A, "INSTALL_FIREWALL_SERVICE_NOT_RUNNING"),
+    (0x00003D0B, "PACKAGE_MOVE_FAILED"),
+    (0x00003D0C, "INSTALL_VOLUME_NOT_EMPTY"),
+    (0x00003D0D, "INSTALL_VOLUME_OFFLINE"),
+    (0x00003D0E, "INSTALL_VOLUME_CORRUPT"),
+    (0x00003D0F, "NEEDS_REGISTRATION"),
+    (0x00003D10, "INSTALL_WRONG_PROCESSOR_ARCHITECTURE"),
+    (0x00003D11, "DEV_SIDELOAD_LIMIT_EXCEEDED"),
+    (0x00003D12, "INSTALL_OPTIONAL_PACKAGE_REQUIRES_MAIN_PACKAGE"),
+    (0x00003D13, "PACKAGE_NOT_SUPPORTED_ON_FILESYSTEM"),
+    (0x00003D14, "PACKAGE_MOVE_BLOCKED_BY_STREAMING"),
+    (0x00003D15, "INSTALL_OPTIONAL_PACKAGE_APPLICATIONID_NOT_UNIQUE"),
+    (0x00003D16, "PACKAGE_STAGING_ONHOLD"),
+    (0x00003D17, "INSTALL_INVALID_RELATED_SET_UPDATE"),
+    (0x00003D18, "INSTALL_OPTIONAL_PACKAGE_REQUIRES_MAIN_PACKAGE_FULLTRUST_CAPABILITY"),
+    (0x00003D19, "DEPLOYMENT_BLOCKED_BY_USER_LOG_OFF"),
+    (0x00003D1A, "PROVISION_OPTIONAL_PACKAGE_REQUIRES_MAIN_PACKAGE_PROVISIONED"),
+    (0x00003D1B, "PACKAGES_REPUTATION_CHECK_FAILED"),
+    (0x00003D1C, "PACKAGES_REPUTATION_CHECK_TIMEDOUT"),
+    (0x00003D1D, "DEPLOYMENT_OPTION_NOT_SUPPORTED"),
+    (0x00003D1E, "APPINSTALLER_ACTIVATION_BLOCKED"),
+    (0x00003D1F, "REGISTRATION_FROM_REMOTE_DRIVE_NOT_SUPPORTED"),
+    (0x00003D20, "APPX_RAW_DATA_WRITE_FAILED"),
+    (0x00003D21, "DEPLOYMENT_BLOCKED_BY_VOLUME_POLICY_PACKAGE"),
+    (0x00003D22, "DEPLOYMENT_BLOCKED_BY_VOLUME_POLICY_MACHINE"),
+    (0x00003D23, "DEPLOYMENT_BLOCKED_BY_PROFILE_POLICY"),
+    (0x00003D24, "DEPLOYMENT_FAILED_CONFLICTING_MUTABLE_PACKAGE_DIRECTORY"),
+    (0x00003D25, "SINGLETON_RESOURCE_INSTALLED_IN_ACTIVE_USER"),
+    (0x00003D26, "DIFFERENT_VERSION_OF_PACKAGED_SERVICE_INSTALLED"),
+    (0x00003D27, "SERVICE_EXISTS_AS_NON_PACKAGED_SERVICE"),
+    (0x00003D28, "PACKAGED_SERVICE_REQUIRES_ADMIN_PRIVILEGES"),
+    (0x00003D29, "REDIRECTION_TO_DEFAULT_ACCOUNT_NOT_ALLOWED"),
+    (0x00003D2A, "PACKAGE_LACKS_CAPABILITY_TO_DEPLOY_ON_HOST"),
+    (0x00003D2B, "UNSIGNED_PACKAGE_INVALID_CONTENT"),
+    (0x00003D2C, "UNSIGNED_PACKAGE_INVALID_PUBLISHER_NAMESPACE"),
+    (0x00003D2D, "SIGNED_PACKAGE_INVALID_PUBLISHER_NAMESPACE"),
+    (0x00003D2E, "PACKAGE_EXTERNAL_LOCATION_NOT_ALLOWED"),
+    (
+        0x00003D2F,
+        "INSTALL_FULLTRUST_HOSTRUNTIME_REQUIRES_MAIN_PACKAGE_FULLTRUST_CAPABILITY",
+    ),
+    (0x00003D30, "PACKAGE_LACKS_CAPABILITY_FOR_MANDATORY_STARTUPTASKS"),
+    (0x00003D31, "INSTALL_RESOLVE_HOSTRUNTIME_DEPENDENCY_FAILED"),
+    (0x00003D32, "MACHINE_SCOPE_NOT_ALLOWED"),
+    (0x00003D33, "CLASSIC_COMPAT_MODE_NOT_ALLOWED"),
+    (0x00003D34, "STAGEFROMUPDATEAGENT_PACKAGE_NOT_APPLICABLE"),
+    (0x00003D35, "PACKAGE_NOT_REGISTERED_FOR_USER"),
+    (0x00003D36, "PACKAGE_NAME_MISMATCH"),
+    (0x00003D37, "APPINSTALLER_URI_IN_USE"),
+    (0x00003D38, "APPINSTALLER_IS_MANAGED_BY_SYSTEM"),
+    (0x00003D54, "APPMODEL_ERROR_NO_PACKAGE"),
+    (0x00003D55, "APPMODEL_ERROR_PACKAGE_RUNTIME_CORRUPT"),
+    (0x00003D56, "APPMODEL_ERROR_PACKAGE_IDENTITY_CORRUPT"),
+    (0x00003D57, "APPMODEL_ERROR_NO_APPLICATION"),
+    (0x00003D58, "APPMODEL_ERROR_DYNAMIC_PROPERTY_READ_FAILED"),
+    (0x00003D59, "APPMODEL_ERROR_DYNAMIC_PROPERTY_INVALID"),
+    (0x00003D5A, "APPMODEL_ERROR_PACKAGE_NOT_AVAILABLE"),
+    (0x00003D5B, "APPMODEL_ERROR_NO_MUTABLE_DIRECTORY"),
+    (0x00003DB8, "STATE_LOAD_STORE_FAILED"),
+    (0x00003DB9, "STATE_GET_VERSION_FAILED"),
+    (0x00003DBA, "STATE_SET_VERSION_FAILED"),
+    (0x00003DBB, "STATE_STRUCTURED_RESET_FAILED"),
+    (0x00003DBC, "STATE_OPEN_CONTAINER_FAILED"),
+    (0x00003DBD, "STATE_CREATE_CONTAINER_FAILED"),
+    (0x00003DBE, "STATE_DELETE_CONTAINER_FAILED"),
+    (0x00003DBF, "STATE_READ_SETTING_FAILED"),
+    (0x00003DC0, "STATE_WRITE_SETTING_FAILED"),
+    (0x00003DC1, "STATE_DELETE_SETTING_FAILED"),
+    (0x00003DC2, "STATE_QUERY_SETTING_FAILED"),
+    (0x00003DC3, "STATE_READ_COMPOSITE_SETTING_FAILED"),
+    (0x00003DC4, "STATE_WRITE_COMPOSITE_SETTING_FAILED"),
+    (0x00003DC5, "STATE_ENUMERATE_CONTAINER_FAILED"),
+    (0x00003DC6, "STATE_ENUMERATE_SETTINGS_FAILED"),
+    (0x00003DC7, "STATE_COMPOSITE_SETTING_VALUE_SIZE_LIMIT_EXCEEDED"),
+    (0x00003DC8, "STATE_SETTING_VALUE_SIZE_LIMIT_EXCEEDED"),
+    (0x00003DC9, "STATE_SETTING_NAME_SIZE_LIMIT_EXCEEDED"),
+    (0x00003DCA, "STATE_CONTAINER_NAME_SIZE_LIMIT_EXCEEDED"),
+    (0x00003DE1, "API_UNAVAILABLE"),
+    (0x00340001, "NDIS_INDICATION_REQUIRED"),
+    (0x00350059, "HV_PENDING_PAGE_REQUESTS"),
+    (0x80340002, "NDIS_INTERFACE_CLOSING"),
+    (0x80340004, "NDIS_BAD_VERSION"),
+    (0x80340005, "NDIS_BAD_CHARACTERISTICS"),
+    (0x80340006, "NDIS_ADAPTER_NOT_FOUND"),
+    (0x80340007, "NDIS_OPEN_FAILED"),
+    (0x80340008, "NDIS_DEVICE_FAILED"),
+    (0x80340009, "NDIS_MULTICAST_FULL"),
+    (0x8034000A, "NDIS_MULTICAST_EXISTS"),
+    (0x8034000B, "NDIS_MULTICAST_NOT_FOUND"),
+    (0x8034000C, "NDIS_REQUEST_ABORTED"),
+    (0x8034000D, "NDIS_RESET_IN_PROGRESS"),
+    (0x8034000F, "NDIS_INVALID_PACKET"),
+    (0x80340010, "NDIS_INVALID_DEVICE_REQUEST"),
+    (0x80340011, "NDIS_ADAPTER_NOT_READY"),
+    (0x80340014, "NDIS_INVALID_LENGTH"),
+    (0x80340015, "NDIS_INVALID_DATA"),
+    (0x80340016, "NDIS_BUFFER_TOO_SHORT"),
+    (0x80340017, "NDIS_INVALID_OID"),
+    (0x80340018, "NDIS_ADAPTER_REMOVED"),
+    (0x80340019, "NDIS_UNSUPPORTED_MEDIA"),
+    (0x8034001A, "NDIS_GROUP_ADDRESS_IN_USE"),
+    (0x8034001B, "NDIS_FILE_NOT_FOUND"),
+    (0x8034001C, "NDIS_ERROR_READING_FILE"),
+    (0x8034001D, "NDIS_ALREADY_MAPPED"),
+    (0x8034001E, "NDIS_RESOURCE_CONFLICT"),
+    (0x8034001F, "NDIS_MEDIA_DISCONNECTED"),
+    (0x80340022, "NDIS_INVALID_ADDRESS"),
+    (0x8034002A, "NDIS_PAUSED"),
+    (0x8034002B, "NDIS_INTERFACE_NOT_FOUND"),
+    (0x8034002C, "NDIS_UNSUPPORTED_REVISION"),
+    (0x8034002D, "NDIS_INVALID_PORT"),
+    (0x8034002E, "NDIS_INVALID_PORT_STATE"),
+    (0x8034002F, "NDIS_LOW_POWER_STATE"),
+    (0x80340030, "NDIS_REINIT_REQUIRED"),
+    (0x80340031, "NDIS_NO_QUEUES"),
+    (0x803400BB, "NDIS_NOT_SUPPORTED"),
+    (0x80342000, "NDIS_DOT11_AUTO_CONFIG_ENABLED"),
+    (0x80342001, "NDIS_DOT11_MEDIA_IN_USE"),
+    (0x80342002, "NDIS_DOT11_POWER_STATE_INVALID"),
+    (0x80342003, "NDIS_PM_WOL_PATTERN_LIST_FULL"),
+    (0x80342004, "NDIS_PM_PROTOCOL_OFFLOAD_LIST_FULL"),
+    (0x80342005, "NDIS_DOT11_AP_CHANNEL_CURRENTLY_NOT_AVAILABLE"),
+    (0x80342006, "NDIS_DOT11_AP_BAND_CURRENTLY_NOT_AVAILABLE"),
+    (0x80342007, "NDIS_DOT11_AP_CHANNEL_NOT_ALLOWED"),
+    (0x80342008, "NDIS_DOT11_AP_BAND_NOT_ALLOWED"),
+    (0x80370001, "VID_REMOTE_NODE_PARENT_GPA_PAGES_USED"),
+    (0x80380001, "VOLMGR_INCOMPLETE_REGENERATION"),
+    (0x80380002, "VOLMGR_INCOMPLETE_DISK_MIGRATION"),
+    (0x80390001, "BCD_NOT_ALL_ENTRIES_IMPORTED"),
+    (0x80390003, "BCD_NOT_ALL_ENTRIES_SYNCHRONIZED"),
+    (0x803A0001, "QUERY_STORAGE_ERROR"),
+    (0xC034100F, "NDIS_OFFLOAD_POLICY"),
+    (0xC0341012, "NDIS_OFFLOAD_CONNECTION_REJECTED"),
+    (0xC0341013, "NDIS_OFFLOAD_PATH_REJECTED"),
+    (0xC0350002, "HV_INVALID_HYPERCALL_CODE"),
+    (0xC0350003, "HV_INVALID_HYPERCALL_INPUT"),
+    (0xC0350004, "HV_INVALID_ALIGNMENT"),
+    (0xC0350005, "HV_INVALID_PARAMETER"),
+    (0xC0350006, "HV_ACCESS_DENIED"),
+    (0xC0350007, "HV_INVALID_PARTITION_STATE"),
+    (0xC0350008, "HV_OPERATION_DENIED"),
+    (0xC0350009, "HV_UNKNOWN_PROPERTY"),
+    (0xC035000A, "HV_PROPERTY_VALUE_OUT_OF_RANGE"),
+    (0xC035000B, "HV_INSUFFICIENT_MEMORY"),
+    (0xC035000C, "HV_PARTITION_TOO_DEEP"),
+    (0xC035000D, "HV_INVALID_PARTITION_ID"),
+    (0xC035000E, "HV_INVALID_VP_INDEX"),
+    (0xC0350011, "HV_INVALID_PORT_ID"),
+    (0xC0350012, "HV_INVALID_CONNECTION_ID"),
+    (0xC0350013, "HV_INSUFFICIENT_BUFFERS"),
+    (0xC0350014, "HV_NOT_ACKNOWLEDGED"),
+    (0xC0350015, "HV_INVALID_VP_STATE"),
+    (0xC0350016, "HV_ACKNOWLEDGED"),
+    (0xC0350017, "HV_INVALID_SAVE_RESTORE_STATE"),
+    (0xC0350018, "HV_INVALID_SYNIC_STATE"),
+    (0xC0350019, "HV_OBJECT_IN_USE"),
+    (0xC035001A, "HV_INVALID_PROXIMITY_DOMAIN_INFO"),
+    (0xC035001B, "HV_NO_DATA"),
+    (0xC035001C, "HV_INACTIVE"),
+    (0xC035001D, "HV_NO_RESOURCES"),
+    (0xC035001E, "HV_FEATURE_UNAVAILABLE"),
+    (0xC0350033, "HV_INSUFFICIENT_BUFFER"),
+    (0xC0350038, "HV_INSUFFICIENT_DEVICE_DOMAINS"),
+    (0xC035003C, "HV_CPUID_FEATURE_VALIDATION"),
+    (0xC035003D, "HV_CPUID_XSAVE_FEATURE_VALIDATION"),
+    (0xC035003E, "HV_PROCESSOR_STARTUP_TIMEOUT"),
+    (0xC035003F, "HV_SMX_ENABLED"),
+    (0xC0350041, "HV_INVALID_LP_INDEX"),
+    (0xC0350050, "HV_INVALID_REGISTER_VALUE"),
+    (0xC0350051, "HV_INVALID_VTL_STATE"),
+    (0xC0350055, "HV_NX_NOT_DETECTED"),
+    (0xC0350057, "HV_INVALID_DEVICE_ID"),
+    (0xC0350058, "HV_INVALID_DEVICE_STATE"),
+    (0xC0350060, "HV_PAGE_REQUEST_INVALID"),
+    (0xC035006F, "HV_INVALID_CPU_GROUP_ID"),
+    (0xC0350070, "HV_INVALID_CPU_GROUP_STATE"),
+    (0xC0350071, "HV_OPERATION_FAILED"),
+    (0xC0350072, "HV_NOT_ALLOWED_WITH_NESTED_VIRT_ACTIVE"),
+    (0xC0350073, "HV_INSUFFICIENT_ROOT_MEMORY"),
+    (0xC0350074, "HV_EVENT_BUFFER_ALREADY_FREED"),
+    (0xC0350075, "HV_INSUFFICIENT_CONTIGUOUS_MEMORY"),
+    (0xC0350076, "HV_DEVICE_NOT_IN_DOMAIN"),
+    (0xC0350077, "HV_NESTED_VM_EXIT"),
+    (0xC0350080, "HV_MSR_ACCESS_FAILED"),
+    (0xC0350081, "HV_INSUFFICIENT_MEMORY_MIRRORING"),
+    (0xC0350082, "HV_INSUFFICIENT_CONTIGUOUS_MEMORY_MIRRORING"),
+    (0xC0350083, "HV_INSUFFICIENT_CONTIGUOUS_ROOT_MEMORY"),
+    (0xC0350084, "HV_INSUFFICIENT_ROOT_MEMORY_MIRRORING"),
+    (0xC0350085, "HV_INSUFFICIENT_CONTIGUOUS_ROOT_MEMORY_MIRRORING"),
+    (0xC0351000, "HV_NOT_PRESENT"),
+    (0xC0370001, "VID_DUPLICATE_HANDLER"),
+    (0xC0370002, "VID_TOO_MANY_HANDLERS"),
+    (0xC0370003, "VID_QUEUE_FULL"),
+    (0xC0370004, "VID_HANDLER_NOT_PRESENT"),
+    (0xC0370005, "VID_INVALID_OBJECT_NAME"),
+    (0xC0370006, "VID_PARTITION_NAME_TOO_LONG"),
+    (0xC0370007, "VID_MESSAGE_QUEUE_NAME_TOO_LONG"),
+    (0xC0370008, "VID_PARTITION_ALREADY_EXISTS"),
+    (0xC0370009, "VID_PARTITION_DOES_NOT_EXIST"),
+    (0xC037000A, "VID_PARTITION_NAME_NOT_FOUND"),
+    (0xC037000B, "VID_MESSAGE_QUEUE_ALREADY_EXISTS"),
+    (0xC037000C, "VID_EXCEEDED_MBP_ENTRY_MAP_LIMIT"),
+    (0xC037000D, "VID_MB_STILL_REFERENCED"),
+    (0xC037000E, "VID_CHILD_GPA_PAGE_SET_CORRUPTED"),
+    (0xC037000F, "VID_INVALID_NUMA_SETTINGS"),
+    (0xC0370010, "VID_INVALID_NUMA_NODE_INDEX"),
+    (0xC0370011, "VID_NOTIFICATION_QUEUE_ALREADY_ASSOCIATED"),
+    (0xC0370012, "VID_INVALID_MEMORY_BLOCK_HANDLE"),
+    (0xC0370013, "VID_PAGE_RANGE_OVERFLOW"),
+    (0xC0370014, "VID_INVALID_MESSAGE_QUEUE_HANDLE"),
+    (0xC0370015, "VID_INVALID_GPA_RANGE_HANDLE"),
+    (0xC0370016, "VID_NO_MEMORY_BLOCK_NOTIFICATION_QUEUE"),
+    (0xC0370017, "VID_MEMORY_BLOCK_LOCK_COUNT_EXCEEDED"),
+    (0xC0370018, "VID_INVALID_PPM_HANDLE"),
+    (0xC0370019, "VID_MBPS_ARE_LOCKED"),
+    (0xC037001A, "VID_MESSAGE_QUEUE_CLOSED"),
+    (0xC037001B, "VID_VIRTUAL_PROCESSOR_LIMIT_EXCEEDED"),
+    (0xC037001C, "VID_STOP_PENDING"),
+    (0xC037001D, "VID_INVALID_PROCESSOR_STATE"),
+    (0xC037001E, "VID_EXCEEDED_KM_CONTEXT_COUNT_LIMIT"),
+    (0xC037001F, "VID_KM_INTERFACE_ALREADY_INITIALIZED"),
+    (0xC0370020, "VID_MB_PROPERTY_ALREADY_SET_RESET"),
+    (0xC0370021, "VID_MMIO_RANGE_DESTROYED"),
+    (0xC0370022, "VID_INVALID_CHILD_GPA_PAGE_SET"),
+    (0xC0370023, "VID_RESERVE_PAGE_SET_IS_BEING_USED"),
+    (0xC0370024, "VID_RESERVE_PAGE_SET_TOO_SMALL"),
+    (0xC0370025, "VID_MBP_ALREADY_LOCKED_USING_RESERVED_PAGE"),
+    (0xC0370026, "VID_MBP_COUNT_EXCEEDED_LIMIT"),
+    (0xC0370027, "VID_SAVED_STATE_CORRUPT"),
+    (0xC0370028, "VID_SAVED_STATE_UNRECOGNIZED_ITEM"),
+    (0xC0370029, "VID_SAVED_STATE_INCOMPATIBLE"),
+    (0xC037002A, "VID_VTL_ACCESS_DENIED"),
+    (0xC037002B, "VID_INSUFFICIENT_RESOURCES_RESERVE"),
+    (0xC037002C, "VID_INSUFFICIENT_RESOURCES_PHYSICAL_BUFFER"),
+    (0xC037002D, "VID_INSUFFICIENT_RESOURCES_HV_DEPOSIT"),
+    (0xC037002E, "VID_MEMORY_TYPE_NOT_SUPPORTED"),
+    (0xC037002F, "VID_INSUFFICIENT_RESOURCES_WITHDRAW"),
+    (0xC0370030, "VID_PROCESS_ALREADY_SET"),
+    (0xC0370100, "VMCOMPUTE_TERMINATED_DURING_START"),
+    (0xC0370101, "VMCOMPUTE_IMAGE_MISMATCH"),
+    (0xC0370102, "VMCOMPUTE_HYPERV_NOT_INSTALLED"),
+    (0xC0370103, "VMCOMPUTE_OPERATION_PENDING"),
+    (0xC0370104, "VMCOMPUTE_TOO_MANY_NOTIFICATIONS"),
+    (0xC0370105, "VMCOMPUTE_INVALID_STATE"),
+    (0xC0370106, "VMCOMPUTE_UNEXPECTED_EXIT"),
+    (0xC0370107, "VMCOMPUTE_TERMINATED"),
+    (0xC0370108, "VMCOMPUTE_CONNECT_FAILED"),
+    (0xC0370109, "VMCOMPUTE_TIMEOUT"),
+    (0xC037010A, "VMCOMPUTE_CONNECTION_CLOSED"),
+    (0xC037010B, "VMCOMPUTE_UNKNOWN_MESSAGE"),
+    (0xC037010C, "VMCOMPUTE_UNSUPPORTED_PROTOCOL_VERSION"),
+    (0xC037010D, "VMCOMPUTE_INVALID_JSON"),
+    (0xC037010E, "VMCOMPUTE_SYSTEM_NOT_FOUND"),
+    (0xC037010F, "VMCOMPUTE_SYSTEM_ALREADY_EXISTS"),
+    (0xC0370110, "VMCOMPUTE_SYSTEM_ALREADY_STOPPED"),
+    (0xC0370111, "VMCOMPUTE_PROTOCOL_ERROR"),
+    (0xC0370112, "VMCOMPUTE_INVALID_LAYER"),
+    (0xC0370113, "VMCOMPUTE_WINDOWS_INSIDER_REQUIRED"),
+    (0xC0370200, "VNET_VIRTUAL_SWITCH_NAME_NOT_FOUND"),
+    (0xC0370400, "VSMB_SAVED_STATE_FILE_NOT_FOUND"),
+    (0xC0370401, "VSMB_SAVED_STATE_CORRUPT"),
+    (0xC0380001, "VOLMGR_DATABASE_FULL"),
+    (0xC0380002, "VOLMGR_DISK_CONFIGURATION_CORRUPTED"),
+    (0xC0380003, "VOLMGR_DISK_CONFIGURATION_NOT_IN_SYNC"),
+    (0xC0380004, "VOLMGR_PACK_CONFIG_UPDATE_FAILED"),
+    (0xC0380005, "VOLMGR_DISK_CONTAINS_NON_SIMPLE_VOLUME"),
+    (0xC0380006, "VOLMGR_DISK_DUPLICATE"),
+    (0xC0380007, "VOLMGR_DISK_DYNAMIC"),
+    (0xC0380008, "VOLMGR_DISK_ID_INVALID"),
+    (0xC0380009, "VOLMGR_DISK_INVALID"),
+    (0xC038000A, "VOLMGR_DISK_LAST_VOTER"),
+    (0xC038000B, "VOLMGR_DISK_LAYOUT_INVALID"),
+    (0xC038000C, "VOLMGR_DISK_LAYOUT_NON_BASIC_BETWEEN_BASIC_PARTITIONS"),
+    (0xC038000D, "VOLMGR_DISK_LAYOUT_NOT_CYLINDER_ALIGNED"),
+    (0xC038000E, "VOLMGR_DISK_LAYOUT_PARTITIONS_TOO_SMALL"),
+    (0xC038000F, "VOLMGR_DISK_LAYOUT_PRIMARY_BETWEEN_LOGICAL_PARTITIONS"),
+    (0xC0380010, "VOLMGR_DISK_LAYOUT_TOO_MANY_PARTITIONS"),
+    (0xC0380011, "VOLMGR_DISK_MISSING"),
+    (0xC0380012, "VOLMGR_DISK_NOT_EMPTY"),
+    (0xC0380013, "VOLMGR_DISK_NOT_ENOUGH_SPACE"),
+    (0xC0380014, "VOLMGR_DISK_REVECTORING_FAILED"),
+    (0xC0380015, "VOLMGR_DISK_SECTOR_SIZE_INVALID"),
+    (0xC0380016, "VOLMGR_DISK_SET_NOT_CONTAINED"),
+    (0xC0380017, "VOLMGR_DISK_USED_BY_MULTIPLE_MEMBERS"),
+    (0xC0380018, "VOLMGR_DISK_USED_BY_MULTIPLE_PLEXES"),
+    (0xC0380019, "VOLMGR_DYNAMIC_DISK_NOT_SUPPORTED"),
+    (0xC038001A, "VOLMGR_EXTENT_ALREADY_USED"),
+    (0xC038001B, "VOLMGR_EXTENT_NOT_CONTIGUOUS"),
+    (0xC038001C, "VOLMGR_EXTENT_NOT_IN_PUBLIC_REGION"),
+    (0xC038001D, "VOLMGR_EXTENT_NOT_SECTOR_ALIGNED"),
+    (0xC038001E, "VOLMGR_EXTENT_OVERLAPS_EBR_PARTITION"),
+    (0xC038001F, "VOLMGR_EXTENT_VOLUME_LENGTHS_DO_NOT_MATCH"),
+    (0xC0380020, "VOLMGR_FAULT_TOLERANT_NOT_SUPPORTED"),
+    (0xC0380021, "VOLMGR_INTERLEAVE_LENGTH_INVALID"),
+    (0xC0380022, "VOLMGR_MAXIMUM_REGISTERED_USERS"),
+    (0xC0380023, "VOLMGR_MEMBER_IN_SYNC"),
+    (0xC0380024, "VOLMGR_MEMBER_INDEX_DUPLICATE"),
+    (0xC0380025, "VOLMGR_MEMBER_INDEX_INVALID"),
+    (0xC0380026, "VOLMGR_MEMBER_MISSING"),
+    (0xC0380027, "VOLMGR_MEMBER_NOT_DETACHED"),
+    (0xC0380028, "VOLMGR_MEMBER_REGENERATING"),
+    (0xC0380029, "VOLMGR_ALL_DISKS_FAILED"),
+    (0xC038002A, "VOLMGR_NO_REGISTERED_USERS"),
+    (0xC038002B, "VOLMGR_NO_SUCH_USER"),
+    (0xC038002C, "VOLMGR_NOTIFICATION_RESET"),
+    (0xC038002D, "VOLMGR_NUMBER_OF_MEMBERS_INVALID"),
+    (0xC038002E, "VOLMGR_NUMBER_OF_PLEXES_INVALID"),
+    (0xC038002F, "VOLMGR_PACK_DUPLICATE"),
+    (0xC0380030, "VOLMGR_PACK_ID_INVALID"),
+    (0xC0380031, "VOLMGR_PACK_INVALID"),
+    (0xC0380032, "VOLMGR_PACK_NAME_INVALID"),
+    (0xC0380033, "VOLMGR_PACK_OFFLINE"),
+    (0xC0380034, "VOLMGR_PACK_HAS_QUORUM"),
+    (0xC0380035, "VOLMGR_PACK_WITHOUT_QUORUM"),
+    (0xC0380036, "VOLMGR_PARTITION_STYLE_INVALID"),
+    (0xC0380037, "VOLMGR_PARTITION_UPDATE_FAILED"),
+    (0xC0380038, "VOLMGR_PLEX_IN_SYNC"),
+    (0xC0380039, "VOLMGR_PLEX_INDEX_DUPLICATE"),
+    (0xC038003A, "VOLMGR_PLEX_INDEX_INVALID"),
+    (0xC038003B, "VOLMGR_PLEX_LAST_ACTIVE"),
+    (0xC038003C, "VOLMGR_PLEX_MISSING"),
+    (0xC038003D, "VOLMGR_PLEX_REGENERATING"),
+    (0xC038003E, "VOLMGR_PLEX_TYPE_INVALID"),
+    (0xC038003F, "VOLMGR_PLEX_NOT_RAID5"),
+    (0xC0380040, "VOLMGR_PLEX_NOT_SIMPLE"),
+    (0xC0380041, "VOLMGR_STRUCTURE_SIZE_INVALID"),
+    (0xC0380042, "VOLMGR_TOO_MANY_NOTIFICATION_REQUESTS"),
+    (0xC0380043, "VOLMGR_TRANSACTION_IN_PROGRESS"),
+    (0xC0380044, "VOLMGR_UNEXPECTED_DISK_LAYOUT_CHANGE"),
+    (0xC0380045, "VOLMGR_VOLUME_CONTAINS_MISSING_DISK"),
+    (0xC0380046, "VOLMGR_VOLUME_ID_INVALID"),
+    (0xC0380047, "VOLMGR_VOLUME_LENGTH_INVALID"),
+    (0xC0380048, "VOLMGR_VOLUME_LENGTH_NOT_SECTOR_SIZE_MULTIPLE"),
+    (0xC0380049, "VOLMGR_VOLUME_NOT_MIRRORED"),
+    (0xC038004A, "VOLMGR_VOLUME_NOT_RETAINED"),
+    (0xC038004B, "VOLMGR_VOLUME_OFFLINE"),
+    (0xC038004C, "VOLMGR_VOLUME_RETAINED"),
+    (0xC038004D, "VOLMGR_NUMBER_OF_EXTENTS_INVALID"),
+    (0xC038004E, "VOLMGR_DIFFERENT_SECTOR_SIZE"),
+    (0xC038004F, "VOLMGR_BAD_BOOT_DISK"),
+    (0xC0380050, "VOLMGR_PACK_CONFIG_OFFLINE"),
+    (0xC0380051, "VOLMGR_PACK_CONFIG_ONLINE"),
+    (0xC0380052, "VOLMGR_NOT_PRIMARY_PACK"),
+    (0xC0380053, "VOLMGR_PACK_LOG_UPDATE_FAILED"),
+    (0xC0380054, "VOLMGR_NUMBER_OF_DISKS_IN_PLEX_INVALID"),
+    (0xC0380055, "VOLMGR_NUMBER_OF_DISKS_IN_MEMBER_INVALID"),
+    (0xC0380056, "VOLMGR_VOLUME_MIRRORED"),
+    (0xC0380057, "VOLMGR_PLEX_NOT_SIMPLE_SPANNED"),
+    (0xC0380058, "VOLMGR_NO_VALID_LOG_COPIES"),
+    (0xC0380059, "VOLMGR_PRIMARY_PACK_PRESENT"),
+    (0xC038005A, "VOLMGR_NUMBER_OF_DISKS_INVALID"),
+    (0xC038005B, "VOLMGR_MIRROR_NOT_SUPPORTED"),
+    (0xC038005C, "VOLMGR_RAID5_NOT_SUPPORTED"),
+    (0xC0390002, "BCD_TOO_MANY_ELEMENTS"),
+    (0xC03A0001, "VHD_DRIVE_FOOTER_MISSING"),
+    (0xC03A0002, "VHD_DRIVE_FOOTER_CHECKSUM_MISMATCH"),
+    (0xC03A0003, "VHD_DRIVE_FOOTER_CORRUPT"),
+    (0xC03A0004, "VHD_FORMAT_UNKNOWN"),
+    (0xC03A0005, "VHD_FORMAT_UNSUPPORTED_VERSION"),
+    (0xC03A0006, "VHD_SPARSE_HEADER_CHECKSUM_MISMATCH"),
+    (0xC03A0007, "VHD_SPARSE_HEADER_UNSUPPORTED_VERSION"),
+    (0xC03A0008, "VHD_SPARSE_HEADER_CORRUPT"),
+    (0xC03A0009, "VHD_BLOCK_ALLOCATION_FAILURE"),
+    (0xC03A000A, "VHD_BLOCK_ALLOCATION_TABLE_CORRUPT"),
+    (0xC03A000B, "VHD_INVALID_BLOCK_SIZE"),
+    (0xC03A000C, "VHD_BITMAP_MISMATCH"),
+    (0xC03A000D, "VHD_PARENT_VHD_NOT_FOUND"),
+    (0xC03A000E, "VHD_CHILD_PARENT_ID_MISMATCH"),
+    (0xC03A000F, "VHD_CHILD_PARENT_TIMESTAMP_MISMATCH"),
+    (0xC03A0010, "VHD_METADATA_READ_FAILURE"),
+    (0xC03A0011, "VHD_METADATA_WRITE_FAILURE"),
+    (0xC03A0012, "VHD_INVALID_SIZE"),
+    (0xC03A0013, "VHD_INVALID_FILE_SIZE"),
+    (0xC03A0014, "VIRTDISK_PROVIDER_NOT_FOUND"),
+    (0xC03A0015, "VIRTDISK_NOT_VIRTUAL_DISK"),
+    (0xC03A0016, "VHD_PARENT_VHD_ACCESS_DENIED"),
+    (0xC03A0017, "VHD_CHILD_PARENT_SIZE_MISMATCH"),
+    (0xC03A0018, "VHD_DIFFERENCING_CHAIN_CYCLE_DETECTED"),
+    (0xC03A0019, "VHD_DIFFERENCING_CHAIN_ERROR_IN_PARENT"),
+    (0xC03A001A, "VIRTUAL_DISK_LIMITATION"),
+    (0xC03A001B, "VHD_INVALID_TYPE"),
+    (0xC03A001C, "VHD_INVALID_STATE"),
+    (0xC03A001D, "VIRTDISK_UNSUPPORTED_DISK_SECTOR_SIZE"),
+    (0xC03A001E, "VIRTDISK_DISK_ALREADY_OWNED"),
+    (0xC03A001F, "VIRTDISK_DISK_ONLINE_AND_WRITABLE"),
+    (0xC03A0020, "CTLOG_TRACKING_NOT_INITIALIZED"),
+    (0xC03A0021, "CTLOG_LOGFILE_SIZE_EXCEEDED_MAXSIZE"),
+    (0xC03A0022, "CTLOG_VHD_CHANGED_OFFLINE"),
+    (0xC03A0023, "CTLOG_INVALID_TRACKING_STATE"),
+    (0xC03A0024, "CTLOG_INCONSISTENT_TRACKING_FILE"),
+    (0xC03A0025, "VHD_RESIZE_WOULD_TRUNCATE_DATA"),
+    (0xC03A0026, "VHD_COULD_NOT_COMPUTE_MINIMUM_VIRTUAL_SIZE"),
+    (0xC03A0027, "VHD_ALREADY_AT_OR_BELOW_MINIMUM_VIRTUAL_SIZE"),
+    (0xC03A0028, "VHD_METADATA_FULL"),
+    (0xC03A0029, "VHD_INVALID_CHANGE_TRACKING_ID"),
+    (0xC03A002A, "VHD_CHANGE_TRACKING_DISABLED"),
+    (0xC03A0030, "VHD_MISSING_CHANGE_TRACKING_INFORMATION"),
+    (0xC03A0034, "VHD_UNEXPECTED_ID"),
+    (0xE0000000, "EXPECTED_SECTION_NAME"),
+    (0xE0000001, "BAD_SECTION_NAME_LINE"),
+    (0xE0000002, "SECTION_NAME_TOO_LONG"),
+    (0xE0000003, "GENERAL_SYNTAX"),
+    (0xE0000100, "WRONG_INF_STYLE"),
+    (0xE0000101, "SECTION_NOT_FOUND"),
+    (0xE0000102, "LINE_NOT_FOUND"),
+    (0xE0000103, "NO_BACKUP"),
+    (0xE0000200, "NO_ASSOCIATED_CLASS"),
+    (0xE0000201, "CLASS_MISMATCH"),
+    (0xE0000202, "DUPLICATE_FOUND"),
+    (0xE0000203, "NO_DRIVER_SELECTED"),
+    (0xE0000204, "KEY_DOES_NOT_EXIST"),
+    (0xE0000205, "INVALID_DEVINST_NAME"),
+    (0xE0000206, "INVALID_CLASS"),
+    (0xE0000207, "DEVINST_ALREADY_EXISTS"),
+    (0xE0000208, "DEVINFO_NOT_REGISTERED"),
+    (0xE0000209, "INVALID_REG_PROPERTY"),
+    (0xE000020A, "NO_INF"),
+    (0xE000020B, "NO_SUCH_DEVINST"),
+    (0xE000020C, "CANT_LOAD_CLASS_ICON"),
+    (0xE000020D, "INVALID_CLASS_INSTALLER"),
+    (0xE000020E, "DI_DO_DEFAULT"),
+    (0xE000020F, "DI_NOFILECOPY"),
+    (0xE0000210, "INVALID_HWPROFILE"),
+    (0xE0000211, "NO_DEVICE_SELECTED"),
+    (0xE0000212, "DEVINFO_LIST_LOCKED"),
+    (0xE0000213, "DEVINFO_DATA_LOCKED"),
+    (0xE0000214, "DI_BAD_PATH"),
+    (0xE0000215, "NO_CLASSINSTALL_PARAMS"),
+    (0xE0000216, "FILEQUEUE_LOCKED"),
+    (0xE0000217, "BAD_SERVICE_INSTALLSECT"),
+    (0xE0000218, "NO_CLASS_DRIVER_LIST"),
+    (0xE0000219, "NO_ASSOCIATED_SERVICE"),
+    (0xE000021A, "NO_DEFAULT_DEVICE_INTERFACE"),
+    (0xE000021B, "DEVICE_INTERFACE_ACTIVE"),
+    (0xE000021C, "DEVICE_INTERFACE_REMOVED"),
+    (0xE000021D, "BAD_INTERFACE_INSTALLSECT"),
+    (0xE000021E, "NO_SUCH_INTERFACE_CLASS"),
+    (0xE000021F, "INVALID_REFERENCE_STRING"),
+    (0xE0000220, "INVALID_MACHINENAME"),
+    (0xE0000221, "REMOTE_COMM_FAILURE"),
+    (0xE0000222, "MACHINE_UNAVAILABLE"),
+    (0xE0000223, "NO_CONFIGMGR_SERVICES"),
+    (0xE0000224, "INVALID_PROPPAGE_PROVIDER"),
+    (0xE0000225, "NO_SUCH_DEVICE_INTERFACE"),
+    (0xE0000226, "DI_POSTPROCESSING_REQUIRED"),
+    (0xE0000227, "INVALID_COINSTALLER"),
+    (0xE0000228, "NO_COMPAT_DRIVERS"),
+    (0xE0000229, "NO_DEVICE_ICON"),
+    (0xE000022A, "INVALID_INF_LOGCONFIG"),
+    (0xE000022B, "DI_DONT_INSTALL"),
+    (0xE000022C, "INVALID_FILTER_DRIVER"),
+    (0xE000022D, "NON_WINDOWS_NT_DRIVER"),
+    (0xE000022E, "NON_WINDOWS_DRIVER"),
+    (0xE000022F, "NO_CATALOG_FOR_OEM_INF"),
+    (0xE0000230, "DEVINSTALL_QUEUE_NONNATIVE"),
+    (0xE0000231, "NOT_DISABLEABLE"),
+    (0xE0000232, "CANT_REMOVE_DEVINST"),
+    (0xE0000233, "INVALID_TARGET"),
+    (0xE0000234, "DRIVER_NONNATIVE"),
+    (0xE0000235, "IN_WOW64"),
+    (0xE0000236, "SET_SYSTEM_RESTORE_POINT"),
+    (0xE0000238, "SCE_DISABLED"),
+    (0xE0000239, "UNKNOWN_EXCEPTION"),
+    (0xE000023A, "PNP_REGISTRY_ERROR"),
+    (0xE000023B, "REMOTE_REQUEST_UNSUPPORTED"),
+    (0xE000023C, "NOT_AN_INSTALLED_OEM_INF"),
+    (0xE000023D, "INF_IN_USE_BY_DEVICES"),
+    (0xE000023E, "DI_FUNCTION_OBSOLETE"),
+    (0xE000023F, "NO_AUTHENTICODE_CATALOG"),
+    (0xE0000240, "AUTHENTICODE_DISALLOWED"),
+    (0xE0000241, "AUTHENTICODE_TRUSTED_PUBLISHER"),
+    (0xE0000242, "AUTHENTICODE_TRUST_NOT_ESTABLISHED"),
+    (0xE0000243, "AUTHENTICODE_PUBLISHER_NOT_TRUSTED"),
+    (0xE0000244, "SIGNATURE_OSATTRIBUTE_MISMATCH"),
+    (0xE0000245, "ONLY_VALIDATE_VIA_AUTHENTICODE"),
+    (0xE0000246, "DEVICE_INSTALLER_NOT_READY"),
+    (0xE0000247, "DRIVER_STORE_ADD_FAILED"),
+    (0xE0000248, "DEVICE_INSTALL_BLOCKED"),
+    (0xE0000249, "DRIVER_INSTALL_BLOCKED"),
+    (0xE000024A, "WRONG_INF_TYPE"),
+    (0xE000024B, "FILE_HASH_NOT_IN_CATALOG"),
+    (0xE000024C, "DRIVER_STORE_DELETE_FAILED"),
+    (0xE0000300, "UNRECOVERABLE_STACK_OVERFLOW"),
+    (0xE0001000, "NOT_INSTALLED"),
+];
+
+/// NTSTATUS codes and their names, sorted by code.
+pub const NTSTATUS_NAMES: &[(u32, &str)] = &[
+    (0x00000000, "STATUS_SUCCESS"),
+    (0x00000001, "STATUS_WAIT_1"),
+    (0x00000002, "STATUS_WAIT_2"),
+    (0x00000003, "STATUS_WAIT_3"),
+    (0x0000003F, "STATUS_WAIT_63"),
+    (0x00000080, "STATUS_ABANDONED"),
+    (0x000000BF, "STATUS_ABANDONED_WAIT_63"),
+    (0x000000C0, "STATUS_USER_APC"),
+    (0x000000FF, "STATUS_ALREADY_COMPLETE"),
+    (0x00000100, "STATUS_KERNEL_APC"),
+    (0x00000101, "STATUS_ALERTED"),
+    (0x00000102, "STATUS_TIMEOUT"),
+    (0x00000103, "STATUS_PENDING"),
+    (0x00000104, "STATUS_REPARSE"),
+    (0x00000105, "STATUS_MORE_ENTRIES"),
+    (0x00000106, "STATUS_NOT_ALL_ASSIGNED"),
+    (0x00000107, "STATUS_SOME_NOT_MAPPED"),
+    (0x00000108, "STATUS_OPLOCK_BREAK_IN_PROGRESS"),
+    (0x00000109, "STATUS_VOLUME_MOUNTED"),
+    (0x0000010A, "STATUS_RXACT_COMMITTED"),
+    (0x0000010B, "STATUS_NOTIFY_CLEANUP"),
+    (0x0000010C, "STATUS_NOTIFY_ENUM_DIR"),
+    (0x0000010D, "STATUS_NO_QUOTAS_FOR_ACCOUNT"),
+    (0x0000010E, "STATUS_PRIMARY_TRANSPORT_CONNECT_FAILED"),
+    (0x00000110, "STATUS_PAGE_FAULT_TRANSITION"),
+    (0x00000111, "STATUS_PAGE_FAULT_DEMAND_ZERO"),
+    (0x00000112, "STATUS_PAGE_FAULT_COPY_ON_WRITE"),
+    (0x00000113, "STATUS_PAGE_FAULT_GUARD_PAGE"),
+    (0x00000114, "STATUS_PAGE_FAULT_PAGING_FILE"),
+    (0x00000115, "STATUS_CACHE_PAGE_LOCKED"),
+    (0x00000116, "STATUS_CRASH_DUMP"),
+    (0x00000117, "STATUS_BUFFER_ALL_ZEROS"),
+    (0x00000118, "STATUS_REPARSE_OBJECT"),
+    (0x00000119, "STATUS_RESOURCE_REQUIREMENTS_CHANGED"),
+    (0x00000120, "STATUS_TRANSLATION_COMPLETE"),
+    (0x00000121, "STATUS_DS_MEMBERSHIP_EVALUATED_LOCALLY"),
+    (0x00000122, "STATUS_NOTHING_TO_TERMINATE"),
+    (0x00000123, "STATUS_PROCESS_NOT_IN_JOB"),
+    (0x00000124, "STATUS_PROCESS_IN_JOB"),
+    (0x00000125, "STATUS_VOLSNAP_HIBERNATE_READY"),
+    (0x00000126, "STATUS_FSFILTER_OP_COMPLETED_SUCCESSFULLY"),
+    (0x00000127, "STATUS_INTERRUPT_VECTOR_ALREADY_CONNECTED"),
+    (0x00000128, "STATUS_INTERRUPT_STILL_CONNECTED"),
+    (0x00000129, "STATUS_PROCESS_CLONED"),
+    (0x0000012A, "STATUS_FILE_LOCKED_WITH_ONLY_READERS"),
+    (0x0000012B, "STATUS_FILE_LOCKED_WITH_WRITERS"),
+    (0x0000012C, "STATUS_VALID_IMAGE_HASH"),
+    (0x0000012D, "STATUS_VALID_CATALOG_HASH"),
+    (0x0000012E, "STATUS_VALID_STRONG_CODE_HASH"),
+    (0x0000012F, "STATUS_GHOSTED"),
+    (0x00000130, "STATUS_DATA_OVERWRITTEN"),
+    (0x00000202, "STATUS_RESOURCEMANAGER_READ_ONLY"),
+    (0x00000210, "STATUS_RING_PREVIOUSLY_EMPTY"),
+    (0x00000211, "STATUS_RING_PREVIOUSLY_FULL"),
+    (0x00000212, "STATUS_RING_PREVIOUSLY_ABOVE_QUOTA"),
+    (0x00000213, "STATUS_RING_NEWLY_EMPTY"),
+    (0x00000214, "STATUS_RING_SIGNAL_OPPOSITE_ENDPOINT"),
+    (0x00000215, "STATUS_OPLOCK_SWITCHED_TO_NEW_HANDLE"),
+    (0x00000216, "STATUS_OPLOCK_HANDLE_CLOSED"),
+    (0x00000367, "STATUS_WAIT_FOR_OPLOCK"),
+    (0x00000368, "STATUS_REPARSE_GLOBAL"),
+    (0x00000369, "STATUS_PAGE_FAULT_RETRY"),
+    (0x00010001, "DBG_EXCEPTION_HANDLED"),
+    (0x00010002, "DBG_CONTINUE"),
+    (0x00040001, "IO_ERR_RETRY_SUCCEEDED"),
+    (0x000400A2, "IO_DUMP_CREATION_SUCCESS"),
+    (0x001C0001, "STATUS_FLT_IO_COMPLETE"),
+    (0x00293000, "STATUS_RTPM_CONTEXT_CONTINUE"),
+    (0x00293001, "STATUS_RTPM_CONTEXT_COMPLETE"),
+    (0x00350059, "STATUS_HV_PENDING_PAGE_REQUESTS"),
+    (0x00E70000, "STATUS_SPACES_REPAIRED"),
+    (0x00E70001, "STATUS_SPACES_PAUSE"),
+    (0x00E70002, "STATUS_SPACES_COMPLETE"),
+    (0x00E70003, "STATUS_SPACES_REDIRECT"),
+    (0x40000000, "STATUS_OBJECT_NAME_EXISTS"),
+    (0x40000001, "STATUS_THREAD_WAS_SUSPENDED"),
+    (0x40000002, "STATUS_WORKING_SET_LIMIT_RANGE"),
+    (0x40000003, "STATUS_IMAGE_NOT_AT_BASE"),
+    (0x40000004, "STATUS_RXACT_STATE_CREATED"),
+    (0x40000005, "STATUS_SEGMENT_NOTIFICATION"),
+    (0x40000006, "STATUS_LOCAL_USER_SESSION_KEY"),
+    (0x40000007, "STATUS_BAD_CURRENT_DIRECTORY"),
+    (0x40000008, "STATUS_SERIAL_MORE_WRITES"),
+    (0x40000009, "STATUS_REGISTRY_RECOVERED"),
+    (0x4000000A, "STATUS_FT_READ_RECOVERY_FROM_BACKUP"),
+    (0x4000000B, "STATUS_FT_WRITE_RECOVERY"),
+    (0x4000000C, "STATUS_SERIAL_COUNTER_TIMEOUT"),
+    (0x4000000D, "STATUS_NULL_LM_PASSWORD"),
+    (0x4000000E, "STATUS_IMAGE_MACHINE_TYPE_MISMATCH"),
+    (0x4000000F, "STATUS_RECEIVE_PARTIAL"),
+    (0x40000010, "STATUS_RECEIVE_EXPEDITED"),
+    (0x40000011, "STATUS_RECEIVE_PARTIAL_EXPEDITED"),
+    (0x40000012, "STATUS_EVENT_DONE"),
+    (0x40000013, "STATUS_EVENT_PENDING"),
+    (0x40000014, "STATUS_CHECKING_FILE_SYSTEM"),
+    (0x40000015, "STATUS_FATAL_APP_EXIT"),
+    (0x40000016, "STATUS_PREDEFINED_HANDLE"),
+    (0x40000017, "STATUS_WAS_UNLOCKED"),
+    (0x40000018, "STATUS_SERVICE_NOTIFICATION"),
+    (0x40000019, "STATUS_WAS_LOCKED"),
+    (0x4000001A, "STATUS_LOG_HARD_ERROR"),
+    (0x4000001B, "STATUS_ALREADY_WIN32"),
+    (0x4000001C, "STATUS_WX86_UNSIMULATE"),
+    (0x4000001D, "STATUS_WX86_CONTINUE"),
+    (0x4000001E, "STATUS_WX86_SINGLE_STEP"),
+    (0x4000001F, "STATUS_WX86_BREAKPOINT"),
+    (0x40000020, "STATUS_WX86_EXCEPTION_CONTINUE"),
+    (0x40000021, "STATUS_WX86_EXCEPTION_LASTCHANCE"),
+    (0x40000022, "STATUS_WX86_EXCEPTION_CHAIN"),
+    (0x40000023, "STATUS_IMAGE_MACHINE_TYPE_MISMATCH_EXE"),
+    (0x40000024, "STATUS_NO_YIELD_PERFORMED"),
+    (0x40000025, "STATUS_TIMER_RESUME_IGNORED"),
+    (0x40000026, "STATUS_ARBITRATION_UNHANDLED"),
+    (0x40000027, "STATUS_CARDBUS_NOT_SUPPORTED"),
+    (0x40000028, "STATUS_WX86_CREATEWX86TIB"),
+    (0x40000029, "STATUS_MP_PROCESSOR_MISMATCH"),
+    (0x4000002A, "STATUS_HIBERNATED"),
+    (0x4000002B, "STATUS_RESUME_HIBERNATION"),
+    (0x4000002C, "STATUS_FIRMWARE_UPDATED"),
+    (0x4000002D, "STATUS_DRIVERS_LEAKING_LOCKED_PAGES"),
+    (0x4000002E, "STATUS_MESSAGE_RETRIEVED"),
+    (0x4000002F, "STATUS_SYSTEM_POWERSTATE_TRANSITION"),
+    (0x40000030, "STATUS_ALPC_CHECK_COMPLETION_LIST"),
+    (0x40000031, "STATUS_SYSTEM_POWERSTATE_COMPLEX_TRANSITION"),
+    (0x40000032, "STATUS_ACCESS_AUDIT_BY_POLICY"),
+    (0x40000033, "STATUS_ABANDON_HIBERFILE"),
+    (0x40000034, "STATUS_BIZRULES_NOT_ENABLED"),
+    (0x40000035, "STATUS_FT_READ_FROM_COPY"),
+    (0x40000036, "STATUS_IMAGE_AT_DIFFERENT_BASE"),
+    (0x40000037, "STATUS_PATCH_DEFERRED"),
+    (0x40000038, "STATUS_EMULATION_BREAKPOINT"),
+    (0x40000039, "STATUS_EMULATION_SYSCALL"),
+    (0x40000294, "STATUS_WAKE_SYSTEM"),
+    (0x40000370, "STATUS_DS_SHUTTING_DOWN"),
+    (0x40000807, "STATUS_DISK_REPAIR_REDIRECTED"),
+    (0x4000A144, "STATUS_SERVICES_FAILED_AUTOSTART"),
+    (0x40010001, "DBG_REPLY_LATER"),
+    (0x40010002, "DBG_UNABLE_TO_PROVIDE_HANDLE"),
+    (0x40010003, "DBG_TERMINATE_THREAD"),
+    (0x40010004, "DBG_TERMINATE_PROCESS"),
+    (0x40010005, "DBG_CONTROL_C"),
+    (0x40010006, "DBG_PRINTEXCEPTION_C"),
+    (0x40010007, "DBG_RIPEXCEPTION"),
+    (0x40010008, "DBG_CONTROL_BREAK"),
+    (0x40010009, "DBG_COMMAND_EXCEPTION"),
+    (0x4001000A, "DBG_PRINTEXCEPTION_WIDE_C"),
+    (0x40020056, "RPC_NT_UUID_LOCAL_ONLY"),
+    (0x400200AF, "RPC_NT_SEND_INCOMPLETE"),
+    (0x40040024, "IO_FILE_QUOTA_THRESHOLD"),
+    (0x40040025, "IO_FILE_QUOTA_LIMIT"),
+    (0x40040026, "IO_FILE_QUOTA_STARTED"),
+    (0x40040027, "IO_FILE_QUOTA_SUCCEEDED"),
+    (0x40040077, "IO_INFO_THROTTLE_COMPLETE"),
+    (0x40040085, "IO_CDROM_EXCLUSIVE_LOCK"),
+    (0x4004009F, "IO_WARNING_DISK_FIRMWARE_UPDATED"),
+    (0x400400A0, "IO_WARNING_ADAPTER_FIRMWARE_UPDATED"),
+    (0x40050070, "MCA_INFO_CPU_THERMAL_THROTTLING_REMOVED"),
+    (0x40050073, "MCA_INFO_NO_MORE_CORRECTED_ERROR_LOGS"),
+    (0x40050074, "MCA_INFO_MEMORY_PAGE_MARKED_BAD"),
+    (0x400A0004, "STATUS_CTX_CDM_CONNECT"),
+    (0x400A0005, "STATUS_CTX_CDM_DISCONNECT"),
+    (0x4015000D, "STATUS_SXS_RELEASE_ACTIVATION_CONTEXT"),
+    (0x40190001, "STATUS_HEURISTIC_DAMAGE_POSSIBLE"),
+    (0x40190034, "STATUS_RECOVERY_NOT_NEEDED"),
+    (0x40190035, "STATUS_RM_ALREADY_STARTED"),
+    (0x401A000C, "STATUS_LOG_NO_RESTART"),
+    (0x401B00EC, "STATUS_VIDEO_DRIVER_DEBUG_REPORT_REQUEST"),
+    (0x401E000A, "STATUS_GRAPHICS_PARTIAL_DATA_POPULATED"),
+    (0x401E0201, "STATUS_GRAPHICS_SKIP_ALLOCATION_PREPARATION"),
+    (0x401E0307, "STATUS_GRAPHICS_MODE_NOT_PINNED"),
+    (0x401E031E, "STATUS_GRAPHICS_NO_PREFERRED_MODE"),
+    (0x401E034B, "STATUS_GRAPHICS_DATASET_IS_EMPTY"),
+    (0x401E034C, "STATUS_GRAPHICS_NO_MORE_ELEMENTS_IN_DATASET"),
+    (0x401E0351, "STATUS_GRAPHICS_PATH_CONTENT_GEOMETRY_TRANSFORMATION_NOT_PINNED"),
+    (0x401E042F, "STATUS_GRAPHICS_UNKNOWN_CHILD_STATUS"),
+    (0x401E0437, "STATUS_GRAPHICS_LEADLINK_START_DEFERRED"),
+    (0x401E0439, "STATUS_GRAPHICS_POLLING_TOO_FREQUENTLY"),
+    (0x401E043A, "STATUS_GRAPHICS_START_DEFERRED"),
+    (0x401E043C, "STATUS_GRAPHICS_DEPENDABLE_CHILD_STATUS"),
+    (0x40230001, "STATUS_NDIS_INDICATION_REQUIRED"),
+    (0x40292023, "STATUS_PCP_UNSUPPORTED_PSS_SALT"),
+    (0x80000001, "STATUS_GUARD_PAGE_VIOLATION"),
+    (0x80000002, "STATUS_DATATYPE_MISALIGNMENT"),
+    (0x80000003, "STATUS_BREAKPOINT"),
+    (0x80000004, "STATUS_SINGLE_STEP"),
+    (0x80000005, "STATUS_BUFFER_OVERFLOW"),
+    (0x80000006, "STATUS_NO_MORE_FILES"),
+    (0x80000007, "STATUS_WAKE_SYSTEM_DEBUGGER"),
+    (0x8000000A, "STATUS_HANDLES_CLOSED"),
+    (0x8000000B, "STATUS_NO_INHERITANCE"),
+    (0x8000000C, "STATUS_GUID_SUBSTITUTION_MADE"),
+    (0x8000000D, "STATUS_PARTIAL_COPY"),
+    (0x8000000E, "STATUS_DEVICE_PAPER_EMPTY"),
+    (0x8000000F, "STATUS_DEVICE_POWERED_OFF"),
+    (0x80000010, "STATUS_DEVICE_OFF_LINE"),
+    (0x80000011, "STATUS_DEVICE_BUSY"),
+    (0x80000012, "STATUS_NO_MORE_EAS"),
+    (0x80000013, "STATUS_INVALID_EA_NAME"),
+    (0x80000014, "STATUS_EA_LIST_INCONSISTENT"),
+    (0x80000015, "STATUS_INVALID_EA_FLAG"),
+    (0x80000016, "STATUS_VERIFY_REQUIRED"),
+    (0x80000017, "STATUS_EXTRANEOUS_INFORMATION"),
+    (0x80000018, "STATUS_RXACT_COMMIT_NECESSARY"),
+    (0x8000001A, "STATUS_NO_MORE_ENTRIES"),
+    (0x8000001B, "STATUS_FILEMARK_DETECTED"),
+    (0x8000001C, "STATUS_MEDIA_CHANGED"),
+    (0x8000001D, "STATUS_BUS_RESET"),
+    (0x8000001E, "STATUS_END_OF_MEDIA"),
+    (0x8000001F, "STATUS_BEGINNING_OF_MEDIA"),
+    (0x80000020, "STATUS_MEDIA_CHECK"),
+    (0x80000021, "STATUS_SETMARK_DETECTED"),
+    (0x80000022, "STATUS_NO_DATA_DETECTED"),
+    (0x80000023, "STATUS_REDIRECTOR_HAS_OPEN_HANDLES"),
+    (0x80000024, "STATUS_SERVER_HAS_OPEN_HANDLES"),
+    (0x80000025, "STATUS_ALREADY_DISCONNECTED"),
+    (0x80000026, "STATUS_LONGJUMP"),
+    (0x80000027, "STATUS_CLEANER_CARTRIDGE_INSTALLED"),
+    (0x80000028, "STATUS_PLUGPLAY_QUERY_VETOED"),
+    (0x80000029, "STATUS_UNWIND_CONSOLIDATE"),
+    (0x8000002A, "STATUS_REGISTRY_HIVE_RECOVERED"),
+    (0x8000002B, "STATUS_DLL_MIGHT_BE_INSECURE"),
+    (0x8000002C, "STATUS_DLL_MIGHT_BE_INCOMPATIBLE"),
+    (0x8000002D, "STATUS_STOPPED_ON_SYMLINK"),
+    (0x8000002E, "STATUS_CANNOT_GRANT_REQUESTED_OPLOCK"),
+    (0x8000002F, "STATUS_NO_ACE_CONDITION"),
+    (0x80000030, "STATUS_DEVICE_SUPPORT_IN_PROGRESS"),
+    (0x80000031, "STATUS_DEVICE_POWER_CYCLE_REQUIRED"),
+    (0x80000032, "STATUS_NO_WORK_DONE"),
+    (0x80000033, "STATUS_RETURN_ADDRESS_HIJACK_ATTEMPT"),
+    (0x80000034, "STATUS_RECOVERABLE_BUGCHECK"),
+    (0x800001B6, "STATUS_DEVICE_RESET_REQUIRED"),
+    (0x80000288, "STATUS_DEVICE_REQUIRES_CLEANING"),
+    (0x80000289, "STATUS_DEVICE_DOOR_OPEN"),
+    (0x80000803, "STATUS_DATA_LOST_REPAIR"),
+    (0x8000A127, "STATUS_GPIO_INTERRUPT_ALREADY_UNMASKED"),
+    (0x8000CF00, "STATUS_CLOUD_FILE_PROPERTY_BLOB_CHECKSUM_MISMATCH"),
+    (0x8000CF04, "STATUS_CLOUD_FILE_PROPERTY_BLOB_TOO_LARGE"),
+    (0x8000CF05, "STATUS_CLOUD_FILE_TOO_MANY_PROPERTY_BLOBS"),
+    (0x80010001, "DBG_EXCEPTION_NOT_HANDLED"),
+    (0x8004001A, "IO_WRN_BAD_FIRMWARE"),
+    (0x80040020, "IO_WRITE_CACHE_ENABLED"),
+    (0x80040021, "IO_RECOVERED_VIA_ECC"),
+    (0x80040022, "IO_WRITE_CACHE_DISABLED"),
+    (0x80040028, "IO_FILE_QUOTA_FAILED"),
+    (0x80040032, "IO_LOST_DELAYED_WRITE"),
+    (0x80040033, "IO_WARNING_PAGING_FAILURE"),
+    (0x80040034, "IO_WRN_FAILURE_PREDICTED"),
+    (0x80040035, "IO_WARNING_INTERRUPT_STILL_PENDING"),
+    (0x80040036, "IO_DRIVER_CANCEL_TIMEOUT"),
+    (0x80040038, "IO_WARNING_ALLOCATION_FAILED"),
+    (0x80040039, "IO_WARNING_LOG_FLUSH_FAILED"),
+    (0x8004003A, "IO_WARNING_DUPLICATE_SIGNATURE"),
+    (0x8004003B, "IO_WARNING_DUPLICATE_PATH"),
+    (0x80040076, "IO_WARNING_BUS_RESET"),
+    (0x80040081, "IO_WARNING_RESET"),
+    (0x80040084, "IO_WARNING_WRITE_FUA_PROBLEM"),
+    (0x8004008B, "IO_LOST_DELAYED_WRITE_NETWORK_DISCONNECTED"),
+    (0x8004008C, "IO_LOST_DELAYED_WRITE_NETWORK_SERVER_ERROR"),
+    (0x8004008D, "IO_LOST_DELAYED_WRITE_NETWORK_LOCAL_DISK_ERROR"),
+    (0x8004008E, "IO_WARNING_VOLUME_LOST_DISK_EXTENT"),
+    (0x8004008F, "IO_WARNING_DEVICE_HAS_INTERNAL_DUMP"),
+    (0x80040090, "IO_WARNING_SOFT_THRESHOLD_REACHED"),
+    (0x80040091, "IO_WARNING_SOFT_THRESHOLD_REACHED_EX"),
+    (0x80040092, "IO_WARNING_SOFT_THRESHOLD_REACHED_EX_LUN_LUN"),
+    (0x80040093, "IO_WARNING_SOFT_THRESHOLD_REACHED_EX_LUN_POOL"),
+    (0x80040094, "IO_WARNING_SOFT_THRESHOLD_REACHED_EX_POOL_LUN"),
+    (0x80040095, "IO_WARNING_SOFT_THRESHOLD_REACHED_EX_POOL_POOL"),
+    (0x80040097, "IO_WARNING_DISK_CAPACITY_CHANGED"),
+    (0x80040098, "IO_WARNING_DISK_PROVISIONING_TYPE_CHANGED"),
+    (0x80040099, "IO_WARNING_IO_OPERATION_RETRIED"),
+    (0x8004009B, "IO_WARNING_COMPLETION_TIME"),
+    (0x8004009C, "IO_WARNING_DUMP_DISABLED_DEVICE_GONE"),
+    (0x8004009D, "IO_WARNING_DISK_SURPRISE_REMOVED"),
+    (0x8004009E, "IO_WARNING_REPEATED_DISK_GUID"),
+    (0x8005003C, "MCA_WARNING_CACHE"),
+    (0x8005003E, "MCA_WARNING_TLB"),
+    (0x80050040, "MCA_WARNING_CPU_BUS"),
+    (0x80050042, "MCA_WARNING_REGISTER_FILE"),
+    (0x80050044, "MCA_WARNING_MAS"),
+    (0x80050046, "MCA_WARNING_MEM_UNKNOWN"),
+    (0x80050048, "MCA_WARNING_MEM_1_2"),
+    (0x8005004A, "MCA_WARNING_MEM_1_2_5"),
+    (0x8005004C, "MCA_WARNING_MEM_1_2_5_4"),
+    (0x8005004E, "MCA_WARNING_SYSTEM_EVENT"),
+    (0x80050050, "MCA_WARNING_PCI_BUS_PARITY"),
+    (0x80050052, "MCA_WARNING_PCI_BUS_PARITY_NO_INFO"),
+    (0x80050054, "MCA_WARNING_PCI_BUS_SERR"),
+    (0x80050056, "MCA_WARNING_PCI_BUS_SERR_NO_INFO"),
+    (0x80050058, "MCA_WARNING_PCI_BUS_MASTER_ABORT"),
+    (0x8005005A, "MCA_WARNING_PCI_BUS_MASTER_ABORT_NO_INFO"),
+    (0x8005005C, "MCA_WARNING_PCI_BUS_TIMEOUT"),
+    (0x8005005E, "MCA_WARNING_PCI_BUS_TIMEOUT_NO_INFO"),
+    (0x80050060, "MCA_WARNING_PCI_BUS_UNKNOWN"),
+    (0x80050062, "MCA_WARNING_PCI_DEVICE"),
+    (0x80050064, "MCA_WARNING_SMBIOS"),
+    (0x80050066, "MCA_WARNING_PLATFORM_SPECIFIC"),
+    (0x80050068, "MCA_WARNING_UNKNOWN"),
+    (0x8005006A, "MCA_WARNING_UNKNOWN_NO_CPU"),
+    (0x8005006D, "MCA_WARNING_CMC_THRESHOLD_EXCEEDED"),
+    (0x8005006E, "MCA_WARNING_CPE_THRESHOLD_EXCEEDED"),
+    (0x8005006F, "MCA_WARNING_CPU_THERMAL_THROTTLED"),
+    (0x80050071, "MCA_WARNING_CPU"),
+    (0x80130001, "STATUS_CLUSTER_NODE_ALREADY_UP"),
+    (0x80130002, "STATUS_CLUSTER_NODE_ALREADY_DOWN"),
+    (0x80130003, "STATUS_CLUSTER_NETWORK_ALREADY_ONLINE"),
+    (0x80130004, "STATUS_CLUSTER_NETWORK_ALREADY_OFFLINE"),
+    (0x80130005, "STATUS_CLUSTER_NODE_ALREADY_MEMBER"),
+    (0x80190009, "STATUS_COULD_NOT_RESIZE_LOG"),
+    (0x80190029, "STATUS_NO_TXF_METADATA"),
+    (0x80190031, "STATUS_CANT_RECOVER_WITH_HANDLE_OPEN"),
+    (0x80190041, "STATUS_TXF_METADATA_ALREADY_PRESENT"),
+    (0x80190042, "STATUS_TRANSACTION_SCOPE_CALLBACKS_NOT_SET"),
+    (0x801B00EB, "STATUS_VIDEO_HUNG_DISPLAY_DRIVER_THREAD_RECOVERED"),
+    (0x801C0001, "STATUS_FLT_BUFFER_TOO_SMALL"),
+    (0x801E0000, "STATUS_GRAPHICS_LINK_CONFIGURATION_IN_PROGRESS"),
+    (0x80210001, "STATUS_FVE_PARTIAL_METADATA"),
+    (0x80210002, "STATUS_FVE_TRANSIENT_STATE"),
+    (0x80370001, "STATUS_VID_REMOTE_NODE_PARENT_GPA_PAGES_USED"),
+    (0x80380001, "STATUS_VOLMGR_INCOMPLETE_REGENERATION"),
+    (0x80380002, "STATUS_VOLMGR_INCOMPLETE_DISK_MIGRATION"),
+    (0x80380003, "VOLMGR_KSR_BYPASS"),
+    (0x80390001, "STATUS_BCD_NOT_ALL_ENTRIES_IMPORTED"),
+    (0x80390003, "STATUS_BCD_NOT_ALL_ENTRIES_SYNCHRONIZED"),
+    (0x803A0001, "STATUS_QUERY_STORAGE_ERROR"),
+    (0x803F0001, "STATUS_GDI_HANDLE_LEAK"),
+    (0x80430006, "STATUS_SECUREBOOT_NOT_ENABLED"),
+    (0xC0000001, "STATUS_UNSUCCESSFUL"),
+    (0xC0000002, "STATUS_NOT_IMPLEMENTED"),
+    (0xC0000003, "STATUS_INVALID_INFO_CLASS"),
+    (0xC0000004, "STATUS_INFO_LENGTH_MISMATCH"),
+    (0xC0000005, "STATUS_ACCESS_VIOLATION"),
+    (0xC0000006, "STATUS_IN_PAGE_ERROR"),
+    (0xC0000007, "STATUS_PAGEFILE_QUOTA"),
+    (0xC0000008, "STATUS_INVALID_HANDLE"),
+    (0xC0000009, "STATUS_BAD_INITIAL_STACK"),
+    (0xC000000A, "STATUS_BAD_INITIAL_PC"),
+    (0xC000000B, "STATUS_INVALID_CID"),
+    (0xC000000C, "STATUS_TIMER_NOT_CANCELED"),
+    (0xC000000D, "STATUS_INVALID_PARAMETER"),
+    (0xC000000E, "STATUS_NO_SUCH_DEVICE"),
+    (0xC000000F, "STATUS_NO_SUCH_FILE"),
+    (0xC0000010, "STATUS_INVALID_DEVICE_REQUEST"),
+    (0xC0000011, "STATUS_END_OF_FILE"),
+    (0xC0000012, "STATUS_WRONG_VOLUME"),
+    (0xC0000013, "STATUS_NO_MEDIA_IN_DEVICE"),
+    (0xC0000014, "STATUS_UNRECOGNIZED_MEDIA"),
+    (0xC0000015, "STATUS_NONEXISTENT_SECTOR"),
+    (0xC0000016, "STATUS_MORE_PROCESSING_REQUIRED"),
+    (0xC0000017, "STATUS_NO_MEMORY"),
+    (0xC0000018, "STATUS_CONFLICTING_ADDRESSES"),
+    (0xC0000019, "STATUS_NOT_MAPPED_VIEW"),
+    (0xC000001A, "STATUS_UNABLE_TO_FREE_VM"),
+    (0xC000001B, "STATUS_UNABLE_TO_DELETE_SECTION"),
+    (0xC000001C, "STATUS_INVALID_SYSTEM_SERVICE"),
+    (0xC000001D, "STATUS_ILLEGAL_INSTRUCTION"),
+    (0xC000001E, "STATUS_INVALID_LOCK_SEQUENCE"),
+    (0xC000001F, "STATUS_INVALID_VIEW_SIZE"),
+    (0xC0000020, "STATUS_INVALID_FILE_FOR_SECTION"),
+    (0xC0000021, "STATUS_ALREADY_COMMITTED"),
+    (0xC0000022, "STATUS_ACCESS_DENIED"),
+    (0xC0000023, "STATUS_BUFFER_TOO_SMALL"),
+    (0xC0000024, "STATUS_OBJECT_TYPE_MISMATCH"),
+    (0xC0000025, "STATUS_NONCONTINUABLE_EXCEPTION"),
+    (0xC0000026, "STATUS_INVALID_DISPOSITION"),
+    (0xC0000027, "STATUS_UNWIND"),
+    (0xC0000028, "STATUS_BAD_STACK"),
+    (0xC0000029, "STATUS_INVALID_UNWIND_TARGET"),
+    (0xC000002A, "STATUS_NOT_LOCKED"),
+    (0xC000002B, "STATUS_PARITY_ERROR"),
+    (0xC000002C, "STATUS_UNABLE_TO_DECOMMIT_VM"),
+    (0xC000002D, "STATUS_NOT_COMMITTED"),
+    (0xC000002E, "STATUS_INVALID_PORT_ATTRIBUTES"),
+    (0xC000002F, "STATUS_PORT_MESSAGE_TOO_LONG"),
+    (0xC0000030, "STATUS_INVALID_PARAMETER_MIX"),
+    (0xC0000031, "STATUS_INVALID_QUOTA_LOWER"),
+    (0xC0000032, "STATUS_DISK_CORRUPT_ERROR"),
+    (0xC0000033, "STATUS_OBJECT_NAME_INVALID"),
+    (0xC0000034, "STATUS_OBJECT_NAME_NOT_FOUND"),
+    (0xC0000035, "STATUS_OBJECT_NAME_COLLISION"),
+    (0xC0000036, "STATUS_PORT_DO_NOT_DISTURB"),
+    (0xC0000037, "STATUS_PORT_DISCONNECTED"),
+    (0xC0000038, "STATUS_DEVICE_ALREADY_ATTACHED"),
+    (0xC0000039, "STATUS_OBJECT_PATH_INVALID"),
+    (0xC000003A, "STATUS_OBJECT_PATH_NOT_FOUND"),
+    (0xC000003B, "STATUS_OBJECT_PATH_SYNTAX_BAD"),
+    (0xC000003C, "STATUS_DATA_OVERRUN"),
+    (0xC000003D, "STATUS_DATA_LATE_ERROR"),
+    (0xC000003E, "STATUS_DATA_ERROR"),
+    (0xC000003F, "STATUS_CRC_ERROR"),
+    (0xC0000040, "STATUS_SECTION_TOO_BIG"),
+    (0xC0000041, "STATUS_PORT_CONNECTION_REFUSED"),
+    (0xC0000042, "STATUS_INVALID_PORT_HANDLE"),
+    (0xC0000043, "STATUS_SHARING_VIOLATION"),
+    (0xC0000044, "STATUS_QUOTA_EXCEEDED"),
+    (0xC0000045, "STATUS_INVALID_PAGE_PROTECTION"),
+    (0xC0000046, "STATUS_MUTANT_NOT_OWNED"),
+    (0xC0000047, "STATUS_SEMAPHORE_LIMIT_EXCEEDED"),
+    (0xC0000048, "STATUS_PORT_ALREADY_SET"),
+    (0xC0000049, "STATUS_SECTION_NOT_IMAGE"),
+    (0xC000004A, "STATUS_SUSPEND_COUNT_EXCEEDED"),
+    (0xC000004B, "STATUS_THREAD_IS_TERMINATING"),
+    (0xC000004C, "STATUS_BAD_WORKING_SET_LIMIT"),
+    (0xC000004D, "STATUS_INCOMPATIBLE_FILE_MAP"),
+    (0xC000004E, "STATUS_SECTION_PROTECTION"),
+    (0xC000004F, "STATUS_EAS_NOT_SUPPORTED"),
+    (0xC0000050, "STATUS_EA_TOO_LARGE"),
+    (0xC0000051, "STATUS_NONEXISTENT_EA_ENTRY"),
+    (0xC0000052, "STATUS_NO_EAS_ON_FILE"),
+    (0xC0000053, "STATUS_EA_CORRUPT_ERROR"),
+    (0xC0000054, "STATUS_FILE_LOCK_CONFLICT"),
+    (0xC0000055, "STATUS_LOCK_NOT_GRANTED"),
+    (0xC0000056, "STATUS_DELETE_PENDING"),
+    (0xC0000057, "STATUS_CTL_FILE_NOT_SUPPORTED"),
+    (0xC0000058, "STATUS_UNKNOWN_REVISION"),
+    (0xC0000059, "STATUS_REVISION_MISMATCH"),
+    (0xC000005A, "STATUS_INVALID_OWNER"),
+    (0xC000005B, "STATUS_INVALID_PRIMARY_GROUP"),
+    (0xC000005C, "STATUS_NO_IMPERSONATION_TOKEN"),
+    (0xC000005D, "STATUS_CANT_DISABLE_MANDATORY"),
+    (0xC000005E, "STATUS_NO_LOGON_SERVERS"),
+    (0xC0000060, "STATUS_NO_SUCH_PRIVILEGE"),
+    (0xC0000061, "STATUS_PRIVILEGE_NOT_HELD"),
+    (0xC0000062, "STATUS_INVALID_ACCOUNT_NAME"),
+    (0xC0000063, "STATUS_USER_EXISTS"),
+    (0xC0000065, "STATUS_GROUP_EXISTS"),
+    (0xC0000066, "STATUS_NO_SUCH_GROUP"),
+    (0xC0000067, "STATUS_MEMBER_IN_GROUP"),
+    (0xC0000068, "STATUS_MEMBER_NOT_IN_GROUP"),
+    (0xC0000069, "STATUS_LAST_ADMIN"),
+    (0xC000006B, "STATUS_ILL_FORMED_PASSWORD"),
+    (0xC000006C, "STATUS_PASSWORD_RESTRICTION"),
+    (0xC000006F, "STATUS_INVALID_LOGON_HOURS"),
+    (0xC0000070, "STATUS_INVALID_WORKSTATION"),
+    (0xC0000073, "STATUS_NONE_MAPPED"),
+    (0xC0000074, "STATUS_TOO_MANY_LUIDS_REQUESTED"),
+    (0xC0000075, "STATUS_LUIDS_EXHAUSTED"),
+    (0xC0000076, "STATUS_INVALID_SUB_AUTHORITY"),
+    (0xC0000077, "STATUS_INVALID_ACL"),
+    (0xC0000078, "STATUS_INVALID_SID"),
+    (0xC0000079, "STATUS_INVALID_SECURITY_DESCR"),
+    (0xC000007A, "STATUS_PROCEDURE_NOT_FOUND"),
+    (0xC000007B, "STATUS_INVALID_IMAGE_FORMAT"),
+    (0xC000007C, "STATUS_NO_TOKEN"),
+    (0xC000007D, "STATUS_BAD_INHERITANCE_ACL"),
+    (0xC000007E, "STATUS_RANGE_NOT_LOCKED"),
+    (0xC000007F, "STATUS_DISK_FULL"),
+    (0xC0000080, "STATUS_SERVER_DISABLED"),
+    (0xC0000081, "STATUS_SERVER_NOT_DISABLED"),
+    (0xC0000082, "STATUS_TOO_MANY_GUIDS_REQUESTED"),
+    (0xC0000083, "STATUS_GUIDS_EXHAUSTED"),
+    (0xC0000084, "STATUS_INVALID_ID_AUTHORITY"),
+    (0xC0000085, "STATUS_AGENTS_EXHAUSTED"),
+    (0xC0000086, "STATUS_INVALID_VOLUME_LABEL"),
+    (0xC0000087, "STATUS_SECTION_NOT_EXTENDED"),
+    (0xC0000088, "STATUS_NOT_MAPPED_DATA"),
+    (0xC0000089, "STATUS_RESOURCE_DATA_NOT_FOUND"),
+    (0xC000008A, "STATUS_RESOURCE_TYPE_NOT_FOUND"),
+    (0xC000008B, "STATUS_RESOURCE_NAME_NOT_FOUND"),
+    (0xC000008C, "STATUS_ARRAY_BOUNDS_EXCEEDED"),
+    (0xC000008D, "STATUS_FLOAT_DENORMAL_OPERAND"),
+    (0xC000008E, "STATUS_FLOAT_DIVIDE_BY_ZERO"),
+    (0xC000008F, "STATUS_FLOAT_INEXACT_RESULT"),
+    (0xC0000090, "STATUS_FLOAT_INVALID_OPERATION"),
+    (0xC0000091, "STATUS_FLOAT_OVERFLOW"),
+    (0xC0000092, "STATUS_FLOAT_STACK_CHECK"),
+    (0xC0000093, "STATUS_FLOAT_UNDERFLOW"),
+    (0xC0000094, "STATUS_INTEGER_DIVIDE_BY_ZERO"),
+    (0xC0000095, "STATUS_INTEGER_OVERFLOW"),
+    (0xC0000096, "STATUS_PRIVILEGED_INSTRUCTION"),
+    (0xC0000097, "STATUS_TOO_MANY_PAGING_FILES"),
+    (0xC0000098, "STATUS_FILE_INVALID"),
+    (0xC0000099, "STATUS_ALLOTTED_SPACE_EXCEEDED"),
+    (0xC000009A, "STATUS_INSUFFICIENT_RESOURCES"),
+    (0xC000009B, "STATUS_DFS_EXIT_PATH_FOUND"),
+    (0xC000009C, "STATUS_DEVICE_DATA_ERROR"),
+    (0xC000009D, "STATUS_DEVICE_NOT_CONNECTED"),
+    (0xC000009E, "STATUS_DEVICE_POWER_FAILURE"),
+    (0xC000009F, "STATUS_FREE_VM_NOT_AT_BASE"),
+    (0xC00000A0, "STATUS_MEMORY_NOT_ALLOCATED"),
+    (0xC00000A1, "STATUS_WORKING_SET_QUOTA"),
+    (0xC00000A2, "STATUS_MEDIA_WRITE_PROTECTED"),
+    (0xC00000A3, "STATUS_DEVICE_NOT_READY"),
+    (0xC00000A4, "STATUS_INVALID_GROUP_ATTRIBUTES"),
+    (0xC00000A5, "STATUS_BAD_IMPERSONATION_LEVEL"),
+    (0xC00000A6, "STATUS_CANT_OPEN_ANONYMOUS"),
+    (0xC00000A7, "STATUS_BAD_VALIDATION_CLASS"),
+    (0xC00000A8, "STATUS_BAD_TOKEN_TYPE"),
+    (0xC00000A9, "STATUS_BAD_MASTER_BOOT_RECORD"),
+    (0xC00000AA, "STATUS_INSTRUCTION_MISALIGNMENT"),
+    (0xC00000AB, "STATUS_INSTANCE_NOT_AVAILABLE"),
+    (0xC00000AC, "STATUS_PIPE_NOT_AVAILABLE"),
+    (0xC00000AD, "STATUS_INVALID_PIPE_STATE"),
+    (0xC00000AE, "STATUS_PIPE_BUSY"),
+    (0xC00000AF, "STATUS_ILLEGAL_FUNCTION"),
+    (0xC00000B0, "STATUS_PIPE_DISCONNECTED"),
+    (0xC00000B1, "STATUS_PIPE_CLOSING"),
+    (0xC00000B2, "STATUS_PIPE_CONNECTED"),
+    (0xC00000B3, "STATUS_PIPE_LISTENING"),
+    (0xC00000B4, "STATUS_INVALID_READ_MODE"),
+    (0xC00000B5, "STATUS_IO_TIMEOUT"),
+    (0xC00000B6, "STATUS_FILE_FORCED_CLOSED"),
+    (0xC00000B7, "STATUS_PROFILING_NOT_STARTED"),
+    (0xC00000B8, "STATUS_PROFILING_NOT_STOPPED"),
+    (0xC00000B9, "STATUS_COULD_NOT_INTERPRET"),
+    (0xC00000BA, "STATUS_FILE_IS_A_DIRECTORY"),
+    (0xC00000BB, "STATUS_NOT_SUPPORTED"),
+    (0xC00000BC, "STATUS_REMOTE_NOT_LISTENING"),
+    (0xC00000BD, "STATUS_DUPLICATE_NAME"),
+    (0xC00000BE, "STATUS_BAD_NETWORK_PATH"),
+    (0xC00000BF, "STATUS_NETWORK_BUSY"),
+    (0xC00000C0, "STATUS_DEVICE_DOES_NOT_EXIST"),
+    (0xC00000C1, "STATUS_TOO_MANY_COMMANDS"),
+    (0xC00000C2, "STATUS_ADAPTER_HARDWARE_ERROR"),
+    (0xC00000C3, "STATUS_INVALID_NETWORK_RESPONSE"),
+    (0xC00000C4, "STATUS_UNEXPECTED_NETWORK_ERROR"),
+    (0xC00000C5, "STATUS_BAD_REMOTE_ADAPTER"),
+    (0xC00000C6, "STATUS_PRINT_QUEUE_FULL"),
+    (0xC00000C7, "STATUS_NO_SPOOL_SPACE"),
+    (0xC00000C8, "STATUS_PRINT_CANCELLED"),
+    (0xC00000C9, "STATUS_NETWORK_NAME_DELETED"),
+    (0xC00000CA, "STATUS_NETWORK_ACCESS_DENIED"),
+    (0xC00000CB, "STATUS_BAD_DEVICE_TYPE"),
+    (0xC00000CC, "STATUS_BAD_NETWORK_NAME"),
+    (0xC00000CD, "STATUS_TOO_MANY_NAMES"),
+    (0xC00000CE, "STATUS_TOO_MANY_SESSIONS"),
+    (0xC00000CF, "STATUS_SHARING_PAUSED"),
+    (0xC00000D0, "STATUS_REQUEST_NOT_ACCEPTED"),
+    (0xC00000D1, "STATUS_REDIRECTOR_PAUSED"),
+    (0xC00000D2, "STATUS_NET_WRITE_FAULT"),
+    (0xC00000D3, "STATUS_PROFILING_AT_LIMIT"),
+    (0xC00000D4, "STATUS_NOT_SAME_DEVICE"),
+    (0xC00000D5, "STATUS_FILE_RENAMED"),
+    (0xC00000D6, "STATUS_VIRTUAL_CIRCUIT_CLOSED"),
+    (0xC00000D7, "STATUS_NO_SECURITY_ON_OBJECT"),
+    (0xC00000D8, "STATUS_CANT_WAIT"),
+    (0xC00000D9, "STATUS_PIPE_EMPTY"),
+    (0xC00000DA, "STATUS_CANT_ACCESS_DOMAIN_INFO"),
+    (0xC00000DB, "STATUS_CANT_TERMINATE_SELF"),
+    (0xC00000DC, "STATUS_INVALID_SERVER_STATE"),
+    (0xC00000DD, "STATUS_INVALID_DOMAIN_STATE"),
+    (0xC00000DE, "STATUS_INVALID_DOMAIN_ROLE"),
+    (0xC00000DF, "STATUS_NO_SUCH_DOMAIN"),
+    (0xC00000E0, "STATUS_DOMAIN_EXISTS"),
+    (0xC00000E1, "STATUS_DOMAIN_LIMIT_EXCEEDED"),
+    (0xC00000E2, "STATUS_OPLOCK_NOT_GRANTED"),
+    (0xC00000E3, "STATUS_INVALID_OPLOCK_PROTOCOL"),
+    (0xC00000E4, "STATUS_INTERNAL_DB_CORRUPTION"),
+    (0xC00000E5, "STATUS_INTERNAL_ERROR"),
+    (0xC00000E6, "STATUS_GENERIC_NOT_MAPPED"),
+    (0xC00000E7, "STATUS_BAD_DESCRIPTOR_FORMAT"),
+    (0xC00000E8, "STATUS_INVALID_USER_BUFFER"),
+    (0xC00000E9, "STATUS_UNEXPECTED_IO_ERROR"),
+    (0xC00000EA, "STATUS_UNEXPECTED_MM_CREATE_ERR"),
+    (0xC00000EB, "STATUS_UNEXPECTED_MM_MAP_ERROR"),
+    (0xC00000EC, "STATUS_UNEXPECTED_MM_EXTEND_ERR"),
+    (0xC00000ED, "STATUS_NOT_LOGON_PROCESS"),
+    (0xC00000EE, "STATUS_LOGON_SESSION_EXISTS"),
+    (0xC00000EF, "STATUS_INVALID_PARAMETER_1"),
+    (0xC00000F0, "STATUS_INVALID_PARAMETER_2"),
+    (0xC00000F1, "STATUS_INVALID_PARAMETER_3"),
+    (0xC00000F2, "STATUS_INVALID_PARAMETER_4"),
+    (0xC00000F3, "STATUS_INVALID_PARAMETER_5"),
+    (0xC00000F4, "STATUS_INVALID_PARAMETER_6"),
+    (0xC00000F5, "STATUS_INVALID_PARAMETER_7"),
+    (0xC00000F6, "STATUS_INVALID_PARAMETER_8"),
+    (0xC00000F7, "STATUS_INVALID_PARAMETER_9"),
+    (0xC00000F8, "STATUS_INVALID_PARAMETER_10"),
+    (0xC00000F9, "STATUS_INVALID_PARAMETER_11"),
+    (0xC00000FA, "STATUS_INVALID_PARAMETER_12"),
+    (0xC00000FB, "STATUS_REDIRECTOR_NOT_STARTED"),
+    (0xC00000FC, "STATUS_REDIRECTOR_STARTED"),
+    (0xC00000FD, "STATUS_STACK_OVERFLOW"),
+    (0xC00000FE, "STATUS_NO_SUCH_PACKAGE"),
+    (0xC00000FF, "STATUS_BAD_FUNCTION_TABLE"),
+    (0xC0000100, "STATUS_VARIABLE_NOT_FOUND"),
+    (0xC0000101, "STATUS_DIRECTORY_NOT_EMPTY"),
+    (0xC0000102, "STATUS_FILE_CORRUPT_ERROR"),
+    (0xC0000103, "STATUS_NOT_A_DIRECTORY"),
+    (0xC0000104, "STATUS_BAD_LOGON_SESSION_STATE"),
+    (0xC0000105, "STATUS_LOGON_SESSION_COLLISION"),
+    (0xC0000106, "STATUS_NAME_TOO_LONG"),
+    (0xC0000107, "STATUS_FILES_OPEN"),
+    (0xC0000108, "STATUS_CONNECTION_IN_USE"),
+    (0xC0000109, "STATUS_MESSAGE_NOT_FOUND"),
+    (0xC000010A, "STATUS_PROCESS_IS_TERMINATING"),
+    (0xC000010B, "STATUS_INVALID_LOGON_TYPE"),
+    (0xC000010C, "STATUS_NO_GUID_TRANSLATION"),
+    (0xC000010D, "STATUS_CANNOT_IMPERSONATE"),
+    (0xC000010E, "STATUS_IMAGE_ALREADY_LOADED"),
+    (0xC000010F, "STATUS_ABIOS_NOT_PRESENT"),
+    (0xC0000110, "STATUS_ABIOS_LID_NOT_EXIST"),
+    (0xC0000111, "STATUS_ABIOS_LID_ALREADY_OWNED"),
+    (0xC0000112, "STATUS_ABIOS_NOT_LID_OWNER"),
+    (0xC0000113, "STATUS_ABIOS_INVALID_COMMAND"),
+    (0xC0000114, "STATUS_ABIOS_INVALID_LID"),
+    (0xC0000115, "STATUS_ABIOS_SELECTOR_NOT_AVAILABLE"),
+    (0xC0000116, "STATUS_ABIOS_INVALID_SELECTOR"),
+    (0xC0000117, "STATUS_NO_LDT"),
+    (0xC0000118, "STATUS_INVALID_LDT_SIZE"),
+    (0xC0000119, "STATUS_INVALID_LDT_OFFSET"),
+    (0xC000011A, "STATUS_INVALID_LDT_DESCRIPTOR"),
+    (0xC000011B, "STATUS_INVALID_IMAGE_NE_FORMAT"),
+    (0xC000011C, "STATUS_RXACT_INVALID_STATE"),
+    (0xC000011D, "STATUS_RXACT_COMMIT_FAILURE"),
+    (0xC000011E, "STATUS_MAPPED_FILE_SIZE_ZERO"),
+    (0xC000011F, "STATUS_TOO_MANY_OPENED_FILES"),
+    (0xC0000120, "STATUS_CANCELLED"),
+    (0xC0000121, "STATUS_CANNOT_DELETE"),
+    (0xC0000122, "STATUS_INVALID_COMPUTER_NAME"),
+    (0xC0000123, "STATUS_FILE_DELETED"),
+    (0xC0000124, "STATUS_SPECIAL_ACCOUNT"),
+    (0xC0000125, "STATUS_SPECIAL_GROUP"),
+    (0xC0000126, "STATUS_SPECIAL_USER"),
+    (0xC0000127, "STATUS_MEMBERS_PRIMARY_GROUP"),
+    (0xC0000128, "STATUS_FILE_CLOSED"),
+    (0xC0000129, "STATUS_TOO_MANY_THREADS"),
+    (0xC000012A, "STATUS_THREAD_NOT_IN_PROCESS"),
+    (0xC000012B, "STATUS_TOKEN_ALREADY_IN_USE"),
+    (0xC000012C, "STATUS_PAGEFILE_QUOTA_EXCEEDED"),
+    (0xC000012D, "STATUS_COMMITMENT_LIMIT"),
+    (0xC000012E, "STATUS_INVALID_IMAGE_LE_FORMAT"),
+    (0xC000012F, "STATUS_INVALID_IMAGE_NOT_MZ"),
+    (0xC0000130, "STATUS_INVALID_IMAGE_PROTECT"),
+    (0xC0000131, "STATUS_INVALID_IMAGE_WIN_16"),
+    (0xC0000132, "STATUS_LOGON_SERVER_CONFLICT"),
+    (0xC0000133, "STATUS_TIME_DIFFERENCE_AT_DC"),
+    (0xC0000134, "STATUS_SYNCHRONIZATION_REQUIRED"),
+    (0xC0000135, "STATUS_DLL_NOT_FOUND"),
+    (0xC0000136, "STATUS_OPEN_FAILED"),
+    (0xC0000137, "STATUS_IO_PRIVILEGE_FAILED"),
+    (0xC0000138, "STATUS_ORDINAL_NOT_FOUND"),
+    (0xC0000139, "STATUS_ENTRYPOINT_NOT_FOUND"),
+    (0xC000013A, "STATUS_CONTROL_C_EXIT"),
+    (0xC000013B, "STATUS_LOCAL_DISCONNECT"),
+    (0xC000013C, "STATUS_REMOTE_DISCONNECT"),
+    (0xC000013D, "STATUS_REMOTE_RESOURCES"),
+    (0xC000013E, "STATUS_LINK_FAILED"),
+    (0xC000013F, "STATUS_LINK_TIMEOUT"),
+    (0xC0000140, "STATUS_INVALID_CONNECTION"),
+    (0xC0000141, "STATUS_INVALID_ADDRESS"),
+    (0xC0000142, "STATUS_DLL_INIT_FAILED"),
+    (0xC0000143, "STATUS_MISSING_SYSTEMFILE"),
+    (0xC0000144, "STATUS_UNHANDLED_EXCEPTION"),
+    (0xC0000145, "STATUS_APP_INIT_FAILURE"),
+    (0xC0000146, "STATUS_PAGEFILE_CREATE_FAILED"),
+    (0xC0000147, "STATUS_NO_PAGEFILE"),
+    (0xC0000148, "STATUS_INVALID_LEVEL"),
+    (0xC0000149, "STATUS_WRONG_PASSWORD_CORE"),
+    (0xC000014A, "STATUS_ILLEGAL_FLOAT_CONTEXT"),
+    (0xC000014B, "STATUS_PIPE_BROKEN"),
+    (0xC000014C, "STATUS_REGISTRY_CORRUPT"),
+    (0xC000014D, "STATUS_REGISTRY_IO_FAILED"),
+    (0xC000014E, "STATUS_NO_EVENT_PAIR"),
+    (0xC000014F, "STATUS_UNRECOGNIZED_VOLUME"),
+    (0xC0000150, "STATUS_SERIAL_NO_DEVICE_INITED"),
+    (0xC0000151, "STATUS_NO_SUCH_ALIAS"),
+    (0xC0000152, "STATUS_MEMBER_NOT_IN_ALIAS"),
+    (0xC0000153, "STATUS_MEMBER_IN_ALIAS"),
+    (0xC0000154, "STATUS_ALIAS_EXISTS"),
+    (0xC0000155, "STATUS_LOGON_NOT_GRANTED"),
+    (0xC0000156, "STATUS_TOO_MANY_SECRETS"),
+    (0xC0000157, "STATUS_SECRET_TOO_LONG"),
+    (0xC0000158, "STATUS_INTERNAL_DB_ERROR"),
+    (0xC0000159, "STATUS_FULLSCREEN_MODE"),
+    (0xC000015A, "STATUS_TOO_MANY_CONTEXT_IDS"),
+    (0xC000015C, "STATUS_NOT_REGISTRY_FILE"),
+    (0xC000015D, "STATUS_NT_CROSS_ENCRYPTION_REQUIRED"),
+    (0xC000015E, "STATUS_DOMAIN_CTRLR_CONFIG_ERROR"),
+    (0xC000015F, "STATUS_FT_MISSING_MEMBER"),
+    (0xC0000160, "STATUS_ILL_FORMED_SERVICE_ENTRY"),
+    (0xC0000161, "STATUS_ILLEGAL_CHARACTER"),
+    (0xC0000162, "STATUS_UNMAPPABLE_CHARACTER"),
+    (0xC0000163, "STATUS_UNDEFINED_CHARACTER"),
+    (0xC0000164, "STATUS_FLOPPY_VOLUME"),
+    (0xC0000165, "STATUS_FLOPPY_ID_MARK_NOT_FOUND"),
+    (0xC0000166, "STATUS_FLOPPY_WRONG_CYLINDER"),
+    (0xC0000167, "STATUS_FLOPPY_UNKNOWN_ERROR"),
+    (0xC0000168, "STATUS_FLOPPY_BAD_REGISTERS"),
+    (0xC0000169, "STATUS_DISK_RECALIBRATE_FAILED"),
+    (0xC000016A, "STATUS_DISK_OPERATION_FAILED"),
+    (0xC000016B, "STATUS_DISK_RESET_FAILED"),
+    (0xC000016C, "STATUS_SHARED_IRQ_BUSY"),
+    (0xC000016D, "STATUS_FT_ORPHANING"),
+    (0xC000016E, "STATUS_BIOS_FAILED_TO_CONNECT_INTERRUPT"),
+    (0xC0000172, "STATUS_PARTITION_FAILURE"),
+    (0xC0000173, "STATUS_INVALID_BLOCK_LENGTH"),
+    (0xC0000174, "STATUS_DEVICE_NOT_PARTITIONED"),
+    (0xC0000175, "STATUS_UNABLE_TO_LOCK_MEDIA"),
+    (0xC0000176, "STATUS_UNABLE_TO_UNLOAD_MEDIA"),
+    (0xC0000177, "STATUS_EOM_OVERFLOW"),
+    (0xC0000178, "STATUS_NO_MEDIA"),
+    (0xC000017A, "STATUS_NO_SUCH_MEMBER"),
+    (0xC000017B, "STATUS_INVALID_MEMBER"),
+    (0xC000017C, "STATUS_KEY_DELETED"),
+    (0xC000017D, "STATUS_NO_LOG_SPACE"),
+    (0xC000017E, "STATUS_TOO_MANY_SIDS"),
+    (0xC000017F, "STATUS_LM_CROSS_ENCRYPTION_REQUIRED"),
+    (0xC0000180, "STATUS_KEY_HAS_CHILDREN"),
+    (0xC0000181, "STATUS_CHILD_MUST_BE_VOLATILE"),
+    (0xC0000182, "STATUS_DEVICE_CONFIGURATION_ERROR"),
+    (0xC0000183, "STATUS_DRIVER_INTERNAL_ERROR"),
+    (0xC0000184, "STATUS_INVALID_DEVICE_STATE"),
+    (0xC0000185, "STATUS_IO_DEVICE_ERROR"),
+    (0xC0000186, "STATUS_DEVICE_PROTOCOL_ERROR"),
+    (0xC0000187, "STATUS_BACKUP_CONTROLLER"),
+    (0xC0000188, "STATUS_LOG_FILE_FULL"),
+    (0xC0000189, "STATUS_TOO_LATE"),
+    (0xC000018A, "STATUS_NO_TRUST_LSA_SECRET"),
+    (0xC000018B, "STATUS_NO_TRUST_SAM_ACCOUNT"),
+    (0xC000018C, "STATUS_TRUSTED_DOMAIN_FAILURE"),
+    (0xC000018D, "STATUS_TRUSTED_RELATIONSHIP_FAILURE"),
+    (0xC000018E, "STATUS_EVENTLOG_FILE_CORRUPT"),
+    (0xC000018F, "STATUS_EVENTLOG_CANT_START"),
+    (0xC0000190, "STATUS_TRUST_FAILURE"),
+    (0xC0000191, "STATUS_MUTANT_LIMIT_EXCEEDED"),
+    (0xC0000192, "STATUS_NETLOGON_NOT_STARTED"),
+    (0xC0000194, "STATUS_POSSIBLE_DEADLOCK"),
+    (0xC0000195, "STATUS_NETWORK_CREDENTIAL_CONFLICT"),
+    (0xC0000196, "STATUS_REMOTE_SESSION_LIMIT"),
+    (0xC0000197, "STATUS_EVENTLOG_FILE_CHANGED"),
+    (0xC0000198, "STATUS_NOLOGON_INTERDOMAIN_TRUST_ACCOUNT"),
+    (0xC0000199, "STATUS_NOLOGON_WORKSTATION_TRUST_ACCOUNT"),
+    (0xC000019A, "STATUS_NOLOGON_SERVER_TRUST_ACCOUNT"),
+    (0xC000019B, "STATUS_DOMAIN_TRUST_INCONSISTENT"),
+    (0xC000019C, "STATUS_FS_DRIVER_REQUIRED"),
+    (0xC000019D, "STATUS_IMAGE_ALREADY_LOADED_AS_DLL"),
+    (0xC000019E, "STATUS_INCOMPATIBLE_WITH_GLOBAL_SHORT_NAME_REGISTRY_SETTING"),
+    (0xC000019F, "STATUS_SHORT_NAMES_NOT_ENABLED_ON_VOLUME"),
+    (0xC00001A0, "STATUS_SECURITY_STREAM_IS_INCONSISTENT"),
+    (0xC00001A1, "STATUS_INVALID_LOCK_RANGE"),
+    (0xC00001A2, "STATUS_INVALID_ACE_CONDITION"),
+    (0xC00001A3, "STATUS_IMAGE_SUBSYSTEM_NOT_PRESENT"),
+    (0xC00001A4, "STATUS_NOTIFICATION_GUID_ALREADY_DEFINED"),
+    (0xC00001A5, "STATUS_INVALID_EXCEPTION_HANDLER"),
+    (0xC00001A6, "STATUS_DUPLICATE_PRIVILEGES"),
+    (0xC00001A7, "STATUS_NOT_ALLOWED_ON_SYSTEM_FILE"),
+    (0xC00001A8, "STATUS_REPAIR_NEEDED"),
+    (0xC00001A9, "STATUS_QUOTA_NOT_ENABLED"),
+    (0xC00001AA, "STATUS_NO_APPLICATION_PACKAGE"),
+    (0xC00001AB, "STATUS_FILE_METADATA_OPTIMIZATION_IN_PROGRESS"),
+    (0xC00001AC, "STATUS_NOT_SAME_OBJECT"),
+    (0xC00001AD, "STATUS_FATAL_MEMORY_EXHAUSTION"),
+    (0xC00001AE, "STATUS_ERROR_PROCESS_NOT_IN_JOB"),
+    (0xC00001AF, "STATUS_CPU_SET_INVALID"),
+    (0xC00001B0, "STATUS_IO_DEVICE_INVALID_DATA"),
+    (0xC00001B1, "STATUS_IO_UNALIGNED_WRITE"),
+    (0xC00001B2, "STATUS_CONTROL_STACK_VIOLATION"),
+    (0xC00001B3, "STATUS_WEAK_WHFBKEY_BLOCKED"),
+    (0xC00001B4, "STATUS_SERVER_TRANSPORT_CONFLICT"),
+    (0xC00001B5, "STATUS_CERTIFICATE_VALIDATION_PREFERENCE_CONFLICT"),
+    (0xC0000201, "STATUS_NETWORK_OPEN_RESTRICTION"),
+    (0xC0000202, "STATUS_NO_USER_SESSION_KEY"),
+    (0xC0000203, "STATUS_USER_SESSION_DELETED"),
+    (0xC0000204, "STATUS_RESOURCE_LANG_NOT_FOUND"),
+    (0xC0000205, "STATUS_INSUFF_SERVER_RESOURCES"),
+    (0xC0000206, "STATUS_INVALID_BUFFER_SIZE"),
+    (0xC0000207, "STATUS_INVALID_ADDRESS_COMPONENT"),
+    (0xC0000208, "STATUS_INVALID_ADDRESS_WILDCARD"),
+    (0xC0000209, "STATUS_TOO_MANY_ADDRESSES"),
+    (0xC000020A, "STATUS_ADDRESS_ALREADY_EXISTS"),
+    (0xC000020B, "STATUS_ADDRESS_CLOSED"),
+    (0xC000020C, "STATUS_CONNECTION_DISCONNECTED"),
+    (0xC000020D, "STATUS_CONNECTION_RESET"),
+    (0xC000020E, "STATUS_TOO_MANY_NODES"),
+    (0xC000020F, "STATUS_TRANSACTION_ABORTED"),
+    (0xC0000210, "STATUS_TRANSACTION_TIMED_OUT"),
+    (0xC0000211, "STATUS_TRANSACTION_NO_RELEASE"),
+    (0xC0000212, "STATUS_TRANSACTION_NO_MATCH"),
+    (0xC0000213, "STATUS_TRANSACTION_RESPONDED"),
+    (0xC0000214, "STATUS_TRANSACTION_INVALID_ID"),
+    (0xC0000215, "STATUS_TRANSACTION_INVALID_TYPE"),
+    (0xC0000216, "STATUS_NOT_SERVER_SESSION"),
+    (0xC0000217, "STATUS_NOT_CLIENT_SESSION"),
+    (0xC0000218, "STATUS_CANNOT_LOAD_REGISTRY_FILE"),
+    (0xC0000219, "STATUS_DEBUG_ATTACH_FAILED"),
+    (0xC000021A, "STATUS_SYSTEM_PROCESS_TERMINATED"),
+    (0xC000021B, "STATUS_DATA_NOT_ACCEPTED"),
+    (0xC000021C, "STATUS_NO_BROWSER_SERVERS_FOUND"),
+    (0xC000021D, "STATUS_VDM_HARD_ERROR"),
+    (0xC000021E, "STATUS_DRIVER_CANCEL_TIMEOUT"),
+    (0xC000021F, "STATUS_REPLY_MESSAGE_MISMATCH"),
+    (0xC0000220, "STATUS_MAPPED_ALIGNMENT"),
+    (0xC0000221, "STATUS_IMAGE_CHECKSUM_MISMATCH"),
+    (0xC0000222, "STATUS_LOST_WRITEBEHIND_DATA"),
+    (0xC0000223, "STATUS_CLIENT_SERVER_PARAMETERS_INVALID"),
+    (0xC0000225, "STATUS_NOT_FOUND"),
+    (0xC0000226, "STATUS_NOT_TINY_STREAM"),
+    (0xC0000227, "STATUS_RECOVERY_FAILURE"),
+    (0xC0000228, "STATUS_STACK_OVERFLOW_READ"),
+    (0xC0000229, "STATUS_FAIL_CHECK"),
+    (0xC000022A, "STATUS_DUPLICATE_OBJECTID"),
+    (0xC000022B, "STATUS_OBJECTID_EXISTS"),
+    (0xC000022C, "STATUS_CONVERT_TO_LARGE"),
+    (0xC000022D, "STATUS_RETRY"),
+    (0xC000022E, "STATUS_FOUND_OUT_OF_SCOPE"),
+    (0xC000022F, "STATUS_ALLOCATE_BUCKET"),
+    (0xC0000230, "STATUS_PROPSET_NOT_FOUND"),
+    (0xC0000231, "STATUS_MARSHALL_OVERFLOW"),
+    (0xC0000232, "STATUS_INVALID_VARIANT"),
+    (0xC0000233, "STATUS_DOMAIN_CONTROLLER_NOT_FOUND"),
+    (0xC0000235, "STATUS_HANDLE_NOT_CLOSABLE"),
+    (0xC0000236, "STATUS_CONNECTION_REFUSED"),
+    (0xC0000237, "STATUS_GRACEFUL_DISCONNECT"),
+    (0xC0000238, "STATUS_ADDRESS_ALREADY_ASSOCIATED"),
+    (0xC0000239, "STATUS_ADDRESS_NOT_ASSOCIATED"),
+    (0xC000023A, "STATUS_CONNECTION_INVALID"),
+    (0xC000023B, "STATUS_CONNECTION_ACTIVE"),
+    (0xC000023C, "STATUS_NETWORK_UNREACHABLE"),
+    (0xC000023D, "STATUS_HOST_UNREACHABLE"),
+    (0xC000023E, "STATUS_PROTOCOL_UNREACHABLE"),
+    (0xC000023F, "STATUS_PORT_UNREACHABLE"),
+    (0xC0000240, "STATUS_REQUEST_ABORTED"),
+    (0xC0000241, "STATUS_CONNECTION_ABORTED"),
+    (0xC0000242, "STATUS_BAD_COMPRESSION_BUFFER"),
+    (0xC0000243, "STATUS_USER_MAPPED_FILE"),
+    (0xC0000244, "STATUS_AUDIT_FAILED"),
+    (0xC0000245, "STATUS_TIMER_RESOLUTION_NOT_SET"),
+    (0xC0000246, "STATUS_CONNECTION_COUNT_LIMIT"),
+    (0xC0000247, "STATUS_LOGIN_TIME_RESTRICTION"),
+    (0xC0000248, "STATUS_LOGIN_WKSTA_RESTRICTION"),
+    (0xC0000249, "STATUS_IMAGE_MP_UP_MISMATCH"),
+    (0xC0000250, "STATUS_INSUFFICIENT_LOGON_INFO"),
+    (0xC0000251, "STATUS_BAD_DLL_ENTRYPOINT"),
+    (0xC0000252, "STATUS_BAD_SERVICE_ENTRYPOINT"),
+    (0xC0000253, "STATUS_LPC_REPLY_LOST"),
+    (0xC0000254, "STATUS_IP_ADDRESS_CONFLICT1"),
+    (0xC0000255, "STATUS_IP_ADDRESS_CONFLICT2"),
+    (0xC0000256, "STATUS_REGISTRY_QUOTA_LIMIT"),
+    (0xC0000257, "STATUS_PATH_NOT_COVERED"),
+    (0xC0000258, "STATUS_NO_CALLBACK_ACTIVE"),
+    (0xC0000259, "STATUS_LICENSE_QUOTA_EXCEEDED"),
+    (0xC000025A, "STATUS_PWD_TOO_SHORT"),
+    (0xC000025B, "STATUS_PWD_TOO_RECENT"),
+    (0xC000025C, "STATUS_PWD_HISTORY_CONFLICT"),
+    (0xC000025E, "STATUS_PLUGPLAY_NO_DEVICE"),
+    (0xC000025F, "STATUS_UNSUPPORTED_COMPRESSION"),
+    (0xC0000260, "STATUS_INVALID_HW_PROFILE"),
+    (0xC0000261, "STATUS_INVALID_PLUGPLAY_DEVICE_PATH"),
+    (0xC0000262, "STATUS_DRIVER_ORDINAL_NOT_FOUND"),
+    (0xC0000263, "STATUS_DRIVER_ENTRYPOINT_NOT_FOUND"),
+    (0xC0000264, "STATUS_RESOURCE_NOT_OWNED"),
+    (0xC0000265, "STATUS_TOO_MANY_LINKS"),
+    (0xC0000266, "STATUS_QUOTA_LIST_INCONSISTENT"),
+    (0xC0000267, "STATUS_FILE_IS_OFFLINE"),
+    (0xC0000268, "STATUS_EVALUATION_EXPIRATION"),
+    (0xC0000269, "STATUS_ILLEGAL_DLL_RELOCATION"),
+    (0xC000026A, "STATUS_LICENSE_VIOLATION"),
+    (0xC000026B, "STATUS_DLL_INIT_FAILED_LOGOFF"),
+    (0xC000026C, "STATUS_DRIVER_UNABLE_TO_LOAD"),
+    (0xC000026D, "STATUS_DFS_UNAVAILABLE"),
+    (0xC000026E, "STATUS_VOLUME_DISMOUNTED"),
+    (0xC000026F, "STATUS_WX86_INTERNAL_ERROR"),
+    (0xC0000270, "STATUS_WX86_FLOAT_STACK_CHECK"),
+    (0xC0000271, "STATUS_VALIDATE_CONTINUE"),
+    (0xC0000272, "STATUS_NO_MATCH"),
+    (0xC0000273, "STATUS_NO_MORE_MATCHES"),
+    (0xC0000275, "STATUS_NOT_A_REPARSE_POINT"),
+    (0xC0000276, "STATUS_IO_REPARSE_TAG_INVALID"),
+    (0xC0000277, "STATUS_IO_REPARSE_TAG_MISMATCH"),
+    (0xC0000278, "STATUS_IO_REPARSE_DATA_INVALID"),
+    (0xC0000279, "STATUS_IO_REPARSE_TAG_NOT_HANDLED"),
+    (0xC000027A, "STATUS_PWD_TOO_LONG"),
+    (0xC000027B, "STATUS_STOWED_EXCEPTION"),
+    (0xC000027C, "STATUS_CONTEXT_STOWED_EXCEPTION"),
+    (0xC0000280, "STATUS_REPARSE_POINT_NOT_RESOLVED"),
+    (0xC0000281, "STATUS_DIRECTORY_IS_A_REPARSE_POINT"),
+    (0xC0000282, "STATUS_RANGE_LIST_CONFLICT"),
+    (0xC0000283, "STATUS_SOURCE_ELEMENT_EMPTY"),
+    (0xC0000284, "STATUS_DESTINATION_ELEMENT_FULL"),
+    (0xC0000285, "STATUS_ILLEGAL_ELEMENT_ADDRESS"),
+    (0xC0000286, "STATUS_MAGAZINE_NOT_PRESENT"),
+    (0xC0000287, "STATUS_REINITIALIZATION_NEEDED"),
+    (0xC000028A, "STATUS_ENCRYPTION_FAILED"),
+    (0xC000028B, "STATUS_DECRYPTION_FAILED"),
+    (0xC000028C, "STATUS_RANGE_NOT_FOUND"),
+    (0xC000028D, "STATUS_NO_RECOVERY_POLICY"),
+    (0xC000028E, "STATUS_NO_EFS"),
+    (0xC000028F, "STATUS_WRONG_EFS"),
+    (0xC0000290, "STATUS_NO_USER_KEYS"),
+    (0xC0000291, "STATUS_FILE_NOT_ENCRYPTED"),
+    (0xC0000292, "STATUS_NOT_EXPORT_FORMAT"),
+    (0xC0000293, "STATUS_FILE_ENCRYPTED"),
+    (0xC0000295, "STATUS_WMI_GUID_NOT_FOUND"),
+    (0xC0000296, "STATUS_WMI_INSTANCE_NOT_FOUND"),
+    (0xC0000297, "STATUS_WMI_ITEMID_NOT_FOUND"),
+    (0xC0000298, "STATUS_WMI_TRY_AGAIN"),
+    (0xC0000299, "STATUS_SHARED_POLICY"),
+    (0xC000029A, "STATUS_POLICY_OBJECT_NOT_FOUND"),
+    (0xC000029B, "STATUS_POLICY_ONLY_IN_DS"),
+    (0xC000029C, "STATUS_VOLUME_NOT_UPGRADED"),
+    (0xC000029D, "STATUS_REMOTE_STORAGE_NOT_ACTIVE"),
+    (0xC000029E, "STATUS_REMOTE_STORAGE_MEDIA_ERROR"),
+    (0xC000029F, "STATUS_NO_TRACKING_SERVICE"),
+    (0xC00002A0, "STATUS_SERVER_SID_MISMATCH"),
+    (0xC00002A1, "STATUS_DS_NO_ATTRIBUTE_OR_VALUE"),
+    (0xC00002A2, "STATUS_DS_INVALID_ATTRIBUTE_SYNTAX"),
+    (0xC00002A3, "STATUS_DS_ATTRIBUTE_TYPE_UNDEFINED"),
+    (0xC00002A4, "STATUS_DS_ATTRIBUTE_OR_VALUE_EXISTS"),
+    (0xC00002A5, "STATUS_DS_BUSY"),
+    (0xC00002A6, "STATUS_DS_UNAVAILABLE"),
+    (0xC00002A7, "STATUS_DS_NO_RIDS_ALLOCATED"),
+    (0xC00002A8, "STATUS_DS_NO_MORE_RIDS"),
+    (0xC00002A9, "STATUS_DS_INCORRECT_ROLE_OWNER"),
+    (0xC00002AA, "STATUS_DS_RIDMGR_INIT_ERROR"),
+    (0xC00002AB, "STATUS_DS_OBJ_CLASS_VIOLATION"),
+    (0xC00002AC, "STATUS_DS_CANT_ON_NON_LEAF"),
+    (0xC00002AD, "STATUS_DS_CANT_ON_RDN"),
+    (0xC00002AE, "STATUS_DS_CANT_MOD_OBJ_CLASS"),
+    (0xC00002AF, "STATUS_DS_CROSS_DOM_MOVE_FAILED"),
+    (0xC00002B0, "STATUS_DS_GC_NOT_AVAILABLE"),
+    (0xC00002B1, "STATUS_DIRECTORY_SERVICE_REQUIRED"),
+    (0xC00002B2, "STATUS_REPARSE_ATTRIBUTE_CONFLICT"),
+    (0xC00002B3, "STATUS_CANT_ENABLE_DENY_ONLY"),
+    (0xC00002B4, "STATUS_FLOAT_MULTIPLE_FAULTS"),
+    (0xC00002B5, "STATUS_FLOAT_MULTIPLE_TRAPS"),
+    (0xC00002B6, "STATUS_DEVICE_REMOVED"),
+    (0xC00002B7, "STATUS_JOURNAL_DELETE_IN_PROGRESS"),
+    (0xC00002B8, "STATUS_JOURNAL_NOT_ACTIVE"),
+    (0xC00002B9, "STATUS_NOINTERFACE"),
+    (0xC00002BA, "STATUS_DS_RIDMGR_DISABLED"),
+    (0xC00002C1, "STATUS_DS_ADMIN_LIMIT_EXCEEDED"),
+    (0xC00002C2, "STATUS_DRIVER_FAILED_SLEEP"),
+    (0xC00002C3, "STATUS_MUTUAL_AUTHENTICATION_FAILED"),
+    (0xC00002C4, "STATUS_CORRUPT_SYSTEM_FILE"),
+    (0xC00002C5, "STATUS_DATATYPE_MISALIGNMENT_ERROR"),
+    (0xC00002C6, "STATUS_WMI_READ_ONLY"),
+    (0xC00002C7, "STATUS_WMI_SET_FAILURE"),
+    (0xC00002C8, "STATUS_COMMITMENT_MINIMUM"),
+    (0xC00002C9, "STATUS_REG_NAT_CONSUMPTION"),
+    (0xC00002CA, "STATUS_TRANSPORT_FULL"),
+    (0xC00002CB, "STATUS_DS_SAM_INIT_FAILURE"),
+    (0xC00002CC, "STATUS_ONLY_IF_CONNECTED"),
+    (0xC00002CD, "STATUS_DS_SENSITIVE_GROUP_VIOLATION"),
+    (0xC00002CE, "STATUS_PNP_RESTART_ENUMERATION"),
+    (0xC00002CF, "STATUS_JOURNAL_ENTRY_DELETED"),
+    (0xC00002D0, "STATUS_DS_CANT_MOD_PRIMARYGROUPID"),
+    (0xC00002D1, "STATUS_SYSTEM_IMAGE_BAD_SIGNATURE"),
+    (0xC00002D2, "STATUS_PNP_REBOOT_REQUIRED"),
+    (0xC00002D3, "STATUS_POWER_STATE_INVALID"),
+    (0xC00002D4, "STATUS_DS_INVALID_GROUP_TYPE"),
+    (0xC00002D5, "STATUS_DS_NO_NEST_GLOBALGROUP_IN_MIXEDDOMAIN"),
+    (0xC00002D6, "STATUS_DS_NO_NEST_LOCALGROUP_IN_MIXEDDOMAIN"),
+    (0xC00002D7, "STATUS_DS_GLOBAL_CANT_HAVE_LOCAL_MEMBER"),
+    (0xC00002D8, "STATUS_DS_GLOBAL_CANT_HAVE_UNIVERSAL_MEMBER"),
+    (0xC00002D9, "STATUS_DS_UNIVERSAL_CANT_HAVE_LOCAL_MEMBER"),
+    (0xC00002DA, "STATUS_DS_GLOBAL_CANT_HAVE_CROSSDOMAIN_MEMBER"),
+    (0xC00002DB, "STATUS_DS_LOCAL_CANT_HAVE_CROSSDOMAIN_LOCAL_MEMBER"),
+    (0xC00002DC, "STATUS_DS_HAVE_PRIMARY_MEMBERS"),
+    (0xC00002DD, "STATUS_WMI_NOT_SUPPORTED"),
+    (0xC00002DE, "STATUS_INSUFFICIENT_POWER"),
+    (0xC00002DF, "STATUS_SAM_NEED_BOOTKEY_PASSWORD"),
+    (0xC00002E0, "STATUS_SAM_NEED_BOOTKEY_FLOPPY"),
+    (0xC00002E1, "STATUS_DS_CANT_START"),
+    (0xC00002E2, "STATUS_DS_INIT_FAILURE"),
+    (0xC00002E3, "STATUS_SAM_INIT_FAILURE"),
+    (0xC00002E4, "STATUS_DS_GC_REQUIRED"),
+    (0xC00002E5, "STATUS_DS_LOCAL_MEMBER_OF_LOCAL_ONLY"),
+    (0xC00002E6, "STATUS_DS_NO_FPO_IN_UNIVERSAL_GROUPS"),
+    (0xC00002E7, "STATUS_DS_MACHINE_ACCOUNT_QUOTA_EXCEEDED"),
+    (0xC00002E8, "STATUS_MULTIPLE_FAULT_VIOLATION"),
+    (0xC00002E9, "STATUS_CURRENT_DOMAIN_NOT_ALLOWED"),
+    (0xC00002EA, "STATUS_CANNOT_MAKE"),
+    (0xC00002EB, "STATUS_SYSTEM_SHUTDOWN"),
+    (0xC00002EC, "STATUS_DS_INIT_FAILURE_CONSOLE"),
+    (0xC00002ED, "STATUS_DS_SAM_INIT_FAILURE_CONSOLE"),
+    (0xC00002EE, "STATUS_UNFINISHED_CONTEXT_DELETED"),
+    (0xC00002EF, "STATUS_NO_TGT_REPLY"),
+    (0xC00002F0, "STATUS_OBJECTID_NOT_FOUND"),
+    (0xC00002F1, "STATUS_NO_IP_ADDRESSES"),
+    (0xC00002F2, "STATUS_WRONG_CREDENTIAL_HANDLE"),
+    (0xC00002F3, "STATUS_CRYPTO_SYSTEM_INVALID"),
+    (0xC00002F4, "STATUS_MAX_REFERRALS_EXCEEDED"),
+    (0xC00002F5, "STATUS_MUST_BE_KDC"),
+    (0xC00002F6, "STATUS_STRONG_CRYPTO_NOT_SUPPORTED"),
+    (0xC00002F7, "STATUS_TOO_MANY_PRINCIPALS"),
+    (0xC00002F8, "STATUS_NO_PA_DATA"),
+    (0xC00002F9, "STATUS_PKINIT_NAME_MISMATCH"),
+    (0xC00002FA, "STATUS_SMARTCARD_LOGON_REQUIRED"),
+    (0xC00002FB, "STATUS_KDC_INVALID_REQUEST"),
+    (0xC00002FC, "STATUS_KDC_UNABLE_TO_REFER"),
+    (0xC00002FD, "STATUS_KDC_UNKNOWN_ETYPE"),
+    (0xC00002FE, "STATUS_SHUTDOWN_IN_PROGRESS"),
+    (0xC00002FF, "STATUS_SERVER_SHUTDOWN_IN_PROGRESS"),
+    (0xC0000300, "STATUS_NOT_SUPPORTED_ON_SBS"),
+    (0xC0000301, "STATUS_WMI_GUID_DISCONNECTED"),
+    (0xC0000302, "STATUS_WMI_ALREADY_DISABLED"),
+    (0xC0000303, "STATUS_WMI_ALREADY_ENABLED"),
+    (0xC0000304, "STATUS_MFT_TOO_FRAGMENTED"),
+    (0xC0000305, "STATUS_COPY_PROTECTION_FAILURE"),
+    (0xC0000306, "STATUS_CSS_AUTHENTICATION_FAILURE"),
+    (0xC0000307, "STATUS_CSS_KEY_NOT_PRESENT"),
+    (0xC0000308, "STATUS_CSS_KEY_NOT_ESTABLISHED"),
+    (0xC0000309, "STATUS_CSS_SCRAMBLED_SECTOR"),
+    (0xC000030A, "STATUS_CSS_REGION_MISMATCH"),
+    (0xC000030B, "STATUS_CSS_RESETS_EXHAUSTED"),
+    (0xC000030C, "STATUS_PASSWORD_CHANGE_REQUIRED"),
+    (0xC000030D, "STATUS_LOST_MODE_LOGON_RESTRICTION"),
+    (0xC0000320, "STATUS_PKINIT_FAILURE"),
+    (0xC0000321, "STATUS_SMARTCARD_SUBSYSTEM_FAILURE"),
+    (0xC0000322, "STATUS_NO_KERB_KEY"),
+    (0xC0000350, "STATUS_HOST_DOWN"),
+    (0xC0000351, "STATUS_UNSUPPORTED_PREAUTH"),
+    (0xC0000352, "STATUS_EFS_ALG_BLOB_TOO_BIG"),
+    (0xC0000353, "STATUS_PORT_NOT_SET"),
+    (0xC0000354, "STATUS_DEBUGGER_INACTIVE"),
+    (0xC0000355, "STATUS_DS_VERSION_CHECK_FAILURE"),
+    (0xC0000356, "STATUS_AUDITING_DISABLED"),
+    (0xC0000357, "STATUS_PRENT4_MACHINE_ACCOUNT"),
+    (0xC0000358, "STATUS_DS_AG_CANT_HAVE_UNIVERSAL_MEMBER"),
+    (0xC0000359, "STATUS_INVALID_IMAGE_WIN_32"),
+    (0xC000035A, "STATUS_INVALID_IMAGE_WIN_64"),
+    (0xC000035B, "STATUS_BAD_BINDINGS"),
+    (0xC000035C, "STATUS_NETWORK_SESSION_EXPIRED"),
+    (0xC000035D, "STATUS_APPHELP_BLOCK"),
+    (0xC000035E, "STATUS_ALL_SIDS_FILTERED"),
+    (0xC000035F, "STATUS_NOT_SAFE_MODE_DRIVER"),
+    (0xC0000361, "STATUS_ACCESS_DISABLED_BY_POLICY_DEFAULT"),
+    (0xC0000362, "STATUS_ACCESS_DISABLED_BY_POLICY_PATH"),
+    (0xC0000363, "STATUS_ACCESS_DISABLED_BY_POLICY_PUBLISHER"),
+    (0xC0000364, "STATUS_ACCESS_DISABLED_BY_POLICY_OTHER"),
+    (0xC0000365, "STATUS_FAILED_DRIVER_ENTRY"),
+    (0xC0000366, "STATUS_DEVICE_ENUMERATION_ERROR"),
+    (0xC0000368, "STATUS_MOUNT_POINT_NOT_RESOLVED"),
+    (0xC0000369, "STATUS_INVALID_DEVICE_OBJECT_PARAMETER"),
+    (0xC000036A, "STATUS_MCA_OCCURED"),
+    (0xC000036B, "STATUS_DRIVER_BLOCKED_CRITICAL"),
+    (0xC000036C, "STATUS_DRIVER_BLOCKED"),
+    (0xC000036D, "STATUS_DRIVER_DATABASE_ERROR"),
+    (0xC000036E, "STATUS_SYSTEM_HIVE_TOO_LARGE"),
+    (0xC000036F, "STATUS_INVALID_IMPORT_OF_NON_DLL"),
+    (0xC0000371, "STATUS_NO_SECRETS"),
+    (0xC0000372, "STATUS_ACCESS_DISABLED_NO_SAFER_UI_BY_POLICY"),
+    (0xC0000373, "STATUS_FAILED_STACK_SWITCH"),
+    (0xC0000374, "STATUS_HEAP_CORRUPTION"),
+    (0xC0000380, "STATUS_SMARTCARD_WRONG_PIN"),
+    (0xC0000381, "STATUS_SMARTCARD_CARD_BLOCKED"),
+    (0xC0000382, "STATUS_SMARTCARD_CARD_NOT_AUTHENTICATED"),
+    (0xC0000383, "STATUS_SMARTCARD_NO_CARD"),
+    (0xC0000384, "STATUS_SMARTCARD_NO_KEY_CONTAINER"),
+    (0xC0000385, "STATUS_SMARTCARD_NO_CERTIFICATE"),
+    (0xC0000386, "STATUS_SMARTCARD_NO_KEYSET"),
+    (0xC0000387, "STATUS_SMARTCARD_IO_ERROR"),
+    (0xC0000389, "STATUS_SMARTCARD_CERT_REVOKED"),
+    (0xC000038A, "STATUS_ISSUING_CA_UNTRUSTED"),
+    (0xC000038B, "STATUS_REVOCATION_OFFLINE_C"),
+    (0xC000038C, "STATUS_PKINIT_CLIENT_FAILURE"),
+    (0xC000038D, "STATUS_SMARTCARD_CERT_EXPIRED"),
+    (0xC000038E, "STATUS_DRIVER_FAILED_PRIOR_UNLOAD"),
+    (0xC000038F, "STATUS_SMARTCARD_SILENT_CONTEXT"),
+    (0xC0000401, "STATUS_PER_USER_TRUST_QUOTA_EXCEEDED"),
+    (0xC0000402, "STATUS_ALL_USER_TRUST_QUOTA_EXCEEDED"),
+    (0xC0000403, "STATUS_USER_DELETE_TRUST_QUOTA_EXCEEDED"),
+    (0xC0000404, "STATUS_DS_NAME_NOT_UNIQUE"),
+    (0xC0000405, "STATUS_DS_DUPLICATE_ID_FOUND"),
+    (0xC0000406, "STATUS_DS_GROUP_CONVERSION_ERROR"),
+    (0xC0000407, "STATUS_VOLSNAP_PREPARE_HIBERNATE"),
+    (0xC0000408, "STATUS_USER2USER_REQUIRED"),
+    (0xC0000409, "STATUS_STACK_BUFFER_OVERRUN"),
+    (0xC000040A, "STATUS_NO_S4U_PROT_SUPPORT"),
+    (0xC000040B, "STATUS_CROSSREALM_DELEGATION_FAILURE"),
+    (0xC000040C, "STATUS_REVOCATION_OFFLINE_KDC"),
+    (0xC000040D, "STATUS_ISSUING_CA_UNTRUSTED_KDC"),
+    (0xC000040E, "STATUS_KDC_CERT_EXPIRED"),
+    (0xC000040F, "STATUS_KDC_CERT_REVOKED"),
+    (0xC0000410, "STATUS_PARAMETER_QUOTA_EXCEEDED"),
+    (0xC0000411, "STATUS_HIBERNATION_FAILURE"),
+    (0xC0000412, "STATUS_DELAY_LOAD_FAILED"),
+    (0xC0000414, "STATUS_VDM_DISALLOWED"),
+    (0xC0000415, "STATUS_HUNG_DISPLAY_DRIVER_THREAD"),
+    (0xC0000416, "STATUS_INSUFFICIENT_RESOURCE_FOR_SPECIFIED_SHARED_SECTION_SIZE"),
+    (0xC0000417, "STATUS_INVALID_CRUNTIME_PARAMETER"),
+    (0xC0000418, "STATUS_NTLM_BLOCKED"),
+    (0xC0000419, "STATUS_DS_SRC_SID_EXISTS_IN_FOREST"),
+    (0xC000041A, "STATUS_DS_DOMAIN_NAME_EXISTS_IN_FOREST"),
+    (0xC000041B, "STATUS_DS_FLAT_NAME_EXISTS_IN_FOREST"),
+    (0xC000041C, "STATUS_INVALID_USER_PRINCIPAL_NAME"),
+    (0xC000041D, "STATUS_FATAL_USER_CALLBACK_EXCEPTION"),
+    (0xC0000420, "STATUS_ASSERTION_FAILURE"),
+    (0xC0000421, "STATUS_VERIFIER_STOP"),
+    (0xC0000423, "STATUS_CALLBACK_POP_STACK"),
+    (0xC0000424, "STATUS_INCOMPATIBLE_DRIVER_BLOCKED"),
+    (0xC0000425, "STATUS_HIVE_UNLOADED"),
+    (0xC0000426, "STATUS_COMPRESSION_DISABLED"),
+    (0xC0000427, "STATUS_FILE_SYSTEM_LIMITATION"),
+    (0xC0000428, "STATUS_INVALID_IMAGE_HASH"),
+    (0xC0000429, "STATUS_NOT_CAPABLE"),
+    (0xC000042A, "STATUS_REQUEST_OUT_OF_SEQUENCE"),
+    (0xC000042B, "STATUS_IMPLEMENTATION_LIMIT"),
+    (0xC000042C, "STATUS_ELEVATION_REQUIRED"),
+    (0xC000042D, "STATUS_NO_SECURITY_CONTEXT"),
+    (0xC000042F, "STATUS_PKU2U_CERT_FAILURE"),
+    (0xC0000432, "STATUS_BEYOND_VDL"),
+    (0xC0000433, "STATUS_ENCOUNTERED_WRITE_IN_PROGRESS"),
+    (0xC0000434, "STATUS_PTE_CHANGED"),
+    (0xC0000435, "STATUS_PURGE_FAILED"),
+    (0xC0000440, "STATUS_CRED_REQUIRES_CONFIRMATION"),
+    (0xC0000441, "STATUS_CS_ENCRYPTION_INVALID_SERVER_RESPONSE"),
+    (0xC0000442, "STATUS_CS_ENCRYPTION_UNSUPPORTED_SERVER"),
+    (0xC0000443, "STATUS_CS_ENCRYPTION_EXISTING_ENCRYPTED_FILE"),
+    (0xC0000444, "STATUS_CS_ENCRYPTION_NEW_ENCRYPTED_FILE"),
+    (0xC0000445, "STATUS_CS_ENCRYPTION_FILE_NOT_CSE"),
+    (0xC0000446, "STATUS_INVALID_LABEL"),
+    (0xC0000450, "STATUS_DRIVER_PROCESS_TERMINATED"),
+    (0xC0000451, "STATUS_AMBIGUOUS_SYSTEM_DEVICE"),
+    (0xC0000452, "STATUS_SYSTEM_DEVICE_NOT_FOUND"),
+    (0xC0000453, "STATUS_RESTART_BOOT_APPLICATION"),
+    (0xC0000454, "STATUS_INSUFFICIENT_NVRAM_RESOURCES"),
+    (0xC0000455, "STATUS_INVALID_SESSION"),
+    (0xC0000456, "STATUS_THREAD_ALREADY_IN_SESSION"),
+    (0xC0000457, "STATUS_THREAD_NOT_IN_SESSION"),
+    (0xC0000458, "STATUS_INVALID_WEIGHT"),
+    (0xC0000459, "STATUS_REQUEST_PAUSED"),
+    (0xC0000460, "STATUS_NO_RANGES_PROCESSED"),
+    (0xC0000461, "STATUS_DISK_RESOURCES_EXHAUSTED"),
+    (0xC0000462, "STATUS_NEEDS_REMEDIATION"),
+    (0xC0000463, "STATUS_DEVICE_FEATURE_NOT_SUPPORTED"),
+    (0xC0000464, "STATUS_DEVICE_UNREACHABLE"),
+    (0xC0000465, "STATUS_INVALID_TOKEN"),
+    (0xC0000466, "STATUS_SERVER_UNAVAILABLE"),
+    (0xC0000467, "STATUS_FILE_NOT_AVAILABLE"),
+    (0xC0000468, "STATUS_DEVICE_INSUFFICIENT_RESOURCES"),
+    (0xC0000469, "STATUS_PACKAGE_UPDATING"),
+    (0xC000046A, "STATUS_NOT_READ_FROM_COPY"),
+    (0xC000046B, "STATUS_FT_WRITE_FAILURE"),
+    (0xC000046C, "STATUS_FT_DI_SCAN_REQUIRED"),
+    (0xC000046D, "STATUS_OBJECT_NOT_EXTERNALLY_BACKED"),
+    (0xC000046E, "STATUS_EXTERNAL_BACKING_PROVIDER_UNKNOWN"),
+    (0xC000046F, "STATUS_COMPRESSION_NOT_BENEFICIAL"),
+    (0xC0000470, "STATUS_DATA_CHECKSUM_ERROR"),
+    (0xC0000471, "STATUS_INTERMIXED_KERNEL_EA_OPERATION"),
+    (0xC0000472, "STATUS_TRIM_READ_ZERO_NOT_SUPPORTED"),
+    (0xC0000473, "STATUS_TOO_MANY_SEGMENT_DESCRIPTORS"),
+    (0xC0000474, "STATUS_INVALID_OFFSET_ALIGNMENT"),
+    (0xC0000475, "STATUS_INVALID_FIELD_IN_PARAMETER_LIST"),
+    (0xC0000476, "STATUS_OPERATION_IN_PROGRESS"),
+    (0xC0000477, "STATUS_INVALID_INITIATOR_TARGET_PATH"),
+    (0xC0000478, "STATUS_SCRUB_DATA_DISABLED"),
+    (0xC0000479, "STATUS_NOT_REDUNDANT_STORAGE"),
+    (0xC000047A, "STATUS_RESIDENT_FILE_NOT_SUPPORTED"),
+    (0xC000047B, "STATUS_COMPRESSED_FILE_NOT_SUPPORTED"),
+    (0xC000047C, "STATUS_DIRECTORY_NOT_SUPPORTED"),
+    (0xC000047D, "STATUS_IO_OPERATION_TIMEOUT"),
+    (0xC000047E, "STATUS_SYSTEM_NEEDS_REMEDIATION"),
+    (0xC000047F, "STATUS_APPX_INTEGRITY_FAILURE_CLR_NGEN"),
+    (0xC0000480, "STATUS_SHARE_UNAVAILABLE"),
+    (0xC0000481, "STATUS_APISET_NOT_HOSTED"),
+    (0xC0000482, "STATUS_APISET_NOT_PRESENT"),
+    (0xC0000483, "STATUS_DEVICE_HARDWARE_ERROR"),
+    (0xC0000484, "STATUS_FIRMWARE_SLOT_INVALID"),
+    (0xC0000485, "STATUS_FIRMWARE_IMAGE_INVALID"),
+    (0xC0000486, "STATUS_STORAGE_TOPOLOGY_ID_MISMATCH"),
+    (0xC0000487, "STATUS_WIM_NOT_BOOTABLE"),
+    (0xC0000488, "STATUS_BLOCKED_BY_PARENTAL_CONTROLS"),
+    (0xC0000489, "STATUS_NEEDS_REGISTRATION"),
+    (0xC000048A, "STATUS_QUOTA_ACTIVITY"),
+    (0xC000048B, "STATUS_CALLBACK_INVOKE_INLINE"),
+    (0xC000048C, "STATUS_BLOCK_TOO_MANY_REFERENCES"),
+    (0xC000048D, "STATUS_MARKED_TO_DISALLOW_WRITES"),
+    (0xC000048E, "STATUS_NETWORK_ACCESS_DENIED_EDP"),
+    (0xC000048F, "STATUS_ENCLAVE_FAILURE"),
+    (0xC0000490, "STATUS_PNP_NO_COMPAT_DRIVERS"),
+    (0xC0000491, "STATUS_PNP_DRIVER_PACKAGE_NOT_FOUND"),
+    (0xC0000492, "STATUS_PNP_DRIVER_CONFIGURATION_NOT_FOUND"),
+    (0xC0000493, "STATUS_PNP_DRIVER_CONFIGURATION_INCOMPLETE"),
+    (0xC0000494, "STATUS_PNP_FUNCTION_DRIVER_REQUIRED"),
+    (0xC0000495, "STATUS_PNP_DEVICE_CONFIGURATION_PENDING"),
+    (0xC0000496, "STATUS_DEVICE_HINT_NAME_BUFFER_TOO_SMALL"),
+    (0xC0000497, "STATUS_PACKAGE_NOT_AVAILABLE"),
+    (0xC0000499, "STATUS_DEVICE_IN_MAINTENANCE"),
+    (0xC000049A, "STATUS_NOT_SUPPORTED_ON_DAX"),
+    (0xC000049B, "STATUS_FREE_SPACE_TOO_FRAGMENTED"),
+    (0xC000049C, "STATUS_DAX_MAPPING_EXISTS"),
+    (0xC000049D, "STATUS_CHILD_PROCESS_BLOCKED"),
+    (0xC000049E, "STATUS_STORAGE_LOST_DATA_PERSISTENCE"),
+    (0xC00004A0, "STATUS_PARTITION_TERMINATING"),
+    (0xC00004A1, "STATUS_EXTERNAL_SYSKEY_NOT_SUPPORTED"),
+    (0xC00004A2, "STATUS_ENCLAVE_VIOLATION"),
+    (0xC00004A3, "STATUS_FILE_PROTECTED_UNDER_DPL"),
+    (0xC00004A4, "STATUS_VOLUME_NOT_CLUSTER_ALIGNED"),
+    (0xC00004A5, "STATUS_NO_PHYSICALLY_ALIGNED_FREE_SPACE_FOUND"),
+    (0xC00004A6, "STATUS_APPX_FILE_NOT_ENCRYPTED"),
+    (0xC00004A7, "STATUS_RWRAW_ENCRYPTED_FILE_NOT_ENCRYPTED"),
+    (0xC00004A8, "STATUS_RWRAW_ENCRYPTED_INVALID_EDATAINFO_FILEOFFSET"),
+    (0xC00004A9, "STATUS_RWRAW_ENCRYPTED_INVALID_EDATAINFO_FILERANGE"),
+    (0xC00004AA, "STATUS_RWRAW_ENCRYPTED_INVALID_EDATAINFO_PARAMETER"),
+    (0xC00004AB, "STATUS_FT_READ_FAILURE"),
+    (0xC00004AC, "STATUS_PATCH_CONFLICT"),
+    (0xC00004AD, "STATUS_STORAGE_RESERVE_ID_INVALID"),
+    (0xC00004AE, "STATUS_STORAGE_RESERVE_DOES_NOT_EXIST"),
+    (0xC00004AF, "STATUS_STORAGE_RESERVE_ALREADY_EXISTS"),
+    (0xC00004B0, "STATUS_STORAGE_RESERVE_NOT_EMPTY"),
+    (0xC00004B1, "STATUS_NOT_A_DAX_VOLUME"),
+    (0xC00004B2, "STATUS_NOT_DAX_MAPPABLE"),
+    (0xC00004B3, "STATUS_CASE_DIFFERING_NAMES_IN_DIR"),
+    (0xC00004B4, "STATUS_FILE_NOT_SUPPORTED"),
+    (0xC00004B5, "STATUS_NOT_SUPPORTED_WITH_BTT"),
+    (0xC00004B6, "STATUS_ENCRYPTION_DISABLED"),
+    (0xC00004B7, "STATUS_ENCRYPTING_METADATA_DISALLOWED"),
+    (0xC00004B8, "STATUS_CANT_CLEAR_ENCRYPTION_FLAG"),
+    (0xC00004B9, "STATUS_UNSATISFIED_DEPENDENCIES"),
+    (0xC00004BA, "STATUS_CASE_SENSITIVE_PATH"),
+    (0xC00004BB, "STATUS_UNSUPPORTED_PAGING_MODE"),
+    (0xC00004BC, "STATUS_UNTRUSTED_MOUNT_POINT"),
+    (0xC00004BD, "STATUS_HAS_SYSTEM_CRITICAL_FILES"),
+    (0xC00004BE, "STATUS_OBJECT_IS_IMMUTABLE"),
+    (0xC00004BF, "STATUS_FT_READ_FROM_COPY_FAILURE"),
+    (0xC00004C0, "STATUS_IMAGE_LOADED_AS_PATCH_IMAGE"),
+    (0xC00004C1, "STATUS_STORAGE_STACK_ACCESS_DENIED"),
+    (0xC00004C2, "STATUS_INSUFFICIENT_VIRTUAL_ADDR_RESOURCES"),
+    (0xC00004C3, "STATUS_ENCRYPTED_FILE_NOT_SUPPORTED"),
+    (0xC00004C4, "STATUS_SPARSE_FILE_NOT_SUPPORTED"),
+    (0xC00004C5, "STATUS_PAGEFILE_NOT_SUPPORTED"),
+    (0xC00004C6, "STATUS_VOLUME_NOT_SUPPORTED"),
+    (0xC00004C7, "STATUS_NOT_SUPPORTED_WITH_BYPASSIO"),
+    (0xC00004C8, "STATUS_NO_BYPASSIO_DRIVER_SUPPORT"),
+    (0xC00004C9, "STATUS_NOT_SUPPORTED_WITH_ENCRYPTION"),
+    (0xC00004CA, "STATUS_NOT_SUPPORTED_WITH_COMPRESSION"),
+    (0xC00004CB, "STATUS_NOT_SUPPORTED_WITH_REPLICATION"),
+    (0xC00004CC, "STATUS_NOT_SUPPORTED_WITH_DEDUPLICATION"),
+    (0xC00004CD, "STATUS_NOT_SUPPORTED_WITH_AUDITING"),
+    (0xC00004CE, "STATUS_NOT_SUPPORTED_WITH_MONITORING"),
+    (0xC00004CF, "STATUS_NOT_SUPPORTED_WITH_SNAPSHOT"),
+    (0xC00004D0, "STATUS_NOT_SUPPORTED_WITH_VIRTUALIZATION"),
+    (0xC00004D1, "STATUS_INDEX_OUT_OF_BOUNDS"),
+    (0xC00004D2, "STATUS_BYPASSIO_FLT_NOT_SUPPORTED"),
+    (0xC00004D3, "STATUS_VOLUME_WRITE_ACCESS_DENIED"),
+    (0xC00004D4, "STATUS_PATCH_NOT_REGISTERED"),
+    (0xC00004D5, "STATUS_NOT_SUPPORTED_WITH_CACHED_HANDLE"),
+    (0xC00004D9, "STATUS_VOLUME_UPGRADE_NOT_NEEDED"),
+    (0xC00004DA, "STATUS_VOLUME_UPGRADE_PENDING"),
+    (0xC00004DB, "STATUS_VOLUME_UPGRADE_DISABLED"),
+    (0xC00004DC, "STATUS_VOLUME_UPGRADE_DISABLED_TILL_OS_DOWNGRADE_EXPIRED"),
+    (0xC00004DD, "STATUS_NOT_A_DEV_VOLUME"),
+    (0xC00004DE, "STATUS_FS_GUID_MISMATCH"),
+    (0xC00004DF, "STATUS_CANT_ATTACH_TO_DEV_VOLUME"),
+    (0xC00004E0, "STATUS_INVALID_CONFIG_VALUE"),
+    (0xC0000500, "STATUS_INVALID_TASK_NAME"),
+    (0xC0000501, "STATUS_INVALID_TASK_INDEX"),
+    (0xC0000502, "STATUS_THREAD_ALREADY_IN_TASK"),
+    (0xC0000503, "STATUS_CALLBACK_BYPASS"),
+    (0xC0000504, "STATUS_UNDEFINED_SCOPE"),
+    (0xC0000505, "STATUS_INVALID_CAP"),
+    (0xC0000506, "STATUS_NOT_GUI_PROCESS"),
+    (0xC0000507, "STATUS_DEVICE_HUNG"),
+    (0xC0000508, "STATUS_CONTAINER_ASSIGNED"),
+    (0xC0000509, "STATUS_JOB_NO_CONTAINER"),
+    (0xC000050A, "STATUS_DEVICE_UNRESPONSIVE"),
+    (0xC000050B, "STATUS_REPARSE_POINT_ENCOUNTERED"),
+    (0xC000050C, "STATUS_ATTRIBUTE_NOT_PRESENT"),
+    (0xC000050D, "STATUS_NOT_A_TIERED_VOLUME"),
+    (0xC000050E, "STATUS_ALREADY_HAS_STREAM_ID"),
+    (0xC000050F, "STATUS_JOB_NOT_EMPTY"),
+    (0xC0000510, "STATUS_ALREADY_INITIALIZED"),
+    (0xC0000511, "STATUS_ENCLAVE_NOT_TERMINATED"),
+    (0xC0000512, "STATUS_ENCLAVE_IS_TERMINATING"),
+    (0xC0000513, "STATUS_SMB1_NOT_AVAILABLE"),
+    (0xC0000514, "STATUS_SMR_GARBAGE_COLLECTION_REQUIRED"),
+    (0xC0000515, "STATUS_INTERRUPTED"),
+    (0xC0000516, "STATUS_THREAD_NOT_RUNNING"),
+    (0xC0000517, "STATUS_SESSION_KEY_TOO_SHORT"),
+    (0xC0000518, "STATUS_FS_METADATA_INCONSISTENT"),
+    (0xC0000602, "STATUS_FAIL_FAST_EXCEPTION"),
+    (0xC0000603, "STATUS_IMAGE_CERT_REVOKED"),
+    (0xC0000604, "STATUS_DYNAMIC_CODE_BLOCKED"),
+    (0xC0000605, "STATUS_IMAGE_CERT_EXPIRED"),
+    (0xC0000606, "STATUS_STRICT_CFG_VIOLATION"),
+    (0xC000060A, "STATUS_SET_CONTEXT_DENIED"),
+    (0xC000060B, "STATUS_CROSS_PARTITION_VIOLATION"),
+    (0xC0000700, "STATUS_PORT_CLOSED"),
+    (0xC0000701, "STATUS_MESSAGE_LOST"),
+    (0xC0000702, "STATUS_INVALID_MESSAGE"),
+    (0xC0000703, "STATUS_REQUEST_CANCELED"),
+    (0xC0000704, "STATUS_RECURSIVE_DISPATCH"),
+    (0xC0000705, "STATUS_LPC_RECEIVE_BUFFER_EXPECTED"),
+    (0xC0000706, "STATUS_LPC_INVALID_CONNECTION_USAGE"),
+    (0xC0000707, "STATUS_LPC_REQUESTS_NOT_ALLOWED"),
+    (0xC0000708, "STATUS_RESOURCE_IN_USE"),
+    (0xC0000709, "STATUS_HARDWARE_MEMORY_ERROR"),
+    (0xC000070A, "STATUS_THREADPOOL_HANDLE_EXCEPTION"),
+    (0xC000070B, "STATUS_THREADPOOL_SET_EVENT_ON_COMPLETION_FAILED"),
+    (0xC000070C, "STATUS_THREADPOOL_RELEASE_SEMAPHORE_ON_COMPLETION_FAILED"),
+    (0xC000070D, "STATUS_THREADPOOL_RELEASE_MUTEX_ON_COMPLETION_FAILED"),
+    (0xC000070E, "STATUS_THREADPOOL_FREE_LIBRARY_ON_COMPLETION_FAILED"),
+    (0xC000070F, "STATUS_THREADPOOL_RELEASED_DURING_OPERATION"),
+    (0xC0000710, "STATUS_CALLBACK_RETURNED_WHILE_IMPERSONATING"),
+    (0xC0000711, "STATUS_APC_RETURNED_WHILE_IMPERSONATING"),
+    (0xC0000712, "STATUS_PROCESS_IS_PROTECTED"),
+    (0xC0000713, "STATUS_MCA_EXCEPTION"),
+    (0xC0000714, "STATUS_CERTIFICATE_MAPPING_NOT_UNIQUE"),
+    (0xC0000715, "STATUS_SYMLINK_CLASS_DISABLED"),
+    (0xC0000716, "STATUS_INVALID_IDN_NORMALIZATION"),
+    (0xC0000717, "STATUS_NO_UNICODE_TRANSLATION"),
+    (0xC0000718, "STATUS_ALREADY_REGISTERED"),
+    (0xC0000719, "STATUS_CONTEXT_MISMATCH"),
+    (0xC000071A, "STATUS_PORT_ALREADY_HAS_COMPLETION_LIST"),
+    (0xC000071B, "STATUS_CALLBACK_RETURNED_THREAD_PRIORITY"),
+    (0xC000071C, "STATUS_INVALID_THREAD"),
+    (0xC000071D, "STATUS_CALLBACK_RETURNED_TRANSACTION"),
+    (0xC000071E, "STATUS_CALLBACK_RETURNED_LDR_LOCK"),
+    (0xC000071F, "STATUS_CALLBACK_RETURNED_LANG"),
+    (0xC0000720, "STATUS_CALLBACK_RETURNED_PRI_BACK"),
+    (0xC0000721, "STATUS_CALLBACK_RETURNED_THREAD_AFFINITY"),
+    (0xC0000722, "STATUS_LPC_HANDLE_COUNT_EXCEEDED"),
+    (0xC0000723, "STATUS_EXECUTABLE_MEMORY_WRITE"),
+    (0xC0000724, "STATUS_KERNEL_EXECUTABLE_MEMORY_WRITE"),
+    (0xC0000725, "STATUS_ATTACHED_EXECUTABLE_MEMORY_WRITE"),
+    (0xC0000726, "STATUS_TRIGGERED_EXECUTABLE_MEMORY_WRITE"),
+    (0xC0000800, "STATUS_DISK_REPAIR_DISABLED"),
+    (0xC0000801, "STATUS_DS_DOMAIN_RENAME_IN_PROGRESS"),
+    (0xC0000802, "STATUS_DISK_QUOTA_EXCEEDED"),
+    (0xC0000804, "STATUS_CONTENT_BLOCKED"),
+    (0xC0000805, "STATUS_BAD_CLUSTERS"),
+    (0xC0000806, "STATUS_VOLUME_DIRTY"),
+    (0xC0000808, "STATUS_DISK_REPAIR_UNSUCCESSFUL"),
+    (0xC0000809, "STATUS_CORRUPT_LOG_OVERFULL"),
+    (0xC000080A, "STATUS_CORRUPT_LOG_CORRUPTED"),
+    (0xC000080B, "STATUS_CORRUPT_LOG_UNAVAILABLE"),
+    (0xC000080C, "STATUS_CORRUPT_LOG_DELETED_FULL"),
+    (0xC000080D, "STATUS_CORRUPT_LOG_CLEARED"),
+    (0xC000080E, "STATUS_ORPHAN_NAME_EXHAUSTED"),
+    (0xC000080F, "STATUS_PROACTIVE_SCAN_IN_PROGRESS"),
+    (0xC0000810, "STATUS_ENCRYPTED_IO_NOT_POSSIBLE"),
+    (0xC0000811, "STATUS_CORRUPT_LOG_UPLEVEL_RECORDS"),
+    (0xC0000901, "STATUS_FILE_CHECKED_OUT"),
+    (0xC0000902, "STATUS_CHECKOUT_REQUIRED"),
+    (0xC0000903, "STATUS_BAD_FILE_TYPE"),
+    (0xC0000904, "STATUS_FILE_TOO_LARGE"),
+    (0xC0000905, "STATUS_FORMS_AUTH_REQUIRED"),
+    (0xC0000906, "STATUS_VIRUS_INFECTED"),
+    (0xC0000907, "STATUS_VIRUS_DELETED"),
+    (0xC0000908, "STATUS_BAD_MCFG_TABLE"),
+    (0xC0000909, "STATUS_CANNOT_BREAK_OPLOCK"),
+    (0xC000090A, "STATUS_BAD_KEY"),
+    (0xC000090B, "STATUS_BAD_DATA"),
+    (0xC000090C, "STATUS_NO_KEY"),
+    (0xC0000910, "STATUS_FILE_HANDLE_REVOKED"),
+    (0xC0000911, "STATUS_SECTION_DIRECT_MAP_ONLY"),
+    (0xC0000912, "STATUS_BLOCK_WEAK_REFERENCE_INVALID"),
+    (0xC0000913, "STATUS_BLOCK_SOURCE_WEAK_REFERENCE_INVALID"),
+    (0xC0000914, "STATUS_BLOCK_TARGET_WEAK_REFERENCE_INVALID"),
+    (0xC0000915, "STATUS_BLOCK_SHARED"),
+    (0xC0000C08, "STATUS_VRF_VOLATILE_CFG_AND_IO_ENABLED"),
+    (0xC0000C09, "STATUS_VRF_VOLATILE_NOT_STOPPABLE"),
+    (0xC0000C0A, "STATUS_VRF_VOLATILE_SAFE_MODE"),
+    (0xC0000C0B, "STATUS_VRF_VOLATILE_NOT_RUNNABLE_SYSTEM"),
+    (0xC0000C0C, "STATUS_VRF_VOLATILE_NOT_SUPPORTED_RULECLASS"),
+    (0xC0000C0D, "STATUS_VRF_VOLATILE_PROTECTED_DRIVER"),
+    (0xC0000C0E, "STATUS_VRF_VOLATILE_NMI_REGISTERED"),
+    (0xC0000C0F, "STATUS_VRF_VOLATILE_SETTINGS_CONFLICT"),
+    (0xC0000C76, "STATUS_DIF_IOCALLBACK_NOT_REPLACED"),
+    (0xC0000C77, "STATUS_DIF_LIVEDUMP_LIMIT_EXCEEDED"),
+    (0xC0000C78, "STATUS_DIF_VOLATILE_SECTION_NOT_LOCKED"),
+    (0xC0000C79, "STATUS_DIF_VOLATILE_DRIVER_HOTPATCHED"),
+    (0xC0000C7A, "STATUS_DIF_VOLATILE_INVALID_INFO"),
+    (0xC0000C7B, "STATUS_DIF_VOLATILE_DRIVER_IS_NOT_RUNNING"),
+    (0xC0000C7C, "STATUS_DIF_VOLATILE_PLUGIN_IS_NOT_RUNNING"),
+    (0xC0000C7D, "STATUS_DIF_VOLATILE_PLUGIN_CHANGE_NOT_ALLOWED"),
+    (0xC0000C7E, "STATUS_DIF_VOLATILE_NOT_ALLOWED"),
+    (0xC0000C7F, "STATUS_DIF_BINDING_API_NOT_FOUND"),
+    (0xC0009898, "STATUS_WOW_ASSERTION"),
+    (0xC000A000, "STATUS_INVALID_SIGNATURE"),
+    (0xC000A001, "STATUS_HMAC_NOT_SUPPORTED"),
+    (0xC000A002, "STATUS_AUTH_TAG_MISMATCH"),
+    (0xC000A003, "STATUS_INVALID_STATE_TRANSITION"),
+    (0xC000A004, "STATUS_INVALID_KERNEL_INFO_VERSION"),
+    (0xC000A005, "STATUS_INVALID_PEP_INFO_VERSION"),
+    (0xC000A006, "STATUS_HANDLE_REVOKED"),
+    (0xC000A007, "STATUS_EOF_ON_GHOSTED_RANGE"),
+    (0xC000A008, "STATUS_CC_NEEDS_CALLBACK_SECTION_DRAIN"),
+    (0xC000A010, "STATUS_IPSEC_QUEUE_OVERFLOW"),
+    (0xC000A011, "STATUS_ND_QUEUE_OVERFLOW"),
+    (0xC000A012, "STATUS_HOPLIMIT_EXCEEDED"),
+    (0xC000A013, "STATUS_PROTOCOL_NOT_SUPPORTED"),
+    (0xC000A014, "STATUS_FASTPATH_REJECTED"),
+    (0xC000A080, "STATUS_LOST_WRITEBEHIND_DATA_NETWORK_DISCONNECTED"),
+    (0xC000A081, "STATUS_LOST_WRITEBEHIND_DATA_NETWORK_SERVER_ERROR"),
+    (0xC000A082, "STATUS_LOST_WRITEBEHIND_DATA_LOCAL_DISK_ERROR"),
+    (0xC000A083, "STATUS_XML_PARSE_ERROR"),
+    (0xC000A084, "STATUS_XMLDSIG_ERROR"),
+    (0xC000A085, "STATUS_WRONG_COMPARTMENT"),
+    (0xC000A086, "STATUS_AUTHIP_FAILURE"),
+    (0xC000A087, "STATUS_DS_OID_MAPPED_GROUP_CANT_HAVE_MEMBERS"),
+    (0xC000A088, "STATUS_DS_OID_NOT_FOUND"),
+    (0xC000A089, "STATUS_INCORRECT_ACCOUNT_TYPE"),
+    (0xC000A08A, "STATUS_LOCAL_POLICY_MODIFICATION_NOT_SUPPORTED"),
+    (0xC000A08B, "STATUS_POLICY_CONTROLLED_ACCOUNT"),
+    (0xC000A08C, "STATUS_LAPS_LEGACY_SCHEMA_MISSING"),
+    (0xC000A08D, "STATUS_LAPS_SCHEMA_MISSING"),
+    (0xC000A08E, "STATUS_LAPS_ENCRYPTION_REQUIRES_2016_DFL"),
+    (0xC000A100, "STATUS_HASH_NOT_SUPPORTED"),
+    (0xC000A101, "STATUS_HASH_NOT_PRESENT"),
+    (0xC000A121, "STATUS_SECONDARY_IC_PROVIDER_NOT_REGISTERED"),
+    (0xC000A122, "STATUS_GPIO_CLIENT_INFORMATION_INVALID"),
+    (0xC000A123, "STATUS_GPIO_VERSION_NOT_SUPPORTED"),
+    (0xC000A124, "STATUS_GPIO_INVALID_REGISTRATION_PACKET"),
+    (0xC000A125, "STATUS_GPIO_OPERATION_DENIED"),
+    (0xC000A126, "STATUS_GPIO_INCOMPATIBLE_CONNECT_MODE"),
+    (0xC000A141, "STATUS_CANNOT_SWITCH_RUNLEVEL"),
+    (0xC000A142, "STATUS_INVALID_RUNLEVEL_SETTING"),
+    (0xC000A143, "STATUS_RUNLEVEL_SWITCH_TIMEOUT"),
+    (0xC000A145, "STATUS_RUNLEVEL_SWITCH_AGENT_TIMEOUT"),
+    (0xC000A146, "STATUS_RUNLEVEL_SWITCH_IN_PROGRESS"),
+    (0xC000A200, "STATUS_NOT_APPCONTAINER"),
+    (0xC000A201, "STATUS_NOT_SUPPORTED_IN_APPCONTAINER"),
+    (0xC000A202, "STATUS_INVALID_PACKAGE_SID_LENGTH"),
+    (0xC000A203, "STATUS_LPAC_ACCESS_DENIED"),
+    (0xC000A204, "STATUS_ADMINLESS_ACCESS_DENIED"),
+    (0xC000A281, "STATUS_APP_DATA_NOT_FOUND"),
+    (0xC000A282, "STATUS_APP_DATA_EXPIRED"),
+    (0xC000A283, "STATUS_APP_DATA_CORRUPT"),
+    (0xC000A284, "STATUS_APP_DATA_LIMIT_EXCEEDED"),
+    (0xC000A285, "STATUS_APP_DATA_REBOOT_REQUIRED"),
+    (0xC000A2A1, "STATUS_OFFLOAD_READ_FLT_NOT_SUPPORTED"),
+    (0xC000A2A2, "STATUS_OFFLOAD_WRITE_FLT_NOT_SUPPORTED"),
+    (0xC000A2A3, "STATUS_OFFLOAD_READ_FILE_NOT_SUPPORTED"),
+    (0xC000A2A4, "STATUS_OFFLOAD_WRITE_FILE_NOT_SUPPORTED"),
+    (0xC000A2A5, "STATUS_WOF_WIM_HEADER_CORRUPT"),
+    (0xC000A2A6, "STATUS_WOF_WIM_RESOURCE_TABLE_CORRUPT"),
+    (0xC000A2A7, "STATUS_WOF_FILE_RESOURCE_TABLE_CORRUPT"),
+    (0xC000C001, "STATUS_CIMFS_IMAGE_CORRUPT"),
+    (0xC000C002, "STATUS_CIMFS_IMAGE_VERSION_NOT_SUPPORTED"),
+    (0xC000CE01, "STATUS_FILE_SYSTEM_VIRTUALIZATION_UNAVAILABLE"),
+    (0xC000CE02, "STATUS_FILE_SYSTEM_VIRTUALIZATION_METADATA_CORRUPT"),
+    (0xC000CE03, "STATUS_FILE_SYSTEM_VIRTUALIZATION_BUSY"),
+    (0xC000CE04, "STATUS_FILE_SYSTEM_VIRTUALIZATION_PROVIDER_UNKNOWN"),
+    (0xC000CE05, "STATUS_FILE_SYSTEM_VIRTUALIZATION_INVALID_OPERATION"),
+    (0xC000CF00, "STATUS_CLOUD_FILE_SYNC_ROOT_METADATA_CORRUPT"),
+    (0xC000CF01, "STATUS_CLOUD_FILE_PROVIDER_NOT_RUNNING"),
+    (0xC000CF02, "STATUS_CLOUD_FILE_METADATA_CORRUPT"),
+    (0xC000CF03, "STATUS_CLOUD_FILE_METADATA_TOO_LARGE"),
+    (0xC000CF06, "STATUS_CLOUD_FILE_PROPERTY_VERSION_NOT_SUPPORTED"),
+    (0xC000CF07, "STATUS_NOT_A_CLOUD_FILE"),
+    (0xC000CF08, "STATUS_CLOUD_FILE_NOT_IN_SYNC"),
+    (0xC000CF09, "STATUS_CLOUD_FILE_ALREADY_CONNECTED"),
+    (0xC000CF0A, "STATUS_CLOUD_FILE_NOT_SUPPORTED"),
+    (0xC000CF0B, "STATUS_CLOUD_FILE_INVALID_REQUEST"),
+    (0xC000CF0C, "STATUS_CLOUD_FILE_READ_ONLY_VOLUME"),
+    (0xC000CF0D, "STATUS_CLOUD_FILE_CONNECTED_PROVIDER_ONLY"),
+    (0xC000CF0E, "STATUS_CLOUD_FILE_VALIDATION_FAILED"),
+    (0xC000CF0F, "STATUS_CLOUD_FILE_AUTHENTICATION_FAILED"),
+    (0xC000CF10, "STATUS_CLOUD_FILE_INSUFFICIENT_RESOURCES"),
+    (0xC000CF11, "STATUS_CLOUD_FILE_NETWORK_UNAVAILABLE"),
+    (0xC000CF12, "STATUS_CLOUD_FILE_UNSUCCESSFUL"),
+    (0xC000CF13, "STATUS_CLOUD_FILE_NOT_UNDER_SYNC_ROOT"),
+    (0xC000CF14, "STATUS_CLOUD_FILE_IN_USE"),
+    (0xC000CF15, "STATUS_CLOUD_FILE_PINNED"),
+    (0xC000CF16, "STATUS_CLOUD_FILE_REQUEST_ABORTED"),
+    (0xC000CF17, "STATUS_CLOUD_FILE_PROPERTY_CORRUPT"),
+    (0xC000CF18, "STATUS_CLOUD_FILE_ACCESS_DENIED"),
+    (0xC000CF19, "STATUS_CLOUD_FILE_INCOMPATIBLE_HARDLINKS"),
+    (0xC000CF1A, "STATUS_CLOUD_FILE_PROPERTY_LOCK_CONFLICT"),
+    (0xC000CF1B, "STATUS_CLOUD_FILE_REQUEST_CANCELED"),
+    (0xC000CF1D, "STATUS_CLOUD_FILE_PROVIDER_TERMINATED"),
+    (0xC000CF1E, "STATUS_NOT_A_CLOUD_SYNC_ROOT"),
+    (0xC000CF1F, "STATUS_CLOUD_FILE_REQUEST_TIMEOUT"),
+    (0xC000CF20, "STATUS_CLOUD_FILE_DEHYDRATION_DISALLOWED"),
+    (0xC000CF21, "STATUS_CLOUD_FILE_US_MESSAGE_TIMEOUT"),
+    (0xC000F500, "STATUS_FILE_SNAP_IN_PROGRESS"),
+    (0xC000F501, "STATUS_FILE_SNAP_USER_SECTION_NOT_SUPPORTED"),
+    (0xC000F502, "STATUS_FILE_SNAP_MODIFY_NOT_SUPPORTED"),
+    (0xC000F503, "STATUS_FILE_SNAP_IO_NOT_COORDINATED"),
+    (0xC000F504, "STATUS_FILE_SNAP_UNEXPECTED_ERROR"),
+    (0xC000F505, "STATUS_FILE_SNAP_INVALID_PARAMETER"),
+    (0xC0010001, "DBG_NO_STATE_CHANGE"),
+    (0xC0010002, "DBG_APP_NOT_IDLE"),
+    (0xC0020001, "RPC_NT_INVALID_STRING_BINDING"),
+    (0xC0020002, "RPC_NT_WRONG_KIND_OF_BINDING"),
+    (0xC0020003, "RPC_NT_INVALID_BINDING"),
+    (0xC0020004, "RPC_NT_PROTSEQ_NOT_SUPPORTED"),
+    (0xC0020005, "RPC_NT_INVALID_RPC_PROTSEQ"),
+    (0xC0020006, "RPC_NT_INVALID_STRING_UUID"),
+    (0xC0020007, "RPC_NT_INVALID_ENDPOINT_FORMAT"),
+    (0xC0020008, "RPC_NT_INVALID_NET_ADDR"),
+    (0xC0020009, "RPC_NT_NO_ENDPOINT_FOUND"),
+    (0xC002000A, "RPC_NT_INVALID_TIMEOUT"),
+    (0xC002000B, "RPC_NT_OBJECT_NOT_FOUND"),
+    (0xC002000C, "RPC_NT_ALREADY_REGISTERED"),
+    (0xC002000D, "RPC_NT_TYPE_ALREADY_REGISTERED"),
+    (0xC002000E, "RPC_NT_ALREADY_LISTENING"),
+    (0xC002000F, "RPC_NT_NO_PROTSEQS_REGISTERED"),
+    (0xC0020010, "RPC_NT_NOT_LISTENING"),
+    (0xC0020011, "RPC_NT_UNKNOWN_MGR_TYPE"),
+    (0xC0020012, "RPC_NT_UNKNOWN_IF"),
+    (0xC0020013, "RPC_NT_NO_BINDINGS"),
+    (0xC0020014, "RPC_NT_NO_PROTSEQS"),
+    (0xC0020015, "RPC_NT_CANT_CREATE_ENDPOINT"),
+    (0xC0020016, "RPC_NT_OUT_OF_RESOURCES"),
+    (0xC0020017, "RPC_NT_SERVER_UNAVAILABLE"),
+    (0xC0020018, "RPC_NT_SERVER_TOO_BUSY"),
+    (0xC0020019, "RPC_NT_INVALID_NETWORK_OPTIONS"),
+    (0xC002001A, "RPC_NT_NO_CALL_ACTIVE"),
+    (0xC002001B, "RPC_NT_CALL_FAILED"),
+    (0xC002001C, "RPC_NT_CALL_FAILED_DNE"),
+    (0xC002001D, "RPC_NT_PROTOCOL_ERROR"),
+    (0xC002001F, "RPC_NT_UNSUPPORTED_TRANS_SYN"),
+    (0xC0020021, "RPC_NT_UNSUPPORTED_TYPE"),
+    (0xC0020022, "RPC_NT_INVALID_TAG"),
+    (0xC0020023, "RPC_NT_INVALID_BOUND"),
+    (0xC0020024, "RPC_NT_NO_ENTRY_NAME"),
+    (0xC0020025, "RPC_NT_INVALID_NAME_SYNTAX"),
+    (0xC0020026, "RPC_NT_UNSUPPORTED_NAME_SYNTAX"),
+    (0xC0020028, "RPC_NT_UUID_NO_ADDRESS"),
+    (0xC0020029, "RPC_NT_DUPLICATE_ENDPOINT"),
+    (0xC002002A, "RPC_NT_UNKNOWN_AUTHN_TYPE"),
+    (0xC002002B, "RPC_NT_MAX_CALLS_TOO_SMALL"),
+    (0xC002002C, "RPC_NT_STRING_TOO_LONG"),
+    (0xC002002D, "RPC_NT_PROTSEQ_NOT_FOUND"),
+    (0xC002002E, "RPC_NT_PROCNUM_OUT_OF_RANGE"),
+    (0xC002002F, "RPC_NT_BINDING_HAS_NO_AUTH"),
+    (0xC0020030, "RPC_NT_UNKNOWN_AUTHN_SERVICE"),
+    (0xC0020031, "RPC_NT_UNKNOWN_AUTHN_LEVEL"),
+    (0xC0020032, "RPC_NT_INVALID_AUTH_IDENTITY"),
+    (0xC0020033, "RPC_NT_UNKNOWN_AUTHZ_SERVICE"),
+    (0xC0020034, "EPT_NT_INVALID_ENTRY"),
+    (0xC0020035, "EPT_NT_CANT_PERFORM_OP"),
+    (0xC0020036, "EPT_NT_NOT_REGISTERED"),
+    (0xC0020037, "RPC_NT_NOTHING_TO_EXPORT"),
+    (0xC0020038, "RPC_NT_INCOMPLETE_NAME"),
+    (0xC0020039, "RPC_NT_INVALID_VERS_OPTION"),
+    (0xC002003A, "RPC_NT_NO_MORE_MEMBERS"),
+    (0xC002003B, "RPC_NT_NOT_ALL_OBJS_UNEXPORTED"),
+    (0xC002003C, "RPC_NT_INTERFACE_NOT_FOUND"),
+    (0xC002003D, "RPC_NT_ENTRY_ALREADY_EXISTS"),
+    (0xC002003E, "RPC_NT_ENTRY_NOT_FOUND"),
+    (0xC002003F, "RPC_NT_NAME_SERVICE_UNAVAILABLE"),
+    (0xC0020040, "RPC_NT_INVALID_NAF_ID"),
+    (0xC0020041, "RPC_NT_CANNOT_SUPPORT"),
+    (0xC0020042, "RPC_NT_NO_CONTEXT_AVAILABLE"),
+    (0xC0020043, "RPC_NT_INTERNAL_ERROR"),
+    (0xC0020044, "RPC_NT_ZERO_DIVIDE"),
+    (0xC0020045, "RPC_NT_ADDRESS_ERROR"),
+    (0xC0020046, "RPC_NT_FP_DIV_ZERO"),
+    (0xC0020047, "RPC_NT_FP_UNDERFLOW"),
+    (0xC0020048, "RPC_NT_FP_OVERFLOW"),
+    (0xC0020049, "RPC_NT_CALL_IN_PROGRESS"),
+    (0xC002004A, "RPC_NT_NO_MORE_BINDINGS"),
+    (0xC002004B, "RPC_NT_GROUP_MEMBER_NOT_FOUND"),
+    (0xC002004C, "EPT_NT_CANT_CREATE"),
+    (0xC002004D, "RPC_NT_INVALID_OBJECT"),
+    (0xC002004F, "RPC_NT_NO_INTERFACES"),
+    (0xC0020050, "RPC_NT_CALL_CANCELLED"),
+    (0xC0020051, "RPC_NT_BINDING_INCOMPLETE"),
+    (0xC0020052, "RPC_NT_COMM_FAILURE"),
+    (0xC0020053, "RPC_NT_UNSUPPORTED_AUTHN_LEVEL"),
+    (0xC0020054, "RPC_NT_NO_PRINC_NAME"),
+    (0xC0020055, "RPC_NT_NOT_RPC_ERROR"),
+    (0xC0020057, "RPC_NT_SEC_PKG_ERROR"),
+    (0xC0020058, "RPC_NT_NOT_CANCELLED"),
+    (0xC0020062, "RPC_NT_INVALID_ASYNC_HANDLE"),
+    (0xC0020063, "RPC_NT_INVALID_ASYNC_CALL"),
+    (0xC0020064, "RPC_NT_PROXY_ACCESS_DENIED"),
+    (0xC0020065, "RPC_NT_COOKIE_AUTH_FAILED"),
+    (0xC0030001, "RPC_NT_NO_MORE_ENTRIES"),
+    (0xC0030002, "RPC_NT_SS_CHAR_TRANS_OPEN_FAIL"),
+    (0xC0030003, "RPC_NT_SS_CHAR_TRANS_SHORT_FILE"),
+    (0xC0030004, "RPC_NT_SS_IN_NULL_CONTEXT"),
+    (0xC0030005, "RPC_NT_SS_CONTEXT_MISMATCH"),
+    (0xC0030006, "RPC_NT_SS_CONTEXT_DAMAGED"),
+    (0xC0030007, "RPC_NT_SS_HANDLES_MISMATCH"),
+    (0xC0030008, "RPC_NT_SS_CANNOT_GET_CALL_HANDLE"),
+    (0xC0030009, "RPC_NT_NULL_REF_POINTER"),
+    (0xC003000A, "RPC_NT_ENUM_VALUE_OUT_OF_RANGE"),
+    (0xC003000B, "RPC_NT_BYTE_COUNT_TOO_SMALL"),
+    (0xC003000C, "RPC_NT_BAD_STUB_DATA"),
+    (0xC0030059, "RPC_NT_INVALID_ES_ACTION"),
+    (0xC003005A, "RPC_NT_WRONG_ES_VERSION"),
+    (0xC003005B, "RPC_NT_WRONG_STUB_VERSION"),
+    (0xC003005C, "RPC_NT_INVALID_PIPE_OBJECT"),
+    (0xC003005D, "RPC_NT_INVALID_PIPE_OPERATION"),
+    (0xC003005E, "RPC_NT_WRONG_PIPE_VERSION"),
+    (0xC003005F, "RPC_NT_PIPE_CLOSED"),
+    (0xC0030060, "RPC_NT_PIPE_DISCIPLINE_ERROR"),
+    (0xC0030061, "RPC_NT_PIPE_EMPTY"),
+    (0xC0040002, "IO_ERR_INSUFFICIENT_RESOURCES"),
+    (0xC0040003, "IO_ERR_CONFIGURATION_ERROR"),
+    (0xC0040004, "IO_ERR_DRIVER_ERROR"),
+    (0xC0040005, "IO_ERR_PARITY"),
+    (0xC0040006, "IO_ERR_SEEK_ERROR"),
+    (0xC0040007, "IO_ERR_BAD_BLOCK"),
+    (0xC0040008, "IO_ERR_OVERRUN_ERROR"),
+    (0xC0040009, "IO_ERR_TIMEOUT"),
+    (0xC004000A, "IO_ERR_SEQUENCE"),
+    (0xC004000B, "IO_ERR_CONTROLLER_ERROR"),
+    (0xC004000C, "IO_ERR_INTERNAL_ERROR"),
+    (0xC004000D, "IO_ERR_INCORRECT_IRQL"),
+    (0xC004000E, "IO_ERR_INVALID_IOBASE"),
+    (0xC004000F, "IO_ERR_NOT_READY"),
+    (0xC0040010, "IO_ERR_INVALID_REQUEST"),
+    (0xC0040011, "IO_ERR_VERSION"),
+    (0xC0040012, "IO_ERR_LAYERED_FAILURE"),
+    (0xC0040013, "IO_ERR_RESET"),
+    (0xC0040014, "IO_ERR_PROTOCOL"),
+    (0xC0040015, "IO_ERR_MEMORY_CONFLICT_DETECTED"),
+    (0xC0040016, "IO_ERR_PORT_CONFLICT_DETECTED"),
+    (0xC0040017, "IO_ERR_DMA_CONFLICT_DETECTED"),
+    (0xC0040018, "IO_ERR_IRQ_CONFLICT_DETECTED"),
+    (0xC0040019, "IO_ERR_BAD_FIRMWARE"),
+    (0xC004001B, "IO_ERR_DMA_RESOURCE_CONFLICT"),
+    (0xC004001C, "IO_ERR_INTERRUPT_RESOURCE_CONFLICT"),
+    (0xC004001D, "IO_ERR_MEMORY_RESOURCE_CONFLICT"),
+    (0xC004001E, "IO_ERR_PORT_RESOURCE_CONFLICT"),
+    (0xC004001F, "IO_BAD_BLOCK_WITH_NAME"),
+    (0xC0040029, "IO_FILE_SYSTEM_CORRUPT"),
+    (0xC004002A, "IO_FILE_QUOTA_CORRUPT"),
+    (0xC004002B, "IO_SYSTEM_SLEEP_FAILED"),
+    (0xC004002C, "IO_DUMP_POINTER_FAILURE"),
+    (0xC004002D, "IO_DUMP_DRIVER_LOAD_FAILURE"),
+    (0xC004002E, "IO_DUMP_INITIALIZATION_FAILURE"),
+    (0xC004002F, "IO_DUMP_DUMPFILE_CONFLICT"),
+    (0xC0040030, "IO_DUMP_DIRECT_CONFIG_FAILED"),
+    (0xC0040031, "IO_DUMP_PAGE_CONFIG_FAILED"),
+    (0xC0040035, "STATUS_PNP_BAD_MPS_TABLE"),
+    (0xC0040036, "STATUS_PNP_TRANSLATION_FAILED"),
+    (0xC0040037, "STATUS_PNP_IRQ_TRANSLATION_FAILED"),
+    (0xC0040038, "STATUS_PNP_INVALID_ID"),
+    (0xC0040039, "STATUS_IO_REISSUE_AS_CACHED"),
+    (0xC004006C, "IO_ERR_THREAD_STUCK_IN_DEVICE_DRIVER"),
+    (0xC0040075, "IO_ERR_PORT_TIMEOUT"),
+    (0xC0040096, "IO_ERROR_DISK_RESOURCES_EXHAUSTED"),
+    (0xC004009A, "IO_ERROR_IO_HARDWARE_ERROR"),
+    (0xC00400A1, "IO_ERROR_DUMP_CREATION_ERROR"),
+    (0xC00400A3, "IO_DUMP_CALLBACK_EXCEPTION"),
+    (0xC00400A4, "IO_DUMP_INIT_DEDICATED_DUMP_FAILURE"),
+    (0xC005003D, "MCA_ERROR_CACHE"),
+    (0xC005003F, "MCA_ERROR_TLB"),
+    (0xC0050041, "MCA_ERROR_CPU_BUS"),
+    (0xC0050043, "MCA_ERROR_REGISTER_FILE"),
+    (0xC0050045, "MCA_ERROR_MAS"),
+    (0xC0050047, "MCA_ERROR_MEM_UNKNOWN"),
+    (0xC0050049, "MCA_ERROR_MEM_1_2"),
+    (0xC005004B, "MCA_ERROR_MEM_1_2_5"),
+    (0xC005004D, "MCA_ERROR_MEM_1_2_5_4"),
+    (0xC005004F, "MCA_ERROR_SYSTEM_EVENT"),
+    (0xC0050051, "MCA_ERROR_PCI_BUS_PARITY"),
+    (0xC0050053, "MCA_ERROR_PCI_BUS_PARITY_NO_INFO"),
+    (0xC0050055, "MCA_ERROR_PCI_BUS_SERR"),
+    (0xC0050057, "MCA_ERROR_PCI_BUS_SERR_NO_INFO"),
+    (0xC0050059, "MCA_ERROR_PCI_BUS_MASTER_ABORT"),
+    (0xC005005B, "MCA_ERROR_PCI_BUS_MASTER_ABORT_NO_INFO"),
+    (0xC005005D, "MCA_ERROR_PCI_BUS_TIMEOUT"),
+    (0xC005005F, "MCA_ERROR_PCI_BUS_TIMEOUT_NO_INFO"),
+    (0xC0050061, "MCA_ERROR_PCI_BUS_UNKNOWN"),
+    (0xC0050063, "MCA_ERROR_PCI_DEVICE"),
+    (0xC0050065, "MCA_ERROR_SMBIOS"),
+    (0xC0050067, "MCA_ERROR_PLATFORM_SPECIFIC"),
+    (0xC0050069, "MCA_ERROR_UNKNOWN"),
+    (0xC005006B, "MCA_ERROR_UNKNOWN_NO_CPU"),
+    (0xC0050072, "MCA_ERROR_CPU"),
+    (0xC0050078, "MCA_MEMORYHIERARCHY_ERROR"),
+    (0xC0050079, "MCA_TLB_ERROR"),
+    (0xC005007A, "MCA_BUS_ERROR"),
+    (0xC005007B, "MCA_BUS_TIMEOUT_ERROR"),
+    (0xC005007C, "MCA_INTERNALTIMER_ERROR"),
+    (0xC005007E, "MCA_MICROCODE_ROM_PARITY_ERROR"),
+    (0xC005007F, "MCA_EXTERNAL_ERROR"),
+    (0xC0050080, "MCA_FRC_ERROR"),
+    (0xC00A0001, "STATUS_CTX_WINSTATION_NAME_INVALID"),
+    (0xC00A0002, "STATUS_CTX_INVALID_PD"),
+    (0xC00A0003, "STATUS_CTX_PD_NOT_FOUND"),
+    (0xC00A0006, "STATUS_CTX_CLOSE_PENDING"),
+    (0xC00A0007, "STATUS_CTX_NO_OUTBUF"),
+    (0xC00A0008, "STATUS_CTX_MODEM_INF_NOT_FOUND"),
+    (0xC00A0009, "STATUS_CTX_INVALID_MODEMNAME"),
+    (0xC00A000A, "STATUS_CTX_RESPONSE_ERROR"),
+    (0xC00A000B, "STATUS_CTX_MODEM_RESPONSE_TIMEOUT"),
+    (0xC00A000C, "STATUS_CTX_MODEM_RESPONSE_NO_CARRIER"),
+    (0xC00A000D, "STATUS_CTX_MODEM_RESPONSE_NO_DIALTONE"),
+    (0xC00A000E, "STATUS_CTX_MODEM_RESPONSE_BUSY"),
+    (0xC00A000F, "STATUS_CTX_MODEM_RESPONSE_VOICE"),
+    (0xC00A0010, "STATUS_CTX_TD_ERROR"),
+    (0xC00A0012, "STATUS_CTX_LICENSE_CLIENT_INVALID"),
+    (0xC00A0013, "STATUS_CTX_LICENSE_NOT_AVAILABLE"),
+    (0xC00A0014, "STATUS_CTX_LICENSE_EXPIRED"),
+    (0xC00A0015, "STATUS_CTX_WINSTATION_NOT_FOUND"),
+    (0xC00A0016, "STATUS_CTX_WINSTATION_NAME_COLLISION"),
+    (0xC00A0017, "STATUS_CTX_WINSTATION_BUSY"),
+    (0xC00A0018, "STATUS_CTX_BAD_VIDEO_MODE"),
+    (0xC00A0022, "STATUS_CTX_GRAPHICS_INVALID"),
+    (0xC00A0024, "STATUS_CTX_NOT_CONSOLE"),
+    (0xC00A0026, "STATUS_CTX_CLIENT_QUERY_TIMEOUT"),
+    (0xC00A0027, "STATUS_CTX_CONSOLE_DISCONNECT"),
+    (0xC00A0028, "STATUS_CTX_CONSOLE_CONNECT"),
+    (0xC00A002A, "STATUS_CTX_SHADOW_DENIED"),
+    (0xC00A002B, "STATUS_CTX_WINSTATION_ACCESS_DENIED"),
+    (0xC00A002E, "STATUS_CTX_INVALID_WD"),
+    (0xC00A002F, "STATUS_CTX_WD_NOT_FOUND"),
+    (0xC00A0030, "STATUS_CTX_SHADOW_INVALID"),
+    (0xC00A0031, "STATUS_CTX_SHADOW_DISABLED"),
+    (0xC00A0032, "STATUS_RDP_PROTOCOL_ERROR"),
+    (0xC00A0033, "STATUS_CTX_CLIENT_LICENSE_NOT_SET"),
+    (0xC00A0034, "STATUS_CTX_CLIENT_LICENSE_IN_USE"),
+    (0xC00A0035, "STATUS_CTX_SHADOW_ENDED_BY_MODE_CHANGE"),
+    (0xC00A0036, "STATUS_CTX_SHADOW_NOT_RUNNING"),
+    (0xC00A0037, "STATUS_CTX_LOGON_DISABLED"),
+    (0xC00A0038, "STATUS_CTX_SECURITY_LAYER_ERROR"),
+    (0xC00A0039, "STATUS_TS_INCOMPATIBLE_SESSIONS"),
+    (0xC00A003A, "STATUS_TS_VIDEO_SUBSYSTEM_ERROR"),
+    (0xC00B0001, "STATUS_MUI_FILE_NOT_FOUND"),
+    (0xC00B0002, "STATUS_MUI_INVALID_FILE"),
+    (0xC00B0003, "STATUS_MUI_INVALID_RC_CONFIG"),
+    (0xC00B0004, "STATUS_MUI_INVALID_LOCALE_NAME"),
+    (0xC00B0005, "STATUS_MUI_INVALID_ULTIMATEFALLBACK_NAME"),
+    (0xC00B0006, "STATUS_MUI_FILE_NOT_LOADED"),
+    (0xC00B0007, "STATUS_RESOURCE_ENUM_USER_STOP"),
+    (0xC0130001, "STATUS_CLUSTER_INVALID_NODE"),
+    (0xC0130002, "STATUS_CLUSTER_NODE_EXISTS"),
+    (0xC0130003, "STATUS_CLUSTER_JOIN_IN_PROGRESS"),
+    (0xC0130004, "STATUS_CLUSTER_NODE_NOT_FOUND"),
+    (0xC0130005, "STATUS_CLUSTER_LOCAL_NODE_NOT_FOUND"),
+    (0xC0130006, "STATUS_CLUSTER_NETWORK_EXISTS"),
+    (0xC0130007, "STATUS_CLUSTER_NETWORK_NOT_FOUND"),
+    (0xC0130008, "STATUS_CLUSTER_NETINTERFACE_EXISTS"),
+    (0xC0130009, "STATUS_CLUSTER_NETINTERFACE_NOT_FOUND"),
+    (0xC013000A, "STATUS_CLUSTER_INVALID_REQUEST"),
+    (0xC013000B, "STATUS_CLUSTER_INVALID_NETWORK_PROVIDER"),
+    (0xC013000C, "STATUS_CLUSTER_NODE_DOWN"),
+    (0xC013000D, "STATUS_CLUSTER_NODE_UNREACHABLE"),
+    (0xC013000E, "STATUS_CLUSTER_NODE_NOT_MEMBER"),
+    (0xC013000F, "STATUS_CLUSTER_JOIN_NOT_IN_PROGRESS"),
+    (0xC0130010, "STATUS_CLUSTER_INVALID_NETWORK"),
+    (0xC0130011, "STATUS_CLUSTER_NO_NET_ADAPTERS"),
+    (0xC0130012, "STATUS_CLUSTER_NODE_UP"),
+    (0xC0130013, "STATUS_CLUSTER_NODE_PAUSED"),
+    (0xC0130014, "STATUS_CLUSTER_NODE_NOT_PAUSED"),
+    (0xC0130015, "STATUS_CLUSTER_NO_SECURITY_CONTEXT"),
+    (0xC0130016, "STATUS_CLUSTER_NETWORK_NOT_INTERNAL"),
+    (0xC0130017, "STATUS_CLUSTER_POISONED"),
+    (0xC0130018, "STATUS_CLUSTER_NON_CSV_PATH"),
+    (0xC0130019, "STATUS_CLUSTER_CSV_VOLUME_NOT_LOCAL"),
+    (0xC0130020, "STATUS_CLUSTER_CSV_READ_OPLOCK_BREAK_IN_PROGRESS"),
+    (0xC0130021, "STATUS_CLUSTER_CSV_AUTO_PAUSE_ERROR"),
+    (0xC0130022, "STATUS_CLUSTER_CSV_REDIRECTED"),
+    (0xC0130023, "STATUS_CLUSTER_CSV_NOT_REDIRECTED"),
+    (0xC0130024, "STATUS_CLUSTER_CSV_VOLUME_DRAINING"),
+    (0xC0130025, "STATUS_CLUSTER_CSV_SNAPSHOT_CREATION_IN_PROGRESS"),
+    (0xC0130026, "STATUS_CLUSTER_CSV_VOLUME_DRAINING_SUCCEEDED_DOWNLEVEL"),
+    (0xC0130027, "STATUS_CLUSTER_CSV_NO_SNAPSHOTS"),
+    (0xC0130028, "STATUS_CSV_IO_PAUSE_TIMEOUT"),
+    (0xC0130029, "STATUS_CLUSTER_CSV_INVALID_HANDLE"),
+    (0xC0130030, "STATUS_CLUSTER_CSV_SUPPORTED_ONLY_ON_COORDINATOR"),
+    (0xC0130031, "STATUS_CLUSTER_CAM_TICKET_REPLAY_DETECTED"),
+    (0xC0140001, "STATUS_ACPI_INVALID_OPCODE"),
+    (0xC0140002, "STATUS_ACPI_STACK_OVERFLOW"),
+    (0xC0140003, "STATUS_ACPI_ASSERT_FAILED"),
+    (0xC0140004, "STATUS_ACPI_INVALID_INDEX"),
+    (0xC0140005, "STATUS_ACPI_INVALID_ARGUMENT"),
+    (0xC0140006, "STATUS_ACPI_FATAL"),
+    (0xC0140007, "STATUS_ACPI_INVALID_SUPERNAME"),
+    (0xC0140008, "STATUS_ACPI_INVALID_ARGTYPE"),
+    (0xC0140009, "STATUS_ACPI_INVALID_OBJTYPE"),
+    (0xC014000A, "STATUS_ACPI_INVALID_TARGETTYPE"),
+    (0xC014000B, "STATUS_ACPI_INCORRECT_ARGUMENT_COUNT"),
+    (0xC014000C, "STATUS_ACPI_ADDRESS_NOT_MAPPED"),
+    (0xC014000D, "STATUS_ACPI_INVALID_EVENTTYPE"),
+    (0xC014000E, "STATUS_ACPI_HANDLER_COLLISION"),
+    (0xC014000F, "STATUS_ACPI_INVALID_DATA"),
+    (0xC0140010, "STATUS_ACPI_INVALID_REGION"),
+    (0xC0140011, "STATUS_ACPI_INVALID_ACCESS_SIZE"),
+    (0xC0140012, "STATUS_ACPI_ACQUIRE_GLOBAL_LOCK"),
+    (0xC0140013, "STATUS_ACPI_ALREADY_INITIALIZED"),
+    (0xC0140014, "STATUS_ACPI_NOT_INITIALIZED"),
+    (0xC0140015, "STATUS_ACPI_INVALID_MUTEX_LEVEL"),
+    (0xC0140016, "STATUS_ACPI_MUTEX_NOT_OWNED"),
+    (0xC0140017, "STATUS_ACPI_MUTEX_NOT_OWNER"),
+    (0xC0140018, "STATUS_ACPI_RS_ACCESS"),
+    (0xC0140019, "STATUS_ACPI_INVALID_TABLE"),
+    (0xC0140020, "STATUS_ACPI_REG_HANDLER_FAILED"),
+    (0xC0140021, "STATUS_ACPI_POWER_REQUEST_FAILED"),
+    (0xC0150001, "STATUS_SXS_SECTION_NOT_FOUND"),
+    (0xC0150002, "STATUS_SXS_CANT_GEN_ACTCTX"),
+    (0xC0150003, "STATUS_SXS_INVALID_ACTCTXDATA_FORMAT"),
+    (0xC0150004, "STATUS_SXS_ASSEMBLY_NOT_FOUND"),
+    (0xC0150005, "STATUS_SXS_MANIFEST_FORMAT_ERROR"),
+    (0xC0150006, "STATUS_SXS_MANIFEST_PARSE_ERROR"),
+    (0xC0150007, "STATUS_SXS_ACTIVATION_CONTEXT_DISABLED"),
+    (0xC0150008, "STATUS_SXS_KEY_NOT_FOUND"),
+    (0xC0150009, "STATUS_SXS_VERSION_CONFLICT"),
+    (0xC015000A, "STATUS_SXS_WRONG_SECTION_TYPE"),
+    (0xC015000B, "STATUS_SXS_THREAD_QUERIES_DISABLED"),
+    (0xC015000C, "STATUS_SXS_ASSEMBLY_MISSING"),
+    (0xC015000E, "STATUS_SXS_PROCESS_DEFAULT_ALREADY_SET"),
+    (0xC015000F, "STATUS_SXS_EARLY_DEACTIVATION"),
+    (0xC0150010, "STATUS_SXS_INVALID_DEACTIVATION"),
+    (0xC0150011, "STATUS_SXS_MULTIPLE_DEACTIVATION"),
+    (0xC0150012, "STATUS_SXS_SYSTEM_DEFAULT_ACTIVATION_CONTEXT_EMPTY"),
+    (0xC0150013, "STATUS_SXS_PROCESS_TERMINATION_REQUESTED"),
+    (0xC0150014, "STATUS_SXS_CORRUPT_ACTIVATION_STACK"),
+    (0xC0150015, "STATUS_SXS_CORRUPTION"),
+    (0xC0150016, "STATUS_SXS_INVALID_IDENTITY_ATTRIBUTE_VALUE"),
+    (0xC0150017, "STATUS_SXS_INVALID_IDENTITY_ATTRIBUTE_NAME"),
+    (0xC0150018, "STATUS_SXS_IDENTITY_DUPLICATE_ATTRIBUTE"),
+    (0xC0150019, "STATUS_SXS_IDENTITY_PARSE_ERROR"),
+    (0xC015001A, "STATUS_SXS_COMPONENT_STORE_CORRUPT"),
+    (0xC015001B, "STATUS_SXS_FILE_HASH_MISMATCH"),
+    (0xC015001C, "STATUS_SXS_MANIFEST_IDENTITY_SAME_BUT_CONTENTS_DIFFERENT"),
+    (0xC015001D, "STATUS_SXS_IDENTITIES_DIFFERENT"),
+    (0xC015001E, "STATUS_SXS_ASSEMBLY_IS_NOT_A_DEPLOYMENT"),
+    (0xC015001F, "STATUS_SXS_FILE_NOT_PART_OF_ASSEMBLY"),
+    (0xC0150020, "STATUS_ADVANCED_INSTALLER_FAILED"),
+    (0xC0150021, "STATUS_XML_ENCODING_MISMATCH"),
+    (0xC0150022, "STATUS_SXS_MANIFEST_TOO_BIG"),
+    (0xC0150023, "STATUS_SXS_SETTING_NOT_REGISTERED"),
+    (0xC0150024, "STATUS_SXS_TRANSACTION_CLOSURE_INCOMPLETE"),
+    (0xC0150025, "STATUS_SMI_PRIMITIVE_INSTALLER_FAILED"),
+    (0xC0150026, "STATUS_GENERIC_COMMAND_FAILED"),
+    (0xC0150027, "STATUS_SXS_FILE_HASH_MISSING"),
+    (0xC0190001, "STATUS_TRANSACTIONAL_CONFLICT"),
+    (0xC0190002, "STATUS_INVALID_TRANSACTION"),
+    (0xC0190003, "STATUS_TRANSACTION_NOT_ACTIVE"),
+    (0xC0190004, "STATUS_TM_INITIALIZATION_FAILED"),
+    (0xC0190005, "STATUS_RM_NOT_ACTIVE"),
+    (0xC0190006, "STATUS_RM_METADATA_CORRUPT"),
+    (0xC0190007, "STATUS_TRANSACTION_NOT_JOINED"),
+    (0xC0190008, "STATUS_DIRECTORY_NOT_RM"),
+    (0xC019000A, "STATUS_TRANSACTIONS_UNSUPPORTED_REMOTE"),
+    (0xC019000B, "STATUS_LOG_RESIZE_INVALID_SIZE"),
+    (0xC019000C, "STATUS_REMOTE_FILE_VERSION_MISMATCH"),
+    (0xC019000F, "STATUS_CRM_PROTOCOL_ALREADY_EXISTS"),
+    (0xC0190010, "STATUS_TRANSACTION_PROPAGATION_FAILED"),
+    (0xC0190011, "STATUS_CRM_PROTOCOL_NOT_FOUND"),
+    (0xC0190012, "STATUS_TRANSACTION_SUPERIOR_EXISTS"),
+    (0xC0190013, "STATUS_TRANSACTION_REQUEST_NOT_VALID"),
+    (0xC0190014, "STATUS_TRANSACTION_NOT_REQUESTED"),
+    (0xC0190015, "STATUS_TRANSACTION_ALREADY_ABORTED"),
+    (0xC0190016, "STATUS_TRANSACTION_ALREADY_COMMITTED"),
+    (0xC0190017, "STATUS_TRANSACTION_INVALID_MARSHALL_BUFFER"),
+    (0xC0190018, "STATUS_CURRENT_TRANSACTION_NOT_VALID"),
+    (0xC0190019, "STATUS_LOG_GROWTH_FAILED"),
+    (0xC0190021, "STATUS_OBJECT_NO_LONGER_EXISTS"),
+    (0xC0190022, "STATUS_STREAM_MINIVERSION_NOT_FOUND"),
+    (0xC0190023, "STATUS_STREAM_MINIVERSION_NOT_VALID"),
+    (0xC0190024, "STATUS_MINIVERSION_INACCESSIBLE_FROM_SPECIFIED_TRANSACTION"),
+    (0xC0190025, "STATUS_CANT_OPEN_MINIVERSION_WITH_MODIFY_INTENT"),
+    (0xC0190026, "STATUS_CANT_CREATE_MORE_STREAM_MINIVERSIONS"),
+    (0xC0190028, "STATUS_HANDLE_NO_LONGER_VALID"),
+    (0xC0190030, "STATUS_LOG_CORRUPTION_DETECTED"),
+    (0xC0190032, "STATUS_RM_DISCONNECTED"),
+    (0xC0190033, "STATUS_ENLISTMENT_NOT_SUPERIOR"),
+    (0xC0190036, "STATUS_FILE_IDENTITY_NOT_PERSISTENT"),
+    (0xC0190037, "STATUS_CANT_BREAK_TRANSACTIONAL_DEPENDENCY"),
+    (0xC0190038, "STATUS_CANT_CROSS_RM_BOUNDARY"),
+    (0xC0190039, "STATUS_TXF_DIR_NOT_EMPTY"),
+    (0xC019003A, "STATUS_INDOUBT_TRANSACTIONS_EXIST"),
+    (0xC019003B, "STATUS_TM_VOLATILE"),
+    (0xC019003C, "STATUS_ROLLBACK_TIMER_EXPIRED"),
+    (0xC019003D, "STATUS_TXF_ATTRIBUTE_CORRUPT"),
+    (0xC019003E, "STATUS_EFS_NOT_ALLOWED_IN_TRANSACTION"),
+    (0xC019003F, "STATUS_TRANSACTIONAL_OPEN_NOT_ALLOWED"),
+    (0xC0190040, "STATUS_TRANSACTED_MAPPING_UNSUPPORTED_REMOTE"),
+    (0xC0190043, "STATUS_TRANSACTION_REQUIRED_PROMOTION"),
+    (0xC0190044, "STATUS_CANNOT_EXECUTE_FILE_IN_TRANSACTION"),
+    (0xC0190045, "STATUS_TRANSACTIONS_NOT_FROZEN"),
+    (0xC0190046, "STATUS_TRANSACTION_FREEZE_IN_PROGRESS"),
+    (0xC0190047, "STATUS_NOT_SNAPSHOT_VOLUME"),
+    (0xC0190048, "STATUS_NO_SAVEPOINT_WITH_OPEN_FILES"),
+    (0xC0190049, "STATUS_SPARSE_NOT_ALLOWED_IN_TRANSACTION"),
+    (0xC019004A, "STATUS_TM_IDENTITY_MISMATCH"),
+    (0xC019004B, "STATUS_FLOATED_SECTION"),
+    (0xC019004C, "STATUS_CANNOT_ACCEPT_TRANSACTED_WORK"),
+    (0xC019004D, "STATUS_CANNOT_ABORT_TRANSACTIONS"),
+    (0xC019004E, "STATUS_TRANSACTION_NOT_FOUND"),
+    (0xC019004F, "STATUS_RESOURCEMANAGER_NOT_FOUND"),
+    (0xC0190050, "STATUS_ENLISTMENT_NOT_FOUND"),
+    (0xC0190051, "STATUS_TRANSACTIONMANAGER_NOT_FOUND"),
+    (0xC0190052, "STATUS_TRANSACTIONMANAGER_NOT_ONLINE"),
+    (0xC0190053, "STATUS_TRANSACTIONMANAGER_RECOVERY_NAME_COLLISION"),
+    (0xC0190054, "STATUS_TRANSACTION_NOT_ROOT"),
+    (0xC0190055, "STATUS_TRANSACTION_OBJECT_EXPIRED"),
+    (0xC0190056, "STATUS_COMPRESSION_NOT_ALLOWED_IN_TRANSACTION"),
+    (0xC0190057, "STATUS_TRANSACTION_RESPONSE_NOT_ENLISTED"),
+    (0xC0190058, "STATUS_TRANSACTION_RECORD_TOO_LONG"),
+    (0xC0190059, "STATUS_NO_LINK_TRACKING_IN_TRANSACTION"),
+    (0xC019005A, "STATUS_OPERATION_NOT_SUPPORTED_IN_TRANSACTION"),
+    (0xC019005B, "STATUS_TRANSACTION_INTEGRITY_VIOLATED"),
+    (0xC019005C, "STATUS_TRANSACTIONMANAGER_IDENTITY_MISMATCH"),
+    (0xC019005D, "STATUS_RM_CANNOT_BE_FROZEN_FOR_SNAPSHOT"),
+    (0xC019005E, "STATUS_TRANSACTION_MUST_WRITETHROUGH"),
+    (0xC019005F, "STATUS_TRANSACTION_NO_SUPERIOR"),
+    (0xC0190060, "STATUS_EXPIRED_HANDLE"),
+    (0xC0190061, "STATUS_TRANSACTION_NOT_ENLISTED"),
+    (0xC01A0001, "STATUS_LOG_SECTOR_INVALID"),
+    (0xC01A0002, "STATUS_LOG_SECTOR_PARITY_INVALID"),
+    (0xC01A0003, "STATUS_LOG_SECTOR_REMAPPED"),
+    (0xC01A0004, "STATUS_LOG_BLOCK_INCOMPLETE"),
+    (0xC01A0005, "STATUS_LOG_INVALID_RANGE"),
+    (0xC01A0006, "STATUS_LOG_BLOCKS_EXHAUSTED"),
+    (0xC01A0007, "STATUS_LOG_READ_CONTEXT_INVALID"),
+    (0xC01A0008, "STATUS_LOG_RESTART_INVALID"),
+    (0xC01A0009, "STATUS_LOG_BLOCK_VERSION"),
+    (0xC01A000A, "STATUS_LOG_BLOCK_INVALID"),
+    (0xC01A000B, "STATUS_LOG_READ_MODE_INVALID"),
+    (0xC01A000D, "STATUS_LOG_METADATA_CORRUPT"),
+    (0xC01A000E, "STATUS_LOG_METADATA_INVALID"),
+    (0xC01A000F, "STATUS_LOG_METADATA_INCONSISTENT"),
+    (0xC01A0010, "STATUS_LOG_RESERVATION_INVALID"),
+    (0xC01A0011, "STATUS_LOG_CANT_DELETE"),
+    (0xC01A0012, "STATUS_LOG_CONTAINER_LIMIT_EXCEEDED"),
+    (0xC01A0013, "STATUS_LOG_START_OF_LOG"),
+    (0xC01A0014, "STATUS_LOG_POLICY_ALREADY_INSTALLED"),
+    (0xC01A0015, "STATUS_LOG_POLICY_NOT_INSTALLED"),
+    (0xC01A0016, "STATUS_LOG_POLICY_INVALID"),
+    (0xC01A0017, "STATUS_LOG_POLICY_CONFLICT"),
+    (0xC01A0018, "STATUS_LOG_PINNED_ARCHIVE_TAIL"),
+    (0xC01A0019, "STATUS_LOG_RECORD_NONEXISTENT"),
+    (0xC01A001A, "STATUS_LOG_RECORDS_RESERVED_INVALID"),
+    (0xC01A001B, "STATUS_LOG_SPACE_RESERVED_INVALID"),
+    (0xC01A001C, "STATUS_LOG_TAIL_INVALID"),
+    (0xC01A001D, "STATUS_LOG_FULL"),
+    (0xC01A001E, "STATUS_LOG_MULTIPLEXED"),
+    (0xC01A001F, "STATUS_LOG_DEDICATED"),
+    (0xC01A0020, "STATUS_LOG_ARCHIVE_NOT_IN_PROGRESS"),
+    (0xC01A0021, "STATUS_LOG_ARCHIVE_IN_PROGRESS"),
+    (0xC01A0022, "STATUS_LOG_EPHEMERAL"),
+    (0xC01A0023, "STATUS_LOG_NOT_ENOUGH_CONTAINERS"),
+    (0xC01A0024, "STATUS_LOG_CLIENT_ALREADY_REGISTERED"),
+    (0xC01A0025, "STATUS_LOG_CLIENT_NOT_REGISTERED"),
+    (0xC01A0026, "STATUS_LOG_FULL_HANDLER_IN_PROGRESS"),
+    (0xC01A0027, "STATUS_LOG_CONTAINER_READ_FAILED"),
+    (0xC01A0028, "STATUS_LOG_CONTAINER_WRITE_FAILED"),
+    (0xC01A0029, "STATUS_LOG_CONTAINER_OPEN_FAILED"),
+    (0xC01A002A, "STATUS_LOG_CONTAINER_STATE_INVALID"),
+    (0xC01A002B, "STATUS_LOG_STATE_INVALID"),
+    (0xC01A002C, "STATUS_LOG_PINNED"),
+    (0xC01A002D, "STATUS_LOG_METADATA_FLUSH_FAILED"),
+    (0xC01A002E, "STATUS_LOG_INCONSISTENT_SECURITY"),
+    (0xC01A002F, "STATUS_LOG_APPENDED_FLUSH_FAILED"),
+    (0xC01A0030, "STATUS_LOG_PINNED_RESERVATION"),
+    (0xC01B00EA, "STATUS_VIDEO_HUNG_DISPLAY_DRIVER_THREAD"),
+    (0xC01C0001, "STATUS_FLT_NO_HANDLER_DEFINED"),
+    (0xC01C0002, "STATUS_FLT_CONTEXT_ALREADY_DEFINED"),
+    (0xC01C0003, "STATUS_FLT_INVALID_ASYNCHRONOUS_REQUEST"),
+    (0xC01C0004, "STATUS_FLT_DISALLOW_FAST_IO"),
+    (0xC01C0005, "STATUS_FLT_INVALID_NAME_REQUEST"),
+    (0xC01C0006, "STATUS_FLT_NOT_SAFE_TO_POST_OPERATION"),
+    (0xC01C0007, "STATUS_FLT_NOT_INITIALIZED"),
+    (0xC01C0008, "STATUS_FLT_FILTER_NOT_READY"),
+    (0xC01C0009, "STATUS_FLT_POST_OPERATION_CLEANUP"),
+    (0xC01C000A, "STATUS_FLT_INTERNAL_ERROR"),
+    (0xC01C000B, "STATUS_FLT_DELETING_OBJECT"),
+    (0xC01C000C, "STATUS_FLT_MUST_BE_NONPAGED_POOL"),
+    (0xC01C000D, "STATUS_FLT_DUPLICATE_ENTRY"),
+    (0xC01C000E, "STATUS_FLT_CBDQ_DISABLED"),
+    (0xC01C000F, "STATUS_FLT_DO_NOT_ATTACH"),
+    (0xC01C0010, "STATUS_FLT_DO_NOT_DETACH"),
+    (0xC01C0011, "STATUS_FLT_INSTANCE_ALTITUDE_COLLISION"),
+    (0xC01C0012, "STATUS_FLT_INSTANCE_NAME_COLLISION"),
+    (0xC01C0013, "STATUS_FLT_FILTER_NOT_FOUND"),
+    (0xC01C0014, "STATUS_FLT_VOLUME_NOT_FOUND"),
+    (0xC01C0015, "STATUS_FLT_INSTANCE_NOT_FOUND"),
+    (0xC01C0016, "STATUS_FLT_CONTEXT_ALLOCATION_NOT_FOUND"),
+    (0xC01C0017, "STATUS_FLT_INVALID_CONTEXT_REGISTRATION"),
+    (0xC01C0018, "STATUS_FLT_NAME_CACHE_MISS"),
+    (0xC01C0019, "STATUS_FLT_NO_DEVICE_OBJECT"),
+    (0xC01C001A, "STATUS_FLT_VOLUME_ALREADY_MOUNTED"),
+    (0xC01C001B, "STATUS_FLT_ALREADY_ENLISTED"),
+    (0xC01C001C, "STATUS_FLT_CONTEXT_ALREADY_LINKED"),
+    (0xC01C0020, "STATUS_FLT_NO_WAITER_FOR_REPLY"),
+    (0xC01C0023, "STATUS_FLT_REGISTRATION_BUSY"),
+    (0xC01C0024, "STATUS_FLT_WCOS_NOT_SUPPORTED"),
+    (0xC01D0001, "STATUS_MONITOR_NO_DESCRIPTOR"),
+    (0xC01D0002, "STATUS_MONITOR_UNKNOWN_DESCRIPTOR_FORMAT"),
+    (0xC01D0003, "STATUS_MONITOR_INVALID_DESCRIPTOR_CHECKSUM"),
+    (0xC01D0004, "STATUS_MONITOR_INVALID_STANDARD_TIMING_BLOCK"),
+    (0xC01D0005, "STATUS_MONITOR_WMI_DATABLOCK_REGISTRATION_FAILED"),
+    (0xC01D0006, "STATUS_MONITOR_INVALID_SERIAL_NUMBER_MONDSC_BLOCK"),
+    (0xC01D0007, "STATUS_MONITOR_INVALID_USER_FRIENDLY_MONDSC_BLOCK"),
+    (0xC01D0008, "STATUS_MONITOR_NO_MORE_DESCRIPTOR_DATA"),
+    (0xC01D0009, "STATUS_MONITOR_INVALID_DETAILED_TIMING_BLOCK"),
+    (0xC01D000A, "STATUS_MONITOR_INVALID_MANUFACTURE_DATE"),
+    (0xC01E0000, "STATUS_GRAPHICS_NOT_EXCLUSIVE_MODE_OWNER"),
+    (0xC01E0001, "STATUS_GRAPHICS_INSUFFICIENT_DMA_BUFFER"),
+    (0xC01E0002, "STATUS_GRAPHICS_INVALID_DISPLAY_ADAPTER"),
+    (0xC01E0003, "STATUS_GRAPHICS_ADAPTER_WAS_RESET"),
+    (0xC01E0004, "STATUS_GRAPHICS_INVALID_DRIVER_MODEL"),
+    (0xC01E0005, "STATUS_GRAPHICS_PRESENT_MODE_CHANGED"),
+    (0xC01E0006, "STATUS_GRAPHICS_PRESENT_OCCLUDED"),
+    (0xC01E0007, "STATUS_GRAPHICS_PRESENT_DENIED"),
+    (0xC01E0008, "STATUS_GRAPHICS_CANNOTCOLORCONVERT"),
+    (0xC01E0009, "STATUS_GRAPHICS_DRIVER_MISMATCH"),
+    (0xC01E000B, "STATUS_GRAPHICS_PRESENT_REDIRECTION_DISABLED"),
+    (0xC01E000C, "STATUS_GRAPHICS_PRESENT_UNOCCLUDED"),
+    (0xC01E000D, "STATUS_GRAPHICS_WINDOWDC_NOT_AVAILABLE"),
+    (0xC01E000E, "STATUS_GRAPHICS_WINDOWLESS_PRESENT_DISABLED"),
+    (0xC01E000F, "STATUS_GRAPHICS_PRESENT_INVALID_WINDOW"),
+    (0xC01E0010, "STATUS_GRAPHICS_PRESENT_BUFFER_NOT_BOUND"),
+    (0xC01E0011, "STATUS_GRAPHICS_VAIL_STATE_CHANGED"),
+    (0xC01E0012, "STATUS_GRAPHICS_INDIRECT_DISPLAY_ABANDON_SWAPCHAIN"),
+    (0xC01E0013, "STATUS_GRAPHICS_INDIRECT_DISPLAY_DEVICE_STOPPED"),
+    (0xC01E0018, "STATUS_GRAPHICS_MPO_ALLOCATION_UNPINNED"),
+    (0xC01E0100, "STATUS_GRAPHICS_NO_VIDEO_MEMORY"),
+    (0xC01E0101, "STATUS_GRAPHICS_CANT_LOCK_MEMORY"),
+    (0xC01E0102, "STATUS_GRAPHICS_ALLOCATION_BUSY"),
+    (0xC01E0103, "STATUS_GRAPHICS_TOO_MANY_REFERENCES"),
+    (0xC01E0104, "STATUS_GRAPHICS_TRY_AGAIN_LATER"),
+    (0xC01E0105, "STATUS_GRAPHICS_TRY_AGAIN_NOW"),
+    (0xC01E0106, "STATUS_GRAPHICS_ALLOCATION_INVALID"),
+    (0xC01E0107, "STATUS_GRAPHICS_UNSWIZZLING_APERTURE_UNAVAILABLE"),
+    (0xC01E0108, "STATUS_GRAPHICS_UNSWIZZLING_APERTURE_UNSUPPORTED"),
+    (0xC01E0109, "STATUS_GRAPHICS_CANT_EVICT_PINNED_ALLOCATION"),
+    (0xC01E0110, "STATUS_GRAPHICS_INVALID_ALLOCATION_USAGE"),
+    (0xC01E0111, "STATUS_GRAPHICS_CANT_RENDER_LOCKED_ALLOCATION"),
+    (0xC01E0112, "STATUS_GRAPHICS_ALLOCATION_CLOSED"),
+    (0xC01E0113, "STATUS_GRAPHICS_INVALID_ALLOCATION_INSTANCE"),
+    (0xC01E0114, "STATUS_GRAPHICS_INVALID_ALLOCATION_HANDLE"),
+    (0xC01E0115, "STATUS_GRAPHICS_WRONG_ALLOCATION_DEVICE"),
+    (0xC01E0116, "STATUS_GRAPHICS_ALLOCATION_CONTENT_LOST"),
+    (0xC01E0200, "STATUS_GRAPHICS_GPU_EXCEPTION_ON_DEVICE"),
+    (0xC01E0300, "STATUS_GRAPHICS_INVALID_VIDPN_TOPOLOGY"),
+    (0xC01E0301, "STATUS_GRAPHICS_VIDPN_TOPOLOGY_NOT_SUPPORTED"),
+    (0xC01E0302, "STATUS_GRAPHICS_VIDPN_TOPOLOGY_CURRENTLY_NOT_SUPPORTED"),
+    (0xC01E0303, "STATUS_GRAPHICS_INVALID_VIDPN"),
+    (0xC01E0304, "STATUS_GRAPHICS_INVALID_VIDEO_PRESENT_SOURCE"),
+    (0xC01E0305, "STATUS_GRAPHICS_INVALID_VIDEO_PRESENT_TARGET"),
+    (0xC01E0306, "STATUS_GRAPHICS_VIDPN_MODALITY_NOT_SUPPORTED"),
+    (0xC01E0308, "STATUS_GRAPHICS_INVALID_VIDPN_SOURCEMODESET"),
+    (0xC01E0309, "STATUS_GRAPHICS_INVALID_VIDPN_TARGETMODESET"),
+    (0xC01E030A, "STATUS_GRAPHICS_INVALID_FREQUENCY"),
+    (0xC01E030B, "STATUS_GRAPHICS_INVALID_ACTIVE_REGION"),
+    (0xC01E030C, "STATUS_GRAPHICS_INVALID_TOTAL_REGION"),
+    (0xC01E0310, "STATUS_GRAPHICS_INVALID_VIDEO_PRESENT_SOURCE_MODE"),
+    (0xC01E0311, "STATUS_GRAPHICS_INVALID_VIDEO_PRESENT_TARGET_MODE"),
+    (0xC01E0312, "STATUS_GRAPHICS_PINNED_MODE_MUST_REMAIN_IN_SET"),
+    (0xC01E0313, "STATUS_GRAPHICS_PATH_ALREADY_IN_TOPOLOGY"),
+    (0xC01E0314, "STATUS_GRAPHICS_MODE_ALREADY_IN_MODESET"),
+    (0xC01E0315, "STATUS_GRAPHICS_INVALID_VIDEOPRESENTSOURCESET"),
+    (0xC01E0316, "STATUS_GRAPHICS_INVALID_VIDEOPRESENTTARGETSET"),
+    (0xC01E0317, "STATUS_GRAPHICS_SOURCE_ALREADY_IN_SET"),
+    (0xC01E0318, "STATUS_GRAPHICS_TARGET_ALREADY_IN_SET"),
+    (0xC01E0319, "STATUS_GRAPHICS_INVALID_VIDPN_PRESENT_PATH"),
+    (0xC01E031A, "STATUS_GRAPHICS_NO_RECOMMENDED_VIDPN_TOPOLOGY"),
+    (0xC01E031B, "STATUS_GRAPHICS_INVALID_MONITOR_FREQUENCYRANGESET"),
+    (0xC01E031C, "STATUS_GRAPHICS_INVALID_MONITOR_FREQUENCYRANGE"),
+    (0xC01E031D, "STATUS_GRAPHICS_FREQUENCYRANGE_NOT_IN_SET"),
+    (0xC01E031F, "STATUS_GRAPHICS_FREQUENCYRANGE_ALREADY_IN_SET"),
+    (0xC01E0320, "STATUS_GRAPHICS_STALE_MODESET"),
+    (0xC01E0321, "STATUS_GRAPHICS_INVALID_MONITOR_SOURCEMODESET"),
+    (0xC01E0322, "STATUS_GRAPHICS_INVALID_MONITOR_SOURCE_MODE"),
+    (0xC01E0323, "STATUS_GRAPHICS_NO_RECOMMENDED_FUNCTIONAL_VIDPN"),
+    (0xC01E0324, "STATUS_GRAPHICS_MODE_ID_MUST_BE_UNIQUE"),
+    (0xC01E0325, "STATUS_GRAPHICS_EMPTY_ADAPTER_MONITOR_MODE_SUPPORT_INTERSECTION"),
+    (0xC01E0326, "STATUS_GRAPHICS_VIDEO_PRESENT_TARGETS_LESS_THAN_SOURCES"),
+    (0xC01E0327, "STATUS_GRAPHICS_PATH_NOT_IN_TOPOLOGY"),
+    (0xC01E0328, "STATUS_GRAPHICS_ADAPTER_MUST_HAVE_AT_LEAST_ONE_SOURCE"),
+    (0xC01E0329, "STATUS_GRAPHICS_ADAPTER_MUST_HAVE_AT_LEAST_ONE_TARGET"),
+    (0xC01E032A, "STATUS_GRAPHICS_INVALID_MONITORDESCRIPTORSET"),
+    (0xC01E032B, "STATUS_GRAPHICS_INVALID_MONITORDESCRIPTOR"),
+    (0xC01E032C, "STATUS_GRAPHICS_MONITORDESCRIPTOR_NOT_IN_SET"),
+    (0xC01E032D, "STATUS_GRAPHICS_MONITORDESCRIPTOR_ALREADY_IN_SET"),
+    (0xC01E032E, "STATUS_GRAPHICS_MONITORDESCRIPTOR_ID_MUST_BE_UNIQUE"),
+    (0xC01E032F, "STATUS_GRAPHICS_INVALID_VIDPN_TARGET_SUBSET_TYPE"),
+    (0xC01E0330, "STATUS_GRAPHICS_RESOURCES_NOT_RELATED"),
+    (0xC01E0331, "STATUS_GRAPHICS_SOURCE_ID_MUST_BE_UNIQUE"),
+    (0xC01E0332, "STATUS_GRAPHICS_TARGET_ID_MUST_BE_UNIQUE"),
+    (0xC01E0333, "STATUS_GRAPHICS_NO_AVAILABLE_VIDPN_TARGET"),
+    (0xC01E0334, "STATUS_GRAPHICS_MONITOR_COULD_NOT_BE_ASSOCIATED_WITH_ADAPTER"),
+    (0xC01E0335, "STATUS_GRAPHICS_NO_VIDPNMGR"),
+    (0xC01E0336, "STATUS_GRAPHICS_NO_ACTIVE_VIDPN"),
+    (0xC01E0337, "STATUS_GRAPHICS_STALE_VIDPN_TOPOLOGY"),
+    (0xC01E0338, "STATUS_GRAPHICS_MONITOR_NOT_CONNECTED"),
+    (0xC01E0339, "STATUS_GRAPHICS_SOURCE_NOT_IN_TOPOLOGY"),
+    (0xC01E033A, "STATUS_GRAPHICS_INVALID_PRIMARYSURFACE_SIZE"),
+    (0xC01E033B, "STATUS_GRAPHICS_INVALID_VISIBLEREGION_SIZE"),
+    (0xC01E033C, "STATUS_GRAPHICS_INVALID_STRIDE"),
+    (0xC01E033D, "STATUS_GRAPHICS_INVALID_PIXELFORMAT"),
+    (0xC01E033E, "STATUS_GRAPHICS_INVALID_COLORBASIS"),
+    (0xC01E033F, "STATUS_GRAPHICS_INVALID_PIXELVALUEACCESSMODE"),
+    (0xC01E0340, "STATUS_GRAPHICS_TARGET_NOT_IN_TOPOLOGY"),
+    (0xC01E0341, "STATUS_GRAPHICS_NO_DISPLAY_MODE_MANAGEMENT_SUPPORT"),
+    (0xC01E0342, "STATUS_GRAPHICS_VIDPN_SOURCE_IN_USE"),
+    (0xC01E0343, "STATUS_GRAPHICS_CANT_ACCESS_ACTIVE_VIDPN"),
+    (0xC01E0344, "STATUS_GRAPHICS_INVALID_PATH_IMPORTANCE_ORDINAL"),
+    (0xC01E0345, "STATUS_GRAPHICS_INVALID_PATH_CONTENT_GEOMETRY_TRANSFORMATION"),
+    (0xC01E0346, "STATUS_GRAPHICS_PATH_CONTENT_GEOMETRY_TRANSFORMATION_NOT_SUPPORTED"),
+    (0xC01E0347, "STATUS_GRAPHICS_INVALID_GAMMA_RAMP"),
+    (0xC01E0348, "STATUS_GRAPHICS_GAMMA_RAMP_NOT_SUPPORTED"),
+    (0xC01E0349, "STATUS_GRAPHICS_MULTISAMPLING_NOT_SUPPORTED"),
+    (0xC01E034A, "STATUS_GRAPHICS_MODE_NOT_IN_MODESET"),
+    (0xC01E034D, "STATUS_GRAPHICS_INVALID_VIDPN_TOPOLOGY_RECOMMENDATION_REASON"),
+    (0xC01E034E, "STATUS_GRAPHICS_INVALID_PATH_CONTENT_TYPE"),
+    (0xC01E034F, "STATUS_GRAPHICS_INVALID_COPYPROTECTION_TYPE"),
+    (0xC01E0350, "STATUS_GRAPHICS_UNASSIGNED_MODESET_ALREADY_EXISTS"),
+    (0xC01E0352, "STATUS_GRAPHICS_INVALID_SCANLINE_ORDERING"),
+    (0xC01E0353, "STATUS_GRAPHICS_TOPOLOGY_CHANGES_NOT_ALLOWED"),
+    (0xC01E0354, "STATUS_GRAPHICS_NO_AVAILABLE_IMPORTANCE_ORDINALS"),
+    (0xC01E0355, "STATUS_GRAPHICS_INCOMPATIBLE_PRIVATE_FORMAT"),
+    (0xC01E0356, "STATUS_GRAPHICS_INVALID_MODE_PRUNING_ALGORITHM"),
+    (0xC01E0357, "STATUS_GRAPHICS_INVALID_MONITOR_CAPABILITY_ORIGIN"),
+    (0xC01E0358, "STATUS_GRAPHICS_INVALID_MONITOR_FREQUENCYRANGE_CONSTRAINT"),
+    (0xC01E0359, "STATUS_GRAPHICS_MAX_NUM_PATHS_REACHED"),
+    (0xC01E035A, "STATUS_GRAPHICS_CANCEL_VIDPN_TOPOLOGY_AUGMENTATION"),
+    (0xC01E035B, "STATUS_GRAPHICS_INVALID_CLIENT_TYPE"),
+    (0xC01E035C, "STATUS_GRAPHICS_CLIENTVIDPN_NOT_SET"),
+    (0xC01E0400, "STATUS_GRAPHICS_SPECIFIED_CHILD_ALREADY_CONNECTED"),
+    (0xC01E0401, "STATUS_GRAPHICS_CHILD_DESCRIPTOR_NOT_SUPPORTED"),
+    (0xC01E0430, "STATUS_GRAPHICS_NOT_A_LINKED_ADAPTER"),
+    (0xC01E0431, "STATUS_GRAPHICS_LEADLINK_NOT_ENUMERATED"),
+    (0xC01E0432, "STATUS_GRAPHICS_CHAINLINKS_NOT_ENUMERATED"),
+    (0xC01E0433, "STATUS_GRAPHICS_ADAPTER_CHAIN_NOT_READY"),
+    (0xC01E0434, "STATUS_GRAPHICS_CHAINLINKS_NOT_STARTED"),
+    (0xC01E0435, "STATUS_GRAPHICS_CHAINLINKS_NOT_POWERED_ON"),
+    (0xC01E0436, "STATUS_GRAPHICS_INCONSISTENT_DEVICE_LINK_STATE"),
+    (0xC01E0438, "STATUS_GRAPHICS_NOT_POST_DEVICE_DRIVER"),
+    (0xC01E043B, "STATUS_GRAPHICS_ADAPTER_ACCESS_NOT_EXCLUDED"),
+    (0xC01E0500, "STATUS_GRAPHICS_OPM_NOT_SUPPORTED"),
+    (0xC01E0501, "STATUS_GRAPHICS_COPP_NOT_SUPPORTED"),
+    (0xC01E0502, "STATUS_GRAPHICS_UAB_NOT_SUPPORTED"),
+    (0xC01E0503, "STATUS_GRAPHICS_OPM_INVALID_ENCRYPTED_PARAMETERS"),
+    (0xC01E0505, "STATUS_GRAPHICS_OPM_NO_PROTECTED_OUTPUTS_EXIST"),
+    (0xC01E050B, "STATUS_GRAPHICS_OPM_INTERNAL_ERROR"),
+    (0xC01E050C, "STATUS_GRAPHICS_OPM_INVALID_HANDLE"),
+    (0xC01E050E, "STATUS_GRAPHICS_PVP_INVALID_CERTIFICATE_LENGTH"),
+    (0xC01E050F, "STATUS_GRAPHICS_OPM_SPANNING_MODE_ENABLED"),
+    (0xC01E0510, "STATUS_GRAPHICS_OPM_THEATER_MODE_ENABLED"),
+    (0xC01E0511, "STATUS_GRAPHICS_PVP_HFS_FAILED"),
+    (0xC01E0512, "STATUS_GRAPHICS_OPM_INVALID_SRM"),
+    (0xC01E0513, "STATUS_GRAPHICS_OPM_OUTPUT_DOES_NOT_SUPPORT_HDCP"),
+    (0xC01E0514, "STATUS_GRAPHICS_OPM_OUTPUT_DOES_NOT_SUPPORT_ACP"),
+    (0xC01E0515, "STATUS_GRAPHICS_OPM_OUTPUT_DOES_NOT_SUPPORT_CGMSA"),
+    (0xC01E0516, "STATUS_GRAPHICS_OPM_HDCP_SRM_NEVER_SET"),
+    (0xC01E0517, "STATUS_GRAPHICS_OPM_RESOLUTION_TOO_HIGH"),
+    (0xC01E0518, "STATUS_GRAPHICS_OPM_ALL_HDCP_HARDWARE_ALREADY_IN_USE"),
+    (0xC01E051A, "STATUS_GRAPHICS_OPM_PROTECTED_OUTPUT_NO_LONGER_EXISTS"),
+    (0xC01E051C, "STATUS_GRAPHICS_OPM_PROTECTED_OUTPUT_DOES_NOT_HAVE_COPP_SEMANTICS"),
+    (0xC01E051D, "STATUS_GRAPHICS_OPM_INVALID_INFORMATION_REQUEST"),
+    (0xC01E051E, "STATUS_GRAPHICS_OPM_DRIVER_INTERNAL_ERROR"),
+    (0xC01E051F, "STATUS_GRAPHICS_OPM_PROTECTED_OUTPUT_DOES_NOT_HAVE_OPM_SEMANTICS"),
+    (0xC01E0520, "STATUS_GRAPHICS_OPM_SIGNALING_NOT_SUPPORTED"),
+    (0xC01E0521, "STATUS_GRAPHICS_OPM_INVALID_CONFIGURATION_REQUEST"),
+    (0xC01E0580, "STATUS_GRAPHICS_I2C_NOT_SUPPORTED"),
+    (0xC01E0581, "STATUS_GRAPHICS_I2C_DEVICE_DOES_NOT_EXIST"),
+    (0xC01E0582, "STATUS_GRAPHICS_I2C_ERROR_TRANSMITTING_DATA"),
+    (0xC01E0583, "STATUS_GRAPHICS_I2C_ERROR_RECEIVING_DATA"),
+    (0xC01E0584, "STATUS_GRAPHICS_DDCCI_VCP_NOT_SUPPORTED"),
+    (0xC01E0585, "STATUS_GRAPHICS_DDCCI_INVALID_DATA"),
+    (0xC01E0586, "STATUS_GRAPHICS_DDCCI_MONITOR_RETURNED_INVALID_TIMING_STATUS_BYTE"),
+    (0xC01E0587, "STATUS_GRAPHICS_DDCCI_INVALID_CAPABILITIES_STRING"),
+    (0xC01E0588, "STATUS_GRAPHICS_MCA_INTERNAL_ERROR"),
+    (0xC01E0589, "STATUS_GRAPHICS_DDCCI_INVALID_MESSAGE_COMMAND"),
+    (0xC01E058A, "STATUS_GRAPHICS_DDCCI_INVALID_MESSAGE_LENGTH"),
+    (0xC01E058B, "STATUS_GRAPHICS_DDCCI_INVALID_MESSAGE_CHECKSUM"),
+    (0xC01E058C, "STATUS_GRAPHICS_INVALID_PHYSICAL_MONITOR_HANDLE"),
+    (0xC01E058D, "STATUS_GRAPHICS_MONITOR_NO_LONGER_EXISTS"),
+    (0xC01E05E0, "STATUS_GRAPHICS_ONLY_CONSOLE_SESSION_SUPPORTED"),
+    (0xC01E05E1, "STATUS_GRAPHICS_NO_DISPLAY_DEVICE_CORRESPONDS_TO_NAME"),
+    (0xC01E05E2, "STATUS_GRAPHICS_DISPLAY_DEVICE_NOT_ATTACHED_TO_DESKTOP"),
+    (0xC01E05E3, "STATUS_GRAPHICS_MIRRORING_DEVICES_NOT_SUPPORTED"),
+    (0xC01E05E4, "STATUS_GRAPHICS_INVALID_POINTER"),
+    (0xC01E05E5, "STATUS_GRAPHICS_NO_MONITORS_CORRESPOND_TO_DISPLAY_DEVICE"),
+    (0xC01E05E6, "STATUS_GRAPHICS_PARAMETER_ARRAY_TOO_SMALL"),
+    (0xC01E05E7, "STATUS_GRAPHICS_INTERNAL_ERROR"),
+    (0xC01E05E8, "STATUS_GRAPHICS_SESSION_TYPE_CHANGE_IN_PROGRESS"),
+    (0xC0210000, "STATUS_FVE_LOCKED_VOLUME"),
+    (0xC0210001, "STATUS_FVE_NOT_ENCRYPTED"),
+    (0xC0210002, "STATUS_FVE_BAD_INFORMATION"),
+    (0xC0210003, "STATUS_FVE_TOO_SMALL"),
+    (0xC0210004, "STATUS_FVE_FAILED_WRONG_FS"),
+    (0xC0210005, "STATUS_FVE_BAD_PARTITION_SIZE"),
+    (0xC0210006, "STATUS_FVE_FS_NOT_EXTENDED"),
+    (0xC0210007, "STATUS_FVE_FS_MOUNTED"),
+    (0xC0210008, "STATUS_FVE_NO_LICENSE"),
+    (0xC0210009, "STATUS_FVE_ACTION_NOT_ALLOWED"),
+    (0xC021000A, "STATUS_FVE_BAD_DATA"),
+    (0xC021000B, "STATUS_FVE_VOLUME_NOT_BOUND"),
+    (0xC021000C, "STATUS_FVE_NOT_DATA_VOLUME"),
+    (0xC021000D, "STATUS_FVE_CONV_READ_ERROR"),
+    (0xC021000E, "STATUS_FVE_CONV_WRITE_ERROR"),
+    (0xC021000F, "STATUS_FVE_OVERLAPPED_UPDATE"),
+    (0xC0210010, "STATUS_FVE_FAILED_SECTOR_SIZE"),
+    (0xC0210011, "STATUS_FVE_FAILED_AUTHENTICATION"),
+    (0xC0210012, "STATUS_FVE_NOT_OS_VOLUME"),
+    (0xC0210013, "STATUS_FVE_KEYFILE_NOT_FOUND"),
+    (0xC0210014, "STATUS_FVE_KEYFILE_INVALID"),
+    (0xC0210015, "STATUS_FVE_KEYFILE_NO_VMK"),
+    (0xC0210016, "STATUS_FVE_TPM_DISABLED"),
+    (0xC0210017, "STATUS_FVE_TPM_SRK_AUTH_NOT_ZERO"),
+    (0xC0210018, "STATUS_FVE_TPM_INVALID_PCR"),
+    (0xC0210019, "STATUS_FVE_TPM_NO_VMK"),
+    (0xC021001A, "STATUS_FVE_PIN_INVALID"),
+    (0xC021001B, "STATUS_FVE_AUTH_INVALID_APPLICATION"),
+    (0xC021001C, "STATUS_FVE_AUTH_INVALID_CONFIG"),
+    (0xC021001D, "STATUS_FVE_DEBUGGER_ENABLED"),
+    (0xC021001E, "STATUS_FVE_DRY_RUN_FAILED"),
+    (0xC021001F, "STATUS_FVE_BAD_METADATA_POINTER"),
+    (0xC0210020, "STATUS_FVE_OLD_METADATA_COPY"),
+    (0xC0210021, "STATUS_FVE_REBOOT_REQUIRED"),
+    (0xC0210022, "STATUS_FVE_RAW_ACCESS"),
+    (0xC0210023, "STATUS_FVE_RAW_BLOCKED"),
+    (0xC0210024, "STATUS_FVE_NO_AUTOUNLOCK_MASTER_KEY"),
+    (0xC0210025, "STATUS_FVE_MOR_FAILED"),
+    (0xC0210026, "STATUS_FVE_NO_FEATURE_LICENSE"),
+    (0xC0210027, "STATUS_FVE_POLICY_USER_DISABLE_RDV_NOT_ALLOWED"),
+    (0xC0210028, "STATUS_FVE_CONV_RECOVERY_FAILED"),
+    (0xC0210029, "STATUS_FVE_VIRTUALIZED_SPACE_TOO_BIG"),
+    (0xC021002A, "STATUS_FVE_INVALID_DATUM_TYPE"),
+    (0xC0210030, "STATUS_FVE_VOLUME_TOO_SMALL"),
+    (0xC0210031, "STATUS_FVE_ENH_PIN_INVALID"),
+    (0xC0210032, "STATUS_FVE_FULL_ENCRYPTION_NOT_ALLOWED_ON_TP_STORAGE"),
+    (0xC0210033, "STATUS_FVE_WIPE_NOT_ALLOWED_ON_TP_STORAGE"),
+    (0xC0210034, "STATUS_FVE_NOT_ALLOWED_ON_CSV_STACK"),
+    (0xC0210035, "STATUS_FVE_NOT_ALLOWED_ON_CLUSTER"),
+    (0xC0210036, "STATUS_FVE_NOT_ALLOWED_TO_UPGRADE_WHILE_CONVERTING"),
+    (0xC0210037, "STATUS_FVE_WIPE_CANCEL_NOT_APPLICABLE"),
+    (0xC0210038, "STATUS_FVE_EDRIVE_DRY_RUN_FAILED"),
+    (0xC0210039, "STATUS_FVE_SECUREBOOT_DISABLED"),
+    (0xC021003A, "STATUS_FVE_SECUREBOOT_CONFIG_CHANGE"),
+    (0xC021003B, "STATUS_FVE_DEVICE_LOCKEDOUT"),
+    (0xC021003C, "STATUS_FVE_VOLUME_EXTEND_PREVENTS_EOW_DECRYPT"),
+    (0xC021003D, "STATUS_FVE_NOT_DE_VOLUME"),
+    (0xC021003E, "STATUS_FVE_PROTECTION_DISABLED"),
+    (0xC021003F, "STATUS_FVE_PROTECTION_CANNOT_BE_DISABLED"),
+    (0xC0210040, "STATUS_FVE_OSV_KSR_NOT_ALLOWED"),
+    (0xC0210041, "STATUS_FVE_EDRIVE_BAND_ENUMERATION_FAILED"),
+    (0xC0210042, "STATUS_FVE_POLICY_ON_RDV_EXCLUSION_LIST"),
+    (0xC0210043, "STATUS_FVE_DATASET_FULL"),
+    (0xC0210044, "STATUS_FVE_METADATA_FULL"),
+    (0xC0220001, "STATUS_FWP_CALLOUT_NOT_FOUND"),
+    (0xC0220002, "STATUS_FWP_CONDITION_NOT_FOUND"),
+    (0xC0220003, "STATUS_FWP_FILTER_NOT_FOUND"),
+    (0xC0220004, "STATUS_FWP_LAYER_NOT_FOUND"),
+    (0xC0220005, "STATUS_FWP_PROVIDER_NOT_FOUND"),
+    (0xC0220006, "STATUS_FWP_PROVIDER_CONTEXT_NOT_FOUND"),
+    (0xC0220007, "STATUS_FWP_SUBLAYER_NOT_FOUND"),
+    (0xC0220008, "STATUS_FWP_NOT_FOUND"),
+    (0xC0220009, "STATUS_FWP_ALREADY_EXISTS"),
+    (0xC022000A, "STATUS_FWP_IN_USE"),
+    (0xC022000B, "STATUS_FWP_DYNAMIC_SESSION_IN_PROGRESS"),
+    (0xC022000C, "STATUS_FWP_WRONG_SESSION"),
+    (0xC022000D, "STATUS_FWP_NO_TXN_IN_PROGRESS"),
+    (0xC022000E, "STATUS_FWP_TXN_IN_PROGRESS"),
+    (0xC022000F, "STATUS_FWP_TXN_ABORTED"),
+    (0xC0220010, "STATUS_FWP_SESSION_ABORTED"),
+    (0xC0220011, "STATUS_FWP_INCOMPATIBLE_TXN"),
+    (0xC0220012, "STATUS_FWP_TIMEOUT"),
+    (0xC0220013, "STATUS_FWP_NET_EVENTS_DISABLED"),
+    (0xC0220014, "STATUS_FWP_INCOMPATIBLE_LAYER"),
+    (0xC0220015, "STATUS_FWP_KM_CLIENTS_ONLY"),
+    (0xC0220016, "STATUS_FWP_LIFETIME_MISMATCH"),
+    (0xC0220017, "STATUS_FWP_BUILTIN_OBJECT"),
+    (0xC0220018, "STATUS_FWP_TOO_MANY_CALLOUTS"),
+    (0xC0220019, "STATUS_FWP_NOTIFICATION_DROPPED"),
+    (0xC022001A, "STATUS_FWP_TRAFFIC_MISMATCH"),
+    (0xC022001B, "STATUS_FWP_INCOMPATIBLE_SA_STATE"),
+    (0xC022001C, "STATUS_FWP_NULL_POINTER"),
+    (0xC022001D, "STATUS_FWP_INVALID_ENUMERATOR"),
+    (0xC022001E, "STATUS_FWP_INVALID_FLAGS"),
+    (0xC022001F, "STATUS_FWP_INVALID_NET_MASK"),
+    (0xC0220020, "STATUS_FWP_INVALID_RANGE"),
+    (0xC0220021, "STATUS_FWP_INVALID_INTERVAL"),
+    (0xC0220022, "STATUS_FWP_ZERO_LENGTH_ARRAY"),
+    (0xC0220023, "STATUS_FWP_NULL_DISPLAY_NAME"),
+    (0xC0220024, "STATUS_FWP_INVALID_ACTION_TYPE"),
+    (0xC0220025, "STATUS_FWP_INVALID_WEIGHT"),
+    (0xC0220026, "STATUS_FWP_MATCH_TYPE_MISMATCH"),
+    (0xC0220027, "STATUS_FWP_TYPE_MISMATCH"),
+    (0xC0220028, "STATUS_FWP_OUT_OF_BOUNDS"),
+    (0xC0220029, "STATUS_FWP_RESERVED"),
+    (0xC022002A, "STATUS_FWP_DUPLICATE_CONDITION"),
+    (0xC022002B, "STATUS_FWP_DUPLICATE_KEYMOD"),
+    (0xC022002C, "STATUS_FWP_ACTION_INCOMPATIBLE_WITH_LAYER"),
+    (0xC022002D, "STATUS_FWP_ACTION_INCOMPATIBLE_WITH_SUBLAYER"),
+    (0xC022002E, "STATUS_FWP_CONTEXT_INCOMPATIBLE_WITH_LAYER"),
+    (0xC022002F, "STATUS_FWP_CONTEXT_INCOMPATIBLE_WITH_CALLOUT"),
+    (0xC0220030, "STATUS_FWP_INCOMPATIBLE_AUTH_METHOD"),
+    (0xC0220031, "STATUS_FWP_INCOMPATIBLE_DH_GROUP"),
+    (0xC0220032, "STATUS_FWP_EM_NOT_SUPPORTED"),
+    (0xC0220033, "STATUS_FWP_NEVER_MATCH"),
+    (0xC0220034, "STATUS_FWP_PROVIDER_CONTEXT_MISMATCH"),
+    (0xC0220035, "STATUS_FWP_INVALID_PARAMETER"),
+    (0xC0220036, "STATUS_FWP_TOO_MANY_SUBLAYERS"),
+    (0xC0220037, "STATUS_FWP_CALLOUT_NOTIFICATION_FAILED"),
+    (0xC0220038, "STATUS_FWP_INVALID_AUTH_TRANSFORM"),
+    (0xC0220039, "STATUS_FWP_INVALID_CIPHER_TRANSFORM"),
+    (0xC022003A, "STATUS_FWP_INCOMPATIBLE_CIPHER_TRANSFORM"),
+    (0xC022003B, "STATUS_FWP_INVALID_TRANSFORM_COMBINATION"),
+    (0xC022003C, "STATUS_FWP_DUPLICATE_AUTH_METHOD"),
+    (0xC022003D, "STATUS_FWP_INVALID_TUNNEL_ENDPOINT"),
+    (0xC022003E, "STATUS_FWP_L2_DRIVER_NOT_READY"),
+    (0xC022003F, "STATUS_FWP_KEY_DICTATOR_ALREADY_REGISTERED"),
+    (0xC0220040, "STATUS_FWP_KEY_DICTATION_INVALID_KEYING_MATERIAL"),
+    (0xC0220041, "STATUS_FWP_CONNECTIONS_DISABLED"),
+    (0xC0220042, "STATUS_FWP_INVALID_DNS_NAME"),
+    (0xC0220043, "STATUS_FWP_STILL_ON"),
+    (0xC0220044, "STATUS_FWP_IKEEXT_NOT_RUNNING"),
+    (0xC0220100, "STATUS_FWP_TCPIP_NOT_READY"),
+    (0xC0220101, "STATUS_FWP_INJECT_HANDLE_CLOSING"),
+    (0xC0220102, "STATUS_FWP_INJECT_HANDLE_STALE"),
+    (0xC0220103, "STATUS_FWP_CANNOT_PEND"),
+    (0xC0220104, "STATUS_FWP_DROP_NOICMP"),
+    (0xC0230002, "STATUS_NDIS_CLOSING"),
+    (0xC0230004, "STATUS_NDIS_BAD_VERSION"),
+    (0xC0230005, "STATUS_NDIS_BAD_CHARACTERISTICS"),
+    (0xC0230006, "STATUS_NDIS_ADAPTER_NOT_FOUND"),
+    (0xC0230007, "STATUS_NDIS_OPEN_FAILED"),
+    (0xC0230008, "STATUS_NDIS_DEVICE_FAILED"),
+    (0xC0230009, "STATUS_NDIS_MULTICAST_FULL"),
+    (0xC023000A, "STATUS_NDIS_MULTICAST_EXISTS"),
+    (0xC023000B, "STATUS_NDIS_MULTICAST_NOT_FOUND"),
+    (0xC023000C, "STATUS_NDIS_REQUEST_ABORTED"),
+    (0xC023000D, "STATUS_NDIS_RESET_IN_PROGRESS"),
+    (0xC023000F, "STATUS_NDIS_INVALID_PACKET"),
+    (0xC0230010, "STATUS_NDIS_INVALID_DEVICE_REQUEST"),
+    (0xC0230011, "STATUS_NDIS_ADAPTER_NOT_READY"),
+    (0xC0230014, "STATUS_NDIS_INVALID_LENGTH"),
+    (0xC0230015, "STATUS_NDIS_INVALID_DATA"),
+    (0xC0230016, "STATUS_NDIS_BUFFER_TOO_SHORT"),
+    (0xC0230017, "STATUS_NDIS_INVALID_OID"),
+    (0xC0230018, "STATUS_NDIS_ADAPTER_REMOVED"),
+    (0xC0230019, "STATUS_NDIS_UNSUPPORTED_MEDIA"),
+    (0xC023001A, "STATUS_NDIS_GROUP_ADDRESS_IN_USE"),
+    (0xC023001B, "STATUS_NDIS_FILE_NOT_FOUND"),
+    (0xC023001C, "STATUS_NDIS_ERROR_READING_FILE"),
+    (0xC023001D, "STATUS_NDIS_ALREADY_MAPPED"),
+    (0xC023001E, "STATUS_NDIS_RESOURCE_CONFLICT"),
+    (0xC023001F, "STATUS_NDIS_MEDIA_DISCONNECTED"),
+    (0xC0230022, "STATUS_NDIS_INVALID_ADDRESS"),
+    (0xC023002A, "STATUS_NDIS_PAUSED"),
+    (0xC023002B, "STATUS_NDIS_INTERFACE_NOT_FOUND"),
+    (0xC023002C, "STATUS_NDIS_UNSUPPORTED_REVISION"),
+    (0xC023002D, "STATUS_NDIS_INVALID_PORT"),
+    (0xC023002E, "STATUS_NDIS_INVALID_PORT_STATE"),
+    (0xC023002F, "STATUS_NDIS_LOW_POWER_STATE"),
+    (0xC0230030, "STATUS_NDIS_REINIT_REQUIRED"),
+    (0xC0230031, "STATUS_NDIS_NO_QUEUES"),
+    (0xC02300BB, "STATUS_NDIS_NOT_SUPPORTED"),
+    (0xC023100F, "STATUS_NDIS_OFFLOAD_POLICY"),
+    (0xC0231012, "STATUS_NDIS_OFFLOAD_CONNECTION_REJECTED"),
+    (0xC0231013, "STATUS_NDIS_OFFLOAD_PATH_REJECTED"),
+    (0xC0232000, "STATUS_NDIS_DOT11_AUTO_CONFIG_ENABLED"),
+    (0xC0232001, "STATUS_NDIS_DOT11_MEDIA_IN_USE"),
+    (0xC0232002, "STATUS_NDIS_DOT11_POWER_STATE_INVALID"),
+    (0xC0232003, "STATUS_NDIS_PM_WOL_PATTERN_LIST_FULL"),
+    (0xC0232004, "STATUS_NDIS_PM_PROTOCOL_OFFLOAD_LIST_FULL"),
+    (0xC0232005, "STATUS_NDIS_DOT11_AP_CHANNEL_CURRENTLY_NOT_AVAILABLE"),
+    (0xC0232006, "STATUS_NDIS_DOT11_AP_BAND_CURRENTLY_NOT_AVAILABLE"),
+    (0xC0232007, "STATUS_NDIS_DOT11_AP_CHANNEL_NOT_ALLOWED"),
+    (0xC0232008, "STATUS_NDIS_DOT11_AP_BAND_NOT_ALLOWED"),
+    (0xC0240000, "STATUS_QUIC_HANDSHAKE_FAILURE"),
+    (0xC0240001, "STATUS_QUIC_VER_NEG_FAILURE"),
+    (0xC0240002, "STATUS_QUIC_USER_CANCELED"),
+    (0xC0240003, "STATUS_QUIC_INTERNAL_ERROR"),
+    (0xC0240004, "STATUS_QUIC_PROTOCOL_VIOLATION"),
+    (0xC0240005, "STATUS_QUIC_CONNECTION_IDLE"),
+    (0xC0240006, "STATUS_QUIC_CONNECTION_TIMEOUT"),
+    (0xC0240007, "STATUS_QUIC_ALPN_NEG_FAILURE"),
+    (0xC0290000, "STATUS_TPM_ERROR_MASK"),
+    (0xC0290001, "STATUS_TPM_AUTHFAIL"),
+    (0xC0290002, "STATUS_TPM_BADINDEX"),
+    (0xC0290003, "STATUS_TPM_BAD_PARAMETER"),
+    (0xC0290004, "STATUS_TPM_AUDITFAILURE"),
+    (0xC0290005, "STATUS_TPM_CLEAR_DISABLED"),
+    (0xC0290006, "STATUS_TPM_DEACTIVATED"),
+    (0xC0290007, "STATUS_TPM_DISABLED"),
+    (0xC0290008, "STATUS_TPM_DISABLED_CMD"),
+    (0xC0290009, "STATUS_TPM_FAIL"),
+    (0xC029000A, "STATUS_TPM_BAD_ORDINAL"),
+    (0xC029000B, "STATUS_TPM_INSTALL_DISABLED"),
+    (0xC029000C, "STATUS_TPM_INVALID_KEYHANDLE"),
+    (0xC029000D, "STATUS_TPM_KEYNOTFOUND"),
+    (0xC029000E, "STATUS_TPM_INAPPROPRIATE_ENC"),
+    (0xC029000F, "STATUS_TPM_MIGRATEFAIL"),
+    (0xC0290010, "STATUS_TPM_INVALID_PCR_INFO"),
+    (0xC0290011, "STATUS_TPM_NOSPACE"),
+    (0xC0290012, "STATUS_TPM_NOSRK"),
+    (0xC0290013, "STATUS_TPM_NOTSEALED_BLOB"),
+    (0xC0290014, "STATUS_TPM_OWNER_SET"),
+    (0xC0290015, "STATUS_TPM_RESOURCES"),
+    (0xC0290016, "STATUS_TPM_SHORTRANDOM"),
+    (0xC0290017, "STATUS_TPM_SIZE"),
+    (0xC0290018, "STATUS_TPM_WRONGPCRVAL"),
+    (0xC0290019, "STATUS_TPM_BAD_PARAM_SIZE"),
+    (0xC029001A, "STATUS_TPM_SHA_THREAD"),
+    (0xC029001B, "STATUS_TPM_SHA_ERROR"),
+    (0xC029001C, "STATUS_TPM_FAILEDSELFTEST"),
+    (0xC029001D, "STATUS_TPM_AUTH2FAIL"),
+    (0xC029001E, "STATUS_TPM_BADTAG"),
+    (0xC029001F, "STATUS_TPM_IOERROR"),
+    (0xC0290020, "STATUS_TPM_ENCRYPT_ERROR"),
+    (0xC0290021, "STATUS_TPM_DECRYPT_ERROR"),
+    (0xC0290022, "STATUS_TPM_INVALID_AUTHHANDLE"),
+    (0xC0290023, "STATUS_TPM_NO_ENDORSEMENT"),
+    (0xC0290024, "STATUS_TPM_INVALID_KEYUSAGE"),
+    (0xC0290025, "STATUS_TPM_WRONG_ENTITYTYPE"),
+    (0xC0290026, "STATUS_TPM_INVALID_POSTINIT"),
+    (0xC0290027, "STATUS_TPM_INAPPROPRIATE_SIG"),
+    (0xC0290028, "STATUS_TPM_BAD_KEY_PROPERTY"),
+    (0xC0290029, "STATUS_TPM_BAD_MIGRATION"),
+    (0xC029002A, "STATUS_TPM_BAD_SCHEME"),
+    (0xC029002B, "STATUS_TPM_BAD_DATASIZE"),
+    (0xC029002C, "STATUS_TPM_BAD_MODE"),
+    (0xC029002D, "STATUS_TPM_BAD_PRESENCE"),
+    (0xC029002E, "STATUS_TPM_BAD_VERSION"),
+    (0xC029002F, "STATUS_TPM_NO_WRAP_TRANSPORT"),
+    (0xC0290030, "STATUS_TPM_AUDITFAIL_UNSUCCESSFUL"),
+    (0xC0290031, "STATUS_TPM_AUDITFAIL_SUCCESSFUL"),
+    (0xC0290032, "STATUS_TPM_NOTRESETABLE"),
+    (0xC0290033, "STATUS_TPM_NOTLOCAL"),
+    (0xC0290034, "STATUS_TPM_BAD_TYPE"),
+    (0xC0290035, "STATUS_TPM_INVALID_RESOURCE"),
+    (0xC0290036, "STATUS_TPM_NOTFIPS"),
+    (0xC0290037, "STATUS_TPM_INVALID_FAMILY"),
+    (0xC0290038, "STATUS_TPM_NO_NV_PERMISSION"),
+    (0xC0290039, "STATUS_TPM_REQUIRES_SIGN"),
+    (0xC029003A, "STATUS_TPM_KEY_NOTSUPPORTED"),
+    (0xC029003B, "STATUS_TPM_AUTH_CONFLICT"),
+    (0xC029003C, "STATUS_TPM_AREA_LOCKED"),
+    (0xC029003D, "STATUS_TPM_BAD_LOCALITY"),
+    (0xC029003E, "STATUS_TPM_READ_ONLY"),
+    (0xC029003F, "STATUS_TPM_PER_NOWRITE"),
+    (0xC0290040, "STATUS_TPM_FAMILYCOUNT"),
+    (0xC0290041, "STATUS_TPM_WRITE_LOCKED"),
+    (0xC0290042, "STATUS_TPM_BAD_ATTRIBUTES"),
+    (0xC0290043, "STATUS_TPM_INVALID_STRUCTURE"),
+    (0xC0290044, "STATUS_TPM_KEY_OWNER_CONTROL"),
+    (0xC0290045, "STATUS_TPM_BAD_COUNTER"),
+    (0xC0290046, "STATUS_TPM_NOT_FULLWRITE"),
+    (0xC0290047, "STATUS_TPM_CONTEXT_GAP"),
+    (0xC0290048, "STATUS_TPM_MAXNVWRITES"),
+    (0xC0290049, "STATUS_TPM_NOOPERATOR"),
+    (0xC029004A, "STATUS_TPM_RESOURCEMISSING"),
+    (0xC029004B, "STATUS_TPM_DELEGATE_LOCK"),
+    (0xC029004C, "STATUS_TPM_DELEGATE_FAMILY"),
+    (0xC029004D, "STATUS_TPM_DELEGATE_ADMIN"),
+    (0xC029004E, "STATUS_TPM_TRANSPORT_NOTEXCLUSIVE"),
+    (0xC029004F, "STATUS_TPM_OWNER_CONTROL"),
+    (0xC0290050, "STATUS_TPM_DAA_RESOURCES"),
+    (0xC0290051, "STATUS_TPM_DAA_INPUT_DATA0"),
+    (0xC0290052, "STATUS_TPM_DAA_INPUT_DATA1"),
+    (0xC0290053, "STATUS_TPM_DAA_ISSUER_SETTINGS"),
+    (0xC0290054, "STATUS_TPM_DAA_TPM_SETTINGS"),
+    (0xC0290055, "STATUS_TPM_DAA_STAGE"),
+    (0xC0290056, "STATUS_TPM_DAA_ISSUER_VALIDITY"),
+    (0xC0290057, "STATUS_TPM_DAA_WRONG_W"),
+    (0xC0290058, "STATUS_TPM_BAD_HANDLE"),
+    (0xC0290059, "STATUS_TPM_BAD_DELEGATE"),
+    (0xC029005A, "STATUS_TPM_BADCONTEXT"),
+    (0xC029005B, "STATUS_TPM_TOOMANYCONTEXTS"),
+    (0xC029005C, "STATUS_TPM_MA_TICKET_SIGNATURE"),
+    (0xC029005D, "STATUS_TPM_MA_DESTINATION"),
+    (0xC029005E, "STATUS_TPM_MA_SOURCE"),
+    (0xC029005F, "STATUS_TPM_MA_AUTHORITY"),
+    (0xC0290061, "STATUS_TPM_PERMANENTEK"),
+    (0xC0290062, "STATUS_TPM_BAD_SIGNATURE"),
+    (0xC0290063, "STATUS_TPM_NOCONTEXTSPACE"),
+    (0xC0290081, "STATUS_TPM_20_E_ASYMMETRIC"),
+    (0xC0290082, "STATUS_TPM_20_E_ATTRIBUTES"),
+    (0xC0290083, "STATUS_TPM_20_E_HASH"),
+    (0xC0290084, "STATUS_TPM_20_E_VALUE"),
+    (0xC0290085, "STATUS_TPM_20_E_HIERARCHY"),
+    (0xC0290087, "STATUS_TPM_20_E_KEY_SIZE"),
+    (0xC0290088, "STATUS_TPM_20_E_MGF"),
+    (0xC0290089, "STATUS_TPM_20_E_MODE"),
+    (0xC029008A, "STATUS_TPM_20_E_TYPE"),
+    (0xC029008B, "STATUS_TPM_20_E_HANDLE"),
+    (0xC029008C, "STATUS_TPM_20_E_KDF"),
+    (0xC029008D, "STATUS_TPM_20_E_RANGE"),
+    (0xC029008E, "STATUS_TPM_20_E_AUTH_FAIL"),
+    (0xC029008F, "STATUS_TPM_20_E_NONCE"),
+    (0xC0290090, "STATUS_TPM_20_E_PP"),
+    (0xC0290092, "STATUS_TPM_20_E_SCHEME"),
+    (0xC0290095, "STATUS_TPM_20_E_SIZE"),
+    (0xC0290096, "STATUS_TPM_20_E_SYMMETRIC"),
+    (0xC0290097, "STATUS_TPM_20_E_TAG"),
+    (0xC0290098, "STATUS_TPM_20_E_SELECTOR"),
+    (0xC029009A, "STATUS_TPM_20_E_INSUFFICIENT"),
+    (0xC029009B, "STATUS_TPM_20_E_SIGNATURE"),
+    (0xC029009C, "STATUS_TPM_20_E_KEY"),
+    (0xC029009D, "STATUS_TPM_20_E_POLICY_FAIL"),
+    (0xC029009F, "STATUS_TPM_20_E_INTEGRITY"),
+    (0xC02900A0, "STATUS_TPM_20_E_TICKET"),
+    (0xC02900A1, "STATUS_TPM_20_E_RESERVED_BITS"),
+    (0xC02900A2, "STATUS_TPM_20_E_BAD_AUTH"),
+    (0xC02900A3, "STATUS_TPM_20_E_EXPIRED"),
+    (0xC02900A4, "STATUS_TPM_20_E_POLICY_CC"),
+    (0xC02900A5, "STATUS_TPM_20_E_BINDING"),
+    (0xC02900A6, "STATUS_TPM_20_E_CURVE"),
+    (0xC02900A7, "STATUS_TPM_20_E_ECC_POINT"),
+    (0xC0290100, "STATUS_TPM_20_E_INITIALIZE"),
+    (0xC0290101, "STATUS_TPM_20_E_FAILURE"),
+    (0xC0290103, "STATUS_TPM_20_E_SEQUENCE"),
+    (0xC029010B, "STATUS_TPM_20_E_PRIVATE"),
+    (0xC0290119, "STATUS_TPM_20_E_HMAC"),
+    (0xC0290120, "STATUS_TPM_20_E_DISABLED"),
+    (0xC0290121, "STATUS_TPM_20_E_EXCLUSIVE"),
+    (0xC0290123, "STATUS_TPM_20_E_ECC_CURVE"),
+    (0xC0290124, "STATUS_TPM_20_E_AUTH_TYPE"),
+    (0xC0290125, "STATUS_TPM_20_E_AUTH_MISSING"),
+    (0xC0290126, "STATUS_TPM_20_E_POLICY"),
+    (0xC0290127, "STATUS_TPM_20_E_PCR"),
+    (0xC0290128, "STATUS_TPM_20_E_PCR_CHANGED"),
+    (0xC029012D, "STATUS_TPM_20_E_UPGRADE"),
+    (0xC029012E, "STATUS_TPM_20_E_TOO_MANY_CONTEXTS"),
+    (0xC029012F, "STATUS_TPM_20_E_AUTH_UNAVAILABLE"),
+    (0xC0290130, "STATUS_TPM_20_E_REBOOT"),
+    (0xC0290131, "STATUS_TPM_20_E_UNBALANCED"),
+    (0xC0290142, "STATUS_TPM_20_E_COMMAND_SIZE"),
+    (0xC0290143, "STATUS_TPM_20_E_COMMAND_CODE"),
+    (0xC0290144, "STATUS_TPM_20_E_AUTHSIZE"),
+    (0xC0290145, "STATUS_TPM_20_E_AUTH_CONTEXT"),
+    (0xC0290146, "STATUS_TPM_20_E_NV_RANGE"),
+    (0xC0290147, "STATUS_TPM_20_E_NV_SIZE"),
+    (0xC0290148, "STATUS_TPM_20_E_NV_LOCKED"),
+    (0xC0290149, "STATUS_TPM_20_E_NV_AUTHORIZATION"),
+    (0xC029014A, "STATUS_TPM_20_E_NV_UNINITIALIZED"),
+    (0xC029014B, "STATUS_TPM_20_E_NV_SPACE"),
+    (0xC029014C, "STATUS_TPM_20_E_NV_DEFINED"),
+    (0xC0290150, "STATUS_TPM_20_E_BAD_CONTEXT"),
+    (0xC0290151, "STATUS_TPM_20_E_CPHASH"),
+    (0xC0290152, "STATUS_TPM_20_E_PARENT"),
+    (0xC0290153, "STATUS_TPM_20_E_NEEDS_TEST"),
+    (0xC0290154, "STATUS_TPM_20_E_NO_RESULT"),
+    (0xC0290155, "STATUS_TPM_20_E_SENSITIVE"),
+    (0xC0290400, "STATUS_TPM_COMMAND_BLOCKED"),
+    (0xC0290401, "STATUS_TPM_INVALID_HANDLE"),
+    (0xC0290402, "STATUS_TPM_DUPLICATE_VHANDLE"),
+    (0xC0290403, "STATUS_TPM_EMBEDDED_COMMAND_BLOCKED"),
+    (0xC0290404, "STATUS_TPM_EMBEDDED_COMMAND_UNSUPPORTED"),
+    (0xC0290800, "STATUS_TPM_RETRY"),
+    (0xC0290801, "STATUS_TPM_NEEDS_SELFTEST"),
+    (0xC0290802, "STATUS_TPM_DOING_SELFTEST"),
+    (0xC0290803, "STATUS_TPM_DEFEND_LOCK_RUNNING"),
+    (0xC0291001, "STATUS_TPM_COMMAND_CANCELED"),
+    (0xC0291002, "STATUS_TPM_TOO_MANY_CONTEXTS"),
+    (0xC0291003, "STATUS_TPM_NOT_FOUND"),
+    (0xC0291004, "STATUS_TPM_ACCESS_DENIED"),
+    (0xC0291005, "STATUS_TPM_INSUFFICIENT_BUFFER"),
+    (0xC0291006, "STATUS_TPM_PPI_FUNCTION_UNSUPPORTED"),
+    (0xC0292000, "STATUS_PCP_ERROR_MASK"),
+    (0xC0292001, "STATUS_PCP_DEVICE_NOT_READY"),
+    (0xC0292002, "STATUS_PCP_INVALID_HANDLE"),
+    (0xC0292003, "STATUS_PCP_INVALID_PARAMETER"),
+    (0xC0292004, "STATUS_PCP_FLAG_NOT_SUPPORTED"),
+    (0xC0292005, "STATUS_PCP_NOT_SUPPORTED"),
+    (0xC0292006, "STATUS_PCP_BUFFER_TOO_SMALL"),
+    (0xC0292007, "STATUS_PCP_INTERNAL_ERROR"),
+    (0xC0292008, "STATUS_PCP_AUTHENTICATION_FAILED"),
+    (0xC0292009, "STATUS_PCP_AUTHENTICATION_IGNORED"),
+    (0xC029200A, "STATUS_PCP_POLICY_NOT_FOUND"),
+    (0xC029200B, "STATUS_PCP_PROFILE_NOT_FOUND"),
+    (0xC029200C, "STATUS_PCP_VALIDATION_FAILED"),
+    (0xC029200D, "STATUS_PCP_DEVICE_NOT_FOUND"),
+    (0xC029200E, "STATUS_PCP_WRONG_PARENT"),
+    (0xC029200F, "STATUS_PCP_KEY_NOT_LOADED"),
+    (0xC0292010, "STATUS_PCP_NO_KEY_CERTIFICATION"),
+    (0xC0292011, "STATUS_PCP_KEY_NOT_FINALIZED"),
+    (0xC0292012, "STATUS_PCP_ATTESTATION_CHALLENGE_NOT_SET"),
+    (0xC0292013, "STATUS_PCP_NOT_PCR_BOUND"),
+    (0xC0292014, "STATUS_PCP_KEY_ALREADY_FINALIZED"),
+    (0xC0292015, "STATUS_PCP_KEY_USAGE_POLICY_NOT_SUPPORTED"),
+    (0xC0292016, "STATUS_PCP_KEY_USAGE_POLICY_INVALID"),
+    (0xC0292017, "STATUS_PCP_SOFT_KEY_ERROR"),
+    (0xC0292018, "STATUS_PCP_KEY_NOT_AUTHENTICATED"),
+    (0xC0292019, "STATUS_PCP_KEY_NOT_AIK"),
+    (0xC029201A, "STATUS_PCP_KEY_NOT_SIGNING_KEY"),
+    (0xC029201B, "STATUS_PCP_LOCKED_OUT"),
+    (0xC029201C, "STATUS_PCP_CLAIM_TYPE_NOT_SUPPORTED"),
+    (0xC029201D, "STATUS_PCP_TPM_VERSION_NOT_SUPPORTED"),
+    (0xC029201E, "STATUS_PCP_BUFFER_LENGTH_MISMATCH"),
+    (0xC029201F, "STATUS_PCP_IFX_RSA_KEY_CREATION_BLOCKED"),
+    (0xC0292020, "STATUS_PCP_TICKET_MISSING"),
+    (0xC0292021, "STATUS_PCP_RAW_POLICY_NOT_SUPPORTED"),
+    (0xC0292022, "STATUS_PCP_KEY_HANDLE_INVALIDATED"),
+    (0xC0293002, "STATUS_RTPM_NO_RESULT"),
+    (0xC0293003, "STATUS_RTPM_PCR_READ_INCOMPLETE"),
+    (0xC0293004, "STATUS_RTPM_INVALID_CONTEXT"),
+    (0xC0293005, "STATUS_RTPM_UNSUPPORTED_CMD"),
+    (0xC0294000, "STATUS_TPM_ZERO_EXHAUST_ENABLED"),
+    (0xC0350002, "STATUS_HV_INVALID_HYPERCALL_CODE"),
+    (0xC0350003, "STATUS_HV_INVALID_HYPERCALL_INPUT"),
+    (0xC0350004, "STATUS_HV_INVALID_ALIGNMENT"),
+    (0xC0350005, "STATUS_HV_INVALID_PARAMETER"),
+    (0xC0350006, "STATUS_HV_ACCESS_DENIED"),
+    (0xC0350007, "STATUS_HV_INVALID_PARTITION_STATE"),
+    (0xC0350008, "STATUS_HV_OPERATION_DENIED"),
+    (0xC0350009, "STATUS_HV_UNKNOWN_PROPERTY"),
+    (0xC035000A, "STATUS_HV_PROPERTY_VALUE_OUT_OF_RANGE"),
+    (0xC035000B, "STATUS_HV_INSUFFICIENT_MEMORY"),
+    (0xC035000C, "STATUS_HV_PARTITION_TOO_DEEP"),
+    (0xC035000D, "STATUS_HV_INVALID_PARTITION_ID"),
+    (0xC035000E, "STATUS_HV_INVALID_VP_INDEX"),
+    (0xC0350011, "STATUS_HV_INVALID_PORT_ID"),
+    (0xC0350012, "STATUS_HV_INVALID_CONNECTION_ID"),
+    (0xC0350013, "STATUS_HV_INSUFFICIENT_BUFFERS"),
+    (0xC0350014, "STATUS_HV_NOT_ACKNOWLEDGED"),
+    (0xC0350015, "STATUS_HV_INVALID_VP_STATE"),
+    (0xC0350016, "STATUS_HV_ACKNOWLEDGED"),
+    (0xC0350017, "STATUS_HV_INVALID_SAVE_RESTORE_STATE"),
+    (0xC0350018, "STATUS_HV_INVALID_SYNIC_STATE"),
+    (0xC0350019, "STATUS_HV_OBJECT_IN_USE"),
+    (0xC035001A, "STATUS_HV_INVALID_PROXIMITY_DOMAIN_INFO"),
+    (0xC035001B, "STATUS_HV_NO_DATA"),
+    (0xC035001C, "STATUS_HV_INACTIVE"),
+    (0xC035001D, "STATUS_HV_NO_RESOURCES"),
+    (0xC035001E, "STATUS_HV_FEATURE_UNAVAILABLE"),
+    (0xC0350033, "STATUS_HV_INSUFFICIENT_BUFFER"),
+    (0xC0350038, "STATUS_HV_INSUFFICIENT_DEVICE_DOMAINS"),
+    (0xC035003C, "STATUS_HV_CPUID_FEATURE_VALIDATION_ERROR"),
+    (0xC035003D, "STATUS_HV_CPUID_XSAVE_FEATURE_VALIDATION_ERROR"),
+    (0xC035003E, "STATUS_HV_PROCESSOR_STARTUP_TIMEOUT"),
+    (0xC035003F, "STATUS_HV_SMX_ENABLED"),
+    (0xC0350041, "STATUS_HV_INVALID_LP_INDEX"),
+    (0xC0350050, "STATUS_HV_INVALID_REGISTER_VALUE"),
+    (0xC0350051, "STATUS_HV_INVALID_VTL_STATE"),
+    (0xC0350055, "STATUS_HV_NX_NOT_DETECTED"),
+    (0xC0350057, "STATUS_HV_INVALID_DEVICE_ID"),
+    (0xC0350058, "STATUS_HV_INVALID_DEVICE_STATE"),
+    (0xC0350060, "STATUS_HV_PAGE_REQUEST_INVALID"),
+    (0xC035006F, "STATUS_HV_INVALID_CPU_GROUP_ID"),
+    (0xC0350070, "STATUS_HV_INVALID_CPU_GROUP_STATE"),
+    (0xC0350071, "STATUS_HV_OPERATION_FAILED"),
+    (0xC0350072, "STATUS_HV_NOT_ALLOWED_WITH_NESTED_VIRT_ACTIVE"),
+    (0xC0350073, "STATUS_HV_INSUFFICIENT_ROOT_MEMORY"),
+    (0xC0350074, "STATUS_HV_EVENT_BUFFER_ALREADY_FREED"),
+    (0xC0350075, "STATUS_HV_INSUFFICIENT_CONTIGUOUS_MEMORY"),
+    (0xC0350076, "STATUS_HV_DEVICE_NOT_IN_DOMAIN"),
+    (0xC0350077, "STATUS_HV_NESTED_VM_EXIT"),
+    (0xC0350079, "STATUS_HV_CALL_PENDING"),
+    (0xC0350080, "STATUS_HV_MSR_ACCESS_FAILED"),
+    (0xC0350081, "STATUS_HV_INSUFFICIENT_MEMORY_MIRRORING"),
+    (0xC0350082, "STATUS_HV_INSUFFICIENT_CONTIGUOUS_MEMORY_MIRRORING"),
+    (0xC0350083, "STATUS_HV_INSUFFICIENT_CONTIGUOUS_ROOT_MEMORY"),
+    (0xC0350084, "STATUS_HV_INSUFFICIENT_ROOT_MEMORY_MIRRORING"),
+    (0xC0350085, "STATUS_HV_INSUFFICIENT_CONTIGUOUS_ROOT_MEMORY_MIRRORING"),
+    (0xC0351000, "STATUS_HV_NOT_PRESENT"),
+    (0xC0360001, "STATUS_IPSEC_BAD_SPI"),
+    (0xC0360002, "STATUS_IPSEC_SA_LIFETIME_EXPIRED"),
+    (0xC0360003, "STATUS_IPSEC_WRONG_SA"),
+    (0xC0360004, "STATUS_IPSEC_REPLAY_CHECK_FAILED"),
+    (0xC0360005, "STATUS_IPSEC_INVALID_PACKET"),
+    (0xC0360006, "STATUS_IPSEC_INTEGRITY_CHECK_FAILED"),
+    (0xC0360007, "STATUS_IPSEC_CLEAR_TEXT_DROP"),
+    (0xC0360008, "STATUS_IPSEC_AUTH_FIREWALL_DROP"),
+    (0xC0360009, "STATUS_IPSEC_THROTTLE_DROP"),
+    (0xC0368000, "STATUS_IPSEC_DOSP_BLOCK"),
+    (0xC0368001, "STATUS_IPSEC_DOSP_RECEIVED_MULTICAST"),
+    (0xC0368002, "STATUS_IPSEC_DOSP_INVALID_PACKET"),
+    (0xC0368003, "STATUS_IPSEC_DOSP_STATE_LOOKUP_FAILED"),
+    (0xC0368004, "STATUS_IPSEC_DOSP_MAX_ENTRIES"),
+    (0xC0368005, "STATUS_IPSEC_DOSP_KEYMOD_NOT_ALLOWED"),
+    (0xC0368006, "STATUS_IPSEC_DOSP_MAX_PER_IP_RATELIMIT_QUEUES"),
+    (0xC0370001, "STATUS_VID_DUPLICATE_HANDLER"),
+    (0xC0370002, "STATUS_VID_TOO_MANY_HANDLERS"),
+    (0xC0370003, "STATUS_VID_QUEUE_FULL"),
+    (0xC0370004, "STATUS_VID_HANDLER_NOT_PRESENT"),
+    (0xC0370005, "STATUS_VID_INVALID_OBJECT_NAME"),
+    (0xC0370006, "STATUS_VID_PARTITION_NAME_TOO_LONG"),
+    (0xC0370007, "STATUS_VID_MESSAGE_QUEUE_NAME_TOO_LONG"),
+    (0xC0370008, "STATUS_VID_PARTITION_ALREADY_EXISTS"),
+    (0xC0370009, "STATUS_VID_PARTITION_DOES_NOT_EXIST"),
+    (0xC037000A, "STATUS_VID_PARTITION_NAME_NOT_FOUND"),
+    (0xC037000B, "STATUS_VID_MESSAGE_QUEUE_ALREADY_EXISTS"),
+    (0xC037000C, "STATUS_VID_EXCEEDED_MBP_ENTRY_MAP_LIMIT"),
+    (0xC037000D, "STATUS_VID_MB_STILL_REFERENCED"),
+    (0xC037000E, "STATUS_VID_CHILD_GPA_PAGE_SET_CORRUPTED"),
+    (0xC037000F, "STATUS_VID_INVALID_NUMA_SETTINGS"),
+    (0xC0370010, "STATUS_VID_INVALID_NUMA_NODE_INDEX"),
+    (0xC0370011, "STATUS_VID_NOTIFICATION_QUEUE_ALREADY_ASSOCIATED"),
+    (0xC0370012, "STATUS_VID_INVALID_MEMORY_BLOCK_HANDLE"),
+    (0xC0370013, "STATUS_VID_PAGE_RANGE_OVERFLOW"),
+    (0xC0370014, "STATUS_VID_INVALID_MESSAGE_QUEUE_HANDLE"),
+    (0xC0370015, "STATUS_VID_INVALID_GPA_RANGE_HANDLE"),
+    (0xC0370016, "STATUS_VID_NO_MEMORY_BLOCK_NOTIFICATION_QUEUE"),
+    (0xC0370017, "STATUS_VID_MEMORY_BLOCK_LOCK_COUNT_EXCEEDED"),
+    (0xC0370018, "STATUS_VID_INVALID_PPM_HANDLE"),
+    (0xC0370019, "STATUS_VID_MBPS_ARE_LOCKED"),
+    (0xC037001A, "STATUS_VID_MESSAGE_QUEUE_CLOSED"),
+    (0xC037001B, "STATUS_VID_VIRTUAL_PROCESSOR_LIMIT_EXCEEDED"),
+    (0xC037001C, "STATUS_VID_STOP_PENDING"),
+    (0xC037001D, "STATUS_VID_INVALID_PROCESSOR_STATE"),
+    (0xC037001E, "STATUS_VID_EXCEEDED_KM_CONTEXT_COUNT_LIMIT"),
+    (0xC037001F, "STATUS_VID_KM_INTERFACE_ALREADY_INITIALIZED"),
+    (0xC0370020, "STATUS_VID_MB_PROPERTY_ALREADY_SET_RESET"),
+    (0xC0370021, "STATUS_VID_MMIO_RANGE_DESTROYED"),
+    (0xC0370022, "STATUS_VID_INVALID_CHILD_GPA_PAGE_SET"),
+    (0xC0370023, "STATUS_VID_RESERVE_PAGE_SET_IS_BEING_USED"),
+    (0xC0370024, "STATUS_VID_RESERVE_PAGE_SET_TOO_SMALL"),
+    (0xC0370025, "STATUS_VID_MBP_ALREADY_LOCKED_USING_RESERVED_PAGE"),
+    (0xC0370026, "STATUS_VID_MBP_COUNT_EXCEEDED_LIMIT"),
+    (0xC0370027, "STATUS_VID_SAVED_STATE_CORRUPT"),
+    (0xC0370028, "STATUS_VID_SAVED_STATE_UNRECOGNIZED_ITEM"),
+    (0xC0370029, "STATUS_VID_SAVED_STATE_INCOMPATIBLE"),
+    (0xC037002A, "STATUS_VID_VTL_ACCESS_DENIED"),
+    (0xC037002B, "STATUS_VID_INSUFFICIENT_RESOURCES_RESERVE"),
+    (0xC037002C, "STATUS_VID_INSUFFICIENT_RESOURCES_PHYSICAL_BUFFER"),
+    (0xC037002D, "STATUS_VID_INSUFFICIENT_RESOURCES_HV_DEPOSIT"),
+    (0xC037002E, "STATUS_VID_MEMORY_TYPE_NOT_SUPPORTED"),
+    (0xC037002F, "STATUS_VID_INSUFFICIENT_RESOURCES_WITHDRAW"),
+    (0xC0370030, "STATUS_VID_PROCESS_ALREADY_SET"),
+    (0xC0370600, "STATUS_DM_OPERATION_LIMIT_EXCEEDED"),
+    (0xC0380001, "STATUS_VOLMGR_DATABASE_FULL"),
+    (0xC0380002, "STATUS_VOLMGR_DISK_CONFIGURATION_CORRUPTED"),
+    (0xC0380003, "STATUS_VOLMGR_DISK_CONFIGURATION_NOT_IN_SYNC"),
+    (0xC0380004, "STATUS_VOLMGR_PACK_CONFIG_UPDATE_FAILED"),
+    (0xC0380005, "STATUS_VOLMGR_DISK_CONTAINS_NON_SIMPLE_VOLUME"),
+    (0xC0380006, "STATUS_VOLMGR_DISK_DUPLICATE"),
+    (0xC0380007, "STATUS_VOLMGR_DISK_DYNAMIC"),
+    (0xC0380008, "STATUS_VOLMGR_DISK_ID_INVALID"),
+    (0xC0380009, "STATUS_VOLMGR_DISK_INVALID"),
+    (0xC038000A, "STATUS_VOLMGR_DISK_LAST_VOTER"),
+    (0xC038000B, "STATUS_VOLMGR_DISK_LAYOUT_INVALID"),
+    (0xC038000C, "STATUS_VOLMGR_DISK_LAYOUT_NON_BASIC_BETWEEN_BASIC_PARTITIONS"),
+    (0xC038000D, "STATUS_VOLMGR_DISK_LAYOUT_NOT_CYLINDER_ALIGNED"),
+    (0xC038000E, "STATUS_VOLMGR_DISK_LAYOUT_PARTITIONS_TOO_SMALL"),
+    (0xC038000F, "STATUS_VOLMGR_DISK_LAYOUT_PRIMARY_BETWEEN_LOGICAL_PARTITIONS"),
+    (0xC0380010, "STATUS_VOLMGR_DISK_LAYOUT_TOO_MANY_PARTITIONS"),
+    (0xC0380011, "STATUS_VOLMGR_DISK_MISSING"),
+    (0xC0380012, "STATUS_VOLMGR_DISK_NOT_EMPTY"),
+    (0xC0380013, "STATUS_VOLMGR_DISK_NOT_ENOUGH_SPACE"),
+    (0xC0380014, "STATUS_VOLMGR_DISK_REVECTORING_FAILED"),
+    (0xC0380015, "STATUS_VOLMGR_DISK_SECTOR_SIZE_INVALID"),
+    (0xC0380016, "STATUS_VOLMGR_DISK_SET_NOT_CONTAINED"),
+    (0xC0380017, "STATUS_VOLMGR_DISK_USED_BY_MULTIPLE_MEMBERS"),
+    (0xC0380018, "STATUS_VOLMGR_DISK_USED_BY_MULTIPLE_PLEXES"),
+    (0xC0380019, "STATUS_VOLMGR_DYNAMIC_DISK_NOT_SUPPORTED"),
+    (0xC038001A, "STATUS_VOLMGR_EXTENT_ALREADY_USED"),
+    (0xC038001B, "STATUS_VOLMGR_EXTENT_NOT_CONTIGUOUS"),
+    (0xC038001C, "STATUS_VOLMGR_EXTENT_NOT_IN_PUBLIC_REGION"),
+    (0xC038001D, "STATUS_VOLMGR_EXTENT_NOT_SECTOR_ALIGNED"),
+    (0xC038001E, "STATUS_VOLMGR_EXTENT_OVERLAPS_EBR_PARTITION"),
+    (0xC038001F, "STATUS_VOLMGR_EXTENT_VOLUME_LENGTHS_DO_NOT_MATCH"),
+    (0xC0380020, "STATUS_VOLMGR_FAULT_TOLERANT_NOT_SUPPORTED"),
+    (0xC0380021, "STATUS_VOLMGR_INTERLEAVE_LENGTH_INVALID"),
+    (0xC0380022, "STATUS_VOLMGR_MAXIMUM_REGISTERED_USERS"),
+    (0xC0380023, "STATUS_VOLMGR_MEMBER_IN_SYNC"),
+    (0xC0380024, "STATUS_VOLMGR_MEMBER_INDEX_DUPLICATE"),
+    (0xC0380025, "STATUS_VOLMGR_MEMBER_INDEX_INVALID"),
+    (0xC0380026, "STATUS_VOLMGR_MEMBER_MISSING"),
+    (0xC0380027, "STATUS_VOLMGR_MEMBER_NOT_DETACHED"),
+    (0xC0380028, "STATUS_VOLMGR_MEMBER_REGENERATING"),
+    (0xC0380029, "STATUS_VOLMGR_ALL_DISKS_FAILED"),
+    (0xC038002A, "STATUS_VOLMGR_NO_REGISTERED_USERS"),
+    (0xC038002B, "STATUS_VOLMGR_NO_SUCH_USER"),
+    (0xC038002C, "STATUS_VOLMGR_NOTIFICATION_RESET"),
+    (0xC038002D, "STATUS_VOLMGR_NUMBER_OF_MEMBERS_INVALID"),
+    (0xC038002E, "STATUS_VOLMGR_NUMBER_OF_PLEXES_INVALID"),
+    (0xC038002F, "STATUS_VOLMGR_PACK_DUPLICATE"),
+    (0xC0380030, "STATUS_VOLMGR_PACK_ID_INVALID"),
+    (0xC0380031, "STATUS_VOLMGR_PACK_INVALID"),
+    (0xC0380032, "STATUS_VOLMGR_PACK_NAME_INVALID"),
+    (0xC0380033, "STATUS_VOLMGR_PACK_OFFLINE"),
+    (0xC0380034, "STATUS_VOLMGR_PACK_HAS_QUORUM"),
+    (0xC0380035, "STATUS_VOLMGR_PACK_WITHOUT_QUORUM"),
+    (0xC0380036, "STATUS_VOLMGR_PARTITION_STYLE_INVALID"),
+    (0xC0380037, "STATUS_VOLMGR_PARTITION_UPDATE_FAILED"),
+    (0xC0380038, "STATUS_VOLMGR_PLEX_IN_SYNC"),
+    (0xC0380039, "STATUS_VOLMGR_PLEX_INDEX_DUPLICATE"),
+    (0xC038003A, "STATUS_VOLMGR_PLEX_INDEX_INVALID"),
+    (0xC038003B, "STATUS_VOLMGR_PLEX_LAST_ACTIVE"),
+    (0xC038003C, "STATUS_VOLMGR_PLEX_MISSING"),
+    (0xC038003D, "STATUS_VOLMGR_PLEX_REGENERATING"),
+    (0xC038003E, "STATUS_VOLMGR_PLEX_TYPE_INVALID"),
+    (0xC038003F, "STATUS_VOLMGR_PLEX_NOT_RAID5"),
+    (0xC0380040, "STATUS_VOLMGR_PLEX_NOT_SIMPLE"),
+    (0xC0380041, "STATUS_VOLMGR_STRUCTURE_SIZE_INVALID"),
+    (0xC0380042, "STATUS_VOLMGR_TOO_MANY_NOTIFICATION_REQUESTS"),
+    (0xC0380043, "STATUS_VOLMGR_TRANSACTION_IN_PROGRESS"),
+    (0xC0380044, "STATUS_VOLMGR_UNEXPECTED_DISK_LAYOUT_CHANGE"),
+    (0xC0380045, "STATUS_VOLMGR_VOLUME_CONTAINS_MISSING_DISK"),
+    (0xC0380046, "STATUS_VOLMGR_VOLUME_ID_INVALID"),
+    (0xC0380047, "STATUS_VOLMGR_VOLUME_LENGTH_INVALID"),
+    (0xC0380048, "STATUS_VOLMGR_VOLUME_LENGTH_NOT_SECTOR_SIZE_MULTIPLE"),
+    (0xC0380049, "STATUS_VOLMGR_VOLUME_NOT_MIRRORED"),
+    (0xC038004A, "STATUS_VOLMGR_VOLUME_NOT_RETAINED"),
+    (0xC038004B, "STATUS_VOLMGR_VOLUME_OFFLINE"),
+    (0xC038004C, "STATUS_VOLMGR_VOLUME_RETAINED"),
+    (0xC038004D, "STATUS_VOLMGR_NUMBER_OF_EXTENTS_INVALID"),
+    (0xC038004E, "STATUS_VOLMGR_DIFFERENT_SECTOR_SIZE"),
+    (0xC038004F, "STATUS_VOLMGR_BAD_BOOT_DISK"),
+    (0xC0380050, "STATUS_VOLMGR_PACK_CONFIG_OFFLINE"),
+    (0xC0380051, "STATUS_VOLMGR_PACK_CONFIG_ONLINE"),
+    (0xC0380052, "STATUS_VOLMGR_NOT_PRIMARY_PACK"),
+    (0xC0380053, "STATUS_VOLMGR_PACK_LOG_UPDATE_FAILED"),
+    (0xC0380054, "STATUS_VOLMGR_NUMBER_OF_DISKS_IN_PLEX_INVALID"),
+    (0xC0380055, "STATUS_VOLMGR_NUMBER_OF_DISKS_IN_MEMBER_INVALID"),
+    (0xC0380056, "STATUS_VOLMGR_VOLUME_MIRRORED"),
+    (0xC0380057, "STATUS_VOLMGR_PLEX_NOT_SIMPLE_SPANNED"),
+    (0xC0380058, "STATUS_VOLMGR_NO_VALID_LOG_COPIES"),
+    (0xC0380059, "STATUS_VOLMGR_PRIMARY_PACK_PRESENT"),
+    (0xC038005A, "STATUS_VOLMGR_NUMBER_OF_DISKS_INVALID"),
+    (0xC038005B, "STATUS_VOLMGR_MIRROR_NOT_SUPPORTED"),
+    (0xC038005C, "STATUS_VOLMGR_RAID5_NOT_SUPPORTED"),
+    (0xC0390002, "STATUS_BCD_TOO_MANY_ELEMENTS"),
+    (0xC03A0001, "STATUS_VHD_DRIVE_FOOTER_MISSING"),
+    (0xC03A0002, "STATUS_VHD_DRIVE_FOOTER_CHECKSUM_MISMATCH"),
+    (0xC03A0003, "STATUS_VHD_DRIVE_FOOTER_CORRUPT"),
+    (0xC03A0004, "STATUS_VHD_FORMAT_UNKNOWN"),
+    (0xC03A0005, "STATUS_VHD_FORMAT_UNSUPPORTED_VERSION"),
+    (0xC03A0006, "STATUS_VHD_SPARSE_HEADER_CHECKSUM_MISMATCH"),
+    (0xC03A0007, "STATUS_VHD_SPARSE_HEADER_UNSUPPORTED_VERSION"),
+    (0xC03A0008, "STATUS_VHD_SPARSE_HEADER_CORRUPT"),
+    (0xC03A0009, "STATUS_VHD_BLOCK_ALLOCATION_FAILURE"),
+    (0xC03A000A, "STATUS_VHD_BLOCK_ALLOCATION_TABLE_CORRUPT"),
+    (0xC03A000B, "STATUS_VHD_INVALID_BLOCK_SIZE"),
+    (0xC03A000C, "STATUS_VHD_BITMAP_MISMATCH"),
+    (0xC03A000D, "STATUS_VHD_PARENT_VHD_NOT_FOUND"),
+    (0xC03A000E, "STATUS_VHD_CHILD_PARENT_ID_MISMATCH"),
+    (0xC03A000F, "STATUS_VHD_CHILD_PARENT_TIMESTAMP_MISMATCH"),
+    (0xC03A0010, "STATUS_VHD_METADATA_READ_FAILURE"),
+    (0xC03A0011, "STATUS_VHD_METADATA_WRITE_FAILURE"),
+    (0xC03A0012, "STATUS_VHD_INVALID_SIZE"),
+    (0xC03A0013, "STATUS_VHD_INVALID_FILE_SIZE"),
+    (0xC03A0014, "STATUS_VIRTDISK_PROVIDER_NOT_FOUND"),
+    (0xC03A0015, "STATUS_VIRTDISK_NOT_VIRTUAL_DISK"),
+    (0xC03A0016, "STATUS_VHD_PARENT_VHD_ACCESS_DENIED"),
+    (0xC03A0017, "STATUS_VHD_CHILD_PARENT_SIZE_MISMATCH"),
+    (0xC03A0018, "STATUS_VHD_DIFFERENCING_CHAIN_CYCLE_DETECTED"),
+    (0xC03A0019, "STATUS_VHD_DIFFERENCING_CHAIN_ERROR_IN_PARENT"),
+    (0xC03A001A, "STATUS_VIRTUAL_DISK_LIMITATION"),
+    (0xC03A001B, "STATUS_VHD_INVALID_TYPE"),
+    (0xC03A001C, "STATUS_VHD_INVALID_STATE"),
+    (0xC03A001D, "STATUS_VIRTDISK_UNSUPPORTED_DISK_SECTOR_SIZE"),
+    (0xC03A001E, "STATUS_VIRTDISK_DISK_ALREADY_OWNED"),
+    (0xC03A001F, "STATUS_VIRTDISK_DISK_ONLINE_AND_WRITABLE"),
+    (0xC03A0020, "STATUS_CTLOG_TRACKING_NOT_INITIALIZED"),
+    (0xC03A0021, "STATUS_CTLOG_LOGFILE_SIZE_EXCEEDED_MAXSIZE"),
+    (0xC03A0022, "STATUS_CTLOG_VHD_CHANGED_OFFLINE"),
+    (0xC03A0023, "STATUS_CTLOG_INVALID_TRACKING_STATE"),
+    (0xC03A0024, "STATUS_CTLOG_INCONSISTENT_TRACKING_FILE"),
+    (0xC03A0028, "STATUS_VHD_METADATA_FULL"),
+    (0xC03A0029, "STATUS_VHD_INVALID_CHANGE_TRACKING_ID"),
+    (0xC03A002A, "STATUS_VHD_CHANGE_TRACKING_DISABLED"),
+    (0xC03A0030, "STATUS_VHD_MISSING_CHANGE_TRACKING_INFORMATION"),
+    (0xC03A0031, "STATUS_VHD_RESIZE_WOULD_TRUNCATE_DATA"),
+    (0xC03A0032, "STATUS_VHD_COULD_NOT_COMPUTE_MINIMUM_VIRTUAL_SIZE"),
+    (0xC03A0033, "STATUS_VHD_ALREADY_AT_OR_BELOW_MINIMUM_VIRTUAL_SIZE"),
+    (0xC03A0034, "STATUS_VHD_UNEXPECTED_ID"),
+    (0xC0400001, "STATUS_RKF_KEY_NOT_FOUND"),
+    (0xC0400002, "STATUS_RKF_DUPLICATE_KEY"),
+    (0xC0400003, "STATUS_RKF_BLOB_FULL"),
+    (0xC0400004, "STATUS_RKF_STORE_FULL"),
+    (0xC0400005, "STATUS_RKF_FILE_BLOCKED"),
+    (0xC0400006, "STATUS_RKF_ACTIVE_KEY"),
+    (0xC0410001, "STATUS_RDBSS_RESTART_OPERATION"),
+    (0xC0410002, "STATUS_RDBSS_CONTINUE_OPERATION"),
+    (0xC0410003, "STATUS_RDBSS_POST_OPERATION"),
+    (0xC0410004, "STATUS_RDBSS_RETRY_LOOKUP"),
+    (0xC0420001, "STATUS_BTH_ATT_INVALID_HANDLE"),
+    (0xC0420002, "STATUS_BTH_ATT_READ_NOT_PERMITTED"),
+    (0xC0420003, "STATUS_BTH_ATT_WRITE_NOT_PERMITTED"),
+    (0xC0420004, "STATUS_BTH_ATT_INVALID_PDU"),
+    (0xC0420005, "STATUS_BTH_ATT_INSUFFICIENT_AUTHENTICATION"),
+    (0xC0420006, "STATUS_BTH_ATT_REQUEST_NOT_SUPPORTED"),
+    (0xC0420007, "STATUS_BTH_ATT_INVALID_OFFSET"),
+    (0xC0420008, "STATUS_BTH_ATT_INSUFFICIENT_AUTHORIZATION"),
+    (0xC0420009, "STATUS_BTH_ATT_PREPARE_QUEUE_FULL"),
+    (0xC042000A, "STATUS_BTH_ATT_ATTRIBUTE_NOT_FOUND"),
+    (0xC042000B, "STATUS_BTH_ATT_ATTRIBUTE_NOT_LONG"),
+    (0xC042000C, "STATUS_BTH_ATT_INSUFFICIENT_ENCRYPTION_KEY_SIZE"),
+    (0xC042000D, "STATUS_BTH_ATT_INVALID_ATTRIBUTE_VALUE_LENGTH"),
+    (0xC042000E, "STATUS_BTH_ATT_UNLIKELY"),
+    (0xC042000F, "STATUS_BTH_ATT_INSUFFICIENT_ENCRYPTION"),
+    (0xC0420010, "STATUS_BTH_ATT_UNSUPPORTED_GROUP_TYPE"),
+    (0xC0420011, "STATUS_BTH_ATT_INSUFFICIENT_RESOURCES"),
+    (0xC0421000, "STATUS_BTH_ATT_UNKNOWN_ERROR"),
+    (0xC0430001, "STATUS_SECUREBOOT_ROLLBACK_DETECTED"),
+    (0xC0430002, "STATUS_SECUREBOOT_POLICY_VIOLATION"),
+    (0xC0430003, "STATUS_SECUREBOOT_INVALID_POLICY"),
+    (0xC0430004, "STATUS_SECUREBOOT_POLICY_PUBLISHER_NOT_FOUND"),
+    (0xC0430005, "STATUS_SECUREBOOT_POLICY_NOT_SIGNED"),
+    (0xC0430007, "STATUS_SECUREBOOT_FILE_REPLACED"),
+    (0xC0430008, "STATUS_SECUREBOOT_POLICY_NOT_AUTHORIZED"),
+    (0xC0430009, "STATUS_SECUREBOOT_POLICY_UNKNOWN"),
+    (0xC043000A, "STATUS_SECUREBOOT_POLICY_MISSING_ANTIROLLBACKVERSION"),
+    (0xC043000B, "STATUS_SECUREBOOT_PLATFORM_ID_MISMATCH"),
+    (0xC043000C, "STATUS_SECUREBOOT_POLICY_ROLLBACK_DETECTED"),
+    (0xC043000D, "STATUS_SECUREBOOT_POLICY_UPGRADE_MISMATCH"),
+    (0xC043000E, "STATUS_SECUREBOOT_REQUIRED_POLICY_FILE_MISSING"),
+    (0xC043000F, "STATUS_SECUREBOOT_NOT_BASE_POLICY"),
+    (0xC0430010, "STATUS_SECUREBOOT_NOT_SUPPLEMENTAL_POLICY"),
+    (0xC0440001, "STATUS_AUDIO_ENGINE_NODE_NOT_FOUND"),
+    (0xC0440002, "STATUS_HDAUDIO_EMPTY_CONNECTION_LIST"),
+    (0xC0440003, "STATUS_HDAUDIO_CONNECTION_LIST_NOT_SUPPORTED"),
+    (0xC0440004, "STATUS_HDAUDIO_NO_LOGICAL_DEVICES_CREATED"),
+    (0xC0440005, "STATUS_HDAUDIO_NULL_LINKED_LIST_ENTRY"),
+    (0xC0450000, "STATUS_VSM_NOT_INITIALIZED"),
+    (0xC0450001, "STATUS_VSM_DMA_PROTECTION_NOT_IN_USE"),
+    (0xC0460001, "STATUS_IORING_REQUIRED_FLAG_NOT_SUPPORTED"),
+    (0xC0460002, "STATUS_IORING_SUBMISSION_QUEUE_FULL"),
+    (0xC0460003, "STATUS_IORING_VERSION_NOT_SUPPORTED"),
+    (0xC0460004, "STATUS_IORING_SUBMISSION_QUEUE_TOO_BIG"),
+    (0xC0460005, "STATUS_IORING_COMPLETION_QUEUE_TOO_BIG"),
+    (0xC0460006, "STATUS_IORING_SUBMIT_IN_PROGRESS"),
+    (0xC0460007, "STATUS_IORING_CORRUPT"),
+    (0xC0460008, "STATUS_IORING_COMPLETION_QUEUE_TOO_FULL"),
+    (0xC0500003, "STATUS_VOLSNAP_BOOTFILE_NOT_VALID"),
+    (0xC0500004, "STATUS_VOLSNAP_ACTIVATION_TIMEOUT"),
+    (0xC0500005, "STATUS_VOLSNAP_NO_BYPASSIO_WITH_SNAPSHOT"),
+    (0xC0510001, "STATUS_IO_PREEMPTED"),
+    (0xC05C0000, "STATUS_SVHDX_ERROR_STORED"),
+    (0xC05CFF00, "STATUS_SVHDX_ERROR_NOT_AVAILABLE"),
+    (0xC05CFF01, "STATUS_SVHDX_UNIT_ATTENTION_AVAILABLE"),
+    (0xC05CFF02, "STATUS_SVHDX_UNIT_ATTENTION_CAPACITY_DATA_CHANGED"),
+    (0xC05CFF03, "STATUS_SVHDX_UNIT_ATTENTION_RESERVATIONS_PREEMPTED"),
+    (0xC05CFF04, "STATUS_SVHDX_UNIT_ATTENTION_RESERVATIONS_RELEASED"),
+    (0xC05CFF05, "STATUS_SVHDX_UNIT_ATTENTION_REGISTRATIONS_PREEMPTED"),
+    (0xC05CFF06, "STATUS_SVHDX_UNIT_ATTENTION_OPERATING_DEFINITION_CHANGED"),
+    (0xC05CFF07, "STATUS_SVHDX_RESERVATION_CONFLICT"),
+    (0xC05CFF08, "STATUS_SVHDX_WRONG_FILE_TYPE"),
+    (0xC05CFF09, "STATUS_SVHDX_VERSION_MISMATCH"),
+    (0xC05CFF0A, "STATUS_VHD_SHARED"),
+    (0xC05CFF0B, "STATUS_SVHDX_NO_INITIATOR"),
+    (0xC05CFF0C, "STATUS_VHDSET_BACKING_STORAGE_NOT_FOUND"),
+    (0xC05D0000, "STATUS_SMB_NO_PREAUTH_INTEGRITY_HASH_OVERLAP"),
+    (0xC05D0001, "STATUS_SMB_BAD_CLUSTER_DIALECT"),
+    (0xC05D0002, "STATUS_SMB_GUEST_LOGON_BLOCKED"),
+    (0xC05D0003, "STATUS_SMB_NO_SIGNING_ALGORITHM_OVERLAP"),
+    (0xC05D0004, "STATUS_NETWORK_AUTHENTICATION_PROMPT_CANCELED"),
+    (0xC0E70001, "STATUS_SPACES_FAULT_DOMAIN_TYPE_INVALID"),
+    (0xC0E70003, "STATUS_SPACES_RESILIENCY_TYPE_INVALID"),
+    (0xC0E70004, "STATUS_SPACES_DRIVE_SECTOR_SIZE_INVALID"),
+    (0xC0E70006, "STATUS_SPACES_DRIVE_REDUNDANCY_INVALID"),
+    (0xC0E70007, "STATUS_SPACES_NUMBER_OF_DATA_COPIES_INVALID"),
+    (0xC0E70009, "STATUS_SPACES_INTERLEAVE_LENGTH_INVALID"),
+    (0xC0E7000A, "STATUS_SPACES_NUMBER_OF_COLUMNS_INVALID"),
+    (0xC0E7000B, "STATUS_SPACES_NOT_ENOUGH_DRIVES"),
+    (0xC0E7000C, "STATUS_SPACES_EXTENDED_ERROR"),
+    (0xC0E7000D, "STATUS_SPACES_PROVISIONING_TYPE_INVALID"),
+    (0xC0E7000E, "STATUS_SPACES_ALLOCATION_SIZE_INVALID"),
+    (0xC0E7000F, "STATUS_SPACES_ENCLOSURE_AWARE_INVALID"),
+    (0xC0E70010, "STATUS_SPACES_WRITE_CACHE_SIZE_INVALID"),
+    (0xC0E70011, "STATUS_SPACES_NUMBER_OF_GROUPS_INVALID"),
+    (0xC0E70012, "STATUS_SPACES_DRIVE_OPERATIONAL_STATE_INVALID"),
+    (0xC0E70013, "STATUS_SPACES_UPDATE_COLUMN_STATE"),
+    (0xC0E70014, "STATUS_SPACES_MAP_REQUIRED"),
+    (0xC0E70015, "STATUS_SPACES_UNSUPPORTED_VERSION"),
+    (0xC0E70016, "STATUS_SPACES_CORRUPT_METADATA"),
+    (0xC0E70017, "STATUS_SPACES_DRT_FULL"),
+    (0xC0E70018, "STATUS_SPACES_INCONSISTENCY"),
+    (0xC0E70019, "STATUS_SPACES_LOG_NOT_READY"),
+    (0xC0E7001A, "STATUS_SPACES_NO_REDUNDANCY"),
+    (0xC0E7001B, "STATUS_SPACES_DRIVE_NOT_READY"),
+    (0xC0E7001C, "STATUS_SPACES_DRIVE_SPLIT"),
+    (0xC0E7001D, "STATUS_SPACES_DRIVE_LOST_DATA"),
+    (0xC0E7001E, "STATUS_SPACES_ENTRY_INCOMPLETE"),
+    (0xC0E7001F, "STATUS_SPACES_ENTRY_INVALID"),
+    (0xC0E70020, "STATUS_SPACES_MARK_DIRTY"),
+    (0xC0E70021, "STATUS_SPACES_PD_NOT_FOUND"),
+    (0xC0E70022, "STATUS_SPACES_PD_LENGTH_MISMATCH"),
+    (0xC0E70023, "STATUS_SPACES_PD_UNSUPPORTED_VERSION"),
+    (0xC0E70024, "STATUS_SPACES_PD_INVALID_DATA"),
+    (0xC0E70025, "STATUS_SPACES_FLUSH_METADATA"),
+    (0xC0E70026, "STATUS_SPACES_CACHE_FULL"),
+    (0xC0E70027, "STATUS_SPACES_REPAIR_IN_PROGRESS"),
+    (0xC0E80000, "STATUS_SECCORE_INVALID_COMMAND"),
+    (0xC0E90001, "STATUS_SYSTEM_INTEGRITY_ROLLBACK_DETECTED"),
+    (0xC0E90002, "STATUS_SYSTEM_INTEGRITY_POLICY_VIOLATION"),
+    (0xC0E90003, "STATUS_SYSTEM_INTEGRITY_INVALID_POLICY"),
+    (0xC0E90004, "STATUS_SYSTEM_INTEGRITY_POLICY_NOT_SIGNED"),
+    (0xC0E90005, "STATUS_SYSTEM_INTEGRITY_TOO_MANY_POLICIES"),
+    (0xC0E90006, "STATUS_SYSTEM_INTEGRITY_SUPPLEMENTAL_POLICY_NOT_AUTHORIZED"),
+    (0xC0E90007, "STATUS_SYSTEM_INTEGRITY_REPUTATION_MALICIOUS"),
+    (0xC0E90008, "STATUS_SYSTEM_INTEGRITY_REPUTATION_PUA"),
+    (0xC0E90009, "STATUS_SYSTEM_INTEGRITY_REPUTATION_DANGEROUS_EXT"),
+    (0xC0E9000A, "STATUS_SYSTEM_INTEGRITY_REPUTATION_OFFLINE"),
+    (0xC0E9000B, "STATUS_SYSTEM_INTEGRITY_REPUTATION_UNFRIENDLY_FILE"),
+    (0xC0E9000C, "STATUS_SYSTEM_INTEGRITY_REPUTATION_UNATTAINABLE"),
+    (0xC0E9000D, "STATUS_SYSTEM_INTEGRITY_REPUTATION_EXPLICIT_DENY_FILE"),
+    (0xC0EA0001, "STATUS_NO_APPLICABLE_APP_LICENSES_FOUND"),
+    (0xC0EA0002, "STATUS_CLIP_LICENSE_NOT_FOUND"),
+    (0xC0EA0003, "STATUS_CLIP_DEVICE_LICENSE_MISSING"),
+    (0xC0EA0004, "STATUS_CLIP_LICENSE_INVALID_SIGNATURE"),
+    (0xC0EA0005, "STATUS_CLIP_KEYHOLDER_LICENSE_MISSING_OR_INVALID"),
+    (0xC0EA0006, "STATUS_CLIP_LICENSE_EXPIRED"),
+    (0xC0EA0007, "STATUS_CLIP_LICENSE_SIGNED_BY_UNKNOWN_SOURCE"),
+    (0xC0EA0008, "STATUS_CLIP_LICENSE_NOT_SIGNED"),
+    (0xC0EA0009, "STATUS_CLIP_LICENSE_HARDWARE_ID_OUT_OF_TOLERANCE"),
+    (0xC0EA000A, "STATUS_CLIP_LICENSE_DEVICE_ID_MISMATCH"),
+    (0xC0EB0001, "STATUS_PLATFORM_MANIFEST_NOT_AUTHORIZED"),
+    (0xC0EB0002, "STATUS_PLATFORM_MANIFEST_INVALID"),
+    (0xC0EB0003, "STATUS_PLATFORM_MANIFEST_FILE_NOT_AUTHORIZED"),
+    (0xC0EB0004, "STATUS_PLATFORM_MANIFEST_CATALOG_NOT_AUTHORIZED"),
+    (0xC0EB0005, "STATUS_PLATFORM_MANIFEST_BINARY_ID_NOT_FOUND"),
+    (0xC0EB0006, "STATUS_PLATFORM_MANIFEST_NOT_ACTIVE"),
+    (0xC0EB0007, "STATUS_PLATFORM_MANIFEST_NOT_SIGNED"),
+    (0xC0EC0000, "STATUS_APPEXEC_CONDITION_NOT_SATISFIED"),
+    (0xC0EC0001, "STATUS_APPEXEC_HANDLE_INVALIDATED"),
+    (0xC0EC0002, "STATUS_APPEXEC_INVALID_HOST_GENERATION"),
+    (0xC0EC0003, "STATUS_APPEXEC_UNEXPECTED_PROCESS_REGISTRATION"),
+    (0xC0EC0004, "STATUS_APPEXEC_INVALID_HOST_STATE"),
+    (0xC0EC0005, "STATUS_APPEXEC_NO_DONOR"),
+    (0xC0EC0006, "STATUS_APPEXEC_HOST_ID_MISMATCH"),
+    (0xC0EC0007, "STATUS_APPEXEC_UNKNOWN_USER"),
+    (0xC0EC0008, "STATUS_APPEXEC_APP_COMPAT_BLOCK"),
+    (0xC0EC0009, "STATUS_APPEXEC_CALLER_WAIT_TIMEOUT"),
+    (0xC0EC000A, "STATUS_APPEXEC_CALLER_WAIT_TIMEOUT_TERMINATION"),
+    (0xC0EC000B, "STATUS_APPEXEC_CALLER_WAIT_TIMEOUT_LICENSING"),
+    (0xC0EC000C, "STATUS_APPEXEC_CALLER_WAIT_TIMEOUT_RESOURCES"),
+    (0xE0000300, "EXCEPTION_SPAPI_UNRECOVERABLE_STACK_OVERFLOW"),
+];
@@ -38,7 +38,7 @@ static ETW_ACTIVE: AtomicBool = AtomicBool::new(false);
 
 /// The ETW event record callback invoked by the OS for each event.
 ///
-/// Extracts process ID from the event's UserData and sends it through the global channel.
+/// Extracts process ID (and the image name of a start) from the event's UserData and sends it through the global channel.
 /// Event ID 1 = ProcessStart, Event ID 2 = ProcessStop.
 unsafe extern "system" fn etw_event_callback(event_record: *mut EVENT_RECORD) {
     unsafe {
@@ -60,20 +60,44 @@ unsafe extern "system" fn etw_event_callback(event_record: *mut EVENT_RECORD) {
 
         let pid = *(record.UserData as *const u32);
         let is_start = event_id == 1;
+        let image_name = if is_start { start_image_name(record) } else { None };
 
         if let Ok(guard) = ETW_SENDER.lock()
             && let Some(ref sender) = *guard
         {
-            let _ = sender.send(EtwProcessEvent { pid, is_start });
+            let _ = sender.send(EtwProcessEvent { pid, is_start, image_name });
         }
     }
 }
 
+/// Reads the lowercased file name of the image from a ProcessStart record.
+///
+/// ProcessStart data is ProcessID (4), CreateTime (8), ParentProcessID (4), SessionID (4), Flags (4, from
+/// version 1 on) and then the NUL-terminated `\Device\...\name.exe` image path.
+unsafe fn start_image_name(record: &EVENT_RECORD) -> Option<String> {
+    let offset = if record.EventHeader.EventDescriptor.Version >= 1 { 24 } else { 20 };
+    let length = record.UserDataLength as usize;
+    if length <= offset {
+        return None;
+    }
+    let data = unsafe { std::slice::from_raw_parts((record.UserData as *const u8).add(offset), length - offset) };
+    let wide: Vec<u16> = data
+        .chunks_exact(2)
+        .map(|c| u16::from_le_bytes([c[0], c[1]]))
+        .take_while(|c| *c != 0)
+        .collect();
+    let path = String::from_utf16_lossy(&wide);
+    let name = path.rsplit('\\').next().unwrap_or_default().to_lowercase();
+    (!name.is_empty()).then_some(name)
+}
+
 /// A process event received from ETW.
 #[derive(Debug, Clone)]
 pub struct EtwProcessEvent {
     pub pid: u32,
     pub is_start: bool,
+    /// Lowercased image file name of a start, `None` for stops or when the event does not carry it.
+    pub image_name: Option<String>,
 }
 
 /// Manages an ETW real-time trace session for process monitoring.
@@ -1,4 +1,7 @@
-use crate::collections::{HashMap, HashSet};
+use crate::{
+    collections::{HashMap, HashSet},
+    error_codes::ErrorSource,
+};
 
 use chrono::{DateTime, Datelike, Local};
 use once_cell::sync::Lazy;
@@ -67,16 +70,28 @@ pub static LOG_FILE: Lazy<Mutex<File>> =
     Lazy::new(|| Mutex::new(OpenOptions::new().append(true).create(true).open(get_log_path("")).unwrap()));
 pub static FIND_LOG_FILE: Lazy<Mutex<File>> =
     Lazy::new(|| Mutex::new(OpenOptions::new().append(true).create(true).open(get_log_path(".find")).unwrap()));
+pub static RESTRAINT_LOG_FILE: Lazy<Mutex<File>> = Lazy::new(|| {
+    Mutex::new(
+        OpenOptions::new()
+            .append(true)
+            .create(true)
+            .open(get_log_path(".restraint"))
+            .unwrap(),
+    )
+});
 pub static FINDS_FAIL_SET: Lazy<Mutex<HashSet<String>>> = Lazy::new(|| Mutex::new(HashSet::default()));
 pub static PID_MAP_FAIL_ENTRY_SET: Lazy<Mutex<HashMap<u32, HashMap<ApplyFailEntry, bool>>>> = Lazy::new(|| Mutex::new(HashMap::default()));
 
-#[derive(PartialEq, Eq, Hash)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 #[allow(dead_code)]
 pub enum Operation {
     OpenProcess2processQueryLimitedInformation,
     OpenProcess2processSetLimitedInformation,
     OpenProcess2processQueryInformation,
     OpenProcess2processSetInformation,
+    OpenProcess2processSetQuota,
+    OpenProcess2processTerminate,
+    OpenProcess2processSuspendResume,
     OpenThread,
     SetPriorityClass,
     GetProcessAffinityMask,
@@ -85,15 +100,58 @@ pub enum Operation {
     SetProcessDefaultCpuSets,
     QueryThreadCycleTime,
     SetThreadSelectedCpuSets,
+    SetThreadAffinityMask,
+    SetThreadIdealProcessorEx2PrimeThread,
     SetThreadPriority,
     NtQueryInformationProcess2ProcessInformationIOPriority,
     NtSetInformationProcess2ProcessInformationIOPriority,
     GetProcessInformation2ProcessMemoryPriority,
     SetProcessInformation2ProcessMemoryPriority,
+    GetProcessInformation2ProcessPowerThrottling,
+    SetProcessInformation2ProcessPowerThrottling,
+    GetThreadInformation2ThreadPowerThrottling,
+    SetThreadInformation2ThreadPowerThrottling,
     SetThreadIdealProcessorEx,
     GetThreadIdealProcessorEx,
+    CreateJobObject,
+    SetInformationJobObject,
+    AssignProcessToJobObject,
+    TerminateProcess,
+    NtSuspendProcess,
+    NtResumeProcess,
     InvalidHandle,
 }
+
+impl Operation {
+    /// Which table the code of a failed operation belongs to: NT calls report an NTSTATUS, a zero code or an
+    /// invalid handle is a failure the service detected itself.
+    pub fn error_source(&self, code: u32) -> ErrorSource {
+        match self {
+            Operation::NtQueryInformationProcess2ProcessInformationIOPriority
+            | Operation::NtSetInformationProcess2ProcessInformationIOPriority
+            | Operation::NtSuspendProcess
+            | Operation::NtResumeProcess => ErrorSource::NtStatus,
+            Operation::InvalidHandle => ErrorSource::Internal,
+            _ if code == 0 => ErrorSource::Internal,
+            _ => ErrorSource::Win32,
+        }
+    }
+
+    /// Opening a process or thread handle, where a vanished PID or TID fails as an invalid parameter.
+    pub fn opens_handle(&self) -> bool {
+        matches!(
+            self,
+            Operation::OpenProcess2processQueryLimitedInformation
+                | Operation::OpenProcess2processSetLimitedInformation
+                | Operation::OpenProcess2processQueryInformation
+                | Operation::OpenProcess2processSetInformation
+                | Operation::OpenProcess2processSetQuota
+                | Operation::OpenProcess2processTerminate
+                | Operation::OpenProcess2processSuspendResume
+                | Operation::OpenThread
+        )
+    }
+}
 #[derive(PartialEq, Eq, Hash)]
 pub struct ApplyFailEntry {
     tid: u32,
@@ -210,6 +268,16 @@ pub fn log_to_find(msg: &str) {
     }
 }
 
+/// Logs a restraint event to the `.restraint` log, mirrored to the main log.
+pub fn log_to_restraint(msg: &str) {
+    log_message(msg);
+    if *get_use_console!() {
+        return;
+    }
+    let time_prefix = get_local_time!().format("%H:%M:%S").to_string();
+    let _ = writeln!(RESTRAINT_LOG_FILE.lock().unwrap(), "[{}]{}", time_prefix, msg);
+}
+
 /// Logs a discovered process from -find mode, deduplicated per day.
 ///
 /// Uses FINDS_SET to ensure each process is logged only once per session,
@@ -0,0 +1,170 @@
+//! Hardware facts used by machine-conditional config sections.
+//!
+//! A shared config can hold `[if ...]` ... `[elif ...]` ... `[else]` ... `[endif]` blocks whose lines are
+//! only read on matching machines. Conditions test the host name, the CPU brand string, the logical CPU
+//! count, the presence of efficiency classes (hybrid CPUs) and the processor group count. `-validate --as`
+//! replaces any of these facts to check the branches of other machines.
+
+use crate::{util::glob_match, winapi::get_cpu_set_information};
+
+use once_cell::sync::Lazy;
+
+#[derive(Debug, Clone, Default)]
+pub struct MachineInfo {
+    pub host: String,
+    pub cpu_brand: String,
+    pub logical_cpus: u32,
+    pub has_efficiency_classes: bool,
+    pub processor_groups: u32,
+}
+
+static CURRENT_MACHINE: Lazy<MachineInfo> = Lazy::new(|| {
+    let cpu_sets = get_cpu_set_information().lock().unwrap();
+    let mut groups: Vec<u16> = cpu_sets.iter().map(|cpu| cpu.group).collect();
+    groups.sort_unstable();
+    groups.dedup();
+    MachineInfo {
+        host: std::env::var("COMPUTERNAME").unwrap_or_default(),
+        cpu_brand: cpu_brand_string(),
+        logical_cpus: cpu_sets.len() as u32,
+        has_efficiency_classes: cpu_sets.iter().any(|cpu| cpu.efficiency_class > 0),
+        processor_groups: groups.len().max(1) as u32,
+    }
+});
+
+impl MachineInfo {
+    /// The machine the service runs on, detected once.
+    pub fn current() -> &'static MachineInfo {
+        &CURRENT_MACHINE
+    }
+
+    /// The current machine with the facts of a `--as` spec replaced.
+    ///
+    /// The spec is a `,` or `;` separated list of `host=`, `cpu=`, `cpus=`, `ecores=` (0/1) and `groups=`,
+    /// e.g. `cpu=AMD Ryzen 9 7950X3D 16-Core Processor,cpus=32,ecores=0`. Facts left out keep the current values.
+    pub fn with_overrides(spec: &str) -> Result<MachineInfo, String> {
+        let mut machine = Self::current().clone();
+        for pair in spec.split([',', ';']).map(str::trim).filter(|pair| !pair.is_empty()) {
+            let Some((key, value)) = pair.split_once('=') else {
+                return Err(format!("expected key=value, got '{}'", pair));
+            };
+            let value = value.trim();
+            let number = || {
+                value
+                    .parse::<u32>()
+                    .map_err(|_| format!("invalid number '{}' for '{}'", value, key.trim()))
+            };
+            match key.trim().to_lowercase().as_str() {
+                "host" => machine.host = value.to_string(),
+                "cpu" => machine.cpu_brand = value.to_string(),
+                "cpus" => machine.logical_cpus = number()?,
+                "groups" => machine.processor_groups = number()?,
+                "ecores" => {
+                    machine.has_efficiency_classes = match value.to_lowercase().as_str() {
+                        "1" | "true" | "yes" => true,
+                        "0" | "false" | "no" => false,
+                        _ => return Err(format!("invalid value '{}' for 'ecores', use 0 or 1", value)),
+                    }
+                }
+                other => return Err(format!("unknown key '{}', use host, cpu, cpus, ecores or groups", other)),
+            }
+        }
+        Ok(machine)
+    }
+
+    pub fn describe(&self) -> String {
+        format!(
+            "host={}, cpu={}, cpus={}, ecores={}, groups={}",
+            self.host, self.cpu_brand, self.logical_cpus, self.has_efficiency_classes as u8, self.processor_groups
+        )
+    }
+
+    /// Evaluates the condition of an `[if ...]` or `[elif ...]` header, every whitespace separated term must hold.
+    ///
+    /// Terms are `host=glob`, `cpu=glob` (case-insensitive, `*` and `?` wildcards, `!=` negates),
+    /// `cpus` or `groups` compared with `=`, `!=`, `>`, `>=`, `<` or `<=`, and the `ecores` flag.
+    /// Any term can be negated with a leading `!`.
+    pub fn matches(&self, condition: &str) -> Result<bool, String> {
+        let mut terms = condition.split_whitespace().peekable();
+        if terms.peek().is_none() {
+            return Err("empty condition".to_string());
+        }
+        for term in terms {
+            let (negated, term) = match term.strip_prefix('!') {
+                Some(rest) => (true, rest),
+                None => (false, term),
+            };
+            if self.matches_term(term)? == negated {
+                return Ok(false);
+            }
+        }
+        Ok(true)
+    }
+
+    fn matches_term(&self, term: &str) -> Result<bool, String> {
+        if term.eq_ignore_ascii_case("ecores") {
+            return Ok(self.has_efficiency_classes);
+        }
+        let Some(op_start) = term.find(['=', '!', '<', '>']) else {
+            return Err(format!("unknown condition '{}'", term));
+        };
+        let key = term[..op_start].to_lowercase();
+        let rest = &term[op_start..];
+        let op = ["!=", ">=", "<=", "=", ">", "<"]
+            .into_iter()
+            .find(|op| rest.starts_with(op))
+            .ok_or_else(|| format!("invalid operator in '{}'", term))?;
+        let value = &rest[op.len()..];
+
+        match key.as_str() {
+            "host" | "cpu" => {
+                let text = if key == "host" { &self.host } else { &self.cpu_brand };
+                let matched = glob_match(&value.to_lowercase(), &text.to_lowercase());
+                match op {
+                    "=" => Ok(matched),
+                    "!=" => Ok(!matched),
+                    _ => Err(format!("'{}' only supports = and !=", key)),
+                }
+            }
+            "cpus" | "groups" => {
+                let actual = if key == "cpus" { self.logical_cpus } else { self.processor_groups };
+                let expected: u32 = value.parse().map_err(|_| format!("invalid number '{}' in '{}'", value, term))?;
+                Ok(match op {
+                    "=" => actual == expected,
+                    "!=" => actual != expected,
+                    ">=" => actual >= expected,
+                    "<=" => actual <= expected,
+                    ">" => actual > expected,
+                    _ => actual < expected,
+                })
+            }
+            _ => Err(format!("unknown condition key '{}', use host, cpu, cpus, ecores or groups", key)),
+        }
+    }
+}
+
+/// The processor brand string from CPUID leaves 0x80000002-0x80000004, e.g. `AMD Ryzen 9 7950X3D 16-Core Processor`.
+#[cfg(target_arch = "x86_64")]
+fn cpu_brand_string() -> String {
+    use std::arch::x86_64::__cpuid;
+
+    let max_extended_leaf = __cpuid(0x8000_0000).eax;
+    if max_extended_leaf < 0x8000_0004 {
+        return String::new();
+    }
+    let mut bytes: Vec<u8> = Vec::with_capacity(48);
+    for leaf in 0x8000_0002..=0x8000_0004u32 {
+        let registers = __cpuid(leaf);
+        for register in [registers.eax, registers.ebx, registers.ecx, registers.edx] {
+            bytes.extend_from_slice(&register.to_le_bytes());
+        }
+    }
+    String::from_utf8_lossy(&bytes)
+        .trim_matches(|c: char| c == '\0' || c.is_whitespace())
+        .to_string()
+}
+
+#[cfg(not(target_arch = "x86_64"))]
+fn cpu_brand_string() -> String {
+    std::env::var("PROCESSOR_IDENTIFIER").unwrap_or_default()
+}
@@ -1,38 +1,63 @@
 mod apply;
+mod blacklist;
+mod capture;
 mod cli;
 mod collections;
+mod condition;
 mod config;
+mod discovery;
+mod drift;
+mod dry_run;
 mod error_codes;
 mod event_trace;
 mod logging;
+mod machine;
+mod originals;
+mod path_resolver;
 mod priority;
 mod process;
+mod profile;
+mod restraint;
 mod scheduler;
+mod stages;
+mod suspend;
+mod util;
 mod winapi;
 
 use crate::{
     apply::{
-        ApplyConfigResult, apply_affinity, apply_ideal_processors, apply_io_priority, apply_memory_priority, apply_prime_threads,
-        apply_priority, apply_process_default_cpuset, prefetch_all_thread_cycles, update_thread_stats,
+        ApplyConfigResult, apply_affinity, apply_ideal_processors, apply_io_priority, apply_job_limits, apply_memory_priority,
+        apply_power_throttling, apply_prime_threads, apply_priority, apply_process_default_cpuset, apply_rule_action,
+        apply_thread_power_throttling, prefetch_all_thread_cycles, update_thread_stats,
     },
+    blacklist::{Blacklist, hotreload_blacklist},
+    capture::capture_config,
     cli::{CliArgs, parse_args, print_help, print_help_all},
     collections::{HashMap, HashSet, List, PENDING, PIDS},
-    config::{
-        ConfigResult, ProcessLevelConfig, ThreadLevelConfig, convert, hotreload_blacklist, hotreload_config, read_bleack_list, read_config,
-        sort_and_group_config,
-    },
+    condition::ConditionTracker,
+    config::{ConfigResult, ProcessLevelConfig, ThreadLevelConfig, convert, hotreload_config, read_config_as, sort_and_group_config},
+    discovery::{DiscoveryTracker, ProcessBehavior, SuggestedCategory, describe_evidence, suggest_category},
+    drift::DriftMonitor,
+    dry_run::{DRY_RUN_REPORT, dry_run_samples},
     event_trace::EtwProcessMonitor,
-    logging::{log_message, log_process_find, log_pure_message, log_to_find, purge_fail_map},
+    logging::{log_message, log_process_find, log_pure_message, purge_fail_map},
+    machine::MachineInfo,
+    originals::OriginalStates,
+    path_resolver::resolver_from_cli,
+    priority::PowerThrottling,
     process::{PID_TO_PROCESS_MAP, ProcessEntry, ProcessSnapshot, SNAPSHOT_BUFFER},
+    profile::{hotreload_profile, profile_switch_mod_time, request_profile_switch},
+    restraint::Restrainer,
     scheduler::PrimeThreadScheduler,
+    stages::StageTracker,
+    suspend::SuspendController,
     winapi::{
         drop_module_cache, enable_debug_privilege, enable_inc_base_priority_privilege, get_process_handle, is_affinity_unset,
         is_running_as_admin, request_uac_elevation, set_timer_resolution, terminate_child_processes,
     },
 };
 
-use chrono::{Local, TimeDelta};
-use encoding_rs::Encoding;
+use chrono::{Datelike, Local, TimeDelta, Timelike};
 use ntapi::ntexapi::SYSTEM_THREAD_INFORMATION;
 use once_cell::unsync::OnceCell;
 use std::{
@@ -40,7 +65,6 @@ use std::{
     fs::{metadata, read_dir, read_to_string, write},
     io::Write,
     mem::size_of,
-    process::Command,
     sync::mpsc::RecvTimeoutError,
     thread,
     time::Duration,
@@ -48,21 +72,24 @@ use std::{
 use windows::Win32::{
     Foundation::CloseHandle,
     System::{
-        Console::GetConsoleOutputCP,
         Diagnostics::ToolHelp::{CreateToolhelp32Snapshot, PROCESSENTRY32W, Process32FirstW, Process32NextW, TH32CS_SNAPPROCESS},
         Threading::GetProcessAffinityMask,
     },
 };
 
 /// Applies process-level settings (one-shot per process).
-/// Includes: priority, affinity (with thread ideal processor reset), CPU set, IO priority, memory priority.
+/// Includes: rule action (terminate), priority, affinity (with thread ideal processor reset), CPU set, IO priority, memory priority, power throttling, job object limits.
 fn apply_process_level<'a>(
     pid: u32,
     config: &ProcessLevelConfig,
     threads: &impl Fn() -> &'a HashMap<u32, SYSTEM_THREAD_INFORMATION>,
     dry_run: bool,
+    blacklist: &Blacklist,
     apply_configs: &mut ApplyConfigResult,
 ) {
+    if apply_rule_action(pid, config, dry_run, blacklist, apply_configs) {
+        return;
+    }
     let Some(process_handle) = get_process_handle(pid, &config.name) else {
         return;
     };
@@ -72,11 +99,13 @@ fn apply_process_level<'a>(
     apply_process_default_cpuset(pid, config, dry_run, &process_handle, threads, apply_configs);
     apply_io_priority(pid, config, dry_run, &process_handle, apply_configs);
     apply_memory_priority(pid, config, dry_run, &process_handle, apply_configs);
+    apply_power_throttling(pid, config, dry_run, &process_handle, apply_configs);
     drop(process_handle);
+    apply_job_limits(pid, config, dry_run, apply_configs);
 }
 
 /// Applies thread-level settings (every polling iteration).
-/// Includes: prime thread scheduling, ideal processor assignment, cycle time tracking.
+/// Includes: prime thread scheduling, ideal processor assignment, thread power throttling, cycle time tracking.
 fn apply_thread_level<'a>(
     pid: u32,
     config: &ThreadLevelConfig,
@@ -90,6 +119,7 @@ fn apply_thread_level<'a>(
         || !config.prime_threads_prefixes.is_empty()
         || !config.ideal_processor_rules.is_empty()
         || config.track_top_x_threads != 0
+        || config.thread_power_throttling != PowerThrottling::None
     {
         // Query current affinity mask for prime thread CPU filtering
         let mut current_mask: usize = 0;
@@ -101,7 +131,7 @@ fn apply_thread_level<'a>(
             let _ = unsafe { GetProcessAffinityMask(r_handle, &mut current_mask, &mut system_mask) };
         }
         drop_module_cache(pid);
-        prime_core_scheduler.set_alive(pid);
+        prime_core_scheduler.set_alive(pid, &config.name);
         prefetch_all_thread_cycles(pid, config, threads, prime_core_scheduler, apply_configs);
         apply_prime_threads(
             pid,
@@ -114,6 +144,7 @@ fn apply_thread_level<'a>(
             apply_configs,
         );
         apply_ideal_processors(pid, config, dry_run, threads, prime_core_scheduler, apply_configs);
+        apply_thread_power_throttling(pid, config, dry_run, threads, prime_core_scheduler, apply_configs);
         update_thread_stats(pid, prime_core_scheduler);
     }
 }
@@ -123,6 +154,8 @@ fn apply_thread_level<'a>(
 fn apply_config(
     cli: &CliArgs,
     configs: &ConfigResult,
+    blacklist: &Blacklist,
+    original_states: &mut OriginalStates,
     prime_core_scheduler: &mut PrimeThreadScheduler,
     process_level_applied: &mut smallvec::SmallVec<[u32; PIDS]>,
     thread_level_applied: &mut smallvec::SmallVec<[u32; PENDING]>,
@@ -135,7 +168,8 @@ fn apply_config(
     let mut result = ApplyConfigResult::new();
     let threads_cache: OnceCell<HashMap<u32, SYSTEM_THREAD_INFORMATION>> = OnceCell::new();
     let threads = || threads_cache.get_or_init(|| process.get_threads());
-    apply_process_level(*pid, process_level_config, &threads, cli.dry_run, &mut result);
+    original_states.capture(*pid, process_level_config, cli.dry_run);
+    apply_process_level(*pid, process_level_config, &threads, cli.dry_run, blacklist, &mut result);
     if let Some(thread_level_config) = match configs.thread_level_configs.get(grade) {
         Some(thread_level_configs) => thread_level_configs.get(*name),
         None => None,
@@ -156,10 +190,9 @@ fn apply_config(
 }
 
 fn log_apply_results(pid: &u32, name: &String, result: ApplyConfigResult) {
+    DRY_RUN_REPORT.lock().unwrap().record(*pid, name, &result.changes);
     if !result.is_empty() {
-        for error in &result.errors {
-            log_to_find(error);
-        }
+        result.log_errors();
         if !result.changes.is_empty() {
             let first = format!("{:>5}::{}::{}", pid, name, result.changes[0]);
             log_message(&first);
@@ -174,21 +207,31 @@ fn log_apply_results(pid: &u32, name: &String, result: ApplyConfigResult) {
 /// Processes log files from -find mode to discover new processes.
 ///
 /// Scans .find.log files for discovered processes, filters out known ones,
-/// and uses Everything search (es.exe) to locate executable paths.
+/// and locates executable paths with the `-resolver` backend (Everything's es.exe or the built-in search).
+/// Processes with behavior stats also get a suggested rule line per category.
 /// Results are written to a text file for manual review.
-fn process_logs(configs: &ConfigResult, blacklist: &[String], logs_path: Option<&str>, output_file: Option<&str>) {
+fn process_logs(cli: &CliArgs, configs: &ConfigResult, blacklist: &Blacklist) {
     *get_use_console!() = true;
-    let logs_path = logs_path.unwrap_or("logs");
-    let output_file = output_file.unwrap_or("new_processes_results.txt");
+    let logs_path = cli.in_file_name.as_deref().unwrap_or("logs");
+    let output_file = cli.out_file_name.as_deref().unwrap_or("new_processes_results.txt");
 
     let mut all_processes = HashSet::default();
+    let mut behaviors: HashMap<String, ProcessBehavior> = HashMap::default();
     if let Ok(entries) = read_dir(logs_path) {
         for entry in entries.flatten() {
             let path = entry.path();
             if path.file_name().and_then(|n| n.to_str()).is_some_and(|n| n.ends_with(".find.log"))
                 && let Ok(content) = read_to_string(&path)
             {
+                // stats lines are cumulative within a run, the last one per run and process counts
+                let mut run_behaviors: HashMap<(u64, String), ProcessBehavior> = HashMap::default();
                 for line in content.lines() {
+                    if let Some(idx) = line.find("]stats ")
+                        && let Some((name, run, behavior)) = ProcessBehavior::parse(&line[idx + 7..])
+                    {
+                        run_behaviors.insert((run, name), behavior);
+                        continue;
+                    }
                     if let Some(idx) = line.find("find ") {
                         let rest = &line[idx + 5..];
                         let proc = if let Some(space_idx) = rest.find(' ') {
@@ -201,6 +244,9 @@ fn process_logs(configs: &ConfigResult, blacklist: &[String], logs_path: Option<
                         }
                     }
                 }
+                for ((_, name), behavior) in run_behaviors {
+                    behaviors.entry(name).or_default().merge(&behavior);
+                }
             }
         }
     }
@@ -215,49 +261,57 @@ fn process_logs(configs: &ConfigResult, blacklist: &[String], logs_path: Option<
                 .values()
                 .any(|grade_configs| grade_configs.contains_key(p))
     };
-    let new_processes: Vec<String> = all_processes
+    let mut new_processes: Vec<String> = all_processes
         .into_iter()
         .filter(|p| !in_any_grade(p) && !blacklist.contains(p))
         .collect();
+    new_processes.sort();
 
+    let mut resolver = match resolver_from_cli(cli, logs_path) {
+        Ok(resolver) => resolver,
+        Err(e) => {
+            log!("Invalid -resolver: {}", e);
+            return;
+        }
+    };
     let mut output = String::new();
-    let acp = unsafe { GetConsoleOutputCP() };
-    let label = if acp == 936 { "gbk" } else { &format!("windows-{}", acp) };
-    let encoding = Encoding::for_label_no_replacement(label.as_bytes()).unwrap_or(encoding_rs::UTF_8);
-    for proc in new_processes {
+    for proc in &new_processes {
         output.push_str(&format!("Process: {}\n", proc));
-
-        let escaped_proc = proc.replace(".", r"\.");
-        let es_output = Command::new("es")
-            .args(["-utf8-bom", "-r", &format!("^{}$", escaped_proc)])
-            .output();
-        match es_output {
-            Ok(output_result) if output_result.status.success() => {
-                let stdout = &output_result.stdout;
-
-                let ansi_bytes = if stdout.starts_with(&[0xEF, 0xBB, 0xBF]) {
-                    &stdout[3..]
-                } else {
-                    stdout
-                };
-
-                let (result, _, _) = encoding.decode(ansi_bytes);
-                if !result.trim().is_empty() {
-                    output.push_str("Found:\n");
-                    for line in result.lines() {
-                        output.push_str(&format!("  {}\n", line));
-                    }
-                } else {
-                    output.push_str("Not found, result empty\n");
+        match resolver.resolve(proc) {
+            Ok(paths) if !paths.is_empty() => {
+                output.push_str("Found:\n");
+                for path in paths {
+                    output.push_str(&format!("  {}\n", path.display()));
                 }
             }
-            _ => {
-                output.push_str("Not found, es failed\n");
-            }
+            Ok(_) => output.push_str(&format!("Not found ({})\n", resolver.name())),
+            Err(e) => output.push_str(&format!("Not found, {}\n", e)),
         }
         output.push_str("---\n");
     }
 
+    // rule lines for the processes with recorded stats, ready to paste into the config
+    let suggestions: Vec<(SuggestedCategory, &String, &ProcessBehavior)> = new_processes
+        .iter()
+        .filter_map(|proc| {
+            let behavior = behaviors.get(proc)?;
+            Some((suggest_category(proc, behavior)?, proc, behavior))
+        })
+        .collect();
+    if !suggestions.is_empty() {
+        output.push_str("\n# Suggested rules, review before pasting into the config\n");
+        for category in SuggestedCategory::all() {
+            let mut in_category = suggestions.iter().filter(|(c, _, _)| *c == category).peekable();
+            if in_category.peek().is_none() {
+                continue;
+            }
+            output.push_str(&format!("\n## {}\n", category.as_str()));
+            for (_, proc, behavior) in in_category {
+                output.push_str(&format!("# {}\n{}:{}\n", describe_evidence(behavior), proc, category.rule()));
+            }
+        }
+    }
+
     if let Err(e) = write(output_file, output) {
         log!("Failed to write output: {}", e);
     } else {
@@ -265,7 +319,7 @@ fn process_logs(configs: &ConfigResult, blacklist: &[String], logs_path: Option<
     }
 }
 
-fn process_find(cli: &CliArgs, configs: &ConfigResult, blacklist: &[String]) -> Result<(), windows::core::Error> {
+fn process_find(cli: &CliArgs, configs: &ConfigResult, blacklist: &Blacklist) -> Result<(), windows::core::Error> {
     let _: () = if cli.find_mode {
         unsafe {
             let snapshot = CreateToolhelp32Snapshot(TH32CS_SNAPPROCESS, 0)?;
@@ -324,24 +378,71 @@ fn main() -> windows::core::Result<()> {
         sort_and_group_config(cli.in_file_name, cli.out_file_name);
         return Ok(());
     }
+    if cli.capture_mode {
+        capture_config(&cli);
+        return Ok(());
+    }
 
     *get_dust_bin_mod!() = cli.skip_log_before_elevation;
-    let mut configs = read_config(&cli.config_file_name);
+    if let Some(ref profile) = cli.switch_profile {
+        request_profile_switch(&cli, profile);
+        return Ok(());
+    }
+    // --as only describes another machine for checking, a running service always uses its own hardware
+    let machine = match cli.machine_as.as_deref() {
+        Some(spec) if cli.validate_mode || cli.explain.is_some() => match MachineInfo::with_overrides(spec) {
+            Ok(machine) => machine,
+            Err(e) => {
+                log!("Invalid --as '{}': {}", spec, e);
+                return Ok(());
+            }
+        },
+        _ => MachineInfo::current().clone(),
+    };
+    if cli.machine_as.is_some() && (cli.validate_mode || cli.explain.is_some()) {
+        log!("Reading config as {}", machine.describe());
+    }
+    let mut configs = read_config_as(&cli.config_file_name, cli.profile.as_deref(), &machine);
     configs.print_report();
-    if !configs.errors.is_empty() || cli.validate_mode {
+    if let Some(ref name) = cli.explain {
+        configs.print_explanation(name);
         return Ok(());
     }
-    let mut blacklist = if let Some(ref bf) = cli.blacklist_file_name {
-        read_bleack_list(bf).unwrap_or_default()
-    } else {
-        Vec::new()
+    if cli.list_groups_mode {
+        configs.print_groups();
+        return Ok(());
+    }
+    if cli.validate_mode && configs.errors.is_empty() {
+        // lines of inactive profiles are skipped while parsing, check each profile on its own
+        for profile in configs.profiles.iter().filter(|p| configs.active_profile.as_ref() != Some(*p)) {
+            let profile_configs = read_config_as(&cli.config_file_name, Some(profile), &machine);
+            if profile_configs.errors.is_empty() {
+                log!("Profile '{}': {} rules", profile, profile_configs.total_rules());
+            } else {
+                log!("Profile '{}' has errors:", profile);
+                profile_configs.print_report();
+            }
+        }
+    }
+    let mut blacklist = match cli.blacklist_file_name {
+        Some(ref bf) => {
+            let blacklist = Blacklist::read(bf);
+            blacklist.print_report();
+            blacklist
+        }
+        None => Blacklist::default(),
     };
+    if !configs.errors.is_empty() || !blacklist.errors.is_empty() || cli.validate_mode {
+        return Ok(());
+    }
     if cli.process_logs_mode {
-        process_logs(&configs, &blacklist, cli.in_file_name.as_deref(), cli.out_file_name.as_deref());
+        process_logs(&cli, &configs, &blacklist);
         return Ok(());
     }
 
-    let mut last_config_mod_time = metadata(&cli.config_file_name).and_then(|m| m.modified()).ok();
+    let mut last_config_signature = configs.signature();
+    // an existing switch file from an earlier run does not override -profile
+    let mut last_profile_switch_mod_time = profile_switch_mod_time(&cli);
     let mut last_blacklist_mod_time = cli
         .blacklist_file_name
         .as_ref()
@@ -401,11 +502,36 @@ fn main() -> windows::core::Result<()> {
     // both-level apply exists to reduce get_threads' enumeration and merge logs for a same process
     let mut process_level_pending: List<[u32; PENDING]> = List::new();
     let mut prime_core_scheduler = PrimeThreadScheduler::new(configs.constants.clone());
+    // restraint of CPU hogs samples every loop, it never acts in dry run
+    let mut restrainer = Restrainer::new(configs.restraint.clone());
+    // rules with a `while` condition, applied and restored as their condition flips
+    let mut condition_tracker = ConditionTracker::new();
+    // state of managed processes before their first apply, restored when a reload or profile switch drops their rule
+    let mut original_states = OriginalStates::new();
+    // read-back of applied processes with -verify
+    let mut drift_monitor = DriftMonitor::new();
+    // per-PID delays and re-applies of rules with delay / stages / on_module
+    let mut stage_tracker = StageTracker::new();
+    // processes suspended by suspend_while rules, resumed once their triggers exit
+    let mut suspend_controller = SuspendController::new();
+    // behavior stats of processes reported by -find, the input of -processlogs rule suggestions
+    let mut discovery = cli.find_mode.then(DiscoveryTracker::new);
+    // thread-level rules are simulated over several samples, the prime selection needs cycle deltas and streaks
+    let dry_run_samples = dry_run_samples(&cli, &configs);
+    if cli.dry_run && cli.report_file_name.is_some() {
+        DRY_RUN_REPORT.lock().unwrap().enable();
+    }
 
     while should_continue {
         if cli.log_loop {
             log!("Loop {} started", current_loop + 1);
         }
+        if cli.dry_run {
+            if dry_run_samples > 1 {
+                log!("Dry run sample {}/{}", current_loop + 1, dry_run_samples);
+            }
+            DRY_RUN_REPORT.lock().unwrap().begin_sample();
+        }
         let buffer = &mut SNAPSHOT_BUFFER.lock().unwrap();
         let pid_to_process = &mut PID_TO_PROCESS_MAP.lock().unwrap();
         buffer.clear();
@@ -417,6 +543,31 @@ fn main() -> windows::core::Result<()> {
             Ok(processes) => {
                 let pids_and_names: List<[(u32, &str); PIDS]> = processes.pid_to_process.values().map(|p| (p.pid(), p.get_name())).collect();
                 prime_core_scheduler.reset_alive();
+                let local_now = *get_local_time!();
+                condition_tracker.update(
+                    &pids_and_names,
+                    processes.pid_to_process,
+                    &configs,
+                    &mut prime_core_scheduler,
+                    &mut original_states,
+                    &mut process_level_applied,
+                    &mut process_level_pending,
+                    (local_now.weekday(), (local_now.hour() * 60 + local_now.minute()) as u16),
+                    cli.dry_run,
+                );
+                stage_tracker.update(
+                    &pids_and_names,
+                    processes.pid_to_process,
+                    &configs,
+                    &mut process_level_applied,
+                    &mut process_level_pending,
+                );
+                // processes in blacklist sections with the rules or never scope get no rule at all
+                process_level_pending.retain(|pid| {
+                    !pids_and_names
+                        .iter()
+                        .any(|(p, name)| p == pid && blacklist.excludes_from_rules(name))
+                });
                 for (grade, graded_process_level_configs) in &configs.process_level_configs {
                     // process_level_pending dont respect grade being applied just in time
                     // since it's retain here, it does not hurt performance in next loop iterations
@@ -424,11 +575,15 @@ fn main() -> windows::core::Result<()> {
                         !pids_and_names.iter().any(|(pid, name)| -> bool {
                             if pid == pid_pending {
                                 if let Some(process_level_config) = graded_process_level_configs.get(*name)
+                                    && condition_tracker.allows(*pid, &process_level_config.condition)
+                                    && !stage_tracker.is_waiting(*pid)
                                     && let Some(process) = processes.pid_to_process.get(pid)
                                 {
                                     apply_config(
                                         &cli,
                                         &configs,
+                                        &blacklist,
+                                        &mut original_states,
                                         &mut prime_core_scheduler,
                                         &mut process_level_applied,
                                         &mut thread_level_applied,
@@ -450,7 +605,7 @@ fn main() -> windows::core::Result<()> {
                     // fallback of cli flag -no_etw, and processes launched before this project's process's running
                     if !full_process_level_match
                         && (!current_loop.is_multiple_of(*grade)
-                            || (prime_core_scheduler.pid_to_process_stats.is_empty() && event_trace_receiver.is_some()))
+                            || (!cli.verify && prime_core_scheduler.pid_to_process_stats.is_empty() && event_trace_receiver.is_some()))
                     {
                         continue;
                     }
@@ -458,6 +613,12 @@ fn main() -> windows::core::Result<()> {
                         let Some(process_level_config) = graded_process_level_configs.get(*name) else {
                             continue;
                         };
+                        if !condition_tracker.allows(*pid, &process_level_config.condition)
+                            || stage_tracker.is_waiting(*pid)
+                            || blacklist.excludes_from_rules(name)
+                        {
+                            continue;
+                        }
                         let Some(process) = processes.pid_to_process.get(pid) else {
                             continue;
                         };
@@ -465,6 +626,8 @@ fn main() -> windows::core::Result<()> {
                             apply_config(
                                 &cli,
                                 &configs,
+                                &blacklist,
+                                &mut original_states,
                                 &mut prime_core_scheduler,
                                 &mut process_level_applied,
                                 &mut thread_level_applied,
@@ -474,6 +637,11 @@ fn main() -> windows::core::Result<()> {
                                 process_level_config,
                                 process,
                             );
+                        } else if cli.verify && !cli.dry_run {
+                            let threads_cache: OnceCell<HashMap<u32, SYSTEM_THREAD_INFORMATION>> = OnceCell::new();
+                            let threads = || threads_cache.get_or_init(|| process.get_threads());
+                            let result = drift_monitor.verify(*pid, process_level_config, &threads);
+                            log_apply_results(pid, &process_level_config.name, result);
                         }
                     }
                 }
@@ -491,6 +659,12 @@ fn main() -> windows::core::Result<()> {
                             let Some(thread_level_config) = graded_thread_level_configs.get(*name) else {
                                 continue;
                             };
+                            if !condition_tracker.allows(*pid, &thread_level_config.condition)
+                                || stage_tracker.is_waiting(*pid)
+                                || blacklist.excludes_from_rules(name)
+                            {
+                                continue;
+                            }
                             let Some(process) = processes.pid_to_process.get(pid) else {
                                 continue;
                             };
@@ -511,6 +685,18 @@ fn main() -> windows::core::Result<()> {
                     }
                 }
 
+                suspend_controller.update(&pids_and_names, &configs, &blacklist, cli.dry_run);
+                if !cli.dry_run {
+                    restrainer.update(processes.pid_to_process.values(), |name| {
+                        configs.has_rule_for(name) || blacklist.never_touch(name)
+                    });
+                }
+                if let Some(discovery) = discovery.as_mut() {
+                    discovery.update(processes.pid_to_process.values(), |name| {
+                        configs.has_rule_for(name) || blacklist.contains(name)
+                    });
+                }
+
                 if event_trace_receiver.is_none() || !prime_core_scheduler.pid_to_process_stats.is_empty() {
                     // fallback of cli flag -no_etw or threal_level tracking, resource cleanup and state update
                     let dead_pids: List<[u32; PENDING]> = prime_core_scheduler
@@ -522,11 +708,11 @@ fn main() -> windows::core::Result<()> {
                         prime_core_scheduler.drop_process_by_pid(&pid);
                     });
                     purge_fail_map(&pids_and_names);
-                    process_level_applied.retain(|pid| pids_and_names.iter().any(|(p, _)| p == pid));
-                }
-                if cli.dry_run {
-                    should_continue = false;
                 }
+                // with ETW but no thread-level rules exits are only seen while waiting on events, prune every loop
+                original_states.retain_alive(&pids_and_names);
+                drift_monitor.retain_alive(&pids_and_names);
+                process_level_applied.retain(|pid| pids_and_names.iter().any(|(p, _)| p == pid));
             }
         };
         process_find(&cli, &configs, &blacklist)?;
@@ -534,6 +720,9 @@ fn main() -> windows::core::Result<()> {
         let _ = get_logger_find!().flush();
         let _ = get_logger!().flush();
         current_loop += 1;
+        if cli.dry_run && current_loop >= dry_run_samples {
+            should_continue = false;
+        }
         if let Some(max_loops) = cli.loop_count
             && current_loop >= max_loops
         {
@@ -548,6 +737,8 @@ fn main() -> windows::core::Result<()> {
             let mut etw_sleep = false;
             if prime_core_scheduler.pid_to_process_stats.is_empty()// thread-level config needs to track threading performance
                 && !cli.continuous_process_level_apply
+                && !cli.verify // verify reads back applied processes on every grade tick
+                && !restrainer.is_enabled() // restraint samples CPU load on every interval
                 && let Some(ref event_trace_receiver) = event_trace_receiver
             {
                 etw_sleep = true;
@@ -561,6 +752,12 @@ fn main() -> windows::core::Result<()> {
                             if !process_level_pending.is_empty() {
                                 break;
                             }
+                            // schedules and apply stages are due by the clock, not by an event
+                            if (condition_tracker.has_schedules() || stage_tracker.has_pending_stages())
+                                && Local::now() - *get_local_time!() > TimeDelta::milliseconds(cli.interval_ms as i64)
+                            {
+                                break;
+                            }
                         }
                         Ok(event) => {
                             let empty_pending_pre = process_level_pending.is_empty();
@@ -571,6 +768,16 @@ fn main() -> windows::core::Result<()> {
                                 process_level_applied.retain(|&mut pid| pid != event.pid);
                                 get_pid_map_fail_entry_set!().remove(&event.pid);
                                 prime_core_scheduler.drop_process_by_pid(&event.pid);
+                                original_states.forget(event.pid);
+                                drift_monitor.drop_process(event.pid);
+                            }
+                            // terminate / suspend_while rules react to starts of their processes and triggers right away,
+                            // while rules to any start and their trigger exits
+                            if (event.is_start && event.image_name.as_deref().is_none_or(|name| configs.has_process_action_for(name)))
+                                || suspend_controller.is_tracked(&event)
+                                || condition_tracker.is_tracked(&event)
+                            {
+                                break;
                             }
                             if (!empty_pending_pre
                                 && Local::now() - *get_local_time!() > TimeDelta::milliseconds(((cli.interval_ms - 16) / 2) as i64))
@@ -595,14 +802,40 @@ fn main() -> windows::core::Result<()> {
             hotreload_config(
                 &cli,
                 &mut configs,
-                &mut last_config_mod_time,
+                &mut last_config_signature,
+                pid_to_process,
+                &mut prime_core_scheduler,
+                &mut original_states,
+                &mut process_level_applied,
+                &mut full_process_level_match,
+            );
+            hotreload_profile(
+                &cli,
+                &mut configs,
+                &mut last_profile_switch_mod_time,
                 &mut prime_core_scheduler,
+                &mut original_states,
                 &mut process_level_applied,
                 &mut full_process_level_match,
             );
+            restrainer.reconfigure(&configs.restraint);
             hotreload_blacklist(&cli, &mut blacklist, &mut last_blacklist_mod_time);
         }
     }
+    restrainer.release_all("shutdown");
+    suspend_controller.resume_all();
+    drift_monitor.log_summary();
+    if let Some(discovery) = discovery.as_mut() {
+        discovery.flush();
+    }
+    if cli.dry_run
+        && let Some(ref report_file) = cli.report_file_name
+    {
+        match DRY_RUN_REPORT.lock().unwrap().write(report_file, &cli.config_file_name) {
+            Ok(()) => log!("Dry run report written to {}", report_file),
+            Err(e) => log!("Failed to write {}: {}", report_file, e),
+        }
+    }
     // Stop ETW process monitor
     if let Some(mut event_trace_monitor) = event_trace_monitor {
         event_trace_monitor.stop();
@@ -0,0 +1,83 @@
+//! Process-level state of managed processes from before their rule was first applied.
+//!
+//! Captured once per PID in `apply_config`, it lets a config reload, a profile switch or a `while`
+//! condition that stops holding give a process its original priority, affinity, CPU set, IO/memory
+//! priority and power throttling back when the rule that changed them is removed, edited or inactive.
+
+use crate::{
+    apply::{ApplyConfigResult, OriginalProcessState, capture_process_state, restore_process_state},
+    collections::{HashMap, List, PENDING},
+    config::ProcessLevelConfig,
+    log,
+    winapi::get_process_handle,
+};
+
+#[derive(Debug, Default)]
+pub struct OriginalStates {
+    originals: HashMap<u32, (String, OriginalProcessState)>,
+}
+
+impl OriginalStates {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Records the state of a process about to receive its rule, once per process.
+    pub fn capture(&mut self, pid: u32, config: &ProcessLevelConfig, dry_run: bool) {
+        if dry_run || self.originals.contains_key(&pid) {
+            return;
+        }
+        if let Some(process_handle) = get_process_handle(pid, &config.name) {
+            self.originals
+                .insert(pid, (config.name.clone(), capture_process_state(config, &process_handle)));
+        }
+    }
+
+    /// Drops the state of a process that exited, so a reused PID is captured afresh.
+    pub fn forget(&mut self, pid: u32) {
+        self.originals.remove(&pid);
+    }
+
+    /// Drops state of exited processes.
+    pub fn retain_alive(&mut self, pids_and_names: &[(u32, &str)]) {
+        self.originals
+            .retain(|pid, (name, _)| pids_and_names.iter().any(|(p, n)| p == pid && n == name));
+    }
+
+    /// Restores every captured process whose name matches, `reason` is appended to the log line.
+    ///
+    /// The state is forgotten afterwards, so the next apply captures it again.
+    pub fn restore_where(&mut self, matches: impl Fn(&str) -> bool, reason: &str) {
+        let pids: List<[u32; PENDING]> = self
+            .originals
+            .iter()
+            .filter(|(_, (name, _))| matches(name))
+            .map(|(pid, _)| *pid)
+            .collect();
+        for pid in pids {
+            let Some(name) = self.originals.get(&pid).map(|(name, _)| name.clone()) else {
+                continue;
+            };
+            let mut result = ApplyConfigResult::new();
+            self.restore(pid, &mut result);
+            log_restore_result(pid, &name, reason, result);
+        }
+    }
+
+    /// Restores one process into `result` and forgets its state, nothing happens when none was captured.
+    pub fn restore(&mut self, pid: u32, result: &mut ApplyConfigResult) {
+        let Some((name, original)) = self.originals.remove(&pid) else {
+            return;
+        };
+        if let Some(process_handle) = get_process_handle(pid, &name) {
+            restore_process_state(pid, &name, &original, &process_handle, result);
+        }
+    }
+}
+
+fn log_restore_result(pid: u32, name: &str, reason: &str, result: ApplyConfigResult) {
+    result.log_errors();
+    if !result.changes.is_empty() {
+        log!("{:>5}::{}::{}: {}", pid, name, reason, result.changes.join(", "));
+    }
+}
@@ -0,0 +1,388 @@
+//! Executable path lookup for `-processlogs`.
+//!
+//! [`PathResolver`] is the backend interface. [`EverythingResolver`] asks Everything's `es.exe`, which is
+//! fast but only works when Everything is installed and running. [`FileSystemResolver`] walks configured
+//! root directories itself, indexing every `*.exe` once and keeping the index in a cache file for later runs.
+//! It only uses `std::fs`, so it works on any directory tree. [`ChainResolver`] tries backends in order,
+//! which is how `-resolver auto` prefers `es` and falls back to the built-in search.
+
+use crate::{cli::CliArgs, collections::HashMap, util::glob_match};
+
+use encoding_rs::Encoding;
+use std::{
+    env,
+    fs::{metadata, read_dir, read_to_string, write},
+    path::{Path, PathBuf},
+    process::Command,
+    time::{Duration, SystemTime},
+};
+use windows::Win32::System::Console::GetConsoleOutputCP;
+
+/// A cached index older than this is rebuilt.
+const CACHE_MAX_AGE: Duration = Duration::from_secs(24 * 60 * 60);
+
+/// Directory names and paths skipped by the built-in search unless `-search_exclude` replaces them.
+const DEFAULT_EXCLUSIONS: &[&str] = &[
+    "$recycle.bin",
+    "system volume information",
+    "*\\windows\\winsxs",
+    "node_modules",
+    ".git",
+];
+
+pub trait PathResolver {
+    fn name(&self) -> &'static str;
+
+    /// Full paths of files named `file_name` (lowercase), `Err` when the backend cannot search at all.
+    fn resolve(&mut self, file_name: &str) -> Result<Vec<PathBuf>, String>;
+}
+
+/// Builds the backend chosen with `-resolver`: `es`, `fs`, or `auto` (default) for `es` with the built-in fallback.
+pub fn resolver_from_cli(cli: &CliArgs, logs_path: &str) -> Result<Box<dyn PathResolver>, String> {
+    let file_system = || {
+        let roots = match &cli.search_roots {
+            Some(roots) => split_list(roots).into_iter().map(PathBuf::from).collect(),
+            None => default_roots(),
+        };
+        let exclusions = match &cli.search_exclude {
+            Some(exclusions) => split_list(exclusions),
+            None => DEFAULT_EXCLUSIONS.iter().map(|exclusion| exclusion.to_string()).collect(),
+        };
+        let cache_file = cli
+            .search_cache
+            .as_ref()
+            .map(PathBuf::from)
+            .unwrap_or_else(|| Path::new(logs_path).join("path_index.cache"));
+        FileSystemResolver::new(roots, exclusions, Some(cache_file))
+    };
+    match cli.resolver.as_deref().unwrap_or("auto").to_lowercase().as_str() {
+        "es" => Ok(Box::new(EverythingResolver::new())),
+        "fs" => Ok(Box::new(file_system())),
+        "auto" => Ok(Box::new(ChainResolver {
+            backends: vec![Box::new(EverythingResolver::new()), Box::new(file_system())],
+        })),
+        other => Err(format!("unknown resolver '{}', use auto, es or fs", other)),
+    }
+}
+
+/// Splits a `;` separated CLI list, empty items are dropped.
+fn split_list(list: &str) -> Vec<String> {
+    list.split(';')
+        .map(str::trim)
+        .filter(|item| !item.is_empty())
+        .map(str::to_string)
+        .collect()
+}
+
+/// Program Files, ProgramData and the per-user application directories.
+fn default_roots() -> Vec<PathBuf> {
+    ["ProgramFiles", "ProgramFiles(x86)", "ProgramData", "LOCALAPPDATA", "APPDATA"]
+        .iter()
+        .filter_map(|var| env::var(var).ok())
+        .map(PathBuf::from)
+        .collect()
+}
+
+/// Everything's command line client, `es -utf8-bom -r ^name$`.
+pub struct EverythingResolver {
+    encoding: &'static Encoding,
+}
+
+impl EverythingResolver {
+    pub fn new() -> Self {
+        // es writes in the console code page unless the UTF-8 BOM is requested and honored
+        let acp = unsafe { GetConsoleOutputCP() };
+        let label = if acp == 936 { "gbk" } else { &format!("windows-{}", acp) };
+        Self {
+            encoding: Encoding::for_label_no_replacement(label.as_bytes()).unwrap_or(encoding_rs::UTF_8),
+        }
+    }
+}
+
+impl PathResolver for EverythingResolver {
+    fn name(&self) -> &'static str {
+        "es"
+    }
+
+    fn resolve(&mut self, file_name: &str) -> Result<Vec<PathBuf>, String> {
+        let output = Command::new("es")
+            .args(["-utf8-bom", "-r", &format!("^{}$", file_name.replace('.', r"\."))])
+            .output()
+            .map_err(|e| format!("es failed: {}", e))?;
+        if !output.status.success() {
+            return Err(format!("es failed: {}", output.status));
+        }
+        let stdout = output.stdout.strip_prefix(&[0xEF, 0xBB, 0xBF]).unwrap_or(&output.stdout);
+        let (decoded, _, _) = self.encoding.decode(stdout);
+        Ok(decoded
+            .lines()
+            .map(str::trim)
+            .filter(|line| !line.is_empty())
+            .map(PathBuf::from)
+            .collect())
+    }
+}
+
+/// Recursive search over root directories, indexed on first use.
+///
+/// Exclusions are `*`/`?` patterns matched against directory names and full paths (lowercase).
+/// Symbolic links and junctions are not followed. With a cache file, the index is reused while it is
+/// younger than a day and was built for the same roots and exclusions.
+pub struct FileSystemResolver {
+    roots: Vec<PathBuf>,
+    exclusions: Vec<String>,
+    cache_file: Option<PathBuf>,
+    index: Option<HashMap<String, Vec<PathBuf>>>,
+}
+
+impl FileSystemResolver {
+    pub fn new(roots: Vec<PathBuf>, exclusions: Vec<String>, cache_file: Option<PathBuf>) -> Self {
+        Self {
+            roots,
+            exclusions: exclusions.into_iter().map(|exclusion| exclusion.to_lowercase()).collect(),
+            cache_file,
+            index: None,
+        }
+    }
+
+    /// First line of the cache file, ties the cached index to the roots and exclusions it was built for.
+    fn cache_header(&self) -> String {
+        let roots: Vec<String> = self.roots.iter().map(|root| root.display().to_string()).collect();
+        format!("# roots={} exclude={}", roots.join(";"), self.exclusions.join(";"))
+    }
+
+    fn load_cache(&self) -> Option<HashMap<String, Vec<PathBuf>>> {
+        let cache_file = self.cache_file.as_ref()?;
+        let age = metadata(cache_file)
+            .and_then(|m| m.modified())
+            .ok()
+            .and_then(|modified| SystemTime::now().duration_since(modified).ok())?;
+        if age > CACHE_MAX_AGE {
+            return None;
+        }
+        let content = read_to_string(cache_file).ok()?;
+        let mut lines = content.lines();
+        if lines.next()? != self.cache_header() {
+            return None;
+        }
+        let mut index: HashMap<String, Vec<PathBuf>> = HashMap::default();
+        for line in lines {
+            if let Some((name, path)) = line.split_once('\t') {
+                index.entry(name.to_string()).or_default().push(PathBuf::from(path));
+            }
+        }
+        Some(index)
+    }
+
+    fn save_cache(&self, index: &HashMap<String, Vec<PathBuf>>) {
+        let Some(cache_file) = &self.cache_file else {
+            return;
+        };
+        let mut content = self.cache_header();
+        content.push('\n');
+        for (name, paths) in index {
+            for path in paths {
+                content.push_str(&format!("{}\t{}\n", name, path.display()));
+            }
+        }
+        // a missing cache only costs the next run a rebuild
+        let _ = write(cache_file, content);
+    }
+
+    fn is_excluded(&self, dir: &Path) -> bool {
+        let name = dir.file_name().map(|n| n.to_string_lossy().to_lowercase()).unwrap_or_default();
+        let full = dir.display().to_string().to_lowercase();
+        self.exclusions
+            .iter()
+            .any(|exclusion| glob_match(exclusion, &name) || glob_match(exclusion, &full))
+    }
+
+    /// Walks every root once and maps lowercase `*.exe` file names to their paths.
+    fn build_index(&self) -> HashMap<String, Vec<PathBuf>> {
+        let mut index: HashMap<String, Vec<PathBuf>> = HashMap::default();
+        let mut pending: Vec<PathBuf> = self.roots.iter().filter(|root| !self.is_excluded(root)).cloned().collect();
+        while let Some(dir) = pending.pop() {
+            // unreadable directories (access denied, removed meanwhile) are skipped
+            let Ok(entries) = read_dir(&dir) else {
+                continue;
+            };
+            for entry in entries.flatten() {
+                let Ok(file_type) = entry.file_type() else {
+                    continue;
+                };
+                let path = entry.path();
+                if file_type.is_dir() {
+                    if !self.is_excluded(&path) {
+                        pending.push(path);
+                    }
+                } else if file_type.is_file() {
+                    let name = entry.file_name().to_string_lossy().to_lowercase();
+                    if name.ends_with(".exe") {
+                        index.entry(name).or_default().push(path);
+                    }
+                }
+            }
+        }
+        for paths in index.values_mut() {
+            paths.sort();
+        }
+        index
+    }
+}
+
+impl PathResolver for FileSystemResolver {
+    fn name(&self) -> &'static str {
+        "fs"
+    }
+
+    fn resolve(&mut self, file_name: &str) -> Result<Vec<PathBuf>, String> {
+        if self.roots.is_empty() {
+            return Err("no search roots".to_string());
+        }
+        if self.index.is_none() {
+            let index = match self.load_cache() {
+                Some(index) => index,
+                None => {
+                    let index = self.build_index();
+                    self.save_cache(&index);
+                    index
+                }
+            };
+            self.index = Some(index);
+        }
+        // a cached index may list files removed since
+        Ok(self
+            .index
+            .as_ref()
+            .and_then(|index| index.get(file_name))
+            .into_iter()
+            .flatten()
+            .filter(|path| path.is_file())
+            .cloned()
+            .collect())
+    }
+}
+
+/// Tries backends in order, the first one able to search answers; a backend that fails once is skipped afterwards.
+pub struct ChainResolver {
+    backends: Vec<Box<dyn PathResolver>>,
+}
+
+impl PathResolver for ChainResolver {
+    fn name(&self) -> &'static str {
+        self.backends.first().map_or("none", |backend| backend.name())
+    }
+
+    fn resolve(&mut self, file_name: &str) -> Result<Vec<PathBuf>, String> {
+        let mut errors: Vec<String> = Vec::new();
+        while let Some(backend) = self.backends.first_mut() {
+            match backend.resolve(file_name) {
+                Ok(paths) => return Ok(paths),
+                Err(e) => {
+                    errors.push(format!("{}: {}", backend.name(), e));
+                    self.backends.remove(0);
+                }
+            }
+        }
+        if errors.is_empty() {
+            return Err("no backend left".to_string());
+        }
+        Err(errors.join(", "))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::{
+        fs::{create_dir_all, remove_dir_all},
+        path::MAIN_SEPARATOR,
+    };
+
+    /// A scratch directory tree holding empty files, removed again on drop.
+    struct TempTree(PathBuf);
+
+    impl TempTree {
+        fn new(name: &str, files: &[&str]) -> Self {
+            let root = env::temp_dir().join(format!("path_resolver_{}_{}", std::process::id(), name));
+            let _ = remove_dir_all(&root);
+            let tree = TempTree(root);
+            for file in files {
+                tree.add(file);
+            }
+            tree
+        }
+
+        fn add(&self, file: &str) {
+            let path = self.path(file);
+            create_dir_all(path.parent().unwrap()).unwrap();
+            write(path, "").unwrap();
+        }
+
+        fn path(&self, file: &str) -> PathBuf {
+            file.split('/').fold(self.0.clone(), |path, part| path.join(part))
+        }
+
+        fn resolver(&self, exclusions: &[&str], cache_file: Option<PathBuf>) -> FileSystemResolver {
+            let exclusions = exclusions.iter().map(|exclusion| exclusion.to_string()).collect();
+            FileSystemResolver::new(vec![self.0.clone()], exclusions, cache_file)
+        }
+    }
+
+    impl Drop for TempTree {
+        fn drop(&mut self) {
+            let _ = remove_dir_all(&self.0);
+        }
+    }
+
+    #[test]
+    fn indexes_executables_of_every_subdirectory() {
+        let tree = TempTree::new("index", &["a/game.exe", "b/c/Tool.EXE", "b/c/game.exe", "readme.txt"]);
+        let mut resolver = tree.resolver(&[], None);
+        assert_eq!(
+            resolver.resolve("game.exe").unwrap(),
+            [tree.path("a/game.exe"), tree.path("b/c/game.exe")]
+        );
+        assert_eq!(resolver.resolve("tool.exe").unwrap(), [tree.path("b/c/Tool.EXE")]);
+        assert!(resolver.resolve("readme.txt").unwrap().is_empty());
+    }
+
+    #[test]
+    fn skips_excluded_directory_names_and_paths() {
+        let tree = TempTree::new(
+            "exclude",
+            &["node_modules/a.exe", "keep/deep/b.exe", "skip/deep/c.exe", "keep/d.exe"],
+        );
+        let deep_under_skip = format!("*{}skip{}deep", MAIN_SEPARATOR, MAIN_SEPARATOR);
+        let mut resolver = tree.resolver(&["node_*", &deep_under_skip], None);
+        assert!(resolver.resolve("a.exe").unwrap().is_empty());
+        assert_eq!(resolver.resolve("b.exe").unwrap(), [tree.path("keep/deep/b.exe")]);
+        assert!(resolver.resolve("c.exe").unwrap().is_empty());
+        assert_eq!(resolver.resolve("d.exe").unwrap(), [tree.path("keep/d.exe")]);
+    }
+
+    #[test]
+    fn cache_is_reused_only_for_the_same_roots_and_exclusions() {
+        let tree = TempTree::new("cache", &["a/old.exe"]);
+        let cache_file = tree.path("index.cache");
+        tree.resolver(&["x"], Some(cache_file.clone())).resolve("old.exe").unwrap();
+
+        // an executable added after the index was cached is not seen while the cache is valid
+        tree.add("b/new.exe");
+        assert!(
+            tree.resolver(&["x"], Some(cache_file.clone()))
+                .resolve("new.exe")
+                .unwrap()
+                .is_empty()
+        );
+
+        // other exclusions or roots do not match the cache header and rebuild the index
+        assert_eq!(
+            tree.resolver(&["y"], Some(cache_file.clone())).resolve("new.exe").unwrap(),
+            [tree.path("b/new.exe")]
+        );
+        tree.add("b/newer.exe");
+        let mut other_roots = FileSystemResolver::new(vec![tree.path("b")], vec!["y".to_string()], Some(cache_file));
+        assert_eq!(other_roots.resolve("newer.exe").unwrap(), [tree.path("b/newer.exe")]);
+    }
+}
@@ -1,7 +1,7 @@
 use windows::Win32::System::Threading::{
     ABOVE_NORMAL_PRIORITY_CLASS, BELOW_NORMAL_PRIORITY_CLASS, HIGH_PRIORITY_CLASS, IDLE_PRIORITY_CLASS, MEMORY_PRIORITY,
     MEMORY_PRIORITY_BELOW_NORMAL, MEMORY_PRIORITY_LOW, MEMORY_PRIORITY_MEDIUM, MEMORY_PRIORITY_NORMAL, MEMORY_PRIORITY_VERY_LOW,
-    NORMAL_PRIORITY_CLASS, PROCESS_CREATION_FLAGS, REALTIME_PRIORITY_CLASS, THREAD_PRIORITY,
+    NORMAL_PRIORITY_CLASS, PROCESS_CREATION_FLAGS, PROCESS_POWER_THROTTLING_EXECUTION_SPEED, REALTIME_PRIORITY_CLASS, THREAD_PRIORITY,
 };
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -158,6 +158,63 @@ impl MemoryPriority {
     }
 }
 
+/// Power throttling (EcoQoS / efficiency mode) state of a process or thread.
+///
+/// `Off` explicitly opts out of throttling, `None` leaves the decision to Windows.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PowerThrottling {
+    None,
+    On,
+    Off,
+}
+
+/// (ControlMask, StateMask) of `PROCESS_POWER_THROTTLING_STATE` / `THREAD_POWER_THROTTLING_STATE`.
+pub type PowerThrottlingMasks = (u32, u32);
+
+impl PowerThrottling {
+    const TABLE: &'static [(Self, &'static str, Option<PowerThrottlingMasks>)] = &[
+        (Self::None, "none", None),
+        (
+            Self::On,
+            "on",
+            Some((PROCESS_POWER_THROTTLING_EXECUTION_SPEED, PROCESS_POWER_THROTTLING_EXECUTION_SPEED)),
+        ),
+        (Self::Off, "off", Some((PROCESS_POWER_THROTTLING_EXECUTION_SPEED, 0))),
+    ];
+
+    pub fn as_str(&self) -> &'static str {
+        Self::TABLE
+            .iter()
+            .find(|(v, _, _)| v == self)
+            .map(|(_, name, _)| *name)
+            .unwrap_or("unknown")
+    }
+
+    pub fn as_win_const(&self) -> Option<PowerThrottlingMasks> {
+        Self::TABLE.iter().find(|(v, _, _)| v == self).and_then(|(_, _, val)| *val)
+    }
+
+    pub fn from_str(s: &str) -> Self {
+        let s = s.to_lowercase();
+        Self::TABLE
+            .iter()
+            .find(|(_, name, _)| *name == s.as_str())
+            .map(|(v, _, _)| *v)
+            .unwrap_or(Self::None)
+    }
+
+    /// Interprets a queried throttling state, only the execution speed bit is considered.
+    pub fn from_win_const(control_mask: u32, state_mask: u32) -> Self {
+        if control_mask & PROCESS_POWER_THROTTLING_EXECUTION_SPEED == 0 {
+            Self::None
+        } else if state_mask & PROCESS_POWER_THROTTLING_EXECUTION_SPEED != 0 {
+            Self::On
+        } else {
+            Self::Off
+        }
+    }
+}
+
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum ThreadPriority {
     None,
@@ -142,4 +142,30 @@ impl ProcessEntry {
     pub fn thread_count(&self) -> u32 {
         self.process.NumberOfThreads
     }
+
+    /// Process creation time, in 100ns units since 1601 (FILETIME).
+    #[inline]
+    pub fn create_time(&self) -> i64 {
+        unsafe { *self.process.CreateTime.QuadPart() }
+    }
+
+    /// Bytes read and written by the process so far, from the snapshot's IO counters.
+    #[inline]
+    pub fn io_bytes(&self) -> u64 {
+        unsafe { (*self.process.ReadTransferCount.QuadPart() + *self.process.WriteTransferCount.QuadPart()) as u64 }
+    }
+
+    /// Kernel + user time of the threads in this snapshot, in 100ns units.
+    pub fn cpu_time(&self) -> i64 {
+        let threads_ptr = self.threads_base_ptr as *const SYSTEM_THREAD_INFORMATION;
+        if threads_ptr.is_null() {
+            return 0;
+        }
+        (0..self.process.NumberOfThreads as usize)
+            .map(|i| unsafe {
+                let thread = &*threads_ptr.add(i);
+                thread.KernelTime.QuadPart() + thread.UserTime.QuadPart()
+            })
+            .sum()
+    }
 }
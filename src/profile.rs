@@ -0,0 +1,124 @@
+//! Runtime switching between the config's named profiles.
+//!
+//! The running service watches `<config>.profile`: writing a profile name into it (which is what
+//! `-switch_profile <name>` does) re-reads the config with that profile active. `none` or an empty file
+//! selects no profile. Processes that got settings from the previous profile are restored first, then
+//! every rule is re-matched through the `full_process_level_match` path.
+
+use crate::{
+    apply::{ApplyConfigResult, release_prime_threads},
+    cli::CliArgs,
+    collections::{List, PENDING, PIDS},
+    config::{ConfigResult, read_config},
+    log,
+    originals::OriginalStates,
+    scheduler::PrimeThreadScheduler,
+};
+
+use std::{
+    fs::{metadata, read_to_string, write},
+    time::SystemTime,
+};
+
+/// The file the running service watches for profile switches.
+pub fn profile_switch_path(config_file_name: &str) -> String {
+    format!("{}.profile", config_file_name)
+}
+
+/// Requests a profile switch from a running service, the `-switch_profile` control command.
+pub fn request_profile_switch(cli: &CliArgs, profile: &str) {
+    let path = profile_switch_path(&cli.config_file_name);
+    match write(&path, profile.trim()) {
+        Ok(()) => log!("Requested profile '{}' via '{}'", profile.trim(), path),
+        Err(e) => log!("Failed to write '{}': {}", path, e),
+    }
+}
+
+pub fn profile_switch_mod_time(cli: &CliArgs) -> Option<SystemTime> {
+    metadata(profile_switch_path(&cli.config_file_name)).and_then(|m| m.modified()).ok()
+}
+
+/// Switches profiles when `<config>.profile` changed since the last check.
+///
+/// The new profile must parse without errors, otherwise the current configuration stays active.
+#[allow(clippy::too_many_arguments)]
+pub fn hotreload_profile(
+    cli: &CliArgs,
+    configs: &mut ConfigResult,
+    last_switch_mod_time: &mut Option<SystemTime>,
+    prime_core_scheduler: &mut PrimeThreadScheduler,
+    original_states: &mut OriginalStates,
+    process_level_applied: &mut List<[u32; PIDS]>,
+    full_process_level_match: &mut bool,
+) {
+    let mod_time = profile_switch_mod_time(cli);
+    if mod_time.is_none() || mod_time == *last_switch_mod_time {
+        return;
+    }
+    *last_switch_mod_time = mod_time;
+    let path = profile_switch_path(&cli.config_file_name);
+    let requested = read_to_string(&path).unwrap_or_default().trim().to_lowercase();
+    let requested = (!requested.is_empty() && requested != "none").then_some(requested);
+    if requested == configs.active_profile {
+        return;
+    }
+    let describe = |profile: &Option<String>| profile.clone().unwrap_or_else(|| "none".to_string());
+    log!(
+        "Profile switch requested: {} -> {}",
+        describe(&configs.active_profile),
+        describe(&requested)
+    );
+    let new_config_result = read_config(&cli.config_file_name, requested.as_deref());
+    if !new_config_result.errors.is_empty() {
+        log!("Profile '{}' has errors, keeping current profile.", describe(&requested));
+        for error in &new_config_result.errors {
+            log!("  - {}", error);
+        }
+        return;
+    }
+
+    // prime threads pinned by profile rules are released before the new rules re-pin them
+    let profile_pids: List<[u32; PENDING]> = prime_core_scheduler
+        .pid_to_process_stats
+        .iter()
+        .filter(|(_, process_stats)| {
+            configs.thread_level_configs.values().any(|graded| {
+                graded
+                    .get(&process_stats.process_name)
+                    .is_some_and(|config| config.profile.is_some())
+            })
+        })
+        .map(|(pid, _)| *pid)
+        .collect();
+    for pid in profile_pids {
+        let mut result = ApplyConfigResult::new();
+        release_prime_threads(pid, prime_core_scheduler, &mut result);
+        let name = prime_core_scheduler.pid_to_process_stats[&pid].process_name.clone();
+        prime_core_scheduler.drop_process_by_pid(&pid);
+        log_profile_result(pid, &name, result);
+    }
+    original_states.restore_where(
+        |name| {
+            configs
+                .process_level_configs
+                .values()
+                .any(|graded| graded.get(name).is_some_and(|config| config.profile.is_some()))
+        },
+        "Profile",
+    );
+
+    *configs = new_config_result;
+    configs.print_report();
+    configs.release_unused_jobs("Profile");
+    prime_core_scheduler.set_constants(&configs.constants);
+    process_level_applied.clear();
+    *full_process_level_match = true;
+    log!("Profile switch complete: {} rules loaded.", configs.total_rules());
+}
+
+fn log_profile_result(pid: u32, name: &str, result: ApplyConfigResult) {
+    result.log_errors();
+    if !result.changes.is_empty() {
+        log!("{:>5}::{}::Profile: {}", pid, name, result.changes.join(", "));
+    }
+}
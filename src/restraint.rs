@@ -0,0 +1,629 @@
+//! ProBalance-style dynamic restraint of CPU hogs.
+//!
+//! When a process without a rule keeps using more than `@RESTRAINT_CPU_SHARE` percent of the
+//! total CPU time for `@RESTRAINT_SUSTAIN` samples while the whole system is busy, its priority
+//! class is lowered and/or it is moved to the restraint CPU set. Once it stays below the release
+//! share for `@RESTRAINT_CALM` samples, the original settings are restored.
+//!
+//! The decision logic in [`RestraintEngine`] is pure: it only consumes cumulative CPU time samples
+//! and returns actions, so it can be driven by synthetic load. [`Restrainer`] feeds it from process
+//! snapshots and performs the actions.
+
+use crate::{
+    collections::{CONSUMER_CPUS, HashMap, List, PENDING, list},
+    config::format_cpu_indices,
+    error_codes::error_from_code_win32,
+    logging::{Operation, is_new_error, log_to_restraint},
+    priority::ProcessPriority,
+    process::ProcessEntry,
+    winapi::{cpusetids_from_indices, get_cpu_set_information, get_process_handle, indices_from_cpusetids},
+};
+
+use std::time::Instant;
+use windows::Win32::{
+    Foundation::GetLastError,
+    System::Threading::{
+        GetCurrentProcessId, GetPriorityClass, GetProcessDefaultCpuSets, PROCESS_CREATION_FLAGS, SetPriorityClass, SetProcessDefaultCpuSets,
+    },
+};
+
+/// Restraint settings from the `@RESTRAINT_*` constants. Restraint is disabled while `cpu_share` is 0.
+#[derive(Debug, Clone, PartialEq)]
+pub struct RestraintSettings {
+    /// Share of the total CPU time (all logical CPUs, percent) above which a process counts as hot.
+    pub cpu_share: f64,
+    /// Share below which a restrained process counts as calm, defaults to half of `cpu_share`.
+    pub release_share: Option<f64>,
+    /// Total system CPU usage (percent) required before anything is restrained.
+    pub system_busy: f64,
+    /// Consecutive hot samples before a process is restrained.
+    pub sustain: u32,
+    /// Consecutive calm samples before a restrained process is restored.
+    pub calm: u32,
+    /// Priority class given to restrained processes, `None` keeps the priority.
+    pub priority: ProcessPriority,
+    /// CPU set restrained processes are moved to, empty keeps the CPU set.
+    pub cpus: List<[u32; CONSUMER_CPUS]>,
+    /// Lowercase process names that are never restrained.
+    pub exempt: Vec<String>,
+    /// Write restrain/release events to the `.restraint` log.
+    pub log: bool,
+}
+
+impl Default for RestraintSettings {
+    fn default() -> Self {
+        RestraintSettings {
+            cpu_share: 0.0,
+            release_share: None,
+            system_busy: 50.0,
+            sustain: 3,
+            calm: 5,
+            priority: ProcessPriority::BelowNormal,
+            cpus: List::new(),
+            exempt: Vec::new(),
+            log: true,
+        }
+    }
+}
+
+impl RestraintSettings {
+    pub fn is_enabled(&self) -> bool {
+        self.cpu_share > 0.0
+    }
+
+    fn release_share(&self) -> f64 {
+        self.release_share.unwrap_or(self.cpu_share / 2.0)
+    }
+}
+
+/// Cumulative CPU time of one process, as found in a snapshot.
+#[derive(Debug, Clone, Copy)]
+pub struct LoadSample<'a> {
+    pub pid: u32,
+    pub name: &'a str,
+    /// Kernel + user time in 100ns units, summed over the process's threads.
+    pub cpu_time: i64,
+    /// Exempt processes are tracked (so releases still work) but never restrained.
+    pub exempt: bool,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum RestraintAction {
+    Restrain {
+        pid: u32,
+        name: String,
+        share: f64,
+        system_busy: f64,
+    },
+    Release {
+        pid: u32,
+        name: String,
+        share: f64,
+    },
+    /// A restrained process exited, nothing left to restore.
+    Exited {
+        pid: u32,
+        name: String,
+    },
+}
+
+#[derive(Debug)]
+struct PidState {
+    name: String,
+    last_cpu_time: i64,
+    hot_streak: u32,
+    calm_streak: u32,
+    restrained: bool,
+}
+
+/// Pure restraint decision logic over successive load samples.
+#[derive(Debug, Default)]
+pub struct RestraintEngine {
+    pub settings: RestraintSettings,
+    states: HashMap<u32, PidState>,
+}
+
+impl RestraintEngine {
+    pub fn new(settings: RestraintSettings) -> Self {
+        RestraintEngine {
+            settings,
+            states: HashMap::default(),
+        }
+    }
+
+    /// Pids currently restrained, with their names.
+    pub fn restrained(&self) -> Vec<(u32, String)> {
+        self.states
+            .iter()
+            .filter(|(_, state)| state.restrained)
+            .map(|(pid, state)| (*pid, state.name.clone()))
+            .collect()
+    }
+
+    /// Marks a process restrained after a [`RestraintAction::Restrain`] was applied, so it is watched for release.
+    pub fn confirm_restrained(&mut self, pid: u32) {
+        if let Some(state) = self.states.get_mut(&pid) {
+            state.restrained = true;
+            state.hot_streak = 0;
+            state.calm_streak = 0;
+        }
+    }
+
+    /// Feeds one sample of every running process and returns the resulting actions.
+    ///
+    /// `elapsed` is the wall time since the previous sample in 100ns units, `cpu_count` the number
+    /// of logical CPUs. Shares are relative to `elapsed * cpu_count`. A pid seen for the first time
+    /// (or reused by a different process) only establishes its baseline.
+    pub fn evaluate(&mut self, samples: &[LoadSample], elapsed: i64, cpu_count: u32) -> List<[RestraintAction; PENDING]> {
+        let mut actions: List<[RestraintAction; PENDING]> = List::new();
+        let capacity = (elapsed.max(1) as f64) * cpu_count.max(1) as f64;
+
+        let mut deltas: List<[(usize, i64); PENDING]> = List::new();
+        let mut total_delta: i64 = 0;
+        for (index, sample) in samples.iter().enumerate() {
+            match self.states.get_mut(&sample.pid) {
+                Some(state) if state.name == sample.name => {
+                    // threads that exited take their CPU time with them, never count that as negative load
+                    let delta = (sample.cpu_time - state.last_cpu_time).max(0);
+                    state.last_cpu_time = sample.cpu_time;
+                    total_delta += delta;
+                    deltas.push((index, delta));
+                }
+                previous => {
+                    if let Some(state) = previous
+                        && state.restrained
+                    {
+                        actions.push(RestraintAction::Exited {
+                            pid: sample.pid,
+                            name: state.name.clone(),
+                        });
+                    }
+                    self.states.insert(
+                        sample.pid,
+                        PidState {
+                            name: sample.name.to_string(),
+                            last_cpu_time: sample.cpu_time,
+                            hot_streak: 0,
+                            calm_streak: 0,
+                            restrained: false,
+                        },
+                    );
+                }
+            }
+        }
+
+        // processes gone since the last sample
+        self.states.retain(|pid, state| {
+            let alive = samples.iter().any(|sample| sample.pid == *pid);
+            if !alive && state.restrained {
+                actions.push(RestraintAction::Exited {
+                    pid: *pid,
+                    name: state.name.clone(),
+                });
+            }
+            alive
+        });
+
+        let system_busy = total_delta as f64 / capacity * 100.0;
+        let release_share = self.settings.release_share();
+        for (index, delta) in deltas {
+            let sample = &samples[index];
+            let share = delta as f64 / capacity * 100.0;
+            let Some(state) = self.states.get_mut(&sample.pid) else {
+                continue;
+            };
+            if state.restrained {
+                state.calm_streak = if share < release_share { state.calm_streak + 1 } else { 0 };
+                if state.calm_streak >= self.settings.calm {
+                    state.restrained = false;
+                    state.calm_streak = 0;
+                    state.hot_streak = 0;
+                    actions.push(RestraintAction::Release {
+                        pid: sample.pid,
+                        name: state.name.clone(),
+                        share,
+                    });
+                }
+            } else if !sample.exempt {
+                state.hot_streak = if share >= self.settings.cpu_share && system_busy >= self.settings.system_busy {
+                    state.hot_streak + 1
+                } else {
+                    0
+                };
+                // the process only counts as restrained once the caller confirms it, until then it is asked again every hot sample
+                if state.hot_streak >= self.settings.sustain {
+                    actions.push(RestraintAction::Restrain {
+                        pid: sample.pid,
+                        name: state.name.clone(),
+                        share,
+                        system_busy,
+                    });
+                }
+            }
+        }
+        actions
+    }
+}
+
+/// Settings a restrained process had before, restored on release.
+#[derive(Debug)]
+struct OriginalSettings {
+    priority_class: Option<u32>,
+    cpu_set_ids: Option<List<[u32; CONSUMER_CPUS]>>,
+}
+
+/// Samples process snapshots into a [`RestraintEngine`] and applies its decisions.
+#[derive(Debug, Default)]
+pub struct Restrainer {
+    engine: RestraintEngine,
+    last_sample: Option<Instant>,
+    originals: HashMap<u32, OriginalSettings>,
+}
+
+impl Restrainer {
+    pub fn new(settings: RestraintSettings) -> Self {
+        Restrainer {
+            engine: RestraintEngine::new(settings),
+            last_sample: None,
+            originals: HashMap::default(),
+        }
+    }
+
+    pub fn is_enabled(&self) -> bool {
+        self.engine.settings.is_enabled()
+    }
+
+    /// Swaps in reloaded settings, restoring every restrained process when they changed.
+    pub fn reconfigure(&mut self, settings: &RestraintSettings) {
+        if &self.engine.settings == settings {
+            return;
+        }
+        self.release_all("settings changed");
+        *self = Restrainer::new(settings.clone());
+    }
+
+    /// Takes one load sample and restrains/releases processes accordingly.
+    ///
+    /// `is_managed` tells whether a process has a rule of its own; such processes are never restrained.
+    pub fn update<'a>(&mut self, processes: impl Iterator<Item = &'a ProcessEntry>, is_managed: impl Fn(&str) -> bool) {
+        if !self.is_enabled() {
+            return;
+        }
+        let now = Instant::now();
+        let elapsed = self.last_sample.map(|last| (now - last).as_nanos() as i64 / 100).unwrap_or(0);
+        self.last_sample = Some(now);
+
+        let self_pid = unsafe { GetCurrentProcessId() };
+        let samples: Vec<LoadSample> = processes
+            .filter(|process| process.pid() > 4)
+            .map(|process| LoadSample {
+                pid: process.pid(),
+                name: process.get_name(),
+                cpu_time: process.cpu_time(),
+                exempt: process.pid() == self_pid
+                    || is_managed(process.get_name())
+                    || self.engine.settings.exempt.iter().any(|e| e == process.get_name()),
+            })
+            .collect();
+        let cpu_count = get_cpu_set_information().lock().unwrap().len() as u32;
+        let actions = self.engine.evaluate(&samples, elapsed, cpu_count);
+        for action in actions {
+            match action {
+                RestraintAction::Restrain {
+                    pid,
+                    name,
+                    share,
+                    system_busy,
+                } => {
+                    if !self.restrain(pid, &name) {
+                        continue;
+                    }
+                    self.engine.confirm_restrained(pid);
+                    self.log(&format!(
+                        "restrain {:>5}-{} (cpu {:.1}%, system {:.1}%) -> {}",
+                        pid,
+                        name,
+                        share,
+                        system_busy,
+                        self.describe_target()
+                    ));
+                }
+                RestraintAction::Release { pid, name, share } => {
+                    self.restore(pid, &name);
+                    self.log(&format!("release {:>5}-{} (cpu {:.1}%)", pid, name, share));
+                }
+                RestraintAction::Exited { pid, name } => {
+                    self.originals.remove(&pid);
+                    self.log(&format!("exited while restrained {:>5}-{}", pid, name));
+                }
+            }
+        }
+    }
+
+    /// Restores every restrained process, used on shutdown and when the settings change.
+    pub fn release_all(&mut self, reason: &str) {
+        for (pid, name) in self.engine.restrained() {
+            self.restore(pid, &name);
+            self.log(&format!("release {:>5}-{} ({})", pid, name, reason));
+        }
+        self.originals.clear();
+    }
+
+    fn describe_target(&self) -> String {
+        let settings = &self.engine.settings;
+        let mut parts: Vec<String> = Vec::new();
+        if settings.priority != ProcessPriority::None {
+            parts.push(format!("priority {}", settings.priority.as_str()));
+        }
+        if !settings.cpus.is_empty() {
+            parts.push(format!("cpuset [{}]", format_cpu_indices(&settings.cpus)));
+        }
+        parts.join(", ")
+    }
+
+    fn log(&self, msg: &str) {
+        if self.engine.settings.log {
+            log_to_restraint(msg);
+        }
+    }
+
+    /// Applies the restraint settings, false when nothing could be applied so the engine asks again next sample.
+    fn restrain(&mut self, pid: u32, name: &str) -> bool {
+        let Some(process_handle) = get_process_handle(pid, name) else {
+            return false;
+        };
+        let r_handle = process_handle.r_handle.unwrap_or(process_handle.r_limited_handle);
+        let w_handle = process_handle.w_handle.unwrap_or(process_handle.w_limited_handle);
+        let settings = &self.engine.settings;
+        let mut original = OriginalSettings {
+            priority_class: None,
+            cpu_set_ids: None,
+        };
+        let mut failed = false;
+
+        if let Some(priority_flag) = settings.priority.as_win_const() {
+            let current_priority = unsafe { GetPriorityClass(r_handle) };
+            if current_priority != 0 && current_priority != priority_flag.0 {
+                if unsafe { SetPriorityClass(w_handle, priority_flag) }.is_ok() {
+                    original.priority_class = Some(current_priority);
+                } else {
+                    failed = true;
+                    self.log_error(pid, name, Operation::SetPriorityClass, "SET_PRIORITY");
+                }
+            }
+        }
+
+        if !settings.cpus.is_empty() {
+            let mut required_count: u32 = 0;
+            let _ = unsafe { GetProcessDefaultCpuSets(r_handle, None, &mut required_count) };
+            let mut current_ids: List<[u32; CONSUMER_CPUS]> = list![0u32; required_count as usize];
+            if required_count == 0 || unsafe { GetProcessDefaultCpuSets(r_handle, Some(&mut current_ids[..]), &mut required_count) }.as_bool()
+            {
+                current_ids.truncate(required_count as usize);
+                let target_ids = cpusetids_from_indices(&settings.cpus);
+                if unsafe { SetProcessDefaultCpuSets(w_handle, Some(&target_ids)) }.as_bool() {
+                    original.cpu_set_ids = Some(current_ids);
+                } else {
+                    failed = true;
+                    self.log_error(pid, name, Operation::SetProcessDefaultCpuSets, "SET_CPUSET");
+                }
+            } else {
+                failed = true;
+                self.log_error(pid, name, Operation::GetProcessDefaultCpuSets, "QUERY_CPUSET");
+            }
+        }
+        // a partly applied restraint still counts, release restores what was changed
+        let applied = !failed || original.priority_class.is_some() || original.cpu_set_ids.is_some();
+        if applied {
+            self.originals.insert(pid, original);
+        }
+        applied
+    }
+
+    fn restore(&mut self, pid: u32, name: &str) {
+        let Some(original) = self.originals.remove(&pid) else {
+            return;
+        };
+        let Some(process_handle) = get_process_handle(pid, name) else {
+            return;
+        };
+        let w_handle = process_handle.w_handle.unwrap_or(process_handle.w_limited_handle);
+        if let Some(priority_class) = original.priority_class
+            && unsafe { SetPriorityClass(w_handle, PROCESS_CREATION_FLAGS(priority_class)) }.is_err()
+        {
+            self.log_error(pid, name, Operation::SetPriorityClass, "RESTORE_PRIORITY");
+        }
+        if let Some(cpu_set_ids) = original.cpu_set_ids {
+            // an empty list clears the process default CPU set again
+            let ids = if cpu_set_ids.is_empty() { None } else { Some(&cpu_set_ids[..]) };
+            if !unsafe { SetProcessDefaultCpuSets(w_handle, ids) }.as_bool() {
+                self.log_error(pid, name, Operation::SetProcessDefaultCpuSets, "RESTORE_CPUSET");
+            } else if self.engine.settings.log && !cpu_set_ids.is_empty() {
+                log_to_restraint(&format!(
+                    "restore {:>5}-{} cpuset [{}]",
+                    pid,
+                    name,
+                    format_cpu_indices(&indices_from_cpusetids(&cpu_set_ids))
+                ));
+            }
+        }
+    }
+
+    fn log_error(&self, pid: u32, name: &str, operation: Operation, tag: &str) {
+        let error_code = unsafe { GetLastError().0 };
+        if is_new_error(pid, 0, name, operation, error_code) {
+            log_to_restraint(&format!(
+                "restraint: [{}][{}] {:>5}-{}",
+                tag,
+                error_from_code_win32(error_code),
+                pid,
+                name
+            ));
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// One logical CPU and 100 time units per sample, so a CPU time delta is its share in percent.
+    const ELAPSED: i64 = 100;
+
+    /// Drives an engine with per-sample CPU time deltas instead of cumulative times.
+    ///
+    /// The first sample of a pid only sets its baseline, so every test starts with an idle sample.
+    struct Load {
+        engine: RestraintEngine,
+        cpu_times: HashMap<u32, i64>,
+    }
+
+    impl Load {
+        fn new(settings: RestraintSettings) -> Self {
+            Load {
+                engine: RestraintEngine::new(settings),
+                cpu_times: HashMap::default(),
+            }
+        }
+
+        /// Feeds `(pid, share, exempt)` for every running process, confirming every restrain.
+        fn sample(&mut self, loads: &[(u32, i64, bool)]) -> Vec<RestraintAction> {
+            let actions = self.sample_unconfirmed(loads);
+            for action in &actions {
+                if let RestraintAction::Restrain { pid, .. } = action {
+                    self.engine.confirm_restrained(*pid);
+                }
+            }
+            actions
+        }
+
+        fn sample_unconfirmed(&mut self, loads: &[(u32, i64, bool)]) -> Vec<RestraintAction> {
+            let samples: Vec<LoadSample> = loads
+                .iter()
+                .map(|&(pid, share, exempt)| {
+                    let cpu_time = self.cpu_times.entry(pid).or_insert(0);
+                    *cpu_time += share;
+                    LoadSample {
+                        pid,
+                        name: "hog.exe",
+                        cpu_time: *cpu_time,
+                        exempt,
+                    }
+                })
+                .collect();
+            self.engine.evaluate(&samples, ELAPSED, 1).into_iter().collect()
+        }
+
+        fn is_restrained(&self, pid: u32) -> bool {
+            self.engine.restrained().iter().any(|(restrained, _)| *restrained == pid)
+        }
+    }
+
+    fn settings() -> RestraintSettings {
+        RestraintSettings {
+            cpu_share: 50.0,
+            ..Default::default()
+        }
+    }
+
+    fn restrains(actions: &[RestraintAction], pid: u32) -> bool {
+        actions
+            .iter()
+            .any(|action| matches!(action, RestraintAction::Restrain { pid: p, .. } if *p == pid))
+    }
+
+    fn releases(actions: &[RestraintAction], pid: u32) -> bool {
+        actions
+            .iter()
+            .any(|action| matches!(action, RestraintAction::Release { pid: p, .. } if *p == pid))
+    }
+
+    #[test]
+    fn restrains_after_sustain_and_releases_after_calm() {
+        let mut load = Load::new(settings());
+        load.sample(&[(10, 0, false)]);
+        for _ in 0..2 {
+            assert!(load.sample(&[(10, 80, false)]).is_empty());
+        }
+        assert!(restrains(&load.sample(&[(10, 80, false)]), 10));
+        assert!(load.is_restrained(10));
+        for _ in 0..4 {
+            assert!(load.sample(&[(10, 10, false)]).is_empty());
+        }
+        assert!(releases(&load.sample(&[(10, 10, false)]), 10));
+        assert!(!load.is_restrained(10));
+    }
+
+    #[test]
+    fn shares_between_release_and_restrain_thresholds_keep_the_state() {
+        let mut load = Load::new(settings());
+        load.sample(&[(10, 0, false), (20, 0, false)]);
+        for _ in 0..3 {
+            load.sample(&[(10, 80, false), (20, 0, false)]);
+        }
+        assert!(load.is_restrained(10));
+        // 30% is below the restrain share but above the release share (half of it)
+        for _ in 0..10 {
+            assert!(load.sample(&[(10, 30, false), (20, 0, false)]).is_empty());
+        }
+        assert!(load.is_restrained(10));
+        // a hot sample restarts the calm streak
+        for _ in 0..4 {
+            load.sample(&[(10, 10, false), (20, 0, false)]);
+        }
+        load.sample(&[(10, 60, false), (20, 0, false)]);
+        for _ in 0..4 {
+            assert!(load.sample(&[(10, 10, false), (20, 0, false)]).is_empty());
+        }
+        assert!(releases(&load.sample(&[(10, 10, false), (20, 0, false)]), 10));
+        // bursts shorter than the sustain count never restrain
+        for _ in 0..10 {
+            assert!(load.sample(&[(20, 80, false)]).is_empty());
+            assert!(load.sample(&[(20, 80, false)]).is_empty());
+            assert!(load.sample(&[(20, 10, false)]).is_empty());
+        }
+    }
+
+    #[test]
+    fn exempt_processes_are_never_restrained() {
+        let mut load = Load::new(settings());
+        load.sample(&[(10, 0, true)]);
+        for _ in 0..10 {
+            assert!(load.sample(&[(10, 90, true)]).is_empty());
+        }
+        assert!(!load.is_restrained(10));
+    }
+
+    #[test]
+    fn nothing_is_restrained_below_the_busy_threshold() {
+        let mut load = Load::new(RestraintSettings {
+            cpu_share: 30.0,
+            ..Default::default()
+        });
+        load.sample(&[(10, 0, false), (20, 0, false)]);
+        // 40% of the CPU is a hog, but the system is only 45% busy
+        for _ in 0..10 {
+            assert!(load.sample(&[(10, 40, false), (20, 5, false)]).is_empty());
+        }
+        // once the system passes 50% it is restrained after the sustain count
+        assert!(load.sample(&[(10, 40, false), (20, 20, false)]).is_empty());
+        assert!(load.sample(&[(10, 40, false), (20, 20, false)]).is_empty());
+        assert!(restrains(&load.sample(&[(10, 40, false), (20, 20, false)]), 10));
+    }
+
+    #[test]
+    fn unconfirmed_restraints_are_asked_again_and_never_released() {
+        let mut load = Load::new(settings());
+        load.sample(&[(10, 0, false)]);
+        for _ in 0..2 {
+            load.sample_unconfirmed(&[(10, 80, false)]);
+        }
+        assert!(restrains(&load.sample_unconfirmed(&[(10, 80, false)]), 10));
+        assert!(!load.is_restrained(10));
+        assert!(restrains(&load.sample_unconfirmed(&[(10, 80, false)]), 10));
+        for _ in 0..10 {
+            assert!(load.sample_unconfirmed(&[(10, 0, false)]).is_empty());
+        }
+        assert!(load.sample_unconfirmed(&[]).is_empty());
+    }
+}
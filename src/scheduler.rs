@@ -1,8 +1,8 @@
 use crate::{
     collections::{CONSUMER_CPUS, HashMap, List, TIDS_CAPED},
-    config::ConfigConstants,
+    config::{ConfigConstants, PrimePinMode, ThreadQuota},
     logging::log_message,
-    priority::ThreadPriority,
+    priority::{PowerThrottling, ThreadPriority},
     winapi::{ThreadHandle, drop_module_cache, resolve_address_to_module},
 };
 
@@ -24,12 +24,33 @@ impl PrimeThreadScheduler {
         }
     }
 
+    /// Swaps in reloaded constants.
+    ///
+    /// Active streaks were counted against the old thresholds, so every thread starts counting again and
+    /// new promotions wait for `min_active_streak` samples under the new ones. Prime threads keep their pins
+    /// and are judged by the new keep threshold on the next selection.
+    pub fn set_constants(&mut self, constants: &ConfigConstants) {
+        if &self.constants == constants {
+            return;
+        }
+        self.constants = constants.clone();
+        for process_stats in self.pid_to_process_stats.values_mut() {
+            for thread_stats in process_stats.tid_to_thread_stats.values_mut() {
+                thread_stats.active_streak = 0;
+            }
+        }
+    }
+
     pub fn reset_alive(&mut self) {
         self.pid_to_process_stats.values_mut().for_each(|stats| stats.alive = false);
     }
 
-    pub fn set_alive(&mut self, pid: u32) {
-        self.pid_to_process_stats.entry(pid).or_insert(ProcessStats::new(pid)).alive = true;
+    pub fn set_alive(&mut self, pid: u32, process_name: &str) {
+        let stats = self.pid_to_process_stats.entry(pid).or_insert(ProcessStats::new(pid));
+        stats.alive = true;
+        if stats.process_name.is_empty() {
+            stats.process_name = process_name.to_string();
+        }
     }
 
     pub fn set_tracking_info(&mut self, pid: u32, track_top_x_threads: i32, process_name: String) {
@@ -94,19 +115,37 @@ impl PrimeThreadScheduler {
         is_currently_assigned: fn(&ThreadStats) -> bool,
     ) {
         tid_with_delta_cycles.sort_unstable_by_key(|&(_, delta, _)| Reverse(delta));
+        self.hysteresis_passes(pid, tid_with_delta_cycles, slot_count, 0, is_currently_assigned, |_| true);
+    }
+
+    /// Keep and entry passes of the hysteresis selection, on threads sorted busiest first.
+    ///
+    /// Threads already marked prime keep their slot, counted in `slots_used`. `admit` is asked once a thread
+    /// qualifies and may refuse it, e.g. when its quota is full; a thread it accepts takes a slot.
+    fn hysteresis_passes(
+        &mut self,
+        pid: u32,
+        tid_with_delta_cycles: &mut [(u32, u64, bool)],
+        slot_count: usize,
+        mut slots_used: usize,
+        is_currently_assigned: fn(&ThreadStats) -> bool,
+        mut admit: impl FnMut(u32) -> bool,
+    ) {
         let max_cycles = tid_with_delta_cycles.first().map(|&(_, c, _)| c).unwrap_or(0u64);
         let entry_min = (max_cycles as f64 * self.constants.entry_threshold) as u64;
         let keep_min = (max_cycles as f64 * self.constants.keep_threshold) as u64;
-        let mut slots_used = 0usize;
 
         // First pass: retain currently-assigned threads that still qualify
         // This prevents threads from losing prime status due to minor fluctuations
         for (tid, delta, is_prime) in tid_with_delta_cycles.iter_mut() {
             if slots_used >= slot_count {
+                break;
+            }
+            if *is_prime {
                 continue;
             }
             // Keep threshold is higher than entry threshold (hysteresis)
-            if is_currently_assigned(self.get_thread_stats(pid, *tid)) && *delta >= keep_min {
+            if is_currently_assigned(self.get_thread_stats(pid, *tid)) && *delta >= keep_min && admit(*tid) {
                 *is_prime = true;
                 slots_used += 1;
             }
@@ -121,13 +160,84 @@ impl PrimeThreadScheduler {
             if *tid == 0 || *is_prime {
                 continue;
             }
-            if *delta >= entry_min && self.get_thread_stats(pid, *tid).active_streak >= self.constants.min_active_streak {
+            if *delta >= entry_min && self.get_thread_stats(pid, *tid).active_streak >= self.constants.min_active_streak && admit(*tid) {
                 *is_prime = true;
                 slots_used += 1;
             }
         }
     }
 
+    /// Selects top threads like [`Self::select_top_threads_with_hysteresis`], honoring per-group quotas.
+    ///
+    /// `group_of` maps each eligible thread to an index into `quotas`, threads missing from it are never selected.
+    /// - Reserve pass: each group gets up to `min` of its own threads, with thresholds measured against the group's
+    ///   busiest thread so a burst in another group cannot starve it
+    /// - Keep and entry passes: as in the plain hysteresis selection, skipping groups that reached `max`
+    pub fn select_top_threads_with_quotas(
+        &mut self,
+        pid: u32,
+        tid_with_delta_cycles: &mut [(u32, u64, bool)],
+        slot_count: usize,
+        is_currently_assigned: fn(&ThreadStats) -> bool,
+        group_of: &HashMap<u32, usize>,
+        quotas: &[ThreadQuota],
+    ) {
+        tid_with_delta_cycles.sort_unstable_by_key(|&(_, delta, _)| Reverse(delta));
+        let entry_threshold = self.constants.entry_threshold;
+        let keep_threshold = self.constants.keep_threshold;
+        let min_active_streak = self.constants.min_active_streak;
+        let mut group_counts: List<[usize; CONSUMER_CPUS]> = quotas.iter().map(|_| 0).collect();
+        let mut slots_used = 0usize;
+
+        // Reserve pass: guarantee each group its minimum, relative to the group's own busiest thread
+        for (group, quota) in quotas.iter().enumerate() {
+            if quota.min == 0 {
+                continue;
+            }
+            let group_max = tid_with_delta_cycles
+                .iter()
+                .filter(|(tid, _, _)| group_of.get(tid) == Some(&group))
+                .map(|&(_, delta, _)| delta)
+                .max()
+                .unwrap_or(0);
+            for (tid, delta, is_prime) in tid_with_delta_cycles.iter_mut() {
+                if slots_used >= slot_count || group_counts[group] >= quota.min {
+                    break;
+                }
+                if *tid == 0 || *is_prime || group_of.get(tid) != Some(&group) {
+                    continue;
+                }
+                let thread_stats = self.get_thread_stats(pid, *tid);
+                let qualifies = if is_currently_assigned(thread_stats) {
+                    *delta >= (group_max as f64 * keep_threshold) as u64
+                } else {
+                    *delta >= (group_max as f64 * entry_threshold) as u64 && thread_stats.active_streak >= min_active_streak
+                };
+                if qualifies && *delta > 0 {
+                    *is_prime = true;
+                    group_counts[group] += 1;
+                    slots_used += 1;
+                }
+            }
+        }
+
+        // Keep and entry passes, skipping groups that reached their max
+        self.hysteresis_passes(
+            pid,
+            tid_with_delta_cycles,
+            slot_count,
+            slots_used,
+            is_currently_assigned,
+            |tid| match group_of.get(&tid) {
+                Some(&group) if group_counts[group] < quotas[group].max => {
+                    group_counts[group] += 1;
+                    true
+                }
+                _ => false,
+            },
+        );
+    }
+
     /// Closes thread handles, clears module cache, and optionally logs
     /// top N threads by cycles for debugging/analysis purposes.
     pub fn drop_process_by_pid(&mut self, pid: &u32) {
@@ -244,6 +354,9 @@ impl Default for IdealProcessorState {
     }
 }
 
+/// Times an unnamed thread's description is queried before it is taken as unnamed for good.
+pub const DESCRIPTION_QUERIES: u8 = 3;
+
 pub struct ThreadStats {
     pub last_total_time: i64,
 
@@ -258,13 +371,32 @@ pub struct ThreadStats {
     /// Check is_valid_handle() before using other handles.
     pub handle: Option<ThreadHandle>,
 
+    /// CPUs the thread was promoted to, non-empty while the thread is prime regardless of pin mode.
     pub pinned_cpu_set_ids: List<[u32; CONSUMER_CPUS]>,
 
+    /// How the thread was pinned, so demotion restores the right attribute even after a config reload.
+    pub pin_mode: PrimePinMode,
+
+    /// Thread affinity mask before an `affinity` mode promotion.
+    pub original_affinity_mask: Option<usize>,
+
+    /// Ideal processor (group, number) before an `ideal` mode promotion.
+    pub original_ideal_processor: Option<(u16, u8)>,
+
     pub active_streak: u8,
 
     pub start_address: usize,
 
+    /// Thread description from `SetThreadDescription`, queried only for rules with `~name` selectors.
+    pub description: String,
+
+    /// Empty description results so far, unnamed threads stop being queried after `DESCRIPTION_QUERIES`.
+    pub description_misses: u8,
+
     pub original_priority: Option<ThreadPriority>,
+
+    /// Thread power throttling last applied by `apply_thread_power_throttling`, `None` = untouched.
+    pub power_throttling: PowerThrottling,
     pub last_system_thread_info: Option<SYSTEM_THREAD_INFORMATION>,
 
     pub ideal_processor: IdealProcessorState,
@@ -279,8 +411,10 @@ impl fmt::Debug for ThreadStats {
             .field("last_cycles", &self.last_cycles)
             .field("cached_cycles", &self.cached_cycles)
             .field("pinned_cpu_set_ids", &self.pinned_cpu_set_ids)
+            .field("pin_mode", &self.pin_mode)
             .field("active_streak", &self.active_streak)
             .field("start_address", &resolve_address_to_module(self.process_id, self.start_address))
+            .field("description", &self.description)
             .field("original_priority", &self.original_priority)
             .field("ideal_processor", &self.ideal_processor)
             .finish()
@@ -296,9 +430,15 @@ impl ThreadStats {
             cached_cycles: 0,
             handle: None,
             pinned_cpu_set_ids: List::new(),
+            pin_mode: PrimePinMode::CpuSet,
+            original_affinity_mask: None,
+            original_ideal_processor: None,
             active_streak: 0,
             start_address: 0,
+            description: String::new(),
+            description_misses: 0,
             original_priority: None,
+            power_throttling: PowerThrottling::None,
             last_system_thread_info: None,
             ideal_processor: IdealProcessorState::new(),
             process_id,
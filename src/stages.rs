@@ -0,0 +1,138 @@
+//! Delayed and staged application of rules with `delay`, `stages` or `on_module` options.
+//!
+//! Some programs reset their own priority or affinity shortly after launch. Instead of relying on
+//! `-continuous_process_level_apply`, every staged process is tracked by PID: the rule is held back until
+//! its first stage is due, and each later stage (or the first load of a listed module) removes the PID
+//! from `process_level_applied` and queues it again so the rule is re-applied in the same loop.
+
+use crate::{
+    collections::{HashMap, List, PENDING, PIDS},
+    config::{ApplyStages, ConfigResult, format_stage_time},
+    log,
+    process::ProcessEntry,
+    winapi::get_process_module_names,
+};
+
+use std::time::{SystemTime, UNIX_EPOCH};
+
+/// Seconds between 1601-01-01 (FILETIME epoch) and 1970-01-01.
+const FILETIME_UNIX_OFFSET_SECS: i64 = 11_644_473_600;
+
+/// Time after process start after which modules from `on_module` that never loaded are no longer waited for.
+const MODULE_WAIT_MS: u32 = 5 * 60 * 1000;
+
+#[derive(Debug)]
+struct StagedProcess {
+    name: String,
+    /// Process creation time, FILETIME units.
+    started: i64,
+    /// Index of the next stage in `ApplyStages::times_ms`, 0 while the first apply is still held back.
+    next_stage: usize,
+    stage_count: usize,
+    /// Modules from `on_module` that have not been seen loaded yet.
+    waiting_modules: Vec<String>,
+}
+
+#[derive(Debug, Default)]
+pub struct StageTracker {
+    processes: HashMap<u32, StagedProcess>,
+}
+
+impl StageTracker {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Whether the rule of `pid` is still held back by its first stage.
+    pub fn is_waiting(&self, pid: u32) -> bool {
+        self.processes.get(&pid).is_some_and(|process| process.next_stage == 0)
+    }
+
+    /// Whether any tracked process still has a stage or module ahead, which needs polling between events.
+    pub fn has_pending_stages(&self) -> bool {
+        self.processes
+            .values()
+            .any(|process| process.next_stage < process.stage_count || !process.waiting_modules.is_empty())
+    }
+
+    /// Starts tracking new staged processes and queues those with a stage due or a module newly loaded.
+    pub fn update(
+        &mut self,
+        pids_and_names: &[(u32, &str)],
+        pid_to_process: &HashMap<u32, ProcessEntry>,
+        configs: &ConfigResult,
+        process_level_applied: &mut List<[u32; PIDS]>,
+        process_level_pending: &mut List<[u32; PENDING]>,
+    ) {
+        // a pid that is gone or reused by another process starts over
+        self.processes
+            .retain(|pid, process| pids_and_names.iter().any(|(p, name)| p == pid && *name == process.name));
+
+        let now = filetime_now();
+        for (pid, name) in pids_and_names {
+            let Some(stages) = find_stages(configs, name) else {
+                // a reload may have dropped the stages, the rule then applies right away
+                self.processes.remove(pid);
+                continue;
+            };
+            let Some(process) = pid_to_process.get(pid) else {
+                continue;
+            };
+            let staged = self.processes.entry(*pid).or_insert_with(|| StagedProcess {
+                name: name.to_string(),
+                started: process.create_time(),
+                next_stage: 0,
+                stage_count: stages.times_ms.len(),
+                waiting_modules: stages.modules.clone(),
+            });
+            staged.stage_count = stages.times_ms.len();
+            let elapsed_ms = ((now - staged.started) / 10_000).max(0);
+
+            let mut reason: Option<String> = None;
+            // stages already passed when the process is first seen collapse into one apply
+            while staged.next_stage < stages.times_ms.len() && elapsed_ms >= stages.times_ms[staged.next_stage] as i64 {
+                reason = Some(format!("+{}", format_stage_time(stages.times_ms[staged.next_stage])));
+                staged.next_stage += 1;
+            }
+            if staged.next_stage > 0 && !staged.waiting_modules.is_empty() {
+                let loaded = get_process_module_names(*pid);
+                let (seen, waiting): (Vec<String>, Vec<String>) = staged.waiting_modules.drain(..).partition(|module| loaded.contains(module));
+                staged.waiting_modules = waiting;
+                if !seen.is_empty() {
+                    reason = Some(format!("{} loaded", seen.join(", ")));
+                }
+                if !staged.waiting_modules.is_empty() && elapsed_ms >= MODULE_WAIT_MS as i64 {
+                    log!(
+                        "{:>5}::{}::Stage: {} not loaded after {}, no longer waiting",
+                        pid,
+                        name,
+                        staged.waiting_modules.join(", "),
+                        format_stage_time(MODULE_WAIT_MS)
+                    );
+                    staged.waiting_modules.clear();
+                }
+            }
+
+            if let Some(reason) = reason {
+                process_level_applied.retain(|applied| applied != pid);
+                if !process_level_pending.contains(pid) {
+                    process_level_pending.push(*pid);
+                }
+                log!("{:>5}::{}::Stage: {} -> apply ({})", pid, name, reason, stages.describe());
+            }
+        }
+    }
+}
+
+fn find_stages<'a>(configs: &'a ConfigResult, name: &str) -> Option<&'a ApplyStages> {
+    configs
+        .process_level_configs
+        .values()
+        .find_map(|graded| graded.get(name))
+        .and_then(|config| config.stages.as_ref())
+}
+
+fn filetime_now() -> i64 {
+    let since_unix = SystemTime::now().duration_since(UNIX_EPOCH).unwrap_or_default();
+    (since_unix.as_secs() as i64 + FILETIME_UNIX_OFFSET_SECS) * 10_000_000 + since_unix.subsec_nanos() as i64 / 100
+}
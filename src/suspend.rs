@@ -0,0 +1,140 @@
+//! Suspension of processes while a trigger process is running, from `suspend_while` rules.
+//!
+//! Every loop the controller compares the running processes against the rules: a matching process is
+//! suspended (NtSuspendProcess) as soon as one of its triggers runs and resumed once none does. Only
+//! processes suspended by the controller are ever resumed, and all of them are resumed on shutdown.
+
+use crate::{
+    blacklist::Blacklist,
+    collections::{HashMap, HashSet, List, PENDING},
+    config::ConfigResult,
+    error_codes::error_name,
+    event_trace::EtwProcessEvent,
+    log,
+    logging::{Operation, is_new_error, log_to_find},
+    winapi::set_process_suspended,
+};
+
+#[derive(Debug)]
+struct Suspension {
+    name: String,
+    trigger: String,
+    origin: String,
+}
+
+#[derive(Debug, Default)]
+pub struct SuspendController {
+    suspended: HashMap<u32, Suspension>,
+    /// Running trigger processes, their exit should be handled without waiting for the next interval.
+    trigger_pids: HashSet<u32>,
+}
+
+impl SuspendController {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Whether an ETW event concerns a trigger or a suspended process.
+    pub fn is_tracked(&self, event: &EtwProcessEvent) -> bool {
+        self.trigger_pids.contains(&event.pid) || self.suspended.contains_key(&event.pid)
+    }
+
+    /// Suspends processes whose triggers are running and resumes those whose triggers all exited.
+    ///
+    /// Blacklisted processes are never suspended.
+    pub fn update(&mut self, pids_and_names: &[(u32, &str)], configs: &ConfigResult, blacklist: &Blacklist, dry_run: bool) {
+        let running: HashSet<&str> = pids_and_names.iter().map(|(_, name)| *name).collect();
+        let mut trigger_names: HashSet<&str> = HashSet::default();
+        let mut wanted: HashMap<u32, (&str, &str, &str)> = HashMap::default();
+        for graded_configs in configs.process_level_configs.values() {
+            for (pid, name) in pids_and_names {
+                let Some(config) = graded_configs.get(*name) else {
+                    continue;
+                };
+                if config.suspend_while.is_empty() {
+                    continue;
+                }
+                trigger_names.extend(config.suspend_while.iter().map(String::as_str));
+                if blacklist.contains(&config.name) {
+                    continue;
+                }
+                if let Some(trigger) = config.suspend_while.iter().find(|trigger| running.contains(trigger.as_str())) {
+                    wanted.insert(*pid, (config.name.as_str(), trigger.as_str(), config.origin.as_str()));
+                }
+            }
+        }
+        self.trigger_pids = pids_and_names
+            .iter()
+            .filter(|(_, name)| trigger_names.contains(name))
+            .map(|(pid, _)| *pid)
+            .collect();
+
+        let released: List<[u32; PENDING]> = self.suspended.keys().filter(|pid| !wanted.contains_key(pid)).copied().collect();
+        for pid in released {
+            let Some(suspension) = self.suspended.remove(&pid) else {
+                continue;
+            };
+            // a pid that is gone or reused by another process has nothing left to resume
+            if pids_and_names.iter().any(|(p, name)| *p == pid && *name == suspension.name) {
+                self.resume(pid, &suspension, "trigger exited");
+            }
+        }
+
+        for (pid, (name, trigger, origin)) in wanted {
+            if self.suspended.contains_key(&pid) {
+                continue;
+            }
+            if dry_run {
+                log!("{:>5}::{}::Suspend: -> while {} is running ({})", pid, name, trigger, origin);
+                continue;
+            }
+            match set_process_suspended(pid, true) {
+                Ok(()) => {
+                    log!("{:>5}::{}::Suspend: -> while {} is running ({})", pid, name, trigger, origin);
+                    self.suspended.insert(
+                        pid,
+                        Suspension {
+                            name: name.to_string(),
+                            trigger: trigger.to_string(),
+                            origin: origin.to_string(),
+                        },
+                    );
+                }
+                Err((operation, error_code)) => log_error(pid, name, operation, error_code),
+            }
+        }
+    }
+
+    /// Resumes every process suspended by the controller, used on shutdown.
+    pub fn resume_all(&mut self) {
+        for (pid, suspension) in std::mem::take(&mut self.suspended) {
+            self.resume(pid, &suspension, "shutdown");
+        }
+    }
+
+    fn resume(&self, pid: u32, suspension: &Suspension, reason: &str) {
+        match set_process_suspended(pid, false) {
+            Ok(()) => log!(
+                "{:>5}::{}::Resume: {} ({}, suspended while {})",
+                pid,
+                suspension.name,
+                reason,
+                suspension.origin,
+                suspension.trigger
+            ),
+            Err((operation, error_code)) => log_error(pid, &suspension.name, operation, error_code),
+        }
+    }
+}
+
+fn log_error(pid: u32, name: &str, operation: Operation, error_code: u32) {
+    let tag = match operation {
+        Operation::OpenProcess2processSuspendResume => "OPEN_PROCESS_SUSPEND_RESUME",
+        Operation::NtSuspendProcess => "SUSPEND",
+        _ => "RESUME",
+    };
+    let message = error_name(operation.error_source(error_code), error_code);
+    if is_new_error(pid, 0, name, operation, error_code) {
+        log_to_find(&format!("suspend_while: [{}][{}] {:>5}-{}", tag, message, pid, name));
+    }
+}
@@ -1,7 +1,8 @@
 use crate::{
     cli::CliArgs,
-    collections::{CONSUMER_CPUS, HashMap, List},
-    error_codes::error_from_code_win32,
+    collections::{CONSUMER_CPUS, HashMap, HashSet, List},
+    config::JobLimits,
+    error_codes::{ErrorKind, ErrorSource, error_from_code_win32},
     get_fail_find_set, log,
     logging::{Operation, is_new_error, log_to_find},
 };
@@ -10,26 +11,33 @@ use once_cell::sync::Lazy;
 use std::{env, ffi::c_void, io, mem::size_of, process::Command, process::exit, sync::Mutex};
 use windows::{
     Win32::{
-        Foundation::{CloseHandle, GetLastError, HANDLE, HMODULE, LUID, NTSTATUS},
+        Foundation::{CloseHandle, GetLastError, HANDLE, HLOCAL, HMODULE, LUID, LocalFree, NTSTATUS},
         Security::{
             AdjustTokenPrivileges, GetTokenInformation, LUID_AND_ATTRIBUTES, LookupPrivilegeValueW, SE_DEBUG_NAME, SE_INC_BASE_PRIORITY_NAME,
             SE_PRIVILEGE_ENABLED, TOKEN_ADJUST_PRIVILEGES, TOKEN_ELEVATION, TOKEN_PRIVILEGES, TOKEN_QUERY, TokenElevation,
         },
         System::{
             Diagnostics::ToolHelp::{CreateToolhelp32Snapshot, PROCESSENTRY32W, Process32FirstW, Process32NextW, TH32CS_SNAPPROCESS},
+            JobObjects::{
+                AssignProcessToJobObject, CreateJobObjectW, IsProcessInJob, JOB_OBJECT_CPU_RATE_CONTROL_ENABLE,
+                JOB_OBJECT_CPU_RATE_CONTROL_HARD_CAP, JOB_OBJECT_LIMIT_ACTIVE_PROCESS, JOB_OBJECT_LIMIT_JOB_MEMORY,
+                JOB_OBJECT_LIMIT_WORKINGSET, JOBOBJECT_CPU_RATE_CONTROL_INFORMATION, JOBOBJECT_EXTENDED_LIMIT_INFORMATION,
+                JobObjectCpuRateControlInformation, JobObjectExtendedLimitInformation, SetInformationJobObject,
+            },
             Kernel::PROCESSOR_NUMBER,
             ProcessStatus::{EnumProcessModulesEx, GetModuleBaseNameW, GetModuleInformation, LIST_MODULES_ALL, MODULEINFO},
             SystemInformation::{GetSystemCpuSetInformation, SYSTEM_CPU_SET_INFORMATION},
             Threading::{
-                GetCurrentProcess, GetCurrentProcessId, GetProcessAffinityMask, GetThreadIdealProcessorEx, OpenProcess, OpenProcessToken,
-                OpenThread, PROCESS_QUERY_INFORMATION, PROCESS_QUERY_LIMITED_INFORMATION, PROCESS_SET_INFORMATION,
-                PROCESS_SET_LIMITED_INFORMATION, PROCESS_TERMINATE, PROCESS_VM_READ, SetThreadIdealProcessorEx, THREAD_ACCESS_RIGHTS,
-                THREAD_QUERY_INFORMATION, THREAD_QUERY_LIMITED_INFORMATION, THREAD_SET_INFORMATION, THREAD_SET_LIMITED_INFORMATION,
-                TerminateProcess,
+                GetCurrentProcess, GetCurrentProcessId, GetProcessAffinityMask, GetThreadDescription, GetThreadIdealProcessorEx, OpenProcess,
+                OpenProcessToken, OpenThread, PROCESS_QUERY_INFORMATION, PROCESS_QUERY_LIMITED_INFORMATION, PROCESS_SET_INFORMATION,
+                PROCESS_SET_LIMITED_INFORMATION, PROCESS_SET_QUOTA, PROCESS_SUSPEND_RESUME, PROCESS_TERMINATE, PROCESS_VM_READ,
+                SetThreadIdealProcessorEx, THREAD_ACCESS_RIGHTS, THREAD_QUERY_INFORMATION, THREAD_QUERY_LIMITED_INFORMATION,
+                THREAD_SET_INFORMATION, THREAD_SET_LIMITED_INFORMATION, TerminateProcess,
             },
         },
+        UI::WindowsAndMessaging::{GetForegroundWindow, GetWindowThreadProcessId},
     },
-    core::Error,
+    core::{BOOL, Error},
 };
 
 #[link(name = "ntdll")]
@@ -59,12 +67,43 @@ unsafe extern "system" {
     ) -> NTSTATUS;
 
     pub fn NtSetTimerResolution(desired_resolution: u32, set_resolution: bool, p_current_resolution: *mut c_void) -> NTSTATUS;
+
+    pub fn NtSuspendProcess(process_handle: HANDLE) -> NTSTATUS;
+
+    pub fn NtResumeProcess(process_handle: HANDLE) -> NTSTATUS;
 }
 
 #[derive(Clone, Copy)]
 pub struct CpuSetData {
     id: u32,
     logical_processor_index: u8,
+    pub group: u16,
+    /// Shared by SMT siblings of the same physical core.
+    pub core_index: u8,
+    /// Shared by all logical processors behind the same last level cache (CCD/L3 domain).
+    pub last_level_cache_index: u8,
+    /// Higher is faster, all processors share class 0 on machines without efficiency cores.
+    pub efficiency_class: u8,
+}
+
+impl CpuSetData {
+    #[inline]
+    pub fn cpu_index(&self) -> u32 {
+        self.logical_processor_index as u32
+    }
+
+    /// A synthetic entry for topology dependent tests, the id follows the config index.
+    #[cfg(test)]
+    pub fn synthetic(group: u16, number: u8, core_index: u8, last_level_cache_index: u8) -> Self {
+        Self {
+            id: 0x100 + group as u32 * 64 + number as u32,
+            logical_processor_index: number,
+            group,
+            core_index,
+            last_level_cache_index,
+            efficiency_class: 0,
+        }
+    }
 }
 
 /// Make sure all handles are valid when newing this.
@@ -307,6 +346,10 @@ unsafe fn extract_cpu_set_data(entry: &SYSTEM_CPU_SET_INFORMATION) -> CpuSetData
         CpuSetData {
             id: entry.Anonymous.CpuSet.Id,
             logical_processor_index: entry.Anonymous.CpuSet.LogicalProcessorIndex,
+            group: entry.Anonymous.CpuSet.Group,
+            core_index: entry.Anonymous.CpuSet.CoreIndex,
+            last_level_cache_index: entry.Anonymous.CpuSet.LastLevelCacheIndex,
+            efficiency_class: entry.Anonymous.CpuSet.EfficiencyClass,
         }
     }
 }
@@ -427,6 +470,22 @@ pub fn mask_from_cpusetids(cpuids: &[u32]) -> usize {
     mask
 }
 
+/// Maps a CPU index to its processor group and group-relative number.
+///
+/// Indices follow the `0-7;64-71` convention of the config: each processor group spans 64 indices.
+#[inline]
+pub fn processor_number_from_index(cpu_index: u32) -> (u16, u8) {
+    ((cpu_index / 64) as u16, (cpu_index % 64) as u8)
+}
+
+/// The topology entry of a config CPU index, matched by processor group and group-relative number.
+pub fn cpu_set_data_of(topology: &[CpuSetData], cpu_index: u32) -> Option<&CpuSetData> {
+    let (group, number) = processor_number_from_index(cpu_index);
+    topology
+        .iter()
+        .find(|data| data.group == group && data.cpu_index() == number as u32)
+}
+
 /// Filters CPU indices to only those allowed by the affinity mask.
 pub fn filter_indices_by_mask(cpu_indices: &[u32], affinity_mask: usize) -> List<[u32; CONSUMER_CPUS]> {
     cpu_indices
@@ -605,7 +664,7 @@ pub fn is_affinity_unset(pid: u32, process_name: &str) -> bool {
                 pid,
                 process_name
             ));
-            if code == 5 {
+            if ErrorKind::classify(ErrorSource::Win32, code) == ErrorKind::AccessDenied {
                 get_fail_find_set!().insert(process_name.to_string());
             }
             return false;
@@ -632,7 +691,7 @@ pub fn is_affinity_unset(pid: u32, process_name: &str) -> bool {
                 pid,
                 process_name
             ));
-            if code == 5 {
+            if ErrorKind::classify(ErrorSource::Win32, code) == ErrorKind::AccessDenied {
                 get_fail_find_set!().insert(process_name.to_string());
             }
             false
@@ -666,6 +725,20 @@ pub fn get_thread_start_address(thread_handle: HANDLE) -> usize {
     if status.is_ok() { start_address } else { 0 }
 }
 
+/// Gets the thread description set via `SetThreadDescription`, empty when unnamed or on failure.
+pub fn get_thread_description(thread_handle: HANDLE) -> String {
+    match unsafe { GetThreadDescription(thread_handle) } {
+        Ok(description) => {
+            let text = unsafe { description.to_string() }.unwrap_or_default();
+            unsafe {
+                let _ = LocalFree(Some(HLOCAL(description.0 as *mut c_void)));
+            }
+            text
+        }
+        Err(_) => String::new(),
+    }
+}
+
 pub fn set_thread_ideal_processor_ex(thread_handle: HANDLE, group: u16, number: u8) -> Result<PROCESSOR_NUMBER, Error> {
     let ideal = PROCESSOR_NUMBER {
         Group: group,
@@ -718,6 +791,194 @@ pub fn resolve_address_to_module(pid: u32, address: usize) -> String {
     format!("0x{:X}", address)
 }
 
+/// Job objects by [`JobLimits::key`], with the limits last applied to each.
+/// Handles are stored as raw values since `HANDLE` is not `Send`; they stay open for the service lifetime
+/// so the jobs (and their limits) outlive the processes placed in them.
+static JOB_OBJECTS: Lazy<Mutex<HashMap<String, (usize, JobLimits)>>> = Lazy::new(|| Mutex::new(HashMap::default()));
+
+/// Minimum working set Windows gives a process by default (50 pages), used when `working_set` has no minimum.
+const DEFAULT_MIN_WORKING_SET: usize = 50 * 4096;
+
+/// Outcome of [`assign_process_to_job`].
+pub enum JobAssignment {
+    /// The process was already in this rule's job.
+    AlreadyAssigned,
+    /// The process was placed into the job, `nested` when it already belonged to another job.
+    Assigned { nested: bool },
+}
+
+/// Gets or creates the job object for `limits.key`, re-applying the limits when they changed since the last call.
+fn get_job_object(limits: &JobLimits) -> std::result::Result<HANDLE, (Operation, u32)> {
+    let mut jobs = JOB_OBJECTS.lock().unwrap();
+    if let Some((raw_handle, applied_limits)) = jobs.get_mut(&limits.key) {
+        let handle = HANDLE(*raw_handle as *mut c_void);
+        if applied_limits != limits {
+            set_job_limits(handle, limits)?;
+            *applied_limits = limits.clone();
+        }
+        return Ok(handle);
+    }
+    let handle = unsafe { CreateJobObjectW(None, None) }.map_err(|_| (Operation::CreateJobObject, unsafe { GetLastError().0 }))?;
+    if let Err(e) = set_job_limits(handle, limits) {
+        unsafe {
+            let _ = CloseHandle(handle);
+        }
+        return Err(e);
+    }
+    jobs.insert(limits.key.clone(), (handle.0 as usize, limits.clone()));
+    Ok(handle)
+}
+
+fn set_job_limits(job: HANDLE, limits: &JobLimits) -> std::result::Result<(), (Operation, u32)> {
+    let mut extended = JOBOBJECT_EXTENDED_LIMIT_INFORMATION::default();
+    if let Some(working_set) = limits.working_set {
+        extended.BasicLimitInformation.LimitFlags |= JOB_OBJECT_LIMIT_WORKINGSET;
+        // a maximum working set requires a non-zero minimum, the default process minimum unless configured
+        extended.BasicLimitInformation.MinimumWorkingSetSize = limits.min_working_set.unwrap_or(DEFAULT_MIN_WORKING_SET).min(working_set);
+        extended.BasicLimitInformation.MaximumWorkingSetSize = working_set;
+    }
+    if let Some(commit) = limits.commit {
+        extended.BasicLimitInformation.LimitFlags |= JOB_OBJECT_LIMIT_JOB_MEMORY;
+        extended.JobMemoryLimit = commit;
+    }
+    if let Some(active_processes) = limits.active_processes {
+        extended.BasicLimitInformation.LimitFlags |= JOB_OBJECT_LIMIT_ACTIVE_PROCESS;
+        extended.BasicLimitInformation.ActiveProcessLimit = active_processes;
+    }
+    unsafe {
+        SetInformationJobObject(
+            job,
+            JobObjectExtendedLimitInformation,
+            &extended as *const _ as *const c_void,
+            size_of::<JOBOBJECT_EXTENDED_LIMIT_INFORMATION>() as u32,
+        )
+    }
+    .map_err(|_| (Operation::SetInformationJobObject, unsafe { GetLastError().0 }))?;
+
+    // an all-zero control structure turns a previously configured cap off again
+    let mut cpu_rate = JOBOBJECT_CPU_RATE_CONTROL_INFORMATION::default();
+    if let Some(rate) = limits.cpu_rate {
+        cpu_rate.ControlFlags = JOB_OBJECT_CPU_RATE_CONTROL_ENABLE | JOB_OBJECT_CPU_RATE_CONTROL_HARD_CAP;
+        // CpuRate is in 1/100 of a percent
+        cpu_rate.Anonymous.CpuRate = rate * 100;
+    }
+    unsafe {
+        SetInformationJobObject(
+            job,
+            JobObjectCpuRateControlInformation,
+            &cpu_rate as *const _ as *const c_void,
+            size_of::<JOBOBJECT_CPU_RATE_CONTROL_INFORMATION>() as u32,
+        )
+    }
+    .map_err(|_| (Operation::SetInformationJobObject, unsafe { GetLastError().0 }))
+}
+
+/// Lifts the limits of every job whose key is not in `keys`, returning each job tried with its Win32 error code on failure.
+///
+/// Processes cannot leave a job, so the job stays open with its processes; a rule that brings the key back
+/// applies its limits to the same job again.
+pub fn release_jobs_except(keys: &HashSet<String>) -> Vec<(String, Option<u32>)> {
+    let mut jobs = JOB_OBJECTS.lock().unwrap();
+    let mut released = Vec::new();
+    for (key, (raw_handle, applied_limits)) in jobs.iter_mut() {
+        if keys.contains(key) || applied_limits.is_empty() {
+            continue;
+        }
+        let unlimited = JobLimits::unlimited(key.clone());
+        let error_code = set_job_limits(HANDLE(*raw_handle as *mut c_void), &unlimited)
+            .err()
+            .map(|(_, error_code)| error_code);
+        if error_code.is_none() {
+            *applied_limits = unlimited;
+        }
+        released.push((key.clone(), error_code));
+    }
+    released
+}
+
+/// Places a process into the job object of its rule, creating the job on first use.
+///
+/// Processes already in another job are nested into ours (Windows 8+); when the existing job forbids that,
+/// `AssignProcessToJobObject` fails and the error is returned like any other.
+pub fn assign_process_to_job(pid: u32, limits: &JobLimits) -> std::result::Result<JobAssignment, (Operation, u32)> {
+    let process = unsafe {
+        OpenProcess(
+            PROCESS_SET_QUOTA | PROCESS_TERMINATE | PROCESS_QUERY_LIMITED_INFORMATION,
+            false,
+            pid,
+        )
+    }
+    .map_err(|_| (Operation::OpenProcess2processSetQuota, unsafe { GetLastError().0 }))?;
+    let result = get_job_object(limits).and_then(|job| {
+        let mut in_our_job = BOOL(0);
+        let mut in_any_job = BOOL(0);
+        unsafe {
+            let _ = IsProcessInJob(process, Some(job), &mut in_our_job);
+            let _ = IsProcessInJob(process, None, &mut in_any_job);
+        }
+        if in_our_job.as_bool() {
+            return Ok(JobAssignment::AlreadyAssigned);
+        }
+        unsafe { AssignProcessToJobObject(job, process) }.map_err(|_| (Operation::AssignProcessToJobObject, unsafe { GetLastError().0 }))?;
+        Ok(JobAssignment::Assigned {
+            nested: in_any_job.as_bool(),
+        })
+    });
+    unsafe {
+        let _ = CloseHandle(process);
+    }
+    result
+}
+
+/// Terminates a process for an `action=terminate` rule.
+pub fn terminate_process(pid: u32) -> std::result::Result<(), (Operation, u32)> {
+    let process = unsafe { OpenProcess(PROCESS_TERMINATE, false, pid) }
+        .map_err(|_| (Operation::OpenProcess2processTerminate, unsafe { GetLastError().0 }))?;
+    let result = unsafe { TerminateProcess(process, 1) }.map_err(|_| (Operation::TerminateProcess, unsafe { GetLastError().0 }));
+    unsafe {
+        let _ = CloseHandle(process);
+    }
+    result
+}
+
+/// Suspends or resumes every thread of a process for `suspend_while` rules.
+///
+/// NtSuspendProcess nests like SuspendThread, so each suspend must be paired with exactly one resume.
+/// Failures of the Nt calls carry the NTSTATUS as error code.
+pub fn set_process_suspended(pid: u32, suspend: bool) -> std::result::Result<(), (Operation, u32)> {
+    let process = unsafe { OpenProcess(PROCESS_SUSPEND_RESUME, false, pid) }
+        .map_err(|_| (Operation::OpenProcess2processSuspendResume, unsafe { GetLastError().0 }))?;
+    let (status, operation) = if suspend {
+        (unsafe { NtSuspendProcess(process) }, Operation::NtSuspendProcess)
+    } else {
+        (unsafe { NtResumeProcess(process) }, Operation::NtResumeProcess)
+    };
+    unsafe {
+        let _ = CloseHandle(process);
+    }
+    if status.0 < 0 { Err((operation, status.0 as u32)) } else { Ok(()) }
+}
+
+/// PID owning the foreground window, 0 when there is none (locked workstation, secure desktop).
+pub fn get_foreground_pid() -> u32 {
+    let mut pid: u32 = 0;
+    unsafe {
+        let window = GetForegroundWindow();
+        if !window.is_invalid() {
+            GetWindowThreadProcessId(window, Some(&mut pid));
+        }
+    }
+    pid
+}
+
+/// Lowercase names of the modules currently loaded in a process, uncached since they change while it starts up.
+pub fn get_process_module_names(pid: u32) -> Vec<String> {
+    enumerate_process_modules(pid)
+        .into_iter()
+        .map(|(_, _, name)| name.to_lowercase())
+        .collect()
+}
+
 pub fn drop_module_cache(pid: u32) {
     let mut cache = MODULE_CACHE.lock().unwrap();
     cache.remove(&pid);